@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ga_lab::{
+    batch::{run_batch, Data},
+    problem::{ClustersOf4, Problem, RandomStrandBuilder, Strand},
+};
+use genevo::{
+    operator::prelude::*, prelude::*, recombination::discrete::SinglePointCrossBreeder,
+    reinsertion::elitist::ElitistReinserter, selection::truncation::MaximizeSelector,
+};
+
+const STRAND_SIZE: usize = 16;
+const POPULATION_SIZE: usize = 64;
+const GENERATIONS: u64 = 50;
+
+// A small GA run of a fixed number of generations, seeded by its thread number
+fn run(problem: &ClustersOf4, thread_number: u64) -> Option<Data> {
+    let initial_population: Population<Strand<ClustersOf4>> = build_population()
+        .with_genome_builder(RandomStrandBuilder(problem.clone()))
+        .of_size(POPULATION_SIZE)
+        .using_seed([thread_number as u8; 32]);
+    let (min_allele, max_allele) = problem.allele_bounds();
+
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(problem.fitness_function())
+            .with_selection(MaximizeSelector::new(0.7, 2))
+            .with_crossover(SinglePointCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, min_allele, max_allele))
+            .with_reinsertion(ElitistReinserter::new(
+                problem.fitness_function(),
+                true,
+                0.7,
+            ))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(GenerationLimit::new(GENERATIONS))
+    .build_with_seed([thread_number as u8; 32]);
+
+    let mut data = vec![];
+    loop {
+        match sim.step().ok()? {
+            SimResult::Intermediate(state) => {
+                data.push(state.result.best_solution.solution.fitness as u32)
+            }
+            SimResult::Final(state, _, _, _) => {
+                data.push(state.result.best_solution.solution.fitness as u32);
+                return Some(data);
+            }
+        }
+    }
+}
+
+fn bench_run_batch(c: &mut Criterion) {
+    let items = vec![("default".to_string(), ClustersOf4::new(STRAND_SIZE))];
+
+    let mut group = c.benchmark_group("run batch of small simulations");
+    group.sample_size(10);
+    for batch_size in [1, 4, 16] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(batch_size),
+            &batch_size,
+            |b, batch_size| b.iter(|| run_batch(&items, *batch_size, run).unwrap()),
+        );
+    }
+}
+
+criterion_group!(benches, bench_run_batch);
+criterion_main!(benches);
@@ -0,0 +1,708 @@
+use std::{sync::atomic::AtomicBool, time::Duration};
+// The runs of a batch run on threads, which wasm32 does not have
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    sync::{atomic::Ordering, mpsc},
+    thread,
+    time::Instant,
+};
+
+// The best fitness of each generation of a run
+pub type Data = Vec<u32>;
+pub type DataSetWithLables = Vec<(String, Data)>;
+pub type RunsWithLables = Vec<(String, Vec<Data>)>;
+// The fitness of each generation scaled to 0..1, comparable between problems of different sizes
+pub type NormalizedDataSetWithLables = Vec<(String, Vec<f64>)>;
+// The seconds from the start of a run to the end of each of its generations
+pub type Timestamps = Vec<f64>;
+// The (seconds, fitness) points of each line over the wall-clock time
+pub type TimedDataSetWithLables = Vec<(String, Vec<(f64, f64)>)>;
+
+// Prints a line of the progress of the runs, unless the dashboard holds it back (see `monitor`)
+pub fn report_line(line: String) {
+    #[cfg(any(feature = "tui", feature = "serve"))]
+    if crate::monitor::log(&line) {
+        return;
+    }
+    println!("{}", line);
+}
+
+// Like `println!`, for the progress of the runs (see `report_line`)
+#[macro_export]
+macro_rules! report {
+    ($($arg:tt)*) => {
+        $crate::batch::report_line(format!($($arg)*))
+    };
+}
+
+// How long a run of a batch may take, how often a failed run is retried and how much memory the
+// runs may take at once. A run that takes longer than the timeout is abandoned: it is told to stop
+// (see `RunContext`), its data is ignored and it is retried like a failed run. The timeout does
+// not kill the run, which has to stop on its own (see `run_batch_with_policy`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunPolicy {
+    pub timeout: Option<Duration>, // from the start of the run, not of the batch
+    pub retries: u32,              // of each run, after which the batch fails
+    pub memory_budget: Option<u64>, // bytes of the estimated footprints of the runs at once
+}
+
+// What the batch tells a run: the number of its thread, its attempt (0 for the first one) and
+// whether it has been abandoned
+pub struct RunContext<'a> {
+    pub thread_number: u64,
+    pub attempt: u32,
+    abandoned: &'a AtomicBool,
+}
+
+impl<'a> RunContext<'a> {
+    // The flag the batch sets when it abandons the run, which should stop it as soon as it can.
+    // A run that does not stop keeps its thread busy until the end of the batch.
+    pub fn abandoned(&self) -> &'a AtomicBool {
+        self.abandoned
+    }
+}
+
+// Copies of the population a generation holds at once: the population, the selected parents and
+// their offspring
+const POPULATION_COPIES: u64 = 3;
+
+// A rough estimate of the memory a run takes in bytes, from the size of its population and of a
+// genome (with its overhead) in bytes
+pub fn run_footprint(population_size: usize, genome_size: usize) -> u64 {
+    POPULATION_COPIES * population_size as u64 * genome_size as u64
+}
+
+// Runs each of the labeled items `batch_size` times, every run in its own thread. `run` gets an
+// item and the number of its thread and returns the run's data, or none if the run failed.
+// Returns the runs of each item, or none as soon as one of the runs failed.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_batch<T, F>(items: &[(String, T)], batch_size: u64, run: F) -> Option<RunsWithLables>
+where
+    T: Sync,
+    F: Fn(&T, u64) -> Option<Data> + Sync,
+{
+    run_batch_with_policy(
+        items,
+        batch_size,
+        RunPolicy::default(),
+        |_| 0,
+        |item, context| run(item, context.thread_number),
+    )
+}
+
+// Like `run_batch`, but the runs are watched by the given policy. The runs are collected as they
+// finish, so a slow run holds up neither the others nor its own timeout. With a memory budget the
+// runs start in the order of their threads as long as their `footprint`s fit in it together, the
+// retries first. A run that does not fit on its own runs alone.
+// The runs share the scope of the batch, so the batch only returns once every run has returned,
+// the abandoned ones too: `run` must poll `RunContext::abandoned` and return as soon as it is set,
+// or a timed-out run holds up the end of the batch until it finishes on its own.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_batch_with_policy<T, M, F>(
+    items: &[(String, T)],
+    batch_size: u64,
+    policy: RunPolicy,
+    footprint: M,
+    run: F,
+) -> Option<RunsWithLables>
+where
+    T: Sync,
+    M: Fn(&T) -> u64,
+    F: Fn(&T, &RunContext) -> Option<Data> + Sync,
+{
+    // The runs are numbered by thread, each attempt of a run has its own abandoned flag
+    let num_runs = items.len() * batch_size as usize;
+    let item_of = |run_idx: usize| run_idx / batch_size as usize;
+    let abandoned = (0..num_runs)
+        .map(|_| {
+            (0..=policy.retries)
+                .map(|_| AtomicBool::new(false))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let footprints = items
+        .iter()
+        .map(|(_, item)| footprint(item))
+        .collect::<Vec<_>>();
+    let budget = policy.memory_budget.unwrap_or(u64::MAX);
+    for ((label, _), footprint) in items.iter().zip(&footprints) {
+        if *footprint > budget {
+            report!(
+                "[thread pool]: The runs with {} parameters take about {} MiB, more than the memory budget of {} MiB, so they run alone.",
+                label,
+                footprint >> 20,
+                budget >> 20
+            );
+        }
+    }
+
+    // Create a thread scope for the items
+    thread::scope(|scope| {
+        let (sender, results) = mpsc::channel();
+        let run = &run;
+        let abandoned = &abandoned;
+        let spawn = |run_idx: usize, attempt: u32| {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                let context = RunContext {
+                    thread_number: run_idx as u64 + 1,
+                    attempt,
+                    abandoned: &abandoned[run_idx][attempt as usize],
+                };
+                // A run that panicked failed (the panic is printed as usual)
+                let data = panic::catch_unwind(AssertUnwindSafe(|| {
+                    run(&items[item_of(run_idx)].1, &context)
+                }))
+                .unwrap_or(None);
+                // The batch is gone if it failed in the meantime
+                let _ = sender.send((run_idx, attempt, data));
+            });
+            (attempt, Instant::now())
+        };
+
+        // The runs waiting for memory with their attempt, and the attempt and the start of each
+        // running one. An abandoned run that has not stopped yet still takes its memory, but does
+        // not hold up the next run once no other one is running.
+        let mut queue = (0..num_runs)
+            .map(|run_idx| (run_idx, 0))
+            .collect::<VecDeque<_>>();
+        let mut running: Vec<Option<(u32, Instant)>> = vec![None; num_runs];
+        let mut in_memory = 0;
+        let mut data_list: Vec<Option<Data>> = vec![None; num_runs];
+
+        // Retries a failed or abandoned run, or fails the batch (abandoning all running runs)
+        let retry_or_fail = |queue: &mut VecDeque<(usize, u32)>,
+                             running: &[Option<(u32, Instant)>],
+                             run_idx,
+                             attempt| {
+            if attempt < policy.retries {
+                report!(
+                    "[thread pool]: Retrying thread #{} with a new seed (retry {} of {}).",
+                    run_idx + 1,
+                    attempt + 1,
+                    policy.retries
+                );
+                queue.push_front((run_idx, attempt + 1));
+                return true;
+            }
+            report!(
+                "[thread pool]: With {} parameters, optimal solution was not always found within the generation limit! Failed in thread #{}.",
+                items[item_of(run_idx)].0, run_idx + 1
+            );
+            for (run_idx, run) in running.iter().enumerate() {
+                if let Some((attempt, _)) = run {
+                    abandoned[run_idx][*attempt as usize].store(true, Ordering::Relaxed);
+                }
+            }
+            false
+        };
+
+        // Wait for all the runs to finish, or to time out
+        loop {
+            // Start the runs that fit in the memory left, in order
+            while let Some((run_idx, attempt)) = queue.front().copied() {
+                let footprint = footprints[item_of(run_idx)];
+                let busy = running.iter().any(Option::is_some);
+                if busy && in_memory + footprint > budget {
+                    break;
+                }
+                queue.pop_front();
+                in_memory += footprint;
+                running[run_idx] = Some(spawn(run_idx, attempt));
+            }
+            if running.iter().all(Option::is_none) {
+                break;
+            }
+
+            let wait = policy.timeout.map(|timeout| {
+                running
+                    .iter()
+                    .flatten()
+                    .map(|(_, started)| {
+                        (*started + timeout).saturating_duration_since(Instant::now())
+                    })
+                    .min()
+                    .unwrap_or_default()
+            });
+            let result = match wait {
+                Some(wait) => results.recv_timeout(wait).ok(),
+                None => results.recv().ok(),
+            };
+
+            match result {
+                // An abandoned run that stopped (its retry, if any, was queued when it was abandoned)
+                Some((run_idx, attempt, _))
+                    if running[run_idx].map(|(a, _)| a) != Some(attempt) =>
+                {
+                    in_memory -= footprints[item_of(run_idx)];
+                }
+                Some((run_idx, _, Some(data))) => {
+                    report!("[thread pool]: Joined thread #{}.", run_idx + 1);
+                    data_list[run_idx] = Some(data);
+                    running[run_idx] = None;
+                    in_memory -= footprints[item_of(run_idx)];
+                }
+                Some((run_idx, attempt, None)) => {
+                    running[run_idx] = None;
+                    in_memory -= footprints[item_of(run_idx)];
+                    if !retry_or_fail(&mut queue, &running, run_idx, attempt) {
+                        return None;
+                    }
+                }
+                None => {
+                    let timeout = policy.timeout.unwrap_or_default();
+                    let timed_out = (0..num_runs)
+                        .filter_map(|run_idx| match running[run_idx] {
+                            Some((attempt, started)) if started.elapsed() >= timeout => {
+                                Some((run_idx, attempt))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    for (run_idx, attempt) in timed_out {
+                        abandoned[run_idx][attempt as usize].store(true, Ordering::Relaxed);
+                        running[run_idx] = None;
+                        report!(
+                            "[thread pool]: Abandoned thread #{} after {} seconds.",
+                            run_idx + 1,
+                            timeout.as_secs()
+                        );
+                        if !retry_or_fail(&mut queue, &running, run_idx, attempt) {
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Label the runs of each item, in the order of their threads
+        let mut runs_list = items
+            .iter()
+            .map(|(label, _)| (label.clone(), vec![]))
+            .collect::<Vec<_>>();
+        for (run_idx, data) in data_list.into_iter().enumerate() {
+            runs_list[item_of(run_idx)].1.push(data.unwrap());
+        }
+        Some(runs_list)
+    }) // thread::scope
+}
+
+// Pads a run with its last fitness (GA runs converged to the optimum) up to the given length
+pub fn padded_run(run: &Data, len: usize) -> Data {
+    let mut run = run.clone();
+    run.resize(len, run.last().copied().unwrap_or_default());
+    run
+}
+
+// Averages the runs of each parameter set into a single labeled line
+pub fn average_runs(runs_list: &RunsWithLables) -> DataSetWithLables {
+    let mut combined_data_list = vec![];
+    for (label, runs) in runs_list.iter() {
+        let max_size = runs.iter().map(|d| d.len()).max().unwrap();
+        let mut combined_data = vec![0; max_size];
+        for run in runs.iter().map(|r| padded_run(r, max_size)) {
+            for (d, s) in combined_data.iter_mut().zip(run) {
+                *d += s;
+            }
+        }
+        for d in combined_data.iter_mut() {
+            *d = (*d as f64 / runs.len() as f64) as u32;
+        }
+        combined_data_list.push((label.clone(), combined_data));
+    }
+    combined_data_list
+}
+
+// The best fitness a run had found by the given second: the fitness of its first generation until
+// that ended, of its last one after the run ended
+pub fn fitness_at(run: &Data, timestamps: &Timestamps, secs: f64) -> u32 {
+    let gens = run.len().min(timestamps.len());
+    let ended = timestamps[..gens].partition_point(|end| *end <= secs);
+    run.get(ended.clamp(1, gens.max(1)) - 1)
+        .copied()
+        .unwrap_or_default()
+}
+
+// Averages the runs of each parameter set over the wall-clock time instead of the generations, at
+// the given number of evenly spaced seconds up to the end of the longest run. The runs (and the
+// timestamps of each run) are in the same order.
+pub fn average_runs_over_time(
+    runs_list: &RunsWithLables,
+    timestamps_list: &[Vec<Timestamps>],
+    points: usize,
+) -> TimedDataSetWithLables {
+    let end = |run: &Data, timestamps: &Timestamps| {
+        let gens = run.len().min(timestamps.len());
+        timestamps[..gens].last().copied().unwrap_or_default()
+    };
+    let secs_max = runs_list
+        .iter()
+        .zip(timestamps_list)
+        .flat_map(|((_, runs), timestamps)| runs.iter().zip(timestamps).map(|(r, t)| end(r, t)))
+        .fold(0.0, f64::max);
+
+    runs_list
+        .iter()
+        .zip(timestamps_list)
+        .map(|((label, runs), timestamps)| {
+            let line = (0..=points)
+                .map(|point| {
+                    let secs = secs_max * point as f64 / points.max(1) as f64;
+                    let total = runs
+                        .iter()
+                        .zip(timestamps)
+                        .map(|(run, timestamps)| fitness_at(run, timestamps, secs) as f64)
+                        .sum::<f64>();
+                    (secs, total / runs.len().max(1) as f64)
+                })
+                .collect();
+            (label.clone(), line)
+        })
+        .collect()
+}
+
+// Scales the fitness of each generation of a run from the given (lowest, highest) bounds to 0..1
+pub fn normalized_run(run: &Data, (lowest, highest): (usize, usize)) -> Vec<f64> {
+    let range = (highest - lowest).max(1) as f64;
+    run.iter()
+        .map(|fitness| (*fitness as f64 - lowest as f64) / range)
+        .collect()
+}
+
+// The generation (counted from 1) at which a run first reached the optimum, if it ever did
+pub fn convergence_gen(run: &Data, optimum: usize) -> Option<usize> {
+    run.iter()
+        .position(|fitness| *fitness as usize >= optimum)
+        .map(|gen| gen + 1)
+}
+
+// The generation (counted from 1) from which a run stays within the tolerance of its final fitness
+pub fn plateau_gen(run: &Data, tolerance: f64) -> usize {
+    let last = run.last().copied().unwrap_or_default() as f64;
+    run.iter()
+        .rposition(|fitness| (*fitness as f64 - last).abs() > tolerance)
+        .map_or(1, |gen| gen + 2)
+}
+
+// The share of the runs that have reached the optimum by each generation, up to the longest run.
+// Runs that stopped at a limit before the optimum never count as converged.
+pub fn success_rate_per_gen(runs: &[Data], optimum: usize) -> Vec<f64> {
+    let len = runs.iter().map(|r| r.len()).max().unwrap_or_default();
+    let mut converged = vec![0; len];
+    for gen in runs.iter().filter_map(|r| convergence_gen(r, optimum)) {
+        for count in converged[gen - 1..].iter_mut() {
+            *count += 1;
+        }
+    }
+    converged
+        .into_iter()
+        .map(|count| count as f64 / runs.len() as f64)
+        .collect()
+}
+
+// The seconds a run took to first reach the target fitness, if it ever did
+pub fn time_to_target(run: &Data, target: usize, timestamps: &Timestamps) -> Option<f64> {
+    timestamps.get(convergence_gen(run, target)? - 1).copied()
+}
+
+// The empirical run-length distribution of the runs from how long (in generations or seconds)
+// each one took to reach a target, if it did: the share of the runs that reached it by each of
+// the lengths, in order. The runs that never reached it keep the share below 1.
+pub fn run_length_distribution(lengths: &[Option<f64>]) -> Vec<(f64, f64)> {
+    let mut reached = lengths.iter().flatten().copied().collect::<Vec<_>>();
+    reached.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    reached
+        .into_iter()
+        .enumerate()
+        .map(|(idx, length)| (length, (idx + 1) as f64 / lengths.len() as f64))
+        .collect()
+}
+
+// Saves the runs as CSV, a row per run with its label, the optimum and the best fitness of each
+// generation, so they can be compared with the runs of later invocations
+pub fn runs_to_csv(runs_list: &RunsWithLables, optimum: usize) -> String {
+    let mut csv = "label,optimum,best fitness of each generation\n".to_string();
+    for (label, runs) in runs_list {
+        for run in runs {
+            csv.push_str(&format!("\"{}\",{}", label.replace('"', "\"\""), optimum));
+            for fitness in run {
+                csv.push_str(&format!(",{}", fitness));
+            }
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+// Reads runs saved by `runs_to_csv`, returns them with the highest optimum of the rows
+pub fn runs_from_csv(csv: &str) -> Result<(RunsWithLables, usize), String> {
+    let mut runs_list: RunsWithLables = vec![];
+    let mut optimum = 0;
+    for (line_idx, line) in csv.lines().enumerate().skip(1) {
+        let bad_line = || format!("bad run in line {}", line_idx + 1);
+        // The label is quoted, with its quotes doubled
+        let rest = line.strip_prefix('"').ok_or_else(bad_line)?;
+        let mut label = String::new();
+        let mut chars = rest.char_indices();
+        let values = loop {
+            match chars.next().ok_or_else(bad_line)? {
+                (i, '"') if rest[i + 1..].starts_with('"') => {
+                    label.push('"');
+                    chars.next();
+                }
+                (i, '"') => break rest[i + 1..].strip_prefix(',').ok_or_else(bad_line)?,
+                (_, c) => label.push(c),
+            }
+        };
+        let mut values = values
+            .split(',')
+            .map(|v| v.trim().parse::<u32>().map_err(|_| bad_line()));
+        optimum = optimum.max(values.next().ok_or_else(bad_line)?? as usize);
+        let run = values.collect::<Result<Data, String>>()?;
+
+        match runs_list.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, runs)) => runs.push(run),
+            None => runs_list.push((label, vec![run])),
+        }
+    }
+    Ok((runs_list, optimum))
+}
+
+// Mean and (sample) variance of the runs at each generation, runs padded to `len`
+pub fn mean_and_variance_per_gen(runs: &[Data], len: usize) -> Vec<(f64, f64)> {
+    let runs = runs
+        .iter()
+        .map(|r| padded_run(r, len))
+        .collect::<Vec<Data>>();
+    let n = runs.len() as f64;
+    (0..len)
+        .map(|g| {
+            let mean = runs.iter().map(|r| r[g] as f64).sum::<f64>() / n;
+            let var = if n > 1.0 {
+                runs.iter()
+                    .map(|r| (r[g] as f64 - mean).powi(2))
+                    .sum::<f64>()
+                    / (n - 1.0)
+            } else {
+                0.0
+            };
+            (mean, var)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU64;
+
+    #[test]
+    fn runs_are_padded_with_their_last_fitness() {
+        assert_eq!(padded_run(&vec![1, 3], 4), vec![1, 3, 3, 3]);
+    }
+
+    #[test]
+    fn average_runs_pads_the_shorter_runs() {
+        let runs = vec![("default".to_string(), vec![vec![2, 4], vec![0, 2, 4, 6]])];
+
+        assert_eq!(
+            average_runs(&runs),
+            vec![("default".to_string(), vec![1, 3, 4, 5])]
+        );
+    }
+
+    #[test]
+    fn normalized_runs_span_the_fitness_bounds() {
+        assert_eq!(
+            normalized_run(&vec![2, 4, 6], (2, 10)),
+            vec![0.0, 0.25, 0.5]
+        );
+    }
+
+    #[test]
+    fn success_rates_count_only_the_converged_runs() {
+        let runs = vec![vec![1, 4], vec![1, 2, 3, 4], vec![1, 2, 3]];
+
+        assert_eq!(convergence_gen(&runs[1], 4), Some(4));
+        assert_eq!(convergence_gen(&runs[2], 4), None);
+        assert_eq!(
+            success_rate_per_gen(&runs, 4),
+            vec![0.0, 1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0]
+        );
+    }
+
+    #[test]
+    fn runs_plateau_once_they_stay_near_their_final_fitness() {
+        assert_eq!(plateau_gen(&vec![1, 5, 9, 10, 10, 10], 0.0), 4);
+        assert_eq!(plateau_gen(&vec![1, 5, 9, 10, 10, 10], 1.0), 3);
+        assert_eq!(plateau_gen(&vec![7, 7, 7], 0.0), 1);
+        assert_eq!(plateau_gen(&vec![], 0.0), 1);
+    }
+
+    #[test]
+    fn run_lengths_count_only_the_runs_that_reached_the_target() {
+        let runs = [vec![1, 4], vec![1, 2, 3, 4], vec![1, 2, 3]];
+        let timestamps = vec![2.0, 4.0, 6.0, 8.0];
+
+        assert_eq!(time_to_target(&runs[0], 4, &timestamps), Some(4.0));
+        assert_eq!(time_to_target(&runs[1], 3, &timestamps), Some(6.0));
+        assert_eq!(time_to_target(&runs[2], 4, &timestamps), None);
+        let gens = runs
+            .iter()
+            .map(|r| convergence_gen(r, 3).map(|gen| gen as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            run_length_distribution(&gens),
+            vec![(2.0, 1.0 / 3.0), (3.0, 2.0 / 3.0), (3.0, 1.0)]
+        );
+        assert_eq!(run_length_distribution(&[None, None]), vec![]);
+    }
+
+    #[test]
+    fn runs_are_averaged_over_the_wall_clock_time() {
+        let run = vec![1, 4, 9];
+        let timestamps = vec![1.0, 2.0, 3.0];
+        assert_eq!(fitness_at(&run, &timestamps, 0.5), 1);
+        assert_eq!(fitness_at(&run, &timestamps, 2.0), 4);
+        assert_eq!(fitness_at(&run, &timestamps, 9.0), 9);
+        assert_eq!(fitness_at(&vec![], &vec![], 1.0), 0);
+
+        // The slower run takes twice as long for each generation
+        let runs_list = vec![
+            ("fast".to_string(), vec![vec![2, 4], vec![4, 4]]),
+            ("slow".to_string(), vec![vec![2, 4]]),
+        ];
+        let timestamps_list = vec![vec![vec![1.0, 2.0], vec![1.0, 2.0]], vec![vec![2.0, 4.0]]];
+        assert_eq!(
+            average_runs_over_time(&runs_list, &timestamps_list, 2),
+            vec![
+                ("fast".to_string(), vec![(0.0, 3.0), (2.0, 4.0), (4.0, 4.0)]),
+                ("slow".to_string(), vec![(0.0, 2.0), (2.0, 2.0), (4.0, 4.0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn runs_survive_a_round_trip_through_csv() {
+        let runs = vec![
+            (
+                "x = 1 (default)".to_string(),
+                vec![vec![1, 2], vec![0, 1, 2]],
+            ),
+            ("a \"quoted\", label".to_string(), vec![vec![2]]),
+        ];
+
+        let csv = runs_to_csv(&runs, 2);
+
+        assert_eq!(runs_from_csv(&csv), Ok((runs, 2)));
+        assert!(runs_from_csv("header\nunquoted,2,1").is_err());
+    }
+
+    #[test]
+    fn run_batch_runs_each_item_batch_size_times() {
+        let items = vec![("one".to_string(), 1), ("two".to_string(), 2)];
+
+        let runs = run_batch(&items, 3, |item, _| Some(vec![*item])).unwrap();
+
+        assert_eq!(
+            runs,
+            vec![
+                ("one".to_string(), vec![vec![1]; 3]),
+                ("two".to_string(), vec![vec![2]; 3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn run_batch_fails_if_any_run_fails() {
+        let items = vec![("one".to_string(), 1), ("two".to_string(), 2)];
+
+        let runs = run_batch(&items, 2, |item, thread_number| {
+            (thread_number != 4).then(|| vec![*item])
+        });
+
+        assert_eq!(runs, None);
+    }
+
+    #[test]
+    fn failed_and_abandoned_runs_are_retried() {
+        let items = vec![("flaky".to_string(), ()), ("slow".to_string(), ())];
+        let policy = RunPolicy {
+            timeout: Some(Duration::from_millis(200)),
+            retries: 1,
+            ..RunPolicy::default()
+        };
+
+        let runs = run_batch_with_policy(
+            &items,
+            2,
+            policy,
+            |_| 0,
+            |_, context| match (context.thread_number, context.attempt) {
+                (1, 0) => None,
+                (3, 0) => {
+                    while !context.abandoned().load(Ordering::Relaxed) {
+                        thread::sleep(Duration::from_millis(10));
+                    }
+                    Some(vec![0])
+                }
+                (thread_number, attempt) => Some(vec![thread_number as u32, attempt]),
+            },
+        );
+
+        assert_eq!(
+            runs,
+            Some(vec![
+                ("flaky".to_string(), vec![vec![1, 1], vec![2, 0]]),
+                ("slow".to_string(), vec![vec![3, 1], vec![4, 0]]),
+            ])
+        );
+        assert_eq!(
+            run_batch_with_policy(
+                &items,
+                1,
+                policy,
+                |_| 0,
+                |_, context| { (context.thread_number != 2).then(|| vec![1]) }
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn the_runs_at_once_fit_in_the_memory_budget() {
+        // The largest sum of the footprints of the runs at once
+        fn peak_memory(items: &[(String, u64)], budget: u64) -> u64 {
+            let (in_use, peak) = (AtomicU64::new(0), AtomicU64::new(0));
+            let policy = RunPolicy {
+                memory_budget: Some(budget),
+                ..RunPolicy::default()
+            };
+            run_batch_with_policy(
+                items,
+                3,
+                policy,
+                |footprint| *footprint,
+                |footprint, _| {
+                    peak.fetch_max(
+                        in_use.fetch_add(*footprint, Ordering::SeqCst) + footprint,
+                        Ordering::SeqCst,
+                    );
+                    thread::sleep(Duration::from_millis(20));
+                    in_use.fetch_sub(*footprint, Ordering::SeqCst);
+                    Some(vec![])
+                },
+            )
+            .unwrap();
+            peak.into_inner()
+        }
+        let items = vec![("large".to_string(), 60), ("small".to_string(), 30)];
+
+        assert_eq!(peak_memory(&items, 100), 90);
+        assert_eq!(peak_memory(&items, u64::MAX), 270);
+        assert_eq!(peak_memory(&[("huge".to_string(), 150)], 100), 150);
+        assert_eq!(run_footprint(256, 100), 76_800);
+    }
+}
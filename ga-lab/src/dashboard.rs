@@ -0,0 +1,172 @@
+// A live dashboard of the runs of the experiments in the terminal: the status and the ETA of the
+// current batch, a sparkline of the best fitness of each of its runs and the latest lines of the
+// progress reported by `report!` (see `monitor`). The reported lines are printed once the
+// dashboard is stopped.
+
+use crate::monitor::{self, sampled, Monitor};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyModifiers},
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Gauge, Paragraph, Sparkline},
+    Frame,
+};
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+const LOG_LINES: usize = 8; // latest reported lines shown below the runs
+const LABEL_WIDTH: u16 = 40; // of the thread number, the best fitness and the label of a run
+
+static DRAWER: Mutex<Option<JoinHandle<io::Result<()>>>> = Mutex::new(None);
+static STOPPING: AtomicBool = AtomicBool::new(false);
+
+// Takes over the terminal and redraws the dashboard until it is stopped, holding the reported
+// lines back from std out. Pressing q (or ctrl-c) gives the terminal back and quits the process.
+pub fn start() -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    monitor::enable();
+    monitor::set_quiet(true);
+    STOPPING.store(false, Ordering::Relaxed);
+
+    let drawer = thread::spawn(move || {
+        while !STOPPING.load(Ordering::Relaxed) {
+            terminal.draw(|frame| {
+                monitor::with(|monitor| draw(frame, monitor));
+            })?;
+            if !event::poll(REFRESH_INTERVAL)? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                let ctrl_c =
+                    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if key.code == KeyCode::Char('q') || ctrl_c {
+                    ratatui::restore();
+                    std::process::exit(130);
+                }
+            }
+        }
+        Ok(())
+    });
+    *DRAWER.lock().unwrap() = Some(drawer);
+    Ok(())
+}
+
+// Gives the terminal back and prints the lines reported while the dashboard was shown
+pub fn stop() {
+    let Some(drawer) = DRAWER.lock().unwrap().take() else {
+        return;
+    };
+    STOPPING.store(true, Ordering::Relaxed);
+    let result = drawer.join();
+    ratatui::restore();
+
+    for line in monitor::set_quiet(false) {
+        println!("{}", line);
+    }
+    match result {
+        Ok(Err(error)) => println!("Problem drawing the dashboard: {}", error),
+        Err(_) => println!("Problem drawing the dashboard: the drawing thread panicked"),
+        Ok(Ok(())) => (),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+fn draw(frame: &mut Frame, monitor: &Monitor) {
+    let [batch_area, runs_area, log_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(LOG_LINES as u16 + 2),
+    ])
+    .areas(frame.area());
+
+    // Status and ETA of the batch
+    let finished = monitor.finished_runs();
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::bordered().title(format!(
+                " Batch {}: {} (running for {}) ",
+                monitor.batch_number,
+                monitor.batch_name,
+                format_duration(monitor.start.elapsed())
+            )))
+            .gauge_style(Style::default().fg(Color::Blue))
+            .ratio((finished as f64 / monitor.batch_runs.max(1) as f64).min(1.0))
+            .label(format!(
+                "{}/{} runs in {}, ETA {}",
+                finished,
+                monitor.batch_runs,
+                format_duration(monitor.batch_start.elapsed()),
+                monitor.eta().map_or("?".to_string(), format_duration)
+            )),
+        batch_area,
+    );
+
+    // A line of each run, as many as fit
+    let runs_block = Block::bordered().title(" Best fitness of each run (q to quit) ");
+    let inner = runs_block.inner(runs_area);
+    frame.render_widget(runs_block, runs_area);
+    let rows = Layout::vertical(vec![Constraint::Length(1); inner.height as usize]).split(inner);
+    for (row, (thread_number, run)) in rows.iter().zip(monitor.runs.iter()) {
+        let [label_area, sparkline_area] =
+            Layout::horizontal([Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)]).areas(*row);
+        frame.render_widget(
+            Paragraph::new(format!(
+                "#{:<4}{:>5} {}",
+                thread_number,
+                run.best_fitness.last().copied().unwrap_or_default(),
+                run.label
+            )),
+            label_area,
+        );
+        frame.render_widget(
+            Sparkline::default()
+                .data(sampled(&run.best_fitness, sparkline_area.width as usize))
+                .max(monitor.optimum)
+                .style(Style::default().fg(if run.finished {
+                    Color::Green
+                } else {
+                    Color::Yellow
+                })),
+            sparkline_area,
+        );
+    }
+
+    // The latest reported lines
+    let log = monitor.log[monitor.log.len().saturating_sub(LOG_LINES)..]
+        .iter()
+        .map(|line| Line::from(line.trim_start()))
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(log).block(Block::bordered().title(" Log ")),
+        log_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_formatted_to_their_two_largest_units() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m 05s");
+        assert_eq!(format_duration(Duration::from_secs(7384)), "2h 03m");
+    }
+}
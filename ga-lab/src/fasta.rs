@@ -0,0 +1,120 @@
+// The FASTA format of sequences, so the strands of the runs (a hall of fame, a final population)
+// can be read by the usual bioinformatics tools and strands from them can seed a population.
+//
+// A record is a header line starting with '>' followed by the lines of its sequence. Lines
+// starting with ';' are comments, the sequence is read in upper case.
+
+use crate::genome::Phenome;
+use std::fmt::Write;
+
+// The width the sequences are wrapped at, as by most tools
+pub const FASTA_LINE_WIDTH: usize = 60;
+
+// A sequence and its header, without the '>'
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastaRecord {
+    pub header: String,
+    pub sequence: Phenome,
+}
+
+impl FastaRecord {
+    pub fn new(header: impl Into<String>, sequence: impl Into<Phenome>) -> Self {
+        Self {
+            header: header.into(),
+            sequence: sequence.into(),
+        }
+    }
+}
+
+// Writes the records, with their sequences wrapped at FASTA_LINE_WIDTH
+pub fn to_fasta(records: &[FastaRecord]) -> String {
+    let mut fasta = String::new();
+    for record in records {
+        writeln!(fasta, ">{}", record.header).unwrap();
+        let sequence = record.sequence.as_bytes();
+        for line in sequence.chunks(FASTA_LINE_WIDTH) {
+            writeln!(fasta, "{}", String::from_utf8_lossy(line)).unwrap();
+        }
+    }
+    fasta
+}
+
+// Reads the records of a FASTA text, an error if a sequence comes before the first header
+pub fn parse_fasta(text: &str) -> Result<Vec<FastaRecord>, String> {
+    let mut records: Vec<FastaRecord> = vec![];
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('>') {
+            records.push(FastaRecord::new(header.trim(), ""));
+            continue;
+        }
+        let record = records
+            .last_mut()
+            .ok_or_else(|| format!("line {} is a sequence without a header", number + 1))?;
+        record.sequence.extend(
+            line.chars()
+                .filter(|c| !c.is_whitespace())
+                .map(|c| c.to_ascii_uppercase()),
+        );
+    }
+    Ok(records)
+}
+
+// Whether a text is in the FASTA format, i.e. its first line that is not blank or a comment is a
+// header
+pub fn is_fasta(text: &str) -> bool {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with(';'))
+        .is_some_and(|line| line.starts_with('>'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_sequences_are_wrapped() {
+        let records = [
+            FastaRecord::new("short fitness=1", "GATC"),
+            FastaRecord::new("long", "A".repeat(FASTA_LINE_WIDTH + 2)),
+        ];
+
+        assert_eq!(
+            to_fasta(&records),
+            format!(
+                ">short fitness=1\nGATC\n>long\n{}\nAA\n",
+                "A".repeat(FASTA_LINE_WIDTH)
+            )
+        );
+    }
+
+    #[test]
+    fn written_records_are_read_back() {
+        let records = [
+            FastaRecord::new("first", "ACGT".repeat(40)),
+            FastaRecord::new("second", "TTTT"),
+        ];
+
+        assert_eq!(parse_fasta(&to_fasta(&records)), Ok(records.to_vec()));
+    }
+
+    #[test]
+    fn comments_blank_lines_and_lower_case_are_read() {
+        let text = "; a comment\n\n>strand 1\ngat\nC A\n>strand 2\n";
+
+        assert!(is_fasta(text));
+        assert!(!is_fasta("fitness,phenome\n2,GATC\n"));
+        assert_eq!(
+            parse_fasta(text),
+            Ok(vec![
+                FastaRecord::new("strand 1", "GATCA"),
+                FastaRecord::new("strand 2", ""),
+            ])
+        );
+        assert!(parse_fasta("GATC\n>strand\n").is_err());
+    }
+}
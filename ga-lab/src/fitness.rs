@@ -0,0 +1,585 @@
+use crate::genome::{BitString, Genome, Nucleotide};
+use genevo::prelude::FitnessFunction;
+
+// The number of Ts of a strand
+pub fn num_ts(strand: &[Nucleotide]) -> usize {
+    strand.iter().filter(|n| **n == Nucleotide::T).count()
+}
+
+// The number of chunks of `chunk_size` identical nucleotides of a strand (a shorter last chunk
+// counts too). Chunks of 4 are counted by the faster `clusters_of_4`.
+pub fn clusters_of(strand: &[Nucleotide], chunk_size: usize) -> usize {
+    match chunk_size {
+        4 => clusters_of_4(strand),
+        _ => scalar_clusters_of(strand, chunk_size),
+    }
+}
+
+fn scalar_clusters_of(strand: &[Nucleotide], chunk_size: usize) -> usize {
+    strand
+        .chunks(chunk_size)
+        .filter(|n| n.iter().all(|x| *x == n[0]))
+        .count()
+}
+
+// The number of chunks of 4 identical nucleotides of a strand. Comparing fixed arrays of 4 lets
+// the compiler drop the bounds checks and the loop of the general case.
+pub fn clusters_of_4(strand: &[Nucleotide]) -> usize {
+    let chunks = strand.chunks_exact(4);
+    let rest = chunks.remainder();
+    let full = chunks
+        .filter(|chunk| {
+            let [a, b, c, d]: &[Nucleotide; 4] = (*chunk).try_into().unwrap();
+            a == b && b == c && c == d
+        })
+        .count();
+    full + scalar_clusters_of(rest, 4)
+}
+
+// The number of Gs and Cs of a strand
+pub fn gc_content(strand: &[Nucleotide]) -> usize {
+    strand
+        .iter()
+        .filter(|n| matches!(n, Nucleotide::G | Nucleotide::C))
+        .count()
+}
+
+// The number of occurrences of a motif in a strand, overlapping ones included
+pub fn motif_count(strand: &[Nucleotide], motif: &[Nucleotide]) -> usize {
+    if motif.is_empty() {
+        return 0;
+    }
+    strand.windows(motif.len()).filter(|w| *w == motif).count()
+}
+
+// The most occurrences of a (non-empty) motif a strand of the given size can hold: the motif
+// repeated at its shortest period, the smallest shift at which it overlaps itself
+pub fn max_motif_count(strand_size: usize, motif: &[Nucleotide]) -> usize {
+    if motif.is_empty() || strand_size < motif.len() {
+        return 0;
+    }
+    let period = (1..motif.len())
+        .find(|shift| motif[*shift..] == motif[..motif.len() - shift])
+        .unwrap_or(motif.len());
+    1 + (strand_size - motif.len()) / period
+}
+
+// The number of 1s of a bitstring
+pub fn one_max(bits: &[bool]) -> usize {
+    bits.iter().filter(|bit| **bit).count()
+}
+
+// The number of 1s a bitstring starts with
+pub fn leading_ones(bits: &[bool]) -> usize {
+    bits.iter().take_while(|bit| **bit).count()
+}
+
+// Holland's Royal Road: each block of `block_size` bits that are all 1s scores its size, any
+// other block nothing. Crossover can put together blocks found by different strands.
+pub fn royal_road(bits: &[bool], block_size: usize) -> usize {
+    bits.chunks(block_size)
+        .filter(|block| block.iter().all(|bit| *bit))
+        .map(|block| block.len())
+        .sum()
+}
+
+// Concatenated deceptive traps of `trap_size` bits: a trap of all 1s scores its size, any other
+// scores the more the fewer 1s it holds, so each bit on its own leads away from the optimum.
+pub fn deceptive_traps(bits: &[bool], trap_size: usize) -> usize {
+    bits.chunks(trap_size)
+        .map(|trap| {
+            let ones = trap.iter().filter(|bit| **bit).count();
+            if ones == trap.len() {
+                ones
+            } else {
+                trap.len() - 1 - ones
+            }
+        })
+        .sum()
+}
+
+// The scores of a global alignment of two strands: of each pair of equal and of different
+// nucleotides and of each gap. A match must score more than a mismatch and above 0, a gap at most 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlignmentScores {
+    pub match_score: i64,
+    pub mismatch: i64,
+    pub gap: i64,
+}
+
+impl Default for AlignmentScores {
+    fn default() -> Self {
+        AlignmentScores {
+            match_score: 1,
+            mismatch: -1,
+            gap: -1,
+        }
+    }
+}
+
+// The score of the best global alignment of two strands by Needleman-Wunsch, keeping only the last
+// row of the table
+pub fn alignment_score(a: &[Nucleotide], b: &[Nucleotide], scores: &AlignmentScores) -> i64 {
+    let mut row = (0..=b.len() as i64)
+        .map(|j| j * scores.gap)
+        .collect::<Vec<_>>();
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = (i as i64 + 1) * scores.gap;
+        for (j, y) in b.iter().enumerate() {
+            let pair = if x == y {
+                scores.match_score
+            } else {
+                scores.mismatch
+            };
+            let best = (diagonal + pair)
+                .max(row[j + 1] + scores.gap)
+                .max(row[j] + scores.gap);
+            diagonal = row[j + 1];
+            row[j + 1] = best;
+        }
+    }
+    row[b.len()]
+}
+
+// The "T" counting fitness function for `Genome`s of the given strand size.
+#[derive(Clone, Debug)]
+pub struct NumTsFitnessCalculator {
+    strand_size: usize,
+}
+
+impl NumTsFitnessCalculator {
+    pub fn new(strand_size: usize) -> Self {
+        NumTsFitnessCalculator { strand_size }
+    }
+}
+
+impl FitnessFunction<Genome, usize> for NumTsFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        num_ts(genome)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The clusters-of-4 counting fitness function for `Genome`s of the given strand size.
+#[derive(Clone, Debug)]
+pub struct ClustersOf4FitnessCalculator {
+    strand_size: usize,
+}
+
+impl ClustersOf4FitnessCalculator {
+    pub fn new(strand_size: usize) -> Self {
+        ClustersOf4FitnessCalculator { strand_size }
+    }
+}
+
+impl FitnessFunction<Genome, usize> for ClustersOf4FitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        clusters_of_4(genome)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size / 4
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The fitness of `Genome`s of the given strand size by how close their number of Gs and Cs is to a
+// target: the strand size less the distance to it.
+#[derive(Clone, Debug)]
+pub struct GcContentFitnessCalculator {
+    strand_size: usize,
+    target_gc: usize,
+}
+
+impl GcContentFitnessCalculator {
+    pub fn new(strand_size: usize, target_gc: usize) -> Self {
+        GcContentFitnessCalculator {
+            strand_size,
+            target_gc,
+        }
+    }
+}
+
+impl FitnessFunction<Genome, usize> for GcContentFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        self.strand_size - gc_content(genome).abs_diff(self.target_gc)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    // No or only Gs and Cs, whichever is further from the target
+    fn lowest_possible_fitness(&self) -> usize {
+        self.strand_size - self.target_gc.max(self.strand_size - self.target_gc)
+    }
+}
+
+// The motif counting fitness function for `Genome`s of the given strand size.
+#[derive(Clone, Debug)]
+pub struct MotifFitnessCalculator {
+    strand_size: usize,
+    motif: Genome,
+}
+
+impl MotifFitnessCalculator {
+    pub fn new(strand_size: usize, motif: Genome) -> Self {
+        MotifFitnessCalculator { strand_size, motif }
+    }
+}
+
+impl FitnessFunction<Genome, usize> for MotifFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        motif_count(genome, &self.motif)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        max_motif_count(self.strand_size, &self.motif)
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The OneMax fitness function for bitstrings of the given size.
+#[derive(Clone, Debug)]
+pub struct OneMaxFitnessCalculator {
+    strand_size: usize,
+}
+
+impl OneMaxFitnessCalculator {
+    pub fn new(strand_size: usize) -> Self {
+        OneMaxFitnessCalculator { strand_size }
+    }
+}
+
+impl FitnessFunction<BitString, usize> for OneMaxFitnessCalculator {
+    fn fitness_of(&self, bits: &BitString) -> usize {
+        one_max(bits)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The LeadingOnes fitness function for bitstrings of the given size.
+#[derive(Clone, Debug)]
+pub struct LeadingOnesFitnessCalculator {
+    strand_size: usize,
+}
+
+impl LeadingOnesFitnessCalculator {
+    pub fn new(strand_size: usize) -> Self {
+        LeadingOnesFitnessCalculator { strand_size }
+    }
+}
+
+impl FitnessFunction<BitString, usize> for LeadingOnesFitnessCalculator {
+    fn fitness_of(&self, bits: &BitString) -> usize {
+        leading_ones(bits)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The Royal Road fitness function for bitstrings of the given size.
+#[derive(Clone, Debug)]
+pub struct RoyalRoadFitnessCalculator {
+    strand_size: usize,
+    block_size: usize,
+}
+
+impl RoyalRoadFitnessCalculator {
+    pub fn new(strand_size: usize, block_size: usize) -> Self {
+        RoyalRoadFitnessCalculator {
+            strand_size,
+            block_size,
+        }
+    }
+}
+
+impl FitnessFunction<BitString, usize> for RoyalRoadFitnessCalculator {
+    fn fitness_of(&self, bits: &BitString) -> usize {
+        royal_road(bits, self.block_size)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The concatenated deceptive traps fitness function for bitstrings of the given size.
+#[derive(Clone, Debug)]
+pub struct DeceptiveTrapsFitnessCalculator {
+    strand_size: usize,
+    trap_size: usize,
+}
+
+impl DeceptiveTrapsFitnessCalculator {
+    pub fn new(strand_size: usize, trap_size: usize) -> Self {
+        DeceptiveTrapsFitnessCalculator {
+            strand_size,
+            trap_size,
+        }
+    }
+}
+
+impl FitnessFunction<BitString, usize> for DeceptiveTrapsFitnessCalculator {
+    fn fitness_of(&self, bits: &BitString) -> usize {
+        deceptive_traps(bits, self.trap_size)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.strand_size
+    }
+
+    // Each trap a single 1 short of the optimum
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The fitness of `Genome`s of the given strand size by their global alignment with a reference
+// strand. The alignment scores are shifted to start at 0 for the worst strand there can be.
+#[derive(Clone, Debug)]
+pub struct AlignmentFitnessCalculator {
+    strand_size: usize,
+    reference: Genome,
+    scores: AlignmentScores,
+}
+
+impl AlignmentFitnessCalculator {
+    pub fn new(strand_size: usize, reference: Genome, scores: AlignmentScores) -> Self {
+        AlignmentFitnessCalculator {
+            strand_size,
+            reference,
+            scores,
+        }
+    }
+
+    // No strand aligns worse than by pairing its nucleotides off as mismatches or leaving them all
+    // to gaps, whichever is better
+    fn lowest_score(&self) -> i64 {
+        let (n, m) = (self.strand_size as i64, self.reference.len() as i64);
+        let paired = n.min(m) * self.scores.mismatch + (n - m).abs() * self.scores.gap;
+        paired.max((n + m) * self.scores.gap)
+    }
+}
+
+impl FitnessFunction<Genome, usize> for AlignmentFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        (alignment_score(genome, &self.reference, &self.scores) - self.lowest_score()) as usize
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    // The reference itself, cut short or padded by gaps to the strand size
+    fn highest_possible_fitness(&self) -> usize {
+        let (n, m) = (self.strand_size as i64, self.reference.len() as i64);
+        let best = n.min(m) * self.scores.match_score + (n - m).abs() * self.scores.gap;
+        (best - self.lowest_score()) as usize
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use Nucleotide::*;
+
+    #[test]
+    fn num_ts_counts_the_ts() {
+        assert_eq!(num_ts(&[T, A, T, G, C, T]), 3);
+    }
+
+    #[test]
+    fn clusters_of_4_counts_like_the_general_case() {
+        let mut rng = rand::thread_rng();
+        for len in 0..64 {
+            // Only two nucleotides, so there are plenty of clusters
+            let strand = (0..len)
+                .map(|_| if rng.gen_bool(0.8) { A } else { G })
+                .collect::<Vec<_>>();
+
+            assert_eq!(clusters_of_4(&strand), scalar_clusters_of(&strand, 4));
+            assert_eq!(clusters_of(&strand, 4), scalar_clusters_of(&strand, 4));
+        }
+    }
+
+    #[test]
+    fn clusters_of_4_counts_the_uniform_chunks() {
+        let strand = vec![A, A, A, A, C, C, C, G, T, T, T, T];
+
+        assert_eq!(clusters_of_4(&strand), 2);
+        assert_eq!(
+            ClustersOf4FitnessCalculator::new(strand.len()).highest_possible_fitness(),
+            3
+        );
+    }
+
+    #[test]
+    fn gc_content_fitness_is_the_distance_to_the_target() {
+        let fitness = GcContentFitnessCalculator::new(8, 6);
+
+        assert_eq!(gc_content(&[G, A, C, T, C]), 3);
+        assert_eq!(fitness.fitness_of(&vec![G, C, G, C, G, C, A, T]), 8);
+        assert_eq!(fitness.fitness_of(&vec![A; 8]), 2);
+        assert_eq!(fitness.lowest_possible_fitness(), 2);
+    }
+
+    #[test]
+    fn motifs_are_counted_overlapping() {
+        assert_eq!(motif_count(&[A, C, G, T, A, C, G, T], &[A, C, G, T]), 2);
+        assert_eq!(motif_count(&[A, A, A, A, A], &[A, A]), 4);
+        assert_eq!(motif_count(&[A, C], &[A, C, G]), 0);
+    }
+
+    #[test]
+    fn the_most_motifs_repeat_at_the_shortest_period() {
+        // ACA overlaps itself after 2 nucleotides: ACACACA
+        assert_eq!(max_motif_count(7, &[A, C, A]), 3);
+        assert_eq!(max_motif_count(8, &[A, C, G, T]), 2);
+        assert_eq!(max_motif_count(5, &[A, A]), 4);
+        assert_eq!(max_motif_count(2, &[A, C, G]), 0);
+        for strand_size in 3..12 {
+            let strand = (0..strand_size)
+                .map(|locus| if locus % 2 == 0 { A } else { C })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                motif_count(&strand, &[A, C, A]),
+                max_motif_count(strand_size, &[A, C, A])
+            );
+        }
+    }
+
+    #[test]
+    fn leading_ones_stops_at_the_first_0() {
+        let bits = [true, true, false, true, true];
+
+        assert_eq!(one_max(&bits), 4);
+        assert_eq!(leading_ones(&bits), 2);
+        assert_eq!(leading_ones(&[false, true]), 0);
+        assert_eq!(leading_ones(&[true; 4]), 4);
+    }
+
+    #[test]
+    fn royal_road_scores_only_complete_blocks() {
+        let bits = [[true; 4], [true, true, false, true], [false; 4], [true; 4]].concat();
+
+        assert_eq!(royal_road(&bits, 4), 8);
+        assert_eq!(royal_road(&[true; 16], 4), 16);
+        assert_eq!(royal_road(&[false; 16], 4), 0);
+    }
+
+    #[test]
+    fn deceptive_traps_lead_away_from_the_optimum() {
+        assert_eq!(deceptive_traps(&[true; 8], 4), 8);
+        assert_eq!(deceptive_traps(&[false; 8], 4), 6);
+        // a trap short of a single 1 is the worst
+        assert_eq!(deceptive_traps(&[true, true, true, false], 4), 0);
+        assert_eq!(
+            deceptive_traps(&[true, false, false, false, true, true, true, true], 4),
+            2 + 4
+        );
+    }
+
+    #[test]
+    fn alignment_scores_the_best_global_alignment() {
+        let scores = AlignmentScores::default();
+
+        // The example of Needleman and Wunsch's algorithm on Wikipedia
+        assert_eq!(
+            alignment_score(&[G, A, T, T, A, C, A], &[G, C, A, T, G, C, T], &scores),
+            0
+        );
+        assert_eq!(alignment_score(&[A, C, G], &[A, C, G], &scores), 3);
+        assert_eq!(alignment_score(&[A, C, G], &[], &scores), -3);
+        // a gap is cheaper than a mismatch and a match
+        assert_eq!(alignment_score(&[A, C, G, T], &[A, G, T], &scores), 2);
+    }
+
+    #[test]
+    fn alignment_fitness_runs_from_the_worst_to_the_reference() {
+        let reference = vec![A, C, G, T, A, C];
+        let fitness =
+            AlignmentFitnessCalculator::new(6, reference.clone(), AlignmentScores::default());
+
+        assert_eq!(
+            fitness.fitness_of(&reference),
+            fitness.highest_possible_fitness()
+        );
+        assert_eq!(fitness.highest_possible_fitness(), 12);
+        // GTACGT-- over --ACGTAC: 4 matches and 4 gaps score 0, 6 above the 6 mismatches
+        assert_eq!(fitness.fitness_of(&vec![G, T, A, C, G, T]), 6);
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let strand = (0..6).map(|_| rng.gen()).collect::<Vec<Nucleotide>>();
+            assert!(fitness.fitness_of(&strand) <= fitness.highest_possible_fitness());
+        }
+    }
+}
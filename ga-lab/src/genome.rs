@@ -0,0 +1,184 @@
+use genevo::{encoding::PackedGenome, operator::prelude::RandomValueMutation};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+// The phenotype
+pub type Phenome = String;
+
+// The genotype
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd)]
+pub enum Nucleotide {
+    A,
+    C,
+    T,
+    G,
+}
+pub type Genome = Vec<Nucleotide>;
+
+// All nucleotides, in the order of their discriminants
+pub const NUCLEOTIDES: [Nucleotide; 4] =
+    [Nucleotide::A, Nucleotide::C, Nucleotide::T, Nucleotide::G];
+
+// Enable random Nucleotide generation
+impl Distribution<Nucleotide> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
+        match rng.gen_range(0..4) {
+            0 => Nucleotide::A,
+            1 => Nucleotide::C,
+            2 => Nucleotide::G,
+            _ => Nucleotide::T,
+        }
+    }
+}
+
+impl RandomValueMutation for Nucleotide {
+    fn random_mutated<R>(_: Self, _: &Self, _: &Self, rng: &mut R) -> Self
+    where
+        R: Rng + Sized,
+    {
+        // The simulation's rng, so a seeded run mutates the same way every time
+        rng.gen()
+    }
+}
+
+// How do the nucleotides of a strand show up in the phenotype
+pub fn render_strand(strand: &[Nucleotide]) -> Phenome {
+    strand
+        .iter()
+        .map(|x| match x {
+            Nucleotide::A => 'A',
+            Nucleotide::C => 'C',
+            Nucleotide::T => 'T',
+            Nucleotide::G => 'G',
+        })
+        .collect::<String>()
+}
+
+// Reads a strand back from its phenotype, None if it holds anything but the 4 nucleotide letters
+pub fn parse_strand(phenome: &str) -> Option<Genome> {
+    phenome
+        .chars()
+        .map(|x| match x {
+            'A' => Some(Nucleotide::A),
+            'C' => Some(Nucleotide::C),
+            'T' => Some(Nucleotide::T),
+            'G' => Some(Nucleotide::G),
+            _ => None,
+        })
+        .collect()
+}
+
+// Packs a strand into 2 bits per nucleotide, each nucleotide stored as its discriminant
+pub fn pack_strand(strand: &[Nucleotide]) -> PackedGenome {
+    strand.iter().map(|n| n.clone() as u8).collect()
+}
+
+// Unpacks a strand packed by `pack_strand`
+pub fn unpack_strand(packed: &PackedGenome) -> Genome {
+    packed
+        .iter()
+        .map(|allele| NUCLEOTIDES[allele as usize].clone())
+        .collect()
+}
+
+// The genotype of the bitstring benchmarks
+pub type BitString = Vec<bool>;
+
+// How do the bits of a bitstring show up in the phenotype, as 0s and 1s
+pub fn render_bits(bits: &[bool]) -> Phenome {
+    bits.iter()
+        .map(|bit| if *bit { '1' } else { '0' })
+        .collect()
+}
+
+// Reads a bitstring back from its phenotype, None if it holds anything but 0s and 1s
+pub fn parse_bits(phenome: &str) -> Option<BitString> {
+    phenome
+        .chars()
+        .map(|x| match x {
+            '0' => Some(false),
+            '1' => Some(true),
+            _ => None,
+        })
+        .collect()
+}
+
+// The most frequent nucleotide at each locus of a population and its frequency
+pub type AlleleFrequencies = Vec<(Nucleotide, f64)>;
+
+// The dominant allele frequencies of a (non-empty) population of equally long strands
+pub fn allele_frequencies(population: &[Genome]) -> AlleleFrequencies {
+    (0..population[0].len())
+        .map(|locus| {
+            let mut counts = [0usize; 4];
+            for genome in population.iter() {
+                counts[genome[locus].clone() as usize] += 1;
+            }
+            let (idx, count) = counts
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, count)| **count)
+                .unwrap();
+            (
+                NUCLEOTIDES[idx].clone(),
+                *count as f64 / population.len() as f64,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_strand_writes_one_letter_per_nucleotide() {
+        let strand = vec![Nucleotide::G, Nucleotide::A, Nucleotide::T, Nucleotide::C];
+
+        assert_eq!(render_strand(&strand), "GATC");
+    }
+
+    #[test]
+    fn parse_strand_reads_a_rendered_strand_back() {
+        let strand = vec![Nucleotide::G, Nucleotide::A, Nucleotide::T, Nucleotide::C];
+
+        assert_eq!(parse_strand(&render_strand(&strand)), Some(strand));
+        assert_eq!(parse_strand("GATX"), None);
+    }
+
+    #[test]
+    fn parse_bits_reads_rendered_bits_back() {
+        let bits = vec![true, false, false, true];
+
+        assert_eq!(render_bits(&bits), "1001");
+        assert_eq!(parse_bits(&render_bits(&bits)), Some(bits));
+        assert_eq!(parse_bits("10A1"), None);
+    }
+
+    #[test]
+    fn packing_a_strand_round_trips() {
+        let strand = vec![Nucleotide::G, Nucleotide::A, Nucleotide::T, Nucleotide::C];
+
+        let packed = pack_strand(&strand);
+
+        assert_eq!(packed.iter().collect::<Vec<_>>(), vec![3, 0, 2, 1]);
+        assert_eq!(unpack_strand(&packed), strand);
+    }
+
+    #[test]
+    fn allele_frequencies_finds_the_dominant_nucleotide_of_each_locus() {
+        let population = vec![
+            vec![Nucleotide::A, Nucleotide::G],
+            vec![Nucleotide::A, Nucleotide::T],
+            vec![Nucleotide::C, Nucleotide::T],
+            vec![Nucleotide::A, Nucleotide::T],
+        ];
+
+        assert_eq!(
+            allele_frequencies(&population),
+            vec![(Nucleotide::A, 0.75), (Nucleotide::T, 0.75)]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+// The best distinct solutions ever seen, so a good strand is not lost when the population moves
+// past it, e.g. because the run ended on a different one or a limit stopped it short.
+
+use crate::{
+    fasta::{to_fasta, FastaRecord},
+    genome::Phenome,
+};
+use std::fmt::Write;
+
+// The `capacity` best distinct genomes seen so far with their fitness, best first
+#[derive(Debug, Clone, PartialEq)]
+pub struct HallOfFame<G> {
+    capacity: usize,
+    entries: Vec<(G, usize)>,
+}
+
+impl<G: Clone + PartialEq> HallOfFame<G> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::with_capacity(capacity + 1),
+        }
+    }
+
+    // Enters a genome if it is not in yet and better than the worst entry of a full hall of fame.
+    // Returns whether it made it in.
+    pub fn insert(&mut self, genome: &G, fitness: usize) -> bool {
+        let full = self.entries.len() >= self.capacity;
+        if full && self.entries.last().is_none_or(|worst| fitness <= worst.1) {
+            return false;
+        }
+        if self.entries.iter().any(|(entry, _)| entry == genome) {
+            return false;
+        }
+        // Behind the entries of the same fitness, the earlier ones were there first
+        let index = self.entries.partition_point(|entry| entry.1 >= fitness);
+        self.entries.insert(index, (genome.clone(), fitness));
+        self.entries.truncate(self.capacity);
+        true
+    }
+
+    // Enters all genomes of another hall of fame, e.g. of a run into the one of its batch
+    pub fn merge(&mut self, other: &HallOfFame<G>) {
+        for (genome, fitness) in &other.entries {
+            self.insert(genome, *fitness);
+        }
+    }
+
+    pub fn entries(&self) -> &[(G, usize)] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // The hall of fame with each genome rendered, e.g. to merge the ones of different genotypes
+    pub fn rendered(&self, render: &dyn Fn(&G) -> Phenome) -> HallOfFame<Phenome> {
+        HallOfFame {
+            capacity: self.capacity,
+            entries: self
+                .entries
+                .iter()
+                .map(|(genome, fitness)| (render(genome), *fitness))
+                .collect(),
+        }
+    }
+}
+
+// A CSV of the entries, best first, with the fitness and the phenotype of each
+pub fn hall_of_fame_to_csv(hall_of_fame: &HallOfFame<Phenome>) -> String {
+    let mut csv = "fitness,phenome\n".to_string();
+    for (phenome, fitness) in hall_of_fame.entries() {
+        writeln!(csv, "{},{}", fitness, phenome).unwrap();
+    }
+    csv
+}
+
+// A FASTA file of the entries, best first, each named after its rank and the name of the hall of
+// fame (e.g. of its parameters) with its fitness in the header
+pub fn hall_of_fame_to_fasta(hall_of_fame: &HallOfFame<Phenome>, name: &str) -> String {
+    let records = hall_of_fame
+        .entries()
+        .iter()
+        .enumerate()
+        .map(|(rank, (phenome, fitness))| {
+            FastaRecord::new(
+                format!("{}_{} fitness={}", name, rank + 1, fitness),
+                phenome.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    to_fasta(&records)
+}
+
+// The hall of fame of the runs of a graph is written next to it
+pub fn hall_of_fame_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_hall_of_fame.csv")
+}
+
+// The FASTA file of a hall of fame is written next to its CSV
+pub fn fasta_out_file(csv_file: &str) -> String {
+    csv_file.replace(".csv", ".fasta")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_best_distinct_genomes_are_kept_best_first() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        assert!(hall_of_fame.insert(&'a', 5));
+        assert!(hall_of_fame.insert(&'b', 7));
+        assert!(!hall_of_fame.insert(&'a', 5));
+        assert!(hall_of_fame.insert(&'c', 5));
+        assert!(hall_of_fame.insert(&'d', 9));
+        assert!(!hall_of_fame.insert(&'e', 5));
+
+        assert_eq!(hall_of_fame.entries(), &[('d', 9), ('b', 7), ('a', 5)]);
+    }
+
+    #[test]
+    fn merging_keeps_the_best_of_both() {
+        let mut batch = HallOfFame::new(2);
+        batch.insert(&'a', 3);
+        let mut run = HallOfFame::new(2);
+        run.insert(&'b', 4);
+        run.insert(&'a', 3);
+
+        batch.merge(&run);
+
+        assert_eq!(batch.entries(), &[('b', 4), ('a', 3)]);
+    }
+
+    #[test]
+    fn hall_of_fame_is_written_as_csv() {
+        let mut hall_of_fame = HallOfFame::new(2);
+        hall_of_fame.insert(&"AAAA".to_string(), 1);
+        hall_of_fame.insert(&"CCCCGGGG".to_string(), 2);
+
+        assert_eq!(
+            hall_of_fame_to_csv(&hall_of_fame),
+            "fitness,phenome\n2,CCCCGGGG\n1,AAAA\n"
+        );
+    }
+
+    #[test]
+    fn hall_of_fame_is_written_as_fasta() {
+        let mut hall_of_fame = HallOfFame::new(2);
+        hall_of_fame.insert(&"AAAA".to_string(), 1);
+        hall_of_fame.insert(&"CCCCGGGG".to_string(), 2);
+
+        assert_eq!(
+            hall_of_fame_to_fasta(&hall_of_fame, "default"),
+            ">default_1 fitness=2\nCCCCGGGG\n>default_2 fitness=1\nAAAA\n"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+// The JSON the experiments write (e.g. the trace of the best strands) is built with `format!`, a
+// line per record. The strings in it are quoted and escaped by `json_string`.
+
+use std::fmt::Write;
+
+// The string as a JSON string literal: in double quotes, with the quote, the backslash and the
+// control characters escaped
+pub fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() && (c as u32) < 0x20 => {
+                write!(json, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_strings_are_only_quoted() {
+        assert_eq!(json_string("ACGT"), "\"ACGT\"");
+        assert_eq!(json_string(""), "\"\"");
+    }
+
+    #[test]
+    fn quotes_backslashes_and_control_characters_are_escaped() {
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("a\nb\tc\r"), "\"a\\nb\\tc\\r\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn apostrophes_and_other_characters_are_kept() {
+        assert_eq!(json_string("it's é ☃"), "\"it's é ☃\"");
+    }
+}
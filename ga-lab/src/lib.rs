@@ -0,0 +1,30 @@
+//! The building blocks the cs415 experiments share: the DNA strand genotype, its fitness
+//! functions, bitstring benchmarks (e.g. NK landscapes) and what the theory expects on some of
+//! them, the `Problem` trait the experiments are generic over, a batch runner, the statistics, a
+//! hall of fame of the best strands, FASTA files of the strands, JSON strings, the plotting of the
+//! runs in a configurable style and (with the `tui` and `serve` features) a live dashboard of the
+//! runs in the terminal and over HTTP. The binaries of the projects are thin front-ends over this crate.
+//!
+//! On wasm32 (see the wasm-demo) the plotting, which draws into files, and the batch runner, which
+//! runs on threads, are left out.
+
+pub mod batch;
+#[cfg(feature = "tui")]
+pub mod dashboard;
+pub mod fasta;
+pub mod fitness;
+pub mod genome;
+pub mod hall_of_fame;
+pub mod json;
+#[cfg(any(feature = "tui", feature = "serve"))]
+pub mod monitor;
+pub mod nk;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod plot;
+pub mod problem;
+#[cfg(feature = "serve")]
+pub mod server;
+pub mod stats;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod style;
+pub mod theory;
@@ -0,0 +1,171 @@
+// The live state of the runs of the experiments, for the dashboard and the metrics server: the
+// current batch, the best fitness of each generation of each of its runs so far and the lines of
+// the progress reported by `report!`. Nothing is recorded until one of them enables it.
+
+use std::{
+    collections::BTreeMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+// A run of the current batch
+pub(crate) struct Run {
+    pub label: String,
+    pub best_fitness: Vec<u64>, // of each generation so far
+    pub finished: bool,
+}
+
+pub(crate) struct Monitor {
+    pub start: Instant,
+    pub batch_name: String,
+    pub batch_number: u32,
+    pub batch_start: Instant,
+    pub batch_runs: u64,
+    pub optimum: u64,
+    pub runs: BTreeMap<u64, Run>, // by thread number
+    pub log: Vec<String>,
+    pub quiet: bool, // whether the reported lines are held back from std out
+}
+
+impl Monitor {
+    pub fn finished_runs(&self) -> u64 {
+        self.runs.values().filter(|run| run.finished).count() as u64
+    }
+
+    // The time left for the rest of the runs of the batch at the rate the runs finished so far.
+    // The runs of a batch run side by side, so it is rough until a good share of them has finished.
+    pub fn eta(&self) -> Option<Duration> {
+        eta(
+            self.batch_start.elapsed(),
+            self.finished_runs(),
+            self.batch_runs,
+        )
+    }
+}
+
+static MONITOR: Mutex<Option<Monitor>> = Mutex::new(None);
+
+// Starts recording the runs, if no one has yet
+pub(crate) fn enable() {
+    MONITOR.lock().unwrap().get_or_insert_with(|| Monitor {
+        start: Instant::now(),
+        batch_name: String::new(),
+        batch_number: 0,
+        batch_start: Instant::now(),
+        batch_runs: 0,
+        optimum: 0,
+        runs: BTreeMap::new(),
+        log: vec![],
+        quiet: false,
+    });
+}
+
+// Holds the reported lines back from std out (or stops to), returning the ones held back so far
+#[cfg(feature = "tui")]
+pub(crate) fn set_quiet(quiet: bool) -> Vec<String> {
+    match MONITOR.lock().unwrap().as_mut() {
+        Some(monitor) => {
+            let held_back = if monitor.quiet {
+                monitor.log.clone()
+            } else {
+                vec![]
+            };
+            monitor.quiet = quiet;
+            held_back
+        }
+        None => vec![],
+    }
+}
+
+// Looks at the state of the runs, if it is recorded
+pub(crate) fn with<T>(f: impl FnOnce(&Monitor) -> T) -> Option<T> {
+    MONITOR.lock().unwrap().as_ref().map(f)
+}
+
+// Records a reported line, returns whether it is held back from std out
+pub fn log(line: &str) -> bool {
+    match MONITOR.lock().unwrap().as_mut() {
+        Some(monitor) => {
+            monitor.log.push(line.to_string());
+            monitor.quiet
+        }
+        None => false,
+    }
+}
+
+// Starts following a batch of the given number of runs, forgetting the runs of the previous one
+pub fn start_batch(name: &str, runs: u64, optimum: u64) {
+    if let Some(monitor) = MONITOR.lock().unwrap().as_mut() {
+        monitor.batch_name = name.to_string();
+        monitor.batch_number += 1;
+        monitor.batch_start = Instant::now();
+        monitor.batch_runs = runs;
+        monitor.optimum = optimum;
+        monitor.runs.clear();
+    }
+}
+
+// Adds the best fitness of the next generation of the run in the given thread
+pub fn update_run(thread_number: u64, label: &str, best_fitness: u32) {
+    if let Some(monitor) = MONITOR.lock().unwrap().as_mut() {
+        run(monitor, thread_number, label)
+            .best_fitness
+            .push(best_fitness as u64);
+    }
+}
+
+pub fn finish_run(thread_number: u64, label: &str) {
+    if let Some(monitor) = MONITOR.lock().unwrap().as_mut() {
+        run(monitor, thread_number, label).finished = true;
+    }
+}
+
+// Forgets the generations of the run in the given thread, which starts over
+pub fn retry_run(thread_number: u64) {
+    if let Some(monitor) = MONITOR.lock().unwrap().as_mut() {
+        monitor.runs.remove(&thread_number);
+    }
+}
+
+fn run<'a>(monitor: &'a mut Monitor, thread_number: u64, label: &str) -> &'a mut Run {
+    monitor.runs.entry(thread_number).or_insert_with(|| Run {
+        label: label.to_string(),
+        best_fitness: vec![],
+        finished: false,
+    })
+}
+
+fn eta(elapsed: Duration, finished: u64, total: u64) -> Option<Duration> {
+    (finished > 0).then(|| elapsed.mul_f64(total.saturating_sub(finished) as f64 / finished as f64))
+}
+
+// Shrinks the values to at most `width` by taking the last value of each of as many even chunks
+pub(crate) fn sampled(values: &[u64], width: usize) -> Vec<u64> {
+    if values.len() <= width {
+        return values.to_vec();
+    }
+    (1..=width)
+        .map(|i| values[i * values.len() / width - 1])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_eta_is_unknown_until_a_run_finished() {
+        let elapsed = Duration::from_secs(60);
+
+        assert_eq!(eta(elapsed, 0, 8), None);
+        assert_eq!(eta(elapsed, 2, 8), Some(Duration::from_secs(180)));
+        assert_eq!(eta(elapsed, 8, 8), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn long_runs_are_sampled_down_to_the_width() {
+        assert_eq!(sampled(&[1, 2, 3], 5), vec![1, 2, 3]);
+        assert_eq!(sampled(&[1, 2, 3, 4, 5, 6], 3), vec![2, 4, 6]);
+        assert_eq!(sampled(&[1, 2, 3, 4, 5, 6, 7], 2), vec![3, 7]);
+    }
+}
@@ -0,0 +1,187 @@
+// Kauffman's NK landscapes, benchmarks of tunable ruggedness: each of the N bits of a bitstring
+// contributes a random value that depends on the bit and on its K neighbours to the right (the
+// bitstring wraps around). K = 0 is a smooth landscape of a single peak, the larger K the more
+// bits a bit interacts with and the more local optima there are.
+//
+// The contributions are drawn from a seed, so a landscape can be built again from its N, K and
+// seed. As the neighbourhoods are adjacent, the best and worst bitstrings are found exactly by
+// dynamic programming over the last K bits.
+
+use crate::genome::BitString;
+use genevo::prelude::FitnessFunction;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::sync::Arc;
+
+// The highest contribution of a single bit, the contributions are drawn from 0..=NK_MAX_CONTRIBUTION
+pub const NK_MAX_CONTRIBUTION: usize = 1000;
+
+// The largest K whose best bitstring can still be found in reasonable time: the dynamic
+// programming takes 2^(2K + 1) steps per bit
+pub const NK_MAX_K: usize = 10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NkLandscape {
+    n: usize,
+    k: usize,
+    // The contribution of each bit by the value of the bit and its K neighbours, the bit itself
+    // being the lowest bit of the index
+    tables: Vec<Vec<usize>>,
+}
+
+impl NkLandscape {
+    pub fn new(n: usize, k: usize, seed: u64) -> Result<Self, String> {
+        if k >= n {
+            return Err(format!("K must be less than N = {}, got {}", n, k));
+        }
+        if k > NK_MAX_K {
+            return Err(format!("K must be at most {}, got {}", NK_MAX_K, k));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let tables = (0..n)
+            .map(|_| {
+                (0..1 << (k + 1))
+                    .map(|_| rng.gen_range(0..=NK_MAX_CONTRIBUTION))
+                    .collect()
+            })
+            .collect();
+        Ok(NkLandscape { n, k, tables })
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    // The sum of the contributions of the bits of a bitstring of N bits
+    pub fn value_of(&self, bits: &[bool]) -> usize {
+        (0..self.n)
+            .map(|i| {
+                let window = (0..=self.k)
+                    .filter(|d| bits[(i + d) % self.n])
+                    .fold(0, |window, d| window | 1 << d);
+                self.tables[i][window]
+            })
+            .sum()
+    }
+
+    // The value of the best bitstring
+    pub fn highest_value(&self) -> usize {
+        self.extreme_value(usize::max)
+    }
+
+    // The value of the worst bitstring
+    pub fn lowest_value(&self) -> usize {
+        self.extreme_value(usize::min)
+    }
+
+    // The best value of a bitstring by `better`. For each of the first K bits, the bits are
+    // chosen one after the other, keeping the best value of each value of the last K bits; the
+    // contribution of a bit is known as soon as the K bits after it are chosen.
+    fn extreme_value(&self, better: fn(usize, usize) -> usize) -> usize {
+        let (n, k) = (self.n, self.k);
+        let window_mask = (1 << (k + 1)) - 1;
+        let mut extreme = None;
+        for prefix in 0..1usize << k {
+            // The best value of the contributions so far by the last K bits, the oldest bit lowest
+            let mut values: Vec<Option<usize>> = vec![None; 1 << k];
+            values[prefix] = Some(0);
+            for i in 0..n - k {
+                let mut next = vec![None; 1 << k];
+                for (last, value) in values.iter().enumerate() {
+                    let Some(value) = value else { continue };
+                    for bit in 0..2 {
+                        let window = last | bit << k;
+                        let value = value + self.tables[i][window];
+                        let state = window >> 1;
+                        next[state] = Some(next[state].map_or(value, |v| better(v, value)));
+                    }
+                }
+                values = next;
+            }
+            // The last K bits wrap around onto the prefix
+            for (last, value) in values.iter().enumerate() {
+                let Some(value) = value else { continue };
+                let bits = last | prefix << k;
+                let value = (0..k)
+                    .map(|t| self.tables[n - k + t][(bits >> t) & window_mask])
+                    .fold(*value, |sum, contribution| sum + contribution);
+                extreme = Some(extreme.map_or(value, |v| better(v, value)));
+            }
+        }
+        extreme.unwrap()
+    }
+}
+
+// The NK landscape fitness function for bitstrings, shared by the clones of a problem. The
+// extreme values are found once, as finding them takes a while for larger K.
+#[derive(Clone, Debug)]
+pub struct NkFitnessCalculator {
+    landscape: Arc<NkLandscape>,
+    bounds: (usize, usize),
+}
+
+impl NkFitnessCalculator {
+    pub fn new(landscape: Arc<NkLandscape>) -> Self {
+        let bounds = (landscape.lowest_value(), landscape.highest_value());
+        NkFitnessCalculator { landscape, bounds }
+    }
+
+    pub fn landscape(&self) -> &NkLandscape {
+        &self.landscape
+    }
+}
+
+impl FitnessFunction<BitString, usize> for NkFitnessCalculator {
+    fn fitness_of(&self, bits: &BitString) -> usize {
+        self.landscape.value_of(bits)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.bounds.1
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        self.bounds.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // All bitstrings of n bits
+    fn all_bitstrings(n: usize) -> Vec<BitString> {
+        (0..1usize << n)
+            .map(|x| (0..n).map(|i| x >> i & 1 == 1).collect())
+            .collect()
+    }
+
+    #[test]
+    fn landscapes_are_rebuilt_from_their_seed() {
+        assert_eq!(NkLandscape::new(8, 2, 7), NkLandscape::new(8, 2, 7));
+        assert_ne!(NkLandscape::new(8, 2, 7), NkLandscape::new(8, 2, 8));
+        assert!(NkLandscape::new(4, 4, 7).is_err());
+        assert!(NkLandscape::new(64, NK_MAX_K + 1, 7).is_err());
+    }
+
+    #[test]
+    fn extreme_values_are_those_of_the_best_and_worst_bitstrings() {
+        for k in 0..5 {
+            let landscape = NkLandscape::new(9, k, k as u64).unwrap();
+            let values = all_bitstrings(9)
+                .iter()
+                .map(|bits| landscape.value_of(bits))
+                .collect::<Vec<_>>();
+
+            assert_eq!(landscape.highest_value(), *values.iter().max().unwrap());
+            assert_eq!(landscape.lowest_value(), *values.iter().min().unwrap());
+        }
+    }
+}
@@ -0,0 +1,942 @@
+use crate::{
+    batch::{
+        mean_and_variance_per_gen, padded_run, plateau_gen, run_length_distribution,
+        success_rate_per_gen, DataSetWithLables, NormalizedDataSetWithLables, RunsWithLables,
+        TimedDataSetWithLables,
+    },
+    genome::{AlleleFrequencies, Genome, Nucleotide, NUCLEOTIDES},
+    problem::Problem,
+    style::{chart_style, chart_text},
+};
+use plotters::prelude::*;
+
+// The share of the fitness range within which a line counts as plateaued on its final fitness
+const PLATEAU_TOLERANCE: f64 = 0.02;
+// A graph whose lines all plateaued within this share of its generations gets a zoomed graph
+const ZOOM_SHARE: f64 = 0.5;
+
+// Something drawn on a graph of fitness over the generations besides its lines
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation {
+    // A vertical line at a generation (e.g. where a run restarted or a parameter changed), in the
+    // color of the line of the dataset with the given index, or in black for none
+    VerticalLine {
+        gen: u32,
+        line: Option<usize>,
+    },
+    // A horizontal line at a fitness, e.g. at the highest fitness of the problem
+    HorizontalLine {
+        fitness: u32,
+    },
+    // A dashed line of the fitness at each generation from the first, e.g. what the theory
+    // expects of a simple algorithm, listed in the legend by its label
+    Curve {
+        fitness: Vec<f64>,
+        label: String,
+    },
+    // A call-out whose upper left corner is at a generation and fitness
+    Text {
+        gen: u32,
+        fitness: u32,
+        text: String,
+    },
+}
+
+// A vertical line at each of the generations of each line of a dataset, in the color of the line
+pub fn vertical_lines(gens_of_lines: &[Vec<u32>]) -> Vec<Annotation> {
+    gens_of_lines
+        .iter()
+        .enumerate()
+        .flat_map(|(line, gens)| {
+            gens.iter().map(move |gen| Annotation::VerticalLine {
+                gen: *gen,
+                line: Some(line),
+            })
+        })
+        .collect()
+}
+
+// Plots labeled lines of fitness over the generations together with the given annotations. The
+// annotations outside of the graph are left out, and a line drawn several times is drawn darker.
+pub fn generate_graph<P: Problem>(
+    problem: &P,
+    graph_name: &str,
+    dataset: DataSetWithLables,
+    annotations: &[Annotation],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    generate_graph_in_bounds(
+        graph_name,
+        problem.fitness_bounds(),
+        dataset,
+        annotations,
+        out_file,
+    )
+}
+
+// Like `generate_graph`, for lines whose problem is only known by its (lowest, highest) fitness.
+// If all the lines plateaued early, e.g. when one long run stretches the graph, a graph zoomed in
+// on the generations up to their plateau goes next to it.
+pub fn generate_graph_in_bounds(
+    graph_name: &str,
+    (lowest_fitness, highest_fitness): (usize, usize),
+    mut dataset: DataSetWithLables,
+    annotations: &[Annotation],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Store the gen at which each simulation finished
+    let gens_list = dataset
+        .iter()
+        .map(|d| d.1.len() as u32)
+        .collect::<Vec<u32>>();
+    // And the max gens any simulation took (width of graph)
+    let gens_max = *gens_list.iter().max().unwrap();
+
+    // Normalize the length of each of the lines in the dataset
+    for (_, d) in dataset.iter_mut() {
+        *d = padded_run(d, gens_max as usize);
+    }
+
+    let bounds = (lowest_fitness as u32, highest_fitness as u32);
+    let lines = (&dataset, &gens_list[..]);
+    draw_fitness_graph(graph_name, bounds, lines, annotations, gens_max, out_file)?;
+
+    let tolerance = PLATEAU_TOLERANCE * (highest_fitness - lowest_fitness) as f64;
+    let plateau = dataset
+        .iter()
+        .map(|(_, d)| plateau_gen(d, tolerance))
+        .max()
+        .unwrap_or_default() as u32;
+    if (plateau as f64) < ZOOM_SHARE * gens_max as f64 {
+        let gens_shown = ((plateau as f64 * 1.2).ceil() as u32).max(2);
+        draw_fitness_graph(
+            &format!("{} (first {} gens)", graph_name, gens_shown),
+            bounds,
+            lines,
+            annotations,
+            gens_shown,
+            &zoomed_out_file(out_file),
+        )?;
+    }
+
+    Ok(())
+}
+
+// Draws the lines of fitness padded to the same length, labeled with the gens each one took, up
+// to the given gen
+fn draw_fitness_graph(
+    graph_name: &str,
+    (lowest, highest): (u32, u32),
+    (dataset, gens_list): (&DataSetWithLables, &[u32]),
+    annotations: &[Annotation],
+    gens_max: u32,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(1..gens_max, lowest..highest)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(6)
+        .x_labels(16)
+        .y_desc("fitness")
+        .x_desc("gens")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw the annotation lines behind the lines of the dataset
+    for annotation in annotations {
+        let (points, color) = match annotation {
+            Annotation::VerticalLine { gen, line } if (1..=gens_max).contains(gen) => (
+                vec![(*gen, lowest), (*gen, highest)],
+                line.map_or(style.foreground().mix(0.4), |line| {
+                    style.line_color(line).mix(0.4)
+                }),
+            ),
+            Annotation::HorizontalLine { fitness } if (lowest..=highest).contains(fitness) => (
+                vec![(1, *fitness), (gens_max, *fitness)],
+                style.foreground().mix(0.4),
+            ),
+            _ => continue,
+        };
+        chart.draw_series(std::iter::once(PathElement::new(
+            points,
+            color.stroke_width(style.line_width.saturating_sub(1).max(1)),
+        )))?;
+    }
+
+    // Draw each line in the dataset
+    for (idx, (label, data)) in dataset.iter().enumerate() {
+        let data = data.iter().take(gens_max as usize).enumerate();
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(
+                data.map(|(x, y)| (x as u32 + 1, *y)),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(format!("{} (gens: {})", label.clone(), gens_list[idx]))
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    // The dashed curves over them, cut to the graph
+    for annotation in annotations {
+        if let Annotation::Curve { fitness, label } = annotation {
+            let color = style.foreground().mix(0.7);
+            let points = fitness
+                .iter()
+                .zip(1..=gens_max)
+                .map(|(fitness, gen)| {
+                    let fitness = fitness.round().clamp(lowest as f64, highest as f64);
+                    (gen, fitness as u32)
+                })
+                .collect::<Vec<_>>();
+            chart
+                .draw_series(DashedLineSeries::new(
+                    points,
+                    10,
+                    6,
+                    color.stroke_width(style.line_width),
+                ))?
+                .label(label.clone())
+                .legend(move |(x, y)| {
+                    PathElement::new(
+                        vec![(x, y), (x + 20, y)],
+                        color.stroke_width(style.line_width),
+                    )
+                });
+        }
+    }
+
+    // And the call-outs in front of them
+    for annotation in annotations {
+        if let Annotation::Text { gen, fitness, text } = annotation {
+            if (1..=gens_max).contains(gen) && (lowest..=highest).contains(fitness) {
+                chart.draw_series(std::iter::once(Text::new(
+                    text.clone(),
+                    (*gen, *fitness),
+                    chart_text(20),
+                )))?;
+            }
+        }
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Like `generate_graph_in_bounds`, for lines over the wall-clock time instead of the generations
+// (see `average_runs_over_time`). Of the annotations only the horizontal lines are drawn, the
+// others are placed at generations.
+pub fn generate_wall_time_graph(
+    graph_name: &str,
+    (lowest_fitness, highest_fitness): (usize, usize),
+    dataset: TimedDataSetWithLables,
+    annotations: &[Annotation],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let secs_max = dataset
+        .iter()
+        .flat_map(|(_, line)| line.last().map(|(secs, _)| *secs))
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+    let (lowest, highest) = (lowest_fitness as f64, highest_fitness as f64);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0.0..secs_max, lowest..highest)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(6)
+        .x_labels(16)
+        .y_desc("fitness")
+        .x_desc("seconds")
+        .y_label_formatter(&|y| format!("{:.0}", y))
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw the horizontal lines behind the lines of the dataset
+    for annotation in annotations {
+        if let Annotation::HorizontalLine { fitness } = annotation {
+            let fitness = *fitness as f64;
+            if (lowest..=highest).contains(&fitness) {
+                chart.draw_series(std::iter::once(PathElement::new(
+                    vec![(0.0, fitness), (secs_max, fitness)],
+                    style
+                        .foreground()
+                        .mix(0.4)
+                        .stroke_width(style.line_width.saturating_sub(1).max(1)),
+                )))?;
+            }
+        }
+    }
+
+    // Draw each line in the dataset
+    for (idx, (label, line)) in dataset.iter().enumerate() {
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(
+                line.iter().copied(),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(label.clone())
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Like `generate_graph`, but for fitness normalized to 0..1, so the lines of problems with
+// different fitness bounds share the y axis
+pub fn generate_normalized_graph(
+    graph_name: &str,
+    dataset: NormalizedDataSetWithLables,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dataset = dataset
+        .into_iter()
+        .map(|(label, data)| (format!("{} (gens: {})", label, data.len()), data))
+        .collect();
+    generate_unit_graph(graph_name, "fitness (normalized)", dataset, out_file)
+}
+
+// Plots the share of the runs of each parameter set that found the optimum by each generation
+// (the ECDF of their convergence generations). Unlike the averaged fitness, it does not depend on
+// how far the runs that never converged got.
+pub fn generate_success_rate_graph(
+    graph_name: &str,
+    runs_list: &RunsWithLables,
+    optimum: usize,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dataset = runs_list
+        .iter()
+        .map(|(label, runs)| {
+            let rates = success_rate_per_gen(runs, optimum);
+            let label = format!(
+                "{} (converged: {:.0}%)",
+                label,
+                rates.last().copied().unwrap_or_default() * 100.0
+            );
+            (label, rates)
+        })
+        .collect();
+    generate_unit_graph(graph_name, "share of runs converged", dataset, out_file)
+}
+
+// Plots the run-length distribution of the runs of each solver or parameter set, from how long (in
+// the unit of `x_desc`) each run took to reach a target, if it did: the share of the runs that
+// reached it by each length. Unlike the averaged fitness, it shows how the runs spread.
+pub fn generate_run_length_graph(
+    graph_name: &str,
+    x_desc: &str,
+    dataset: &[(String, Vec<Option<f64>>)],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let lengths_max = dataset
+        .iter()
+        .flat_map(|(_, lengths)| lengths.iter().flatten().copied())
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0.0..lengths_max * 1.05, 0.0..1.0)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(6)
+        .x_labels(16)
+        .y_desc("share of runs that reached the target")
+        .x_desc(x_desc)
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each distribution as a step up at each run that reached the target, up to the right
+    for (idx, (label, lengths)) in dataset.iter().enumerate() {
+        let distribution = run_length_distribution(lengths);
+        let mut steps = vec![(0.0, 0.0)];
+        for (length, share) in distribution.iter() {
+            steps.push((*length, steps[steps.len() - 1].1));
+            steps.push((*length, *share));
+        }
+        let reached = steps[steps.len() - 1].1;
+        steps.push((lengths_max * 1.05, reached));
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(steps, color.stroke_width(style.line_width)))?
+            .label(format!("{} (reached: {:.0}%)", label, reached * 100.0))
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerRight)
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Draws labeled lines of values in 0..1 over the generations
+fn generate_unit_graph(
+    graph_name: &str,
+    y_desc: &str,
+    mut dataset: NormalizedDataSetWithLables,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // The max gens any line covers (width of graph)
+    let gens_max = dataset.iter().map(|d| d.1.len() as u32).max().unwrap();
+
+    // Normalize the length of each of the lines in the dataset
+    for (_, d) in dataset.iter_mut() {
+        d.resize(gens_max as usize, d.last().copied().unwrap_or_default());
+    }
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(1..gens_max, 0.0..1.0)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(6)
+        .x_labels(16)
+        .y_desc(y_desc)
+        .x_desc("gens")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each line in the dataset
+    for (idx, (label, data)) in dataset.iter().enumerate() {
+        let data = data.iter().enumerate();
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(
+                data.map(|(x, y)| (x as u32 + 1, *y)),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(label.clone())
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// The color a nucleotide is drawn with
+pub fn nucleotide_color(nucleotide: &Nucleotide) -> RGBColor {
+    match nucleotide {
+        Nucleotide::A => RGBColor(230, 75, 60),
+        Nucleotide::C => RGBColor(60, 120, 220),
+        Nucleotide::T => RGBColor(240, 190, 40),
+        Nucleotide::G => RGBColor(60, 170, 90),
+    }
+}
+
+// Draws the best strand at (up to `samples`) sampled generations as colored strips,
+// stacked from the first generation at the top to the last one at the bottom
+pub fn generate_evolution_graph(
+    graph_name: &str,
+    best_genomes: &[Genome],
+    samples: usize,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gens = best_genomes.len() as u32;
+    let strand_size = best_genomes.first().map_or(0, Vec::len) as u32;
+    let step = (gens as usize).div_ceil(samples).max(1);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart (generations grow downwards)
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(0..strand_size, (gens + 1)..1)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .y_labels(8)
+        .x_labels(10)
+        .y_desc("gens")
+        .x_desc("locus")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each sampled strand as a strip reaching down to the next sample
+    chart.draw_series(best_genomes.iter().enumerate().step_by(step).flat_map(
+        |(gen, genome)| {
+            let top = gen as u32 + 1;
+            let bottom = (top + step as u32).min(gens + 1);
+            genome.iter().enumerate().map(move |(locus, nucleotide)| {
+                Rectangle::new(
+                    [(locus as u32, top), (locus as u32 + 1, bottom)],
+                    nucleotide_color(nucleotide).filled(),
+                )
+            })
+        },
+    ))?;
+
+    // Legend entries for the nucleotides
+    for nucleotide in NUCLEOTIDES {
+        let color = nucleotide_color(&nucleotide);
+        chart
+            .draw_series(std::iter::empty::<Rectangle<(u32, u32)>>())?
+            .label(format!("{:?}", nucleotide))
+            .legend(move |(x, y)| Rectangle::new([(x, y - 8), (x + 20, y + 8)], color.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Draws the dominant allele at each locus (y) and (up to `samples`) sampled generations (x) of a
+// run, colored by the nucleotide and fading to the background as its frequency in the population
+// drops to 1/4
+pub fn generate_allele_heatmap(
+    graph_name: &str,
+    frequencies: &[AlleleFrequencies],
+    samples: usize,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gens = frequencies.len() as u32;
+    let strand_size = frequencies.first().map_or(0, Vec::len) as u32;
+    let step = (gens as usize).div_ceil(samples).max(1);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(1..(gens + 1), 0..strand_size)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .y_labels(10)
+        .x_labels(16)
+        .y_desc("locus")
+        .x_desc("gens")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each sampled generation as a column reaching to the next sample
+    let RGBColor(to_r, to_g, to_b) = style.background();
+    chart.draw_series(
+        frequencies
+            .iter()
+            .enumerate()
+            .step_by(step)
+            .flat_map(|(gen, loci)| {
+                let left = gen as u32 + 1;
+                let right = (left + step as u32).min(gens + 1);
+                loci.iter()
+                    .enumerate()
+                    .map(move |(locus, (nucleotide, frequency))| {
+                        // A frequency of 1/4 is no better than chance, 1 is fixation
+                        let strength = ((frequency - 0.25) / 0.75).clamp(0.0, 1.0);
+                        let RGBColor(r, g, b) = nucleotide_color(nucleotide);
+                        let fade = |c: u8, to: u8| {
+                            (to as f64 + (c as f64 - to as f64) * strength).round() as u8
+                        };
+                        Rectangle::new(
+                            [(left, locus as u32), (right, locus as u32 + 1)],
+                            RGBColor(fade(r, to_r), fade(g, to_g), fade(b, to_b)).filled(),
+                        )
+                    })
+            }),
+    )?;
+
+    // Legend entries for the nucleotides
+    for nucleotide in NUCLEOTIDES {
+        let color = nucleotide_color(&nucleotide);
+        chart
+            .draw_series(std::iter::empty::<Rectangle<(u32, u32)>>())?
+            .label(format!("{:?}", nucleotide))
+            .legend(move |(x, y)| Rectangle::new([(x, y - 8), (x + 20, y + 8)], color.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Animates the histogram of the fitness of the population at (up to `frames`) sampled generations
+// of a run into a GIF, showing each generation for `frame_delay` milliseconds. The axes are the
+// same in every frame, so the distribution is seen moving towards the highest fitness.
+pub fn generate_fitness_animation(
+    graph_name: &str,
+    (lowest_fitness, highest_fitness): (usize, usize),
+    fitness_values: &[Vec<usize>],
+    frames: usize,
+    frame_delay: u32,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let step = fitness_values.len().div_ceil(frames).max(1);
+    let population_size = fitness_values.iter().map(Vec::len).max().unwrap_or(0);
+
+    // Drawing root, each frame is drawn on it and presented in turn
+    let style = chart_style();
+    let root =
+        BitMapBackend::gif(out_file, (style.width, style.height), frame_delay)?.into_drawing_area();
+
+    for (gen, values) in fitness_values.iter().enumerate().step_by(step) {
+        root.fill(&style.background())?;
+
+        // Chart
+        let mut chart = ChartBuilder::on(&root)
+            .caption(format!("{} (gen {})", graph_name, gen + 1), chart_text(50))
+            .margin(10)
+            .x_label_area_size(60)
+            .y_label_area_size(80)
+            .build_cartesian_2d(
+                (lowest_fitness..highest_fitness + 1).into_segmented(),
+                0..population_size,
+            )?;
+
+        // Mesh configuration
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .y_labels(10)
+            .x_labels(16)
+            .y_desc("individuals")
+            .x_desc("fitness")
+            .label_style(chart_text(25))
+            .bold_line_style(style.foreground().mix(0.2))
+            .light_line_style(style.foreground().mix(0.1))
+            .axis_style(style.foreground())
+            .draw()?;
+
+        chart.draw_series(
+            Histogram::vertical(&chart)
+                .style(style.line_color(0).filled())
+                .margin(1)
+                .data(values.iter().map(|fitness| (*fitness, 1))),
+        )?;
+
+        root.present()?;
+    }
+
+    Ok(())
+}
+
+// Plots the per-generation difference between each setting's mean curve and the default's.
+// The shaded band around each difference is its 95% confidence interval (Welch), so a
+// setting is significantly better or worse from the generation on where its band leaves 0.
+pub fn generate_differential_graph(
+    graph_name: &str,
+    runs_list: &RunsWithLables,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let default_runs = match runs_list
+        .iter()
+        .find(|(label, _)| label.ends_with("(default)"))
+    {
+        Some((_, runs)) => runs,
+        None => return Ok(()), // nothing to compare against
+    };
+
+    // The width of the graph is the max gens any simulation took
+    let gens_max = runs_list
+        .iter()
+        .flat_map(|(_, runs)| runs.iter().map(|r| r.len()))
+        .max()
+        .unwrap();
+    let default_stats = mean_and_variance_per_gen(default_runs, gens_max);
+
+    // Difference to the default and the half width of its confidence band at each generation
+    let diffs_list = runs_list
+        .iter()
+        .filter(|(label, _)| !label.ends_with("(default)"))
+        .map(|(label, runs)| {
+            let diffs = mean_and_variance_per_gen(runs, gens_max)
+                .iter()
+                .zip(default_stats.iter())
+                .map(|((mean, var), (d_mean, d_var))| {
+                    let std_err =
+                        (var / runs.len() as f64 + d_var / default_runs.len() as f64).sqrt();
+                    (mean - d_mean, 1.96 * std_err)
+                })
+                .collect::<Vec<(f64, f64)>>();
+            (label.clone(), diffs)
+        })
+        .collect::<Vec<_>>();
+
+    let y_max = diffs_list
+        .iter()
+        .flat_map(|(_, d)| {
+            d.iter()
+                .map(|(diff, band)| (diff + band).abs().max((diff - band).abs()))
+        })
+        .fold(1.0, f64::max)
+        .ceil();
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(1..gens_max as u32, -y_max..y_max)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(6)
+        .x_labels(16)
+        .y_desc("fitness - default fitness")
+        .x_desc("gens")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // The zero line: no difference to the default
+    chart.draw_series(LineSeries::new(
+        (1..gens_max as u32).map(|x| (x, 0.0)),
+        style.foreground().stroke_width(1),
+    ))?;
+
+    // Draw each difference with its shaded significance band
+    for (idx, (label, diffs)) in diffs_list.iter().enumerate() {
+        let color = style.line_color(idx).mix(0.6);
+
+        let band = diffs
+            .iter()
+            .enumerate()
+            .map(|(x, (diff, band))| (x as u32 + 1, diff + band))
+            .chain(
+                diffs
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(x, (diff, band))| (x as u32 + 1, diff - band)),
+            )
+            .collect::<Vec<(u32, f64)>>();
+        chart.draw_series(std::iter::once(Polygon::new(
+            band,
+            style.line_color(idx).mix(0.15).filled(),
+        )))?;
+
+        chart
+            .draw_series(LineSeries::new(
+                diffs
+                    .iter()
+                    .enumerate()
+                    .map(|(x, (diff, _))| (x as u32 + 1, *diff)),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(label.clone())
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// The output path of the differential graph that accompanies a sweep's graph
+pub fn differential_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_diff.png")
+}
+
+// The output path of the success rate graph that accompanies a sweep's graph
+pub fn success_rate_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_success.png")
+}
+
+// The output path of the graph zoomed in on the generations before all lines of a graph plateaued
+pub fn zoomed_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_zoom.png")
+}
+
+// The output path of the graph over the wall-clock time that accompanies a graph over the
+// generations
+pub fn wall_time_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_time.png")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_vertical_lines_take_the_index_of_their_line() {
+        assert_eq!(
+            vertical_lines(&[vec![3, 5], vec![], vec![3]]),
+            vec![
+                Annotation::VerticalLine {
+                    gen: 3,
+                    line: Some(0)
+                },
+                Annotation::VerticalLine {
+                    gen: 5,
+                    line: Some(0)
+                },
+                Annotation::VerticalLine {
+                    gen: 3,
+                    line: Some(2)
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,890 @@
+use crate::{
+    fasta::{is_fasta, parse_fasta},
+    fitness::{
+        AlignmentFitnessCalculator, AlignmentScores, ClustersOf4FitnessCalculator,
+        DeceptiveTrapsFitnessCalculator, GcContentFitnessCalculator, LeadingOnesFitnessCalculator,
+        MotifFitnessCalculator, OneMaxFitnessCalculator, RoyalRoadFitnessCalculator,
+    },
+    genome::{
+        parse_bits, parse_strand, render_bits, render_strand, Nucleotide, Phenome, NUCLEOTIDES,
+    },
+    nk::{NkFitnessCalculator, NkLandscape},
+};
+use genevo::{
+    operator::{
+        prelude::{RandomValueMutation, SelfAdaptive},
+        GeneticOperator, MutationOp,
+    },
+    prelude::*,
+};
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+use std::{fmt::Debug, fs::read_to_string, hash::Hash, sync::Arc};
+
+// A benchmark whose strands of alleles are evolved. The GA, the baselines, the batch runner and
+// the plotting are generic over it, so a new benchmark only needs an implementation of this trait.
+pub trait Problem: Clone + Debug + Send + Sync {
+    // A single gene of a strand
+    type Allele: Clone + Debug + Eq + Hash + Send + Sync + RandomValueMutation;
+    // The fitness function that is maximized
+    type Fitness: FitnessFunction<Strand<Self>, usize> + Debug + Sync;
+
+    fn fitness_function(&self) -> Self::Fitness;
+
+    // The number of alleles of each strand
+    fn strand_size(&self) -> usize;
+
+    // A random allele, the building block of random strands; there must be at least two alleles
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Allele;
+
+    // All alleles, in the order the weights of a biased initialization are given in
+    fn alleles(&self) -> Vec<Self::Allele>;
+
+    // The bounds passed on to `RandomValueMutation` when mutating an allele
+    fn allele_bounds(&self) -> (Self::Allele, Self::Allele);
+
+    // How do the genes of the genotype show up in the phenotype
+    fn render(&self, strand: &[Self::Allele]) -> Phenome;
+
+    // The strand a phenotype has been rendered from, None if it is not a strand of this problem
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>>;
+
+    // The lowest and highest possible fitness, every GA run has to reach the highest
+    fn fitness_bounds(&self) -> (usize, usize) {
+        let fitness = self.fitness_function();
+        (
+            fitness.lowest_possible_fitness(),
+            fitness.highest_possible_fitness(),
+        )
+    }
+}
+
+// The genotype of a problem
+pub type Strand<P> = Vec<<P as Problem>::Allele>;
+
+// Find DNA strands made up of as many clusters of 4 identical nucleotides as possible
+#[derive(Clone, Debug)]
+pub struct ClustersOf4 {
+    strand_size: usize,
+}
+
+impl ClustersOf4 {
+    pub fn new(strand_size: usize) -> Self {
+        assert_eq!(
+            strand_size % 4,
+            0,
+            "the strand size must be a multiple of 4"
+        );
+        ClustersOf4 { strand_size }
+    }
+}
+
+impl Problem for ClustersOf4 {
+    type Allele = Nucleotide;
+    type Fitness = ClustersOf4FitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        ClustersOf4FitnessCalculator::new(self.strand_size)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<Nucleotide> {
+        NUCLEOTIDES.to_vec()
+    }
+
+    fn allele_bounds(&self) -> (Nucleotide, Nucleotide) {
+        (Nucleotide::A, Nucleotide::A)
+    }
+
+    fn render(&self, strand: &[Nucleotide]) -> Phenome {
+        render_strand(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_strand(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find DNA strands whose share of Gs and Cs is a target percentage
+#[derive(Clone, Debug)]
+pub struct GcContent {
+    strand_size: usize,
+    target_gc: usize, // the number of Gs and Cs, the target percentage of the strand size rounded
+}
+
+impl GcContent {
+    pub fn new(strand_size: usize, target_percent: f64) -> Result<Self, String> {
+        if !(0.0..=100.0).contains(&target_percent) {
+            return Err(format!(
+                "the target GC-content must be between 0 and 100%, got {}",
+                target_percent
+            ));
+        }
+        let target_gc = (strand_size as f64 * target_percent / 100.0).round() as usize;
+        Ok(GcContent {
+            strand_size,
+            target_gc,
+        })
+    }
+}
+
+impl Problem for GcContent {
+    type Allele = Nucleotide;
+    type Fitness = GcContentFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        GcContentFitnessCalculator::new(self.strand_size, self.target_gc)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<Nucleotide> {
+        NUCLEOTIDES.to_vec()
+    }
+
+    fn allele_bounds(&self) -> (Nucleotide, Nucleotide) {
+        (Nucleotide::A, Nucleotide::A)
+    }
+
+    fn render(&self, strand: &[Nucleotide]) -> Phenome {
+        render_strand(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_strand(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find DNA strands holding as many occurrences of a motif (e.g. "ACGT") as possible
+#[derive(Clone, Debug)]
+pub struct Motif {
+    strand_size: usize,
+    motif: Vec<Nucleotide>,
+}
+
+impl Motif {
+    pub fn new(strand_size: usize, motif: &str) -> Result<Self, String> {
+        let motif = parse_strand(motif)
+            .filter(|motif| !motif.is_empty())
+            .ok_or_else(|| format!("the motif {:?} is not a strand", motif))?;
+        if motif.len() > strand_size {
+            return Err(format!(
+                "the motif of {} nucleotides does not fit a strand of {}",
+                motif.len(),
+                strand_size
+            ));
+        }
+        Ok(Motif { strand_size, motif })
+    }
+}
+
+impl Problem for Motif {
+    type Allele = Nucleotide;
+    type Fitness = MotifFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        MotifFitnessCalculator::new(self.strand_size, self.motif.clone())
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<Nucleotide> {
+        NUCLEOTIDES.to_vec()
+    }
+
+    fn allele_bounds(&self) -> (Nucleotide, Nucleotide) {
+        (Nucleotide::A, Nucleotide::A)
+    }
+
+    fn render(&self, strand: &[Nucleotide]) -> Phenome {
+        render_strand(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_strand(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find DNA strands aligning as well as possible with a reference strand, by the score of their
+// global alignment. A nucleotide pays off only together with its neighbors, as the gaps shift
+// the rest of the alignment.
+#[derive(Clone, Debug)]
+pub struct Alignment {
+    strand_size: usize,
+    reference: Vec<Nucleotide>,
+    scores: AlignmentScores,
+}
+
+impl Alignment {
+    pub fn new(
+        strand_size: usize,
+        reference: &str,
+        scores: AlignmentScores,
+    ) -> Result<Self, String> {
+        let reference = parse_strand(reference)
+            .filter(|reference| !reference.is_empty())
+            .ok_or_else(|| format!("the reference {:?} is not a strand", reference))?;
+        if scores.match_score <= 0 || scores.mismatch >= scores.match_score || scores.gap > 0 {
+            return Err(format!(
+                "a match must score above 0 and a mismatch, a gap at most 0, got {:?}",
+                scores
+            ));
+        }
+        Ok(Alignment {
+            strand_size,
+            reference,
+            scores,
+        })
+    }
+}
+
+impl Problem for Alignment {
+    type Allele = Nucleotide;
+    type Fitness = AlignmentFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        AlignmentFitnessCalculator::new(self.strand_size, self.reference.clone(), self.scores)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<Nucleotide> {
+        NUCLEOTIDES.to_vec()
+    }
+
+    fn allele_bounds(&self) -> (Nucleotide, Nucleotide) {
+        (Nucleotide::A, Nucleotide::A)
+    }
+
+    fn render(&self, strand: &[Nucleotide]) -> Phenome {
+        render_strand(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_strand(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find the best bitstring of an NK landscape (see `nk`), whose ruggedness grows with K
+#[derive(Clone, Debug)]
+pub struct Nk {
+    fitness: NkFitnessCalculator,
+}
+
+impl Nk {
+    // The landscape of N bits interacting with K neighbours each, drawn from the seed
+    pub fn new(n: usize, k: usize, seed: u64) -> Result<Self, String> {
+        let landscape = NkLandscape::new(n, k, seed)?;
+        Ok(Nk {
+            fitness: NkFitnessCalculator::new(Arc::new(landscape)),
+        })
+    }
+}
+
+impl Problem for Nk {
+    type Allele = bool;
+    type Fitness = NkFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        self.fitness.clone()
+    }
+
+    fn strand_size(&self) -> usize {
+        self.fitness.landscape().n()
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn allele_bounds(&self) -> (bool, bool) {
+        (false, true)
+    }
+
+    fn render(&self, strand: &[bool]) -> Phenome {
+        render_bits(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_bits(phenome).filter(|strand| strand.len() == self.strand_size())
+    }
+}
+
+// Find the bitstring of all 1s by its number of 1s, the simplest of the bitstring benchmarks
+#[derive(Clone, Debug)]
+pub struct OneMax {
+    strand_size: usize,
+}
+
+impl OneMax {
+    pub fn new(strand_size: usize) -> Self {
+        OneMax { strand_size }
+    }
+}
+
+impl Problem for OneMax {
+    type Allele = bool;
+    type Fitness = OneMaxFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        OneMaxFitnessCalculator::new(self.strand_size)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn allele_bounds(&self) -> (bool, bool) {
+        (false, true)
+    }
+
+    fn render(&self, strand: &[bool]) -> Phenome {
+        render_bits(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_bits(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find the bitstring of all 1s by the number of 1s it starts with, a 1 only counting once all
+// the bits before it are 1s
+#[derive(Clone, Debug)]
+pub struct LeadingOnes {
+    strand_size: usize,
+}
+
+impl LeadingOnes {
+    pub fn new(strand_size: usize) -> Self {
+        LeadingOnes { strand_size }
+    }
+}
+
+impl Problem for LeadingOnes {
+    type Allele = bool;
+    type Fitness = LeadingOnesFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        LeadingOnesFitnessCalculator::new(self.strand_size)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn allele_bounds(&self) -> (bool, bool) {
+        (false, true)
+    }
+
+    fn render(&self, strand: &[bool]) -> Phenome {
+        render_bits(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_bits(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find the bitstring of all 1s on Holland's Royal Road, which only pays for complete blocks of 1s
+#[derive(Clone, Debug)]
+pub struct RoyalRoad {
+    strand_size: usize,
+    block_size: usize,
+}
+
+impl RoyalRoad {
+    pub fn new(strand_size: usize, block_size: usize) -> Result<Self, String> {
+        if block_size == 0 || !strand_size.is_multiple_of(block_size) {
+            return Err(format!(
+                "the strand size {} must be a multiple of the block size {}",
+                strand_size, block_size
+            ));
+        }
+        Ok(RoyalRoad {
+            strand_size,
+            block_size,
+        })
+    }
+}
+
+impl Problem for RoyalRoad {
+    type Allele = bool;
+    type Fitness = RoyalRoadFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        RoyalRoadFitnessCalculator::new(self.strand_size, self.block_size)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn allele_bounds(&self) -> (bool, bool) {
+        (false, true)
+    }
+
+    fn render(&self, strand: &[bool]) -> Phenome {
+        render_bits(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_bits(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Find the bitstring of all 1s through concatenated deceptive traps, whose bits each lead to 0s
+#[derive(Clone, Debug)]
+pub struct DeceptiveTraps {
+    strand_size: usize,
+    trap_size: usize,
+}
+
+impl DeceptiveTraps {
+    pub fn new(strand_size: usize, trap_size: usize) -> Result<Self, String> {
+        if trap_size < 2 {
+            return Err(format!(
+                "a trap of {} bits is not deceptive, it takes at least 2",
+                trap_size
+            ));
+        }
+        if !strand_size.is_multiple_of(trap_size) {
+            return Err(format!(
+                "the strand size {} must be a multiple of the trap size {}",
+                strand_size, trap_size
+            ));
+        }
+        Ok(DeceptiveTraps {
+            strand_size,
+            trap_size,
+        })
+    }
+}
+
+impl Problem for DeceptiveTraps {
+    type Allele = bool;
+    type Fitness = DeceptiveTrapsFitnessCalculator;
+
+    fn fitness_function(&self) -> Self::Fitness {
+        DeceptiveTrapsFitnessCalculator::new(self.strand_size, self.trap_size)
+    }
+
+    fn strand_size(&self) -> usize {
+        self.strand_size
+    }
+
+    fn random_allele<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        rng.gen()
+    }
+
+    fn alleles(&self) -> Vec<bool> {
+        vec![false, true]
+    }
+
+    fn allele_bounds(&self) -> (bool, bool) {
+        (false, true)
+    }
+
+    fn render(&self, strand: &[bool]) -> Phenome {
+        render_bits(strand)
+    }
+
+    fn parse(&self, phenome: &str) -> Option<Strand<Self>> {
+        parse_bits(phenome).filter(|strand| strand.len() == self.strand_size)
+    }
+}
+
+// Self-adaptive strands are evaluated by their strand alone.
+#[derive(Clone, Debug)]
+pub struct SelfAdaptiveFitnessCalculator<F>(pub F);
+
+impl<G, F> FitnessFunction<SelfAdaptive<G>, usize> for SelfAdaptiveFitnessCalculator<F>
+where
+    G: Genotype,
+    F: FitnessFunction<G, usize>,
+{
+    fn fitness_of(&self, genome: &SelfAdaptive<G>) -> usize {
+        self.0.fitness_of(&genome.genome)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        self.0.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        self.0.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        self.0.lowest_possible_fitness()
+    }
+}
+
+// Replaces a single random allele by a different one, the neighbourhood of the hill climbing
+#[derive(Clone, Debug, PartialEq)]
+pub struct SingleLocusFlip<P>(pub P);
+
+impl<P: Problem> GeneticOperator for SingleLocusFlip<P> {
+    fn name() -> String {
+        "Single-Locus-Flip".to_string()
+    }
+}
+
+impl<P: Problem> MutationOp<Strand<P>> for SingleLocusFlip<P> {
+    fn mutate<R>(&self, mut genome: Strand<P>, rng: &mut R) -> Strand<P>
+    where
+        R: Rng + Sized,
+    {
+        let locus = rng.gen_range(0..genome.len());
+        genome[locus] = loop {
+            let allele = self.0.random_allele(rng);
+            if allele != genome[locus] {
+                break allele;
+            }
+        };
+        genome
+    }
+}
+
+// Replaces each allele by a different one with the given probability, the standard bit mutation
+// of the (1+1)-EA (at 1/n for strands of n alleles)
+#[derive(Clone, Debug, PartialEq)]
+pub struct StandardBitMutation<P>(pub P, pub f64);
+
+impl<P: Problem> GeneticOperator for StandardBitMutation<P> {
+    fn name() -> String {
+        "Standard-Bit-Mutation".to_string()
+    }
+}
+
+impl<P: Problem> MutationOp<Strand<P>> for StandardBitMutation<P> {
+    fn mutate<R>(&self, mut genome: Strand<P>, rng: &mut R) -> Strand<P>
+    where
+        R: Rng + Sized,
+    {
+        for allele in genome.iter_mut() {
+            if rng.gen_bool(self.1) {
+                *allele = loop {
+                    let other = self.0.random_allele(rng);
+                    if other != *allele {
+                        break other;
+                    }
+                };
+            }
+        }
+        genome
+    }
+}
+
+// Build some random strands.
+#[derive(Clone, Debug)]
+pub struct RandomStrandBuilder<P>(pub P);
+
+impl<P: Problem> GenomeBuilder<Strand<P>> for RandomStrandBuilder<P> {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Strand<P>
+    where
+        R: Rng + Sized,
+    {
+        (0..self.0.strand_size())
+            .map(|_| self.0.random_allele(rng))
+            .collect()
+    }
+}
+
+// Build random strands biased by a heuristic: the strand is split into blocks of `block_size`
+// loci that each get a single allele, drawn by the given weights of the alleles. Blocks of 1
+// without weights draw the same strands as the `RandomStrandBuilder`.
+#[derive(Clone, Debug)]
+pub struct BiasedStrandBuilder<P: Problem> {
+    problem: P,
+    block_size: usize,
+    // The alleles and the distribution of their indices, None to draw them uniformly
+    weights: Option<(Vec<P::Allele>, WeightedIndex<f64>)>,
+}
+
+impl<P: Problem> BiasedStrandBuilder<P> {
+    // The weights are given in the order of the problem's alleles, none draws them uniformly
+    pub fn new(problem: P, block_size: usize, weights: &[f64]) -> Result<Self, String> {
+        if block_size == 0 {
+            return Err("the block size must be at least 1".to_string());
+        }
+        let weights = if weights.is_empty() {
+            None
+        } else {
+            let alleles = problem.alleles();
+            if weights.len() != alleles.len() {
+                return Err(format!(
+                    "expected {} allele weights, got {}",
+                    alleles.len(),
+                    weights.len()
+                ));
+            }
+            let distribution = WeightedIndex::new(weights)
+                .map_err(|error| format!("bad allele weights: {}", error))?;
+            Some((alleles, distribution))
+        };
+        Ok(BiasedStrandBuilder {
+            problem,
+            block_size,
+            weights,
+        })
+    }
+
+    fn random_allele<R: Rng + Sized>(&self, rng: &mut R) -> P::Allele {
+        match &self.weights {
+            Some((alleles, distribution)) => alleles[distribution.sample(rng)].clone(),
+            None => self.problem.random_allele(rng),
+        }
+    }
+}
+
+impl<P: Problem> GenomeBuilder<Strand<P>> for BiasedStrandBuilder<P> {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Strand<P>
+    where
+        R: Rng + Sized,
+    {
+        let strand_size = self.problem.strand_size();
+        let mut strand = Vec::with_capacity(strand_size);
+        while strand.len() < strand_size {
+            let allele = self.random_allele(rng);
+            let block_size = self.block_size.min(strand_size - strand.len());
+            strand.extend(std::iter::repeat_n(allele, block_size));
+        }
+        strand
+    }
+}
+
+// Build some random strands that all start with the given mutation rate.
+#[derive(Clone, Debug)]
+pub struct SelfAdaptiveStrandBuilder<P>(pub P, pub f64);
+
+impl<P: Problem> GenomeBuilder<SelfAdaptive<Strand<P>>> for SelfAdaptiveStrandBuilder<P> {
+    fn build_genome<R>(&self, index: usize, rng: &mut R) -> SelfAdaptive<Strand<P>>
+    where
+        R: Rng + Sized,
+    {
+        SelfAdaptive::new(
+            RandomStrandBuilder(self.0.clone()).build_genome(index, rng),
+            self.1,
+        )
+    }
+}
+
+// Reads the strands an initial population is seeded with from a file, e.g. a hall of fame CSV or
+// hand-written strands, one per line. The phenotype is the last column of a CSV line; the header,
+// empty lines and lines starting with '#' are left out. A FASTA file (see `fasta`) seeds the
+// sequences of its records.
+pub fn read_seed_strands<P: Problem>(problem: &P, path: &str) -> Result<Vec<Strand<P>>, String> {
+    let text = read_to_string(path).map_err(|error| format!("cannot read {}: {}", path, error))?;
+    if is_fasta(&text) {
+        return parse_fasta(&text)
+            .map_err(|error| format!("{} of {}", error, path))?
+            .iter()
+            .map(|record| {
+                problem
+                    .parse(&record.sequence)
+                    .ok_or_else(|| format!("record {} of {} is not a strand", record.header, path))
+            })
+            .collect();
+    }
+    text.lines()
+        .enumerate()
+        .map(|(number, line)| (number, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .filter(|(_, line)| *line != "fitness,phenome")
+        .map(|(number, line)| {
+            let phenome = line.rsplit(',').next().unwrap_or(line).trim();
+            problem
+                .parse(phenome)
+                .ok_or_else(|| format!("line {} of {} is not a strand", number + 1, path))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genevo::random::{get_rng, random_seed};
+
+    #[test]
+    fn biased_strands_are_made_of_blocks_of_the_weighted_alleles() {
+        // Only C and G have a weight
+        let builder = BiasedStrandBuilder::new(ClustersOf4::new(12), 4, &[0.0, 1.0, 0.0, 1.0]);
+        let strand = builder
+            .unwrap()
+            .build_genome(0, &mut get_rng(random_seed()));
+
+        assert_eq!(strand.len(), 12);
+        for block in strand.chunks(4) {
+            assert!(block.iter().all(|allele| *allele == block[0]));
+            assert!(block[0] == Nucleotide::C || block[0] == Nucleotide::G);
+        }
+    }
+
+    #[test]
+    fn unbiased_strands_are_the_random_strands() {
+        let problem = ClustersOf4::new(8);
+        let builder = BiasedStrandBuilder::new(problem.clone(), 1, &[]).unwrap();
+        let seed = random_seed();
+
+        assert_eq!(
+            builder.build_genome(0, &mut get_rng(seed)),
+            RandomStrandBuilder(problem).build_genome(0, &mut get_rng(seed))
+        );
+    }
+
+    #[test]
+    fn seed_strands_are_read_from_fasta() {
+        let path = std::env::temp_dir().join(format!("seeds_{}.fasta", std::process::id()));
+        std::fs::write(
+            &path,
+            ">default_1 fitness=2\nAAAAC\nCCC\n>default_2\ngatc\nGATC\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let strands = read_seed_strands(&ClustersOf4::new(8), path);
+        std::fs::write(path, ">bad\nAAAACCCX\n").unwrap();
+        let bad = read_seed_strands(&ClustersOf4::new(8), path);
+        std::fs::remove_file(path).unwrap();
+
+        use Nucleotide::*;
+        assert_eq!(
+            strands,
+            Ok(vec![
+                vec![A, A, A, A, C, C, C, C],
+                vec![G, A, T, C, G, A, T, C]
+            ])
+        );
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn the_bounds_of_the_composition_problems_follow_their_parameters() {
+        assert_eq!(
+            GcContent::new(100, 40.0).unwrap().fitness_bounds(),
+            (40, 100)
+        );
+        assert_eq!(Motif::new(12, "ACGT").unwrap().fitness_bounds(), (0, 3));
+        assert!(GcContent::new(100, 120.0).is_err());
+        assert!(Motif::new(12, "ACGX").is_err());
+        assert!(Motif::new(2, "ACGT").is_err());
+    }
+
+    #[test]
+    fn nk_strands_are_bitstrings_of_n_bits() {
+        let problem = Nk::new(12, 3, 7).unwrap();
+        let strand =
+            RandomStrandBuilder(problem.clone()).build_genome(0, &mut get_rng(random_seed()));
+
+        assert_eq!(strand.len(), 12);
+        assert_eq!(problem.parse(&problem.render(&strand)), Some(strand));
+        assert_eq!(problem.parse("0101"), None);
+        assert!(Nk::new(12, 12, 7).is_err());
+    }
+
+    #[test]
+    fn standard_bit_mutation_replaces_each_allele_by_a_different_one() {
+        let problem = OneMax::new(64);
+        let mut rng = get_rng(random_seed());
+
+        let mutated = StandardBitMutation(problem.clone(), 1.0).mutate(vec![false; 64], &mut rng);
+        let kept = StandardBitMutation(problem, 0.0).mutate(vec![false; 64], &mut rng);
+
+        assert_eq!(mutated, vec![true; 64]);
+        assert_eq!(kept, vec![false; 64]);
+    }
+
+    #[test]
+    fn blocks_and_traps_must_divide_the_strand() {
+        assert_eq!(RoyalRoad::new(64, 8).unwrap().fitness_bounds(), (0, 64));
+        assert!(RoyalRoad::new(64, 0).is_err());
+        assert!(RoyalRoad::new(60, 8).is_err());
+        assert_eq!(
+            DeceptiveTraps::new(40, 4).unwrap().fitness_bounds(),
+            (0, 40)
+        );
+        assert!(DeceptiveTraps::new(40, 1).is_err());
+        assert!(DeceptiveTraps::new(42, 4).is_err());
+    }
+
+    #[test]
+    fn alignment_scores_must_favor_matches() {
+        let scores = AlignmentScores::default();
+        let problem = Alignment::new(4, "GATTACA", scores).unwrap();
+
+        // 4 of the 7 nucleotides of the reference fit, the rest are gaps
+        assert_eq!(problem.fitness_bounds(), (0, 4 - 3 + 7));
+        assert!(Alignment::new(4, "", scores).is_err());
+        for scores in [
+            AlignmentScores {
+                match_score: 0,
+                ..scores
+            },
+            AlignmentScores {
+                mismatch: 1,
+                ..scores
+            },
+            AlignmentScores { gap: 1, ..scores },
+        ] {
+            assert!(Alignment::new(4, "GATTACA", scores).is_err());
+        }
+    }
+
+    #[test]
+    fn allele_weights_must_match_the_alleles() {
+        assert!(BiasedStrandBuilder::new(ClustersOf4::new(8), 1, &[1.0, 1.0]).is_err());
+        assert!(BiasedStrandBuilder::new(ClustersOf4::new(8), 0, &[]).is_err());
+    }
+}
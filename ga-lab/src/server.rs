@@ -0,0 +1,302 @@
+// A tiny HTTP server of the live metrics of the runs (see `monitor`), to follow the experiments
+// running on another machine from a browser: `/metrics` is the state of the current batch as JSON
+// and `/` is a page that charts it, refreshing itself every few seconds. Other tools subscribe to
+// the events of each generation of the runs on the WebSocket `/events` instead of polling.
+
+use crate::monitor::{self, sampled, Monitor};
+use std::{
+    io::{Read, Write},
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Mutex,
+    },
+    thread,
+};
+use tiny_http::{Header, Request, Response, Server};
+use tungstenite::{handshake::derive_accept_key, protocol::Role, Message, WebSocket};
+
+const SAMPLES: usize = 256; // at most as many best fitness values of each run are served
+const LOG_LINES: usize = 20; // latest reported lines served
+
+// What a run reports of one of its generations to the subscribers of the events
+pub struct GenerationEvent<'a> {
+    pub generation: u64,
+    pub best_fitness: usize,
+    pub average_fitness: usize,
+    pub diversity: f64, // the share of distinct strands in the population
+    pub new_best: Option<&'a str>, // the phenome of the best strand, if none before was as fit
+}
+
+// A channel to the connection of each subscriber of the events
+static SUBSCRIBERS: Mutex<Vec<Sender<String>>> = Mutex::new(vec![]);
+
+// Whether anyone listens to the events, which are not worth making otherwise
+pub fn has_subscribers() -> bool {
+    !SUBSCRIBERS.lock().unwrap().is_empty()
+}
+
+// Sends the event of a generation of the run in the given thread to each subscriber
+pub fn publish_generation(thread_number: u64, label: &str, event: &GenerationEvent) {
+    let mut subscribers = SUBSCRIBERS.lock().unwrap();
+    if subscribers.is_empty() {
+        return;
+    }
+    let json = event_json(thread_number, label, event);
+    // The connections of the subscribers that left have dropped their end
+    subscribers.retain(|subscriber| subscriber.send(json.clone()).is_ok());
+}
+
+fn event_json(thread_number: u64, label: &str, event: &GenerationEvent) -> String {
+    format!(
+        "{{\"thread\":{},\"label\":{:?},\"generation\":{},\"best_fitness\":{},\
+        \"average_fitness\":{},\"diversity\":{:.4},\"new_best\":{}}}",
+        thread_number,
+        label,
+        event.generation,
+        event.best_fitness,
+        event.average_fitness,
+        event.diversity,
+        event
+            .new_best
+            .map_or("null".to_string(), |phenome| format!("{:?}", phenome)),
+    )
+}
+
+// Serves the metrics on the given port, in its own thread, until the process ends
+pub fn start(port: u16) -> Result<(), String> {
+    let server = Server::http(("0.0.0.0", port)).map_err(|error| error.to_string())?;
+    monitor::enable();
+
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let (content, content_type) = match request.url() {
+                "/events" => {
+                    subscribe(request);
+                    continue;
+                }
+                "/" => (PAGE.to_string(), "text/html; charset=utf-8"),
+                "/metrics" => (
+                    monitor::with(metrics_json).unwrap_or_default(),
+                    "application/json",
+                ),
+                _ => {
+                    let _ = request.respond(Response::empty(404));
+                    continue;
+                }
+            };
+            let header = Header::from_bytes("Content-Type", content_type).unwrap();
+            let _ = request.respond(Response::from_string(content).with_header(header));
+        }
+    });
+    Ok(())
+}
+
+// Upgrades the request to a WebSocket and streams the events to it in its own thread, until either
+// side closes it
+fn subscribe(request: Request) {
+    let key = request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Sec-WebSocket-Key"))
+        .map(|header| derive_accept_key(header.value.as_bytes()));
+    let Some(key) = key else {
+        let _ =
+            request.respond(Response::from_string("expected a WebSocket").with_status_code(400));
+        return;
+    };
+    let response =
+        Response::empty(101).with_header(Header::from_bytes("Sec-WebSocket-Accept", key).unwrap());
+    let stream = request.upgrade("websocket", response);
+
+    let (sender, events) = channel();
+    SUBSCRIBERS.lock().unwrap().push(sender);
+    thread::spawn(move || {
+        stream_events(
+            WebSocket::from_raw_socket(stream, Role::Server, None),
+            events,
+        )
+    });
+}
+
+fn stream_events<S: Read + Write>(mut socket: WebSocket<S>, events: Receiver<String>) {
+    for event in events {
+        if socket.send(Message::Text(event)).is_err() {
+            break;
+        }
+    }
+}
+
+// The state of the current batch, its runs and the latest reported lines
+fn metrics_json(monitor: &Monitor) -> String {
+    let runs = monitor
+        .runs
+        .iter()
+        .map(|(thread_number, run)| {
+            format!(
+                "{{\"thread\":{},\"label\":{:?},\"finished\":{},\"generations\":{},\
+                \"best_fitness\":{:?}}}",
+                thread_number,
+                run.label,
+                run.finished,
+                run.best_fitness.len(),
+                sampled(&run.best_fitness, SAMPLES),
+            )
+        })
+        .collect::<Vec<_>>();
+    let log = &monitor.log[monitor.log.len().saturating_sub(LOG_LINES)..];
+    format!(
+        "{{\"elapsed_secs\":{},\"batch\":{{\"number\":{},\"name\":{:?},\"runs\":{},\
+        \"finished_runs\":{},\"elapsed_secs\":{},\"eta_secs\":{},\"optimum\":{}}},\
+        \"runs\":[{}],\"log\":{:?}}}",
+        monitor.start.elapsed().as_secs(),
+        monitor.batch_number,
+        monitor.batch_name,
+        monitor.batch_runs,
+        monitor.finished_runs(),
+        monitor.batch_start.elapsed().as_secs(),
+        monitor
+            .eta()
+            .map_or("null".to_string(), |eta| eta.as_secs().to_string()),
+        monitor.optimum,
+        runs.join(","),
+        log.iter().map(|line| line.trim_start()).collect::<Vec<_>>(),
+    )
+}
+
+const PAGE: &str = r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>cs415 runs</title>
+<style>
+  body { font-family: monospace; margin: 2em; }
+  canvas { border: 1px solid #ccc; }
+  pre { background: #f4f4f4; padding: 1em; }
+</style>
+</head>
+<body>
+<h2 id="batch">Waiting for the first batch...</h2>
+<p id="status"></p>
+<canvas id="chart" width="1200" height="500"></canvas>
+<pre id="log"></pre>
+<script>
+const colors = ["#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#46f0f0", "#f032e6", "#9a6324"];
+
+function duration(secs) {
+  if (secs === null) return "?";
+  return secs < 60 ? secs + "s" : Math.floor(secs / 60) + "m " + (secs % 60) + "s";
+}
+
+// The best fitness of each run over its generations, a color for each label
+function draw(metrics) {
+  const canvas = document.getElementById("chart");
+  const ctx = canvas.getContext("2d");
+  ctx.clearRect(0, 0, canvas.width, canvas.height);
+  const gens = Math.max(1, ...metrics.runs.map(run => run.generations));
+  const optimum = Math.max(1, metrics.batch.optimum);
+  const labels = [...new Set(metrics.runs.map(run => run.label))];
+  for (const run of metrics.runs) {
+    const color = colors[labels.indexOf(run.label) % colors.length];
+    const step = run.generations / Math.max(1, run.best_fitness.length);
+    ctx.strokeStyle = color;
+    ctx.lineWidth = run.finished ? 1 : 2;
+    ctx.beginPath();
+    run.best_fitness.forEach((fitness, i) => {
+      const x = ((i + 1) * step / gens) * canvas.width;
+      const y = canvas.height - (fitness / optimum) * canvas.height;
+      i === 0 ? ctx.moveTo(x, y) : ctx.lineTo(x, y);
+    });
+    ctx.stroke();
+  }
+  labels.forEach((label, i) => {
+    ctx.fillStyle = colors[i % colors.length];
+    ctx.fillText(label, 10, 15 + 15 * i);
+  });
+}
+
+async function refresh() {
+  try {
+    const metrics = await (await fetch("/metrics")).json();
+    const batch = metrics.batch;
+    document.getElementById("batch").textContent =
+      "Batch " + batch.number + ": " + batch.name + " (running for " + duration(metrics.elapsed_secs) + ")";
+    document.getElementById("status").textContent =
+      batch.finished_runs + "/" + batch.runs + " runs in " + duration(batch.elapsed_secs) +
+      ", ETA " + duration(batch.eta_secs);
+    document.getElementById("log").textContent = metrics.log.join("\n");
+    draw(metrics);
+  } catch (error) {
+    document.getElementById("status").textContent = "The experiments are not running: " + error;
+  }
+}
+
+refresh();
+setInterval(refresh, 2000);
+</script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeMap, time::Instant};
+
+    #[test]
+    fn the_events_are_sent_as_json() {
+        let event = GenerationEvent {
+            generation: 12,
+            best_fitness: 18,
+            average_fitness: 11,
+            diversity: 0.96875,
+            new_best: Some("ACGTACGT"),
+        };
+
+        assert_eq!(
+            event_json(3, "default", &event),
+            "{\"thread\":3,\"label\":\"default\",\"generation\":12,\"best_fitness\":18,\
+            \"average_fitness\":11,\"diversity\":0.9688,\"new_best\":\"ACGTACGT\"}"
+        );
+        assert!(event_json(
+            3,
+            "default",
+            &GenerationEvent {
+                new_best: None,
+                ..event
+            }
+        )
+        .ends_with("\"new_best\":null}"));
+    }
+
+    #[test]
+    fn the_metrics_are_served_as_json() {
+        let mut monitor = Monitor {
+            start: Instant::now(),
+            batch_name: "MutationRate([0.01])".to_string(),
+            batch_number: 2,
+            batch_start: Instant::now(),
+            batch_runs: 8,
+            optimum: 100,
+            runs: BTreeMap::new(),
+            log: vec!["\t[thread #1]: Starting a simulation".to_string()],
+            quiet: false,
+        };
+        monitor.runs.insert(
+            1,
+            monitor::Run {
+                label: "mutation_rate=0.01".to_string(),
+                best_fitness: vec![40, 45],
+                finished: false,
+            },
+        );
+
+        assert_eq!(
+            metrics_json(&monitor),
+            "{\"elapsed_secs\":0,\"batch\":{\"number\":2,\"name\":\"MutationRate([0.01])\",\
+            \"runs\":8,\"finished_runs\":0,\"elapsed_secs\":0,\"eta_secs\":null,\"optimum\":100},\
+            \"runs\":[{\"thread\":1,\"label\":\"mutation_rate=0.01\",\"finished\":false,\
+            \"generations\":2,\"best_fitness\":[40, 45]}],\
+            \"log\":[\"[thread #1]: Starting a simulation\"]}"
+        );
+    }
+}
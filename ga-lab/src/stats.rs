@@ -0,0 +1,396 @@
+use crate::batch::{convergence_gen, Data, RunsWithLables};
+use std::{fmt::Write, time::Duration};
+
+// The convergence generation of each run, the runs that never converged ranked behind all others
+pub fn convergence_gens(runs: &[Vec<u32>], optimum: usize) -> Vec<f64> {
+    runs.iter()
+        .map(|r| convergence_gen(r, optimum).map_or(f64::INFINITY, |gen| gen as f64))
+        .collect()
+}
+
+// The two-sided p-value of the Mann-Whitney U test of whether the two samples come from the same
+// distribution, from the normal approximation with tie and continuity correction. It makes no
+// assumption about the shape of the distributions, which suits the few and skewed runs of a batch.
+pub fn mann_whitney_u(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    let n = n1 + n2;
+
+    // Rank the pooled samples, tied values get the average of their ranks
+    let mut pooled = a
+        .iter()
+        .map(|x| (*x, true))
+        .chain(b.iter().map(|x| (*x, false)))
+        .collect::<Vec<_>>();
+    pooled.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < pooled.len() {
+        let end = start
+            + pooled[start..]
+                .iter()
+                .take_while(|x| x.0 == pooled[start].0)
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        rank_sum_a += rank * pooled[start..end].iter().filter(|x| x.1).count() as f64;
+        let ties = (end - start) as f64;
+        tie_term += ties.powi(3) - ties;
+        start = end;
+    }
+
+    let u = rank_sum_a - n1 * (n1 + 1.0) / 2.0;
+    let mean = n1 * n2 / 2.0;
+    let sigma = (n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)))).sqrt();
+    if sigma == 0.0 {
+        return Some(1.0); // all values tied
+    }
+    let z = ((u - mean).abs() - 0.5).max(0.0) / sigma;
+    Some(erfc(z / std::f64::consts::SQRT_2).min(1.0))
+}
+
+// The complementary error function (Abramowitz and Stegun 7.1.26, error below 1.5e-7)
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erfc = poly * (-x * x).exp();
+    if x < 0.0 {
+        2.0 - erfc
+    } else {
+        erfc
+    }
+}
+
+// Labels the runs of each setting with the p-value of its convergence generations against the
+// default's, so the legends tell which differences are more than the noise of the batch
+pub fn label_with_p_values(runs_list: &RunsWithLables, optimum: usize) -> RunsWithLables {
+    let default_gens = match runs_list
+        .iter()
+        .find(|(label, _)| label.ends_with("(default)"))
+    {
+        Some((_, runs)) => convergence_gens(runs, optimum),
+        None => return runs_list.clone(), // nothing to compare against
+    };
+
+    runs_list
+        .iter()
+        .map(|(label, runs)| {
+            let p = mann_whitney_u(&convergence_gens(runs, optimum), &default_gens);
+            match p {
+                Some(p) if !label.ends_with("(default)") => {
+                    (format!("{} (p = {:.3})", label, p), runs.clone())
+                }
+                _ => (label.clone(), runs.clone()),
+            }
+        })
+        .collect()
+}
+
+// The index of the setting that converged fastest among the ones that beat the default's runs
+// significantly (p below `alpha`), or the default's if none did. None without a default.
+pub fn best_setting(
+    runs_list: &[(String, Vec<Data>)],
+    optimum: usize,
+    alpha: f64,
+) -> Option<usize> {
+    let default_idx = runs_list
+        .iter()
+        .position(|(label, _)| label.ends_with("(default)"))?;
+    let default_gens = convergence_gens(&runs_list[default_idx].1, optimum);
+    // Unconverged runs count as infinitely slow, so a setting that rarely converges loses
+    let median = |gens: &[f64]| {
+        let mut gens = gens.to_vec();
+        gens.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        gens[gens.len() / 2]
+    };
+
+    let mut best = (default_idx, median(&default_gens));
+    for (idx, (_, runs)) in runs_list.iter().enumerate() {
+        let gens = convergence_gens(runs, optimum);
+        let significant = mann_whitney_u(&gens, &default_gens).is_some_and(|p| p < alpha);
+        if significant && median(&gens) < best.1 {
+            best = (idx, median(&gens));
+        }
+    }
+    Some(best.0)
+}
+
+// The convergence generations, success rate and wall-time of the runs of a setting
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub label: String,
+    pub runs: usize,
+    pub success_rate: f64,
+    // Mean, median, standard deviation, min and max of the converged runs' generations
+    pub convergence: Option<(f64, f64, f64, f64, f64)>,
+    pub mean_wall_time: Duration,
+}
+
+pub fn summarize(label: &str, runs: &[Data], optimum: usize, wall_times: &[Duration]) -> Summary {
+    let mut gens = runs
+        .iter()
+        .filter_map(|r| convergence_gen(r, optimum))
+        .map(|gen| gen as f64)
+        .collect::<Vec<f64>>();
+    gens.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let convergence = (!gens.is_empty()).then(|| {
+        let n = gens.len() as f64;
+        let mean = gens.iter().sum::<f64>() / n;
+        let median = (gens[(gens.len() - 1) / 2] + gens[gens.len() / 2]) / 2.0;
+        let std_dev = if n > 1.0 {
+            (gens.iter().map(|g| (g - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+        (mean, median, std_dev, gens[0], gens[gens.len() - 1])
+    });
+
+    Summary {
+        label: label.to_string(),
+        runs: runs.len(),
+        success_rate: gens.len() as f64 / runs.len().max(1) as f64,
+        convergence,
+        mean_wall_time: wall_times.iter().sum::<Duration>() / wall_times.len().max(1) as u32,
+    }
+}
+
+// A Markdown table of the summaries, one row per setting
+pub fn summary_table(summaries: &[Summary]) -> String {
+    let mut table = String::new();
+    table.push_str("| parms | runs | success rate | mean gens | median gens | std-dev gens | min gens | max gens | mean wall-time |\n");
+    table.push_str("|---|---:|---:|---:|---:|---:|---:|---:|---:|\n");
+    for summary in summaries {
+        let convergence = match summary.convergence {
+            Some((mean, median, std_dev, min, max)) => format!(
+                "{:.1} | {:.1} | {:.1} | {} | {}",
+                mean, median, std_dev, min, max
+            ),
+            None => "- | - | - | - | -".to_string(),
+        };
+        writeln!(
+            table,
+            "| {} | {} | {:.0}% | {} | {:.2}s |",
+            summary.label,
+            summary.runs,
+            summary.success_rate * 100.0,
+            convergence,
+            summary.mean_wall_time.as_secs_f64()
+        )
+        .unwrap();
+    }
+    table
+}
+
+// The mean and the highest of the best fitness each run ended with
+pub fn final_fitness(runs: &[Data]) -> Option<(f64, u32)> {
+    let finals = runs
+        .iter()
+        .filter_map(|r| r.last().copied())
+        .collect::<Vec<_>>();
+    let highest = *finals.iter().max()?;
+    let mean = finals.iter().map(|f| *f as f64).sum::<f64>() / finals.len() as f64;
+    Some((mean, highest))
+}
+
+// A Markdown table of solvers run on the same problem: as most of the baselines never find the
+// optimum, the fitness the runs ended with next to how fast they converged
+pub fn solver_table(summaries: &[Summary], runs_list: &RunsWithLables) -> String {
+    let mut table = String::new();
+    table.push_str("| solver | runs | success rate | mean final fitness | best final fitness | mean gens | mean wall-time |\n");
+    table.push_str("|---|---:|---:|---:|---:|---:|---:|\n");
+    for (summary, (_, runs)) in summaries.iter().zip(runs_list) {
+        let (mean_final, best_final) = match final_fitness(runs) {
+            Some((mean, best)) => (format!("{:.1}", mean), best.to_string()),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        let mean_gens = summary
+            .convergence
+            .map_or("-".to_string(), |(mean, ..)| format!("{:.1}", mean));
+        writeln!(
+            table,
+            "| {} | {} | {:.0}% | {} | {} | {} | {:.2}s |",
+            summary.label,
+            summary.runs,
+            summary.success_rate * 100.0,
+            mean_final,
+            best_final,
+            mean_gens,
+            summary.mean_wall_time.as_secs_f64()
+        )
+        .unwrap();
+    }
+    table
+}
+
+// A Markdown table of the mean convergence generations (and success rates) of the settings of
+// several invocations, with the difference of each later invocation to the first one
+pub fn comparison_table(names: &[String], summaries: &[Vec<Summary>]) -> String {
+    let mut labels: Vec<&str> = vec![];
+    for summary in summaries.iter().flatten() {
+        if !labels.contains(&summary.label.as_str()) {
+            labels.push(&summary.label);
+        }
+    }
+    let mean_gens = |summaries: &[Summary], label: &str| {
+        let summary = summaries.iter().find(|s| s.label == label)?;
+        Some((summary.convergence?.0, summary.success_rate))
+    };
+
+    let mut table = format!("| parms | {} mean gens (success rate)", names[0]);
+    for name in &names[1..] {
+        write!(table, " | {} mean gens (success rate) | delta", name).unwrap();
+    }
+    table.push_str(" |\n|---|---:");
+    table.push_str(&"|---:|---:".repeat(names.len() - 1));
+    table.push_str("|\n");
+
+    for label in labels {
+        let first = mean_gens(&summaries[0], label);
+        write!(table, "| {} | {}", label, cell(first)).unwrap();
+        for later in &summaries[1..] {
+            let later = mean_gens(later, label);
+            let delta = match (first, later) {
+                (Some((first, _)), Some((later, _))) => format!("{:+.1}", later - first),
+                _ => "-".to_string(),
+            };
+            write!(table, " | {} | {}", cell(later), delta).unwrap();
+        }
+        table.push_str(" |\n");
+    }
+    table
+}
+
+fn cell(mean_gens: Option<(f64, f64)>) -> String {
+    match mean_gens {
+        Some((gens, success_rate)) => format!("{:.1} ({:.0}%)", gens, success_rate * 100.0),
+        None => "-".to_string(),
+    }
+}
+
+// The output path of the summary table that accompanies a sweep's graph
+pub fn summary_out_file(out_file: &str) -> String {
+    out_file.replace(".png", "_summary.md")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mann_whitney_u_matches_the_normal_approximation() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+
+        // scipy.stats.mannwhitneyu(a, b, method="asymptotic") gives 0.01219
+        assert!((mann_whitney_u(&a, &b).unwrap() - 0.01219).abs() < 1e-4);
+        assert!(mann_whitney_u(&a, &a).unwrap() > 0.999);
+        assert_eq!(mann_whitney_u(&a, &[]), None);
+    }
+
+    #[test]
+    fn the_best_setting_has_to_beat_the_default_significantly() {
+        let runs_list = vec![
+            ("slow".to_string(), vec![vec![1, 2, 3]; 4]),
+            (
+                "same (default)".to_string(),
+                vec![
+                    vec![1, 2, 4],
+                    vec![1, 2, 4],
+                    vec![1, 2, 3, 4],
+                    vec![1, 2, 3, 4],
+                ],
+            ),
+            ("lucky".to_string(), vec![vec![4], vec![1, 2, 3]]),
+            ("fast".to_string(), vec![vec![1, 4]; 8]),
+        ];
+
+        assert_eq!(best_setting(&runs_list, 4, 0.05), Some(3));
+        assert_eq!(best_setting(&runs_list[..3], 4, 0.05), Some(1));
+        assert_eq!(best_setting(&runs_list[..1], 4, 0.05), None);
+    }
+
+    #[test]
+    fn summaries_describe_the_converged_runs() {
+        let runs = vec![vec![1, 4], vec![1, 2, 3, 4], vec![1, 2, 3], vec![4]];
+        let wall_times = [1, 2, 3, 6].map(Duration::from_secs);
+
+        let summary = summarize("default", &runs, 4, &wall_times);
+
+        assert_eq!(summary.success_rate, 0.75);
+        let (mean, median, std_dev, min, max) = summary.convergence.unwrap();
+        assert_eq!((mean, median, min, max), (7.0 / 3.0, 2.0, 1.0, 4.0));
+        assert!((std_dev - 1.5275).abs() < 1e-4);
+        assert_eq!(summary.mean_wall_time, Duration::from_secs(3));
+        assert!(summary_table(&[summary])
+            .ends_with("| default | 4 | 75% | 2.3 | 2.0 | 1.5 | 1 | 4 | 3.00s |\n"));
+    }
+
+    #[test]
+    fn comparison_tables_show_the_difference_to_the_first_invocation() {
+        let before = vec![summarize("default", &[vec![1, 2, 4]], 4, &[])];
+        let after = vec![
+            summarize("default", &[vec![1, 4], vec![1, 2, 3]], 4, &[]),
+            summarize("new", &[vec![4]], 4, &[]),
+        ];
+
+        let table = comparison_table(
+            &["before".to_string(), "after".to_string()],
+            &[before, after],
+        );
+
+        assert_eq!(
+            table.lines().skip(2).collect::<Vec<_>>(),
+            vec![
+                "| default | 3.0 (100%) | 2.0 (50%) | -1.0 |",
+                "| new | - | 1.0 (100%) | - |"
+            ]
+        );
+    }
+
+    #[test]
+    fn solver_tables_show_the_final_fitness_of_unconverged_solvers() {
+        let runs_list = vec![
+            (
+                "genetic algorithm".to_string(),
+                vec![vec![1, 4], vec![2, 3, 4]],
+            ),
+            ("random search".to_string(), vec![vec![1, 2], vec![1, 3]]),
+        ];
+        let summaries = runs_list
+            .iter()
+            .map(|(label, runs)| summarize(label, runs, 4, &[Duration::from_secs(1)]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(final_fitness(&runs_list[1].1), Some((2.5, 3)));
+        assert_eq!(final_fitness(&[]), None);
+        assert_eq!(
+            solver_table(&summaries, &runs_list)
+                .lines()
+                .skip(2)
+                .collect::<Vec<_>>(),
+            vec![
+                "| genetic algorithm | 2 | 100% | 4.0 | 4 | 2.5 | 1.00s |",
+                "| random search | 2 | 0% | 2.5 | 3 | - | 1.00s |"
+            ]
+        );
+    }
+
+    #[test]
+    fn unconverged_runs_rank_behind_the_converged_ones() {
+        let runs_list = vec![
+            ("fast".to_string(), vec![vec![1, 4]; 4]),
+            ("slow (default)".to_string(), vec![vec![1, 2, 3]; 4]),
+        ];
+
+        let labeled = label_with_p_values(&runs_list, 4);
+
+        assert_eq!(convergence_gens(&runs_list[1].1, 4), vec![f64::INFINITY; 4]);
+        assert_eq!(labeled[0].0, "fast (p = 0.013)");
+        assert_eq!(labeled[1].0, "slow (default)");
+    }
+}
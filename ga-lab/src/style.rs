@@ -0,0 +1,206 @@
+// The look of the graphs: the fonts, the size, the colors and the stroke widths. The style of the
+// graphs of a process is set once before the first graph is drawn, e.g. from a file.
+
+use font_kit::source::SystemSource;
+use plotters::style::{IntoFont, Palette, Palette100, Palette99, Palette9999, RGBColor, TextStyle};
+use std::sync::OnceLock;
+
+// The palettes of plotters the lines of a graph can be colored by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinePalette {
+    Palette99,
+    Palette9999,
+    Palette100,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartStyle {
+    pub fonts: Vec<String>, // by preference, the first one that is installed is used
+    pub width: u32,
+    pub height: u32,
+    pub palette: LinePalette,
+    pub dark: bool,      // light text and lines on a dark background
+    pub line_width: u32, // of the lines of the data, the other lines are drawn thinner
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self {
+            fonts: vec![
+                "Consolas".to_string(),
+                "DejaVu Sans Mono".to_string(),
+                "monospace".to_string(),
+            ],
+            width: 1280,
+            height: 720,
+            palette: LinePalette::Palette99,
+            dark: false,
+            line_width: 3,
+        }
+    }
+}
+
+impl ChartStyle {
+    // Parses a style from lines of "key = value", e.g. "fonts = Consolas, sans-serif". The keys are
+    // the fields of the style, the palette is one of 99, 9999 and 100. Blank lines and lines
+    // starting with '#' are skipped, the fields that are left out keep their default.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        fn value<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("bad value {} of {}", value, key))
+        }
+
+        let mut style = ChartStyle::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, v) = line
+                .split_once('=')
+                .map(|(key, v)| (key.trim(), v.trim()))
+                .ok_or_else(|| format!("expected key = value, got {}", line))?;
+            match key {
+                "fonts" => {
+                    style.fonts = v
+                        .split(',')
+                        .map(|font| font.trim().to_string())
+                        .filter(|font| !font.is_empty())
+                        .collect()
+                }
+                "width" => style.width = value(key, v)?,
+                "height" => style.height = value(key, v)?,
+                "palette" => {
+                    style.palette = match v {
+                        "99" => LinePalette::Palette99,
+                        "9999" => LinePalette::Palette9999,
+                        "100" => LinePalette::Palette100,
+                        _ => return Err(format!("unknown palette {}", v)),
+                    }
+                }
+                "dark" => style.dark = value(key, v)?,
+                "line_width" => style.line_width = value(key, v)?,
+                _ => return Err(format!("unknown key {}", key)),
+            }
+        }
+        Ok(style)
+    }
+
+    // The first of the fonts that is installed, the generic families always are. Falls back on
+    // "sans-serif" if none of them is.
+    pub fn font(&self) -> &str {
+        let system = SystemSource::new();
+        self.fonts
+            .iter()
+            .find(|font| {
+                ["serif", "sans-serif", "monospace"].contains(&font.as_str())
+                    || system.select_family_by_name(font).is_ok()
+            })
+            .map_or("sans-serif", String::as_str)
+    }
+
+    // The color of the line of a dataset with the given index
+    pub fn line_color(&self, idx: usize) -> RGBColor {
+        let colors = match self.palette {
+            LinePalette::Palette99 => Palette99::COLORS,
+            LinePalette::Palette9999 => Palette9999::COLORS,
+            LinePalette::Palette100 => Palette100::COLORS,
+        };
+        let (r, g, b) = colors[idx % colors.len()];
+        RGBColor(r, g, b)
+    }
+
+    pub fn background(&self) -> RGBColor {
+        if self.dark {
+            RGBColor(30, 30, 30)
+        } else {
+            RGBColor(255, 255, 255)
+        }
+    }
+
+    // The color of the text, the axes and the lines that are not data
+    pub fn foreground(&self) -> RGBColor {
+        if self.dark {
+            RGBColor(220, 220, 220)
+        } else {
+            RGBColor(0, 0, 0)
+        }
+    }
+}
+
+// The file the binaries read the chart style from, in the directory they are run from
+pub const CHART_STYLE_FILE: &str = "chart_style.cfg";
+
+static CHART_STYLE: OnceLock<ChartStyle> = OnceLock::new();
+static CHART_FONT: OnceLock<String> = OnceLock::new();
+
+// Sets the style of all graphs of the process. Fails once a style is set or a graph is drawn.
+pub fn set_chart_style(style: ChartStyle) -> Result<(), String> {
+    CHART_STYLE
+        .set(style)
+        .map_err(|_| "the chart style is already set".to_string())
+}
+
+// The style of the graphs, the default one if none has been set
+pub fn chart_style() -> &'static ChartStyle {
+    CHART_STYLE.get_or_init(ChartStyle::default)
+}
+
+// Text of the given size in the font and the foreground color of the chart style
+pub fn chart_text(size: u32) -> TextStyle<'static> {
+    let font = CHART_FONT.get_or_init(|| chart_style().font().to_string());
+    (font.as_str(), size)
+        .into_font()
+        .color(&chart_style().foreground())
+}
+
+// Reads the style of all graphs of the process from a file (see `ChartStyle::parse`) if it exists
+pub fn load_chart_style(path: &str) -> Result<(), String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => set_chart_style(ChartStyle::parse(&text)?),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(format!("{}: {}", path, error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_style_is_parsed_over_the_default() {
+        let style = ChartStyle::parse(
+            "# presentation\n\nfonts = Fira Code, monospace\nwidth = 1920\n palette = 9999 \ndark = true\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            style,
+            ChartStyle {
+                fonts: vec!["Fira Code".to_string(), "monospace".to_string()],
+                width: 1920,
+                palette: LinePalette::Palette9999,
+                dark: true,
+                ..ChartStyle::default()
+            }
+        );
+    }
+
+    #[test]
+    fn bad_styles_are_rejected() {
+        assert!(ChartStyle::parse("width: 1920").is_err());
+        assert!(ChartStyle::parse("height = tall").is_err());
+        assert!(ChartStyle::parse("palette = 42").is_err());
+        assert!(ChartStyle::parse("colour = red").is_err());
+    }
+
+    #[test]
+    fn missing_fonts_fall_back_on_the_next_one() {
+        let style = ChartStyle {
+            fonts: vec!["No Such Font 4b1d".to_string(), "monospace".to_string()],
+            ..ChartStyle::default()
+        };
+
+        assert_eq!(style.font(), "monospace");
+    }
+}
@@ -0,0 +1,74 @@
+// What the runtime analysis of the (1+1)-EA expects on the bitstring benchmarks of n bits, to be
+// drawn over the runs of the experiments (see `Annotation::Curve`). The (1+1)-EA flips each bit
+// of its single bitstring with probability 1/n and keeps the offspring unless it is worse.
+//
+// The expected best fitness is given by the number of evaluations, starting from a random
+// bitstring, by the usual approximation (1 - 1/n)^n ≈ 1/e of the fitness-level method.
+
+use std::f64::consts::E;
+
+// OneMax: one of the n - i 0s of a bitstring of i 1s flips on its own with probability
+// ≈ (n - i) / (e n), so the 0s drop by a factor of e^(-1 / (e n)) per evaluation from n / 2
+pub fn one_max_expected_fitness(n: usize, evaluations: u64) -> f64 {
+    let n = n as f64;
+    n - n / 2.0 * (-(evaluations as f64) / (E * n)).exp()
+}
+
+// The evaluations until the optimum of OneMax, e n ln(n): Θ(n log n)
+pub fn one_max_expected_runtime(n: usize) -> f64 {
+    let n = n as f64;
+    E * n * n.ln()
+}
+
+// LeadingOnes: the first 0 after i leading 1s flips on its own with probability ≈ e^(-i/n) / n,
+// and the random bits after it add another leading 1 on average. The fitness grows by
+// df/dt = 2 e^(-f/n) / n from the 1 leading 1 of a random bitstring, f(t) = n ln(e^(1/n) + 2t / n²).
+pub fn leading_ones_expected_fitness(n: usize, evaluations: u64) -> f64 {
+    let n = n as f64;
+    (n * ((1.0 / n).exp() + 2.0 * evaluations as f64 / (n * n)).ln()).min(n)
+}
+
+// The evaluations until the optimum of LeadingOnes, n² (e - e^(1/n)) / 2 ≈ 0.86 n²: Θ(n²)
+pub fn leading_ones_expected_runtime(n: usize) -> f64 {
+    let n = n as f64;
+    n * n * (E - (1.0 / n).exp()) / 2.0
+}
+
+// The expected fitness at each generation from the first, for `evaluations_per_gen` evaluations
+// per generation (e.g. those of a generation of the GA), up to `gens` generations
+pub fn expected_fitness_per_gen(
+    expected_fitness: impl Fn(u64) -> f64,
+    evaluations_per_gen: u64,
+    gens: u32,
+) -> Vec<f64> {
+    (1..=gens as u64)
+        .map(|gen| expected_fitness(gen * evaluations_per_gen))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_expected_fitness_reaches_the_optimum_at_the_expected_runtime() {
+        let n = 100;
+
+        assert_eq!(one_max_expected_fitness(n, 0), 50.0);
+        assert!(one_max_expected_fitness(n, one_max_expected_runtime(n) as u64) > 99.0);
+        assert!((leading_ones_expected_fitness(n, 0) - 1.0).abs() < 1e-9);
+        assert!(
+            (leading_ones_expected_fitness(n, leading_ones_expected_runtime(n) as u64) - 100.0)
+                .abs()
+                < 0.01
+        );
+        assert_eq!(leading_ones_expected_fitness(n, u64::MAX), 100.0);
+    }
+
+    #[test]
+    fn the_expected_fitness_is_sampled_once_per_gen() {
+        let curve = expected_fitness_per_gen(|evaluations| evaluations as f64, 4, 3);
+
+        assert_eq!(curve, vec![4.0, 8.0, 12.0]);
+    }
+}
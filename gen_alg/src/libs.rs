@@ -1,37 +1,19 @@
 
+use plotters::prelude::*;
 use rand::{
-    prelude::{random, Distribution},
-    distributions::Standard, thread_rng, seq::SliceRandom, Rng,
+    distributions::{Distribution, Standard},
+    rngs::StdRng, seq::SliceRandom, Rng, SeedableRng,
 };
+use std::fmt::{self, Debug, Display, Formatter};
 
-#[derive(Debug, Clone, Copy)]
-enum Nucleotide {
-    A,
-    C,
-    G,
-    T
-}
-
-impl Distribution<Nucleotide> for Standard {
-    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Nucleotide {
-        match rng.gen_range(0..=3) {
-            0 => Nucleotide::A,
-            1 => Nucleotide::C,
-            2 => Nucleotide::G,
-            _ => Nucleotide::T,
-        }
-    }
-}
-
-type Genome = Vec<Nucleotide>;
-
+// A genome of genes of type T, e.g. nucleotides, bits or integers
 #[derive(Debug, Clone)]
-pub struct Individual {
-    genome: Genome,
+pub struct Individual<T> {
+    genome: Vec<T>,
     fitness: u32,
 }
 
-impl Individual {
+impl<T: Debug> Individual<T> {
     pub fn new() -> Self {
         Self {
             genome: Vec::new(),
@@ -39,70 +21,296 @@ impl Individual {
         }
     }
 
-    pub fn init(&mut self, genome_size: u32) {
+    pub fn init<R: Rng>(&mut self, genome_size: u32, rng: &mut R)
+    where
+        Standard: Distribution<T>,
+    {
         for _ in 0..genome_size {
-            self.genome.push(random());
+            self.genome.push(rng.gen());
         }
     }
 
     pub fn print(&self) {
-        print!("Member {{ genome: [");
-        for nuc in self.genome.iter() {
-            print!("{}", match nuc {
-                Nucleotide::A => "A",
-                Nucleotide::C => "C",
-                Nucleotide::T => "T",
-                Nucleotide::G => "G",
-            });
-        }
-        println!("], fitness: {} }}", self.fitness);
+        println!("Member {{ genome: {:?}, fitness: {} }}", self.genome, self.fitness);
 
     }
 
-    pub fn update_fitness(&mut self) {
-        self.fitness = 0;
-        for nuc in self.genome.iter() {
-            self.fitness += match nuc {
-                Nucleotide::T => 1,
-                _ => 0
-            }
+    pub fn update_fitness<F: Fn(&[T]) -> u32>(&mut self, fitness: &F) {
+        self.fitness = fitness(&self.genome);
+    }
+}
+
+impl<T> From<Vec<T>> for Individual<T> {
+    fn from(genome: Vec<T>) -> Self {
+        Self {
+            genome,
+            fitness: 0,
         }
     }
 }
 
-type Population = Vec<Individual>;
+type Population<T> = Vec<Individual<T>>;
+
+// How the children of a generation are inserted back into the population
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Replacement {
+    // A single pair of children replaces the two worst individuals (steady state)
+    ReplaceWorst,
+    // The children replace the whole population, except for the `elites` best individuals
+    Generational { elites: u32 },
+}
+
+// The fitness of the population after a generation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenerationStats {
+    pub best: u32,
+    pub mean: f32,
+    pub worst: u32,
+}
+
+// The fitness of the population after each generation of a simulation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct History {
+    generations: Vec<GenerationStats>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
+    pub fn record(&mut self, stats: GenerationStats) {
+        self.generations.push(stats);
+    }
+
+    pub fn generations(&self) -> &[GenerationStats] {
+        &self.generations
+    }
+}
+
+// A simulation of genomes of genes of type T, which are maximized by the `fitness` closure. It
+// draws all of its random numbers from its own `rng`, so that a simulation with a seeded `rng` is
+// reproducible. The genes are drawn from rand's `Standard` distribution, which has to yield at
+// least two different genes for the mutation to flip a gene. A simulation is built by a
+// `SimulationBuilder`, which validates its parameters.
 #[derive(Debug)]
-pub struct Simulation {
+pub struct Simulation<T, F, R: Rng = StdRng> {
     population_size: u32,
     genome_size: u32,
     generations: u32,
     mutation_rate: f32,
     selection_pressure: f32,
-    population: Population,
+    replacement: Replacement,
+    fitness: F,
+    population: Population<T>,
+    history: History,
+    rng: R,
 }
 
-impl Simulation {
-    pub fn new(
+impl<T, F> Simulation<T, F>
+where
+    T: Clone + Debug + PartialEq,
+    Standard: Distribution<T>,
+    F: Fn(&[T]) -> u32,
+{
+    fn new(
+        population_size: u32,
+        genome_size: u32,
+        generations: u32,
+        mutation_rate: f32,
+        selection_pressure: f32,
+        fitness: F,
+    ) -> Self {
+        Self::with_rng(population_size, genome_size, generations, mutation_rate, selection_pressure, fitness, StdRng::from_entropy())
+    }
+
+    // The same simulation for the same seed
+    fn with_seed(
         population_size: u32,
         genome_size: u32,
         generations: u32,
         mutation_rate: f32,
         selection_pressure: f32,
+        fitness: F,
+        seed: u64,
+    ) -> Self {
+        Self::with_rng(population_size, genome_size, generations, mutation_rate, selection_pressure, fitness, StdRng::seed_from_u64(seed))
+    }
+}
+
+// Why a `SimulationBuilder` could not build a simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuildError {
+    // The mutation rate is a probability, so it has to be within 0..=1
+    MutationRateOutOfRange(f32),
+    // The selection pressure is the share of the population left out of the selection
+    SelectionPressureOutOfRange(f32),
+    // Selection needs at least 2 individuals to pick the parents from
+    PopulationTooSmall(u32),
+    // Crossover needs at least 2 genes to split the genomes at
+    GenomeTooSmall(u32),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MutationRateOutOfRange(rate) => {
+                write!(f, "mutation rate {} is not within 0..=1", rate)
+            }
+            BuildError::SelectionPressureOutOfRange(pressure) => {
+                write!(f, "selection pressure {} is not within 0..=1", pressure)
+            }
+            BuildError::PopulationTooSmall(size) => {
+                write!(f, "population size {} is less than 2", size)
+            }
+            BuildError::GenomeTooSmall(size) => write!(f, "genome size {} is less than 2", size),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
 
+// Builds a `Simulation` from named parameters, all of which but the fitness closure have a default
+#[derive(Debug, Clone)]
+pub struct SimulationBuilder<F> {
+    population_size: u32,
+    genome_size: u32,
+    generations: u32,
+    mutation_rate: f32,
+    selection_pressure: f32,
+    replacement: Replacement,
+    seed: Option<u64>,
+    fitness: F,
+}
+
+impl<F> SimulationBuilder<F> {
+    pub fn new(fitness: F) -> Self {
+        Self {
+            population_size: 20,
+            genome_size: 8,
+            generations: 50,
+            mutation_rate: 0.05,
+            selection_pressure: 0.4,
+            replacement: Replacement::ReplaceWorst,
+            seed: None,
+            fitness,
+        }
+    }
+
+    pub fn population_size(mut self, population_size: u32) -> Self {
+        self.population_size = population_size;
+        self
+    }
+
+    pub fn genome_size(mut self, genome_size: u32) -> Self {
+        self.genome_size = genome_size;
+        self
+    }
+
+    pub fn generations(mut self, generations: u32) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    pub fn mutation_rate(mut self, mutation_rate: f32) -> Self {
+        self.mutation_rate = mutation_rate;
+        self
+    }
+
+    pub fn selection_pressure(mut self, selection_pressure: f32) -> Self {
+        self.selection_pressure = selection_pressure;
+        self
+    }
+
+    pub fn replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    // Seeds the simulation's rng, so the same seed builds the same simulation
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn build<T>(self) -> Result<Simulation<T, F>, BuildError>
+    where
+        T: Clone + Debug + PartialEq,
+        Standard: Distribution<T>,
+        F: Fn(&[T]) -> u32,
+    {
+        if !(0.0..=1.0).contains(&self.mutation_rate) {
+            return Err(BuildError::MutationRateOutOfRange(self.mutation_rate));
+        }
+        if !(0.0..=1.0).contains(&self.selection_pressure) {
+            return Err(BuildError::SelectionPressureOutOfRange(self.selection_pressure));
+        }
+        if self.population_size < 2 {
+            return Err(BuildError::PopulationTooSmall(self.population_size));
+        }
+        if self.genome_size < 2 {
+            return Err(BuildError::GenomeTooSmall(self.genome_size));
+        }
+
+        let mut sim = match self.seed {
+            Some(seed) => Simulation::with_seed(
+                self.population_size,
+                self.genome_size,
+                self.generations,
+                self.mutation_rate,
+                self.selection_pressure,
+                self.fitness,
+                seed,
+            ),
+            None => Simulation::new(
+                self.population_size,
+                self.genome_size,
+                self.generations,
+                self.mutation_rate,
+                self.selection_pressure,
+                self.fitness,
+            ),
+        };
+        sim.set_replacement(self.replacement);
+        Ok(sim)
+    }
+}
+
+impl<T, F, R> Simulation<T, F, R>
+where
+    T: Clone + Debug + PartialEq,
+    Standard: Distribution<T>,
+    F: Fn(&[T]) -> u32,
+    R: Rng,
+{
+    fn with_rng(
+        population_size: u32,
+        genome_size: u32,
+        generations: u32,
+        mutation_rate: f32,
+        selection_pressure: f32,
+        fitness: F,
+        rng: R,
     ) -> Self { Self {
         population_size,
         genome_size,
         generations,
         mutation_rate,
         selection_pressure,
+        replacement: Replacement::ReplaceWorst,
+        fitness,
         population: Vec::new(),
+        history: History::new(),
+        rng,
     }}
+    pub fn set_replacement(&mut self, replacement: Replacement) {
+        self.replacement = replacement;
+    }
 
     pub fn init(&mut self) {
         for _ in 0..self.population_size {
             let mut mem = Individual::new();
-            mem.init(self.genome_size);
+            mem.init(self.genome_size, &mut self.rng);
             self.population.push(mem);
         }
         self.fit();
@@ -119,48 +327,397 @@ impl Simulation {
 
     fn fit(&mut self) {
         for mem in self.population.iter_mut() {
-            mem.update_fitness();
+            mem.update_fitness(&self.fitness);
         }
-        self.population.sort_by(|a, b| a.fitness.cmp(&b.fitness));
+        self.population.sort_by_key(|mem| mem.fitness);
     }
 
-    pub fn run(&mut self) {
+    // Runs the simulation, recording the fitness of the population after each generation
+    pub fn run(&mut self) -> &History {
         println!(
-            "--------------------------------------------------------\n| Running simulation with the following parameters:\n| population_size: {}\n| genome_size: {}\n| generations: {}\n| mutation_rate: {}\n| selection_pressure: {}\n--------------------------------------------------------",
-            self.population_size, self.genome_size, self.generations, self.mutation_rate, self.selection_pressure
+            "--------------------------------------------------------\n| Running simulation with the following parameters:\n| population_size: {}\n| genome_size: {}\n| generations: {}\n| mutation_rate: {}\n| selection_pressure: {}\n| replacement: {:?}\n--------------------------------------------------------",
+            self.population_size, self.genome_size, self.generations, self.mutation_rate, self.selection_pressure, self.replacement
         );
 
-        for gen in 1..=self.generations {
-            println!("Generation #{}:", gen);
-            
-            let parents = select(&self.population, self.selection_pressure);
-            let children = breed(parents, self.genome_size);
+        for _ in 1..=self.generations {
+            self.replace();
+            self.fit();
+            let stats = self.stats();
+            self.history.record(stats);
+        }
+        &self.history
+    }
 
-            println!("Parents: {:?}\nChildren: {:?}", parents, children);
+    pub fn history(&self) -> &History {
+        &self.history
+    }
 
-            self.fit();
-            //self.print();
+    // Plots the best, mean and worst fitness of each recorded generation
+    pub fn plot(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let generations = self.history.generations();
+        let gens_max = generations.len().max(2) as u32;
+        let fitness_max = generations.iter().map(|stats| stats.best).max().unwrap_or(0) + 1;
+
+        // Drawing root
+        let root = BitMapBackend::new(path, (1280, 720)).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        // Chart
+        let mut chart = ChartBuilder::on(&root)
+            .caption("Convergence", ("Consolas", 50).into_font())
+            .margin(10)
+            .x_label_area_size(60)
+            .y_label_area_size(60)
+            .build_cartesian_2d(1..gens_max, 0.0..fitness_max as f32)?;
+
+        // Mesh configuration
+        chart
+            .configure_mesh()
+            .y_labels(6)
+            .x_labels(16)
+            .y_desc("fitness")
+            .x_desc("gens")
+            .label_style(("Consolas", 25).into_font())
+            .draw()?;
+
+        // Draw a line for each of the best, mean and worst fitness
+        let lines = [
+            ("best", generations.iter().map(|stats| stats.best as f32).collect::<Vec<_>>()),
+            ("mean", generations.iter().map(|stats| stats.mean).collect()),
+            ("worst", generations.iter().map(|stats| stats.worst as f32).collect()),
+        ];
+        for (idx, (label, values)) in lines.into_iter().enumerate() {
+            let color = Palette99::pick(idx).mix(0.6);
+            chart
+                .draw_series(LineSeries::new(
+                    values.into_iter().enumerate().map(|(x, y)| (x as u32 + 1, y)),
+                    color.stroke_width(3),
+                ))?
+                .label(label)
+                .legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(3))
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .label_font(("Consolas", 25).into_font())
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+
+        root.present()?;
+
+        Ok(())
+    }
+
+    // Selects two parents and breeds a pair of mutated children from them
+    fn offspring(&mut self) -> (Individual<T>, Individual<T>) {
+        let parents = select(&self.population, self.selection_pressure, &mut self.rng);
+        let mut children = breed(parents, self.genome_size, &mut self.rng);
+        mutate(&mut children.0, self.mutation_rate, &mut self.rng);
+        mutate(&mut children.1, self.mutation_rate, &mut self.rng);
+        (children.0.into(), children.1.into())
+    }
+
+    // Inserts the children of a generation into the (sorted) population
+    fn replace(&mut self) {
+        match self.replacement {
+            Replacement::ReplaceWorst => {
+                let children = self.offspring();
+                self.population[0] = children.0;
+                self.population[1] = children.1;
+            }
+            Replacement::Generational { elites } => {
+                let elites = (elites as usize).min(self.population.len());
+                let mut next = self.population[self.population.len() - elites..].to_vec();
+                while next.len() < self.population_size as usize {
+                    let children = self.offspring();
+                    next.push(children.0);
+                    if next.len() < self.population_size as usize {
+                        next.push(children.1);
+                    }
+                }
+                self.population = next;
+            }
         }
     }
 
+    fn stats(&self) -> GenerationStats {
+        GenerationStats {
+            best: self.population.iter().map(|mem| mem.fitness).max().unwrap_or(0),
+            mean: self.population.iter().map(|mem| mem.fitness).sum::<u32>() as f32
+                / self.population.len() as f32,
+            worst: self.population.iter().map(|mem| mem.fitness).min().unwrap_or(0),
+        }
+    }
+}
+
+// Picks two parents at random from the fittest of the (sorted) population. The selection pressure
+// is the share of the least fit individuals left out, so 0 picks from the whole population and 1
+// from the 2 fittest only
+fn select<'a, T, R: Rng>(
+    population: &'a Population<T>,
+    selection_pressure: f32,
+    rng: &mut R,
+) -> (&'a Vec<T>, &'a Vec<T>) {
+    let selected = ((1.0 - selection_pressure) * population.len() as f32).ceil() as usize;
+    let fittest = &population[population.len() - selected.max(2).min(population.len())..];
+    (&fittest.choose(rng).unwrap().genome, &fittest.choose(rng).unwrap().genome)
+}
+
+// Single-point crossover: picks a split in 1..genome_size, so that each child gets at least one
+// gene from each parent
+fn breed<T: Clone, R: Rng>(parents: (&Vec<T>, &Vec<T>), genome_size: u32, rng: &mut R) -> (Vec<T>, Vec<T>) {
+    assert!(genome_size >= 2, "crossover needs a genome of at least 2 genes");
+    let split = rng.gen_range(1..genome_size);
+    crossover(parents, genome_size, split)
+}
+
+// The first child takes the genes before the split from the first parent and the rest from the
+// second one, the second child the other way around
+fn crossover<T: Clone>(parents: (&Vec<T>, &Vec<T>), genome_size: u32, split: u32) -> (Vec<T>, Vec<T>) {
+    let (genome_size, split) = (genome_size as usize, split as usize);
+    (
+        parents.0[..split].iter().chain(parents.1[split..genome_size].iter()).cloned().collect(),
+        parents.1[..split].iter().chain(parents.0[split..genome_size].iter()).cloned().collect(),
+    )
 }
 
-fn select(population: &Population, _selection_pressure: f32) -> (&Vec<Nucleotide>, &Vec<Nucleotide>) {
-    let mut rng = thread_rng();
-    (&population.choose(&mut rng).unwrap().genome, &population.choose(&mut rng).unwrap().genome)
+// Flips each gene of the genome to a different random one with a probability of `mutation_rate`
+fn mutate<T: PartialEq, R: Rng>(genome: &mut [T], mutation_rate: f32, rng: &mut R)
+where
+    Standard: Distribution<T>,
+{
+    for gene in genome.iter_mut() {
+        if rng.gen::<f32>() < mutation_rate {
+            *gene = loop {
+                let flipped: T = rng.gen();
+                if flipped != *gene {
+                    break flipped;
+                }
+            };
+        }
+    }
 }
 
-fn breed(parents: (&Vec<Nucleotide>, &Vec<Nucleotide>), genome_size: u32) -> (Vec<Nucleotide>, Vec<Nucleotide>) {
-    let mut rng = thread_rng();
-    let split = rng.gen_range(1..(genome_size - 1));
-    println!("split: {}", split);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ga_lab::{fitness::num_ts, genome::Nucleotide};
+
+    fn num_ts_fitness(genome: &[Nucleotide]) -> u32 {
+        num_ts(genome) as u32
+    }
+
+    #[test]
+    fn crossover_swaps_the_genes_after_the_split() {
+        let parents = (vec![Nucleotide::A; 8], vec![Nucleotide::T; 8]);
+
+        let children = crossover((&parents.0, &parents.1), 8, 3);
+
+        assert_eq!(children.0, [vec![Nucleotide::A; 3], vec![Nucleotide::T; 5]].concat());
+        assert_eq!(children.1, [vec![Nucleotide::T; 3], vec![Nucleotide::A; 5]].concat());
+    }
+
+    #[test]
+    fn breed_returns_children_of_genome_size_with_genes_of_both_parents() {
+        let mut rng = StdRng::seed_from_u64(415);
+        let parents = (vec![Nucleotide::A; 8], vec![Nucleotide::T; 8]);
+
+        for _ in 0..100 {
+            let children = breed((&parents.0, &parents.1), 8, &mut rng);
+
+            assert_eq!(children.0.len(), 8);
+            assert_eq!(children.1.len(), 8);
+            // Each child starts with its own parent's genes and switches to the other's once
+            let split = children.0.iter().position(|n| *n == Nucleotide::T).unwrap();
+            assert!(split >= 1);
+            assert!(children.0[split..].iter().all(|n| *n == Nucleotide::T));
+            assert!(children.1[..split].iter().all(|n| *n == Nucleotide::T));
+            assert!(children.1[split..].iter().all(|n| *n == Nucleotide::A));
+        }
+    }
+
+    #[test]
+    fn mutate_with_rate_0_leaves_the_genome_unchanged() {
+        let mut rng = StdRng::seed_from_u64(415);
+        let mut genome = vec![Nucleotide::A; 100];
+
+        mutate(&mut genome, 0.0, &mut rng);
+
+        assert_eq!(genome, vec![Nucleotide::A; 100]);
+    }
+
+    #[test]
+    fn mutate_with_rate_1_flips_every_nucleotide() {
+        let mut rng = StdRng::seed_from_u64(415);
+        let mut genome = vec![Nucleotide::A; 100];
+
+        mutate(&mut genome, 1.0, &mut rng);
+
+        assert!(genome.iter().all(|n| *n != Nucleotide::A));
+    }
+
+    #[test]
+    fn mutate_flips_each_nucleotide_with_the_mutation_rate() {
+        let mut rng = StdRng::seed_from_u64(415);
+        let mut genome = vec![Nucleotide::A; 10_000];
+
+        mutate(&mut genome, 0.1, &mut rng);
+
+        let flipped = genome.iter().filter(|n| **n != Nucleotide::A).count();
+        assert!((900..1100).contains(&flipped), "flipped {} of 10000", flipped);
+    }
+
+    #[test]
+    fn select_picks_the_parents_from_the_fittest_share_of_the_population() {
+        let mut rng = StdRng::seed_from_u64(415);
+        // Sorted by fitness like the population of a simulation, the genome is the rank
+        let population: Population<u32> = (0..10).map(|rank| vec![rank].into()).collect();
 
-    let parents = (parents.0.iter().enumerate(), parents.1.iter().enumerate());
-    let mut children = (Vec::<Nucleotide>::new(), Vec::<Nucleotide>::new());
-    
-    //for i in 0..split {
-        //children.0.push(parents.1.next());
-    //}
-    
-    children
-}
\ No newline at end of file
+        for _ in 0..100 {
+            let parents = select(&population, 0.5, &mut rng);
+            assert!(parents.0[0] >= 5 && parents.1[0] >= 5);
+
+            let parents = select(&population, 1.0, &mut rng);
+            assert!(parents.0[0] >= 8 && parents.1[0] >= 8);
+        }
+    }
+
+    #[test]
+    fn run_returns_the_stats_of_each_generation() {
+        let mut sim = Simulation::with_seed(20, 8, 30, 0.05, 0.4, num_ts_fitness, 415);
+        sim.init();
+
+        sim.run();
+
+        let stats = sim.history().generations();
+        assert_eq!(stats.len(), 30);
+        assert_eq!(sim.population.len(), 20);
+        assert!(stats
+            .iter()
+            .all(|s| s.worst as f32 <= s.mean && s.mean <= s.best as f32));
+    }
+
+    #[test]
+    fn replace_worst_never_loses_the_best_individual() {
+        let mut sim = Simulation::with_seed(20, 8, 50, 0.05, 0.4, num_ts_fitness, 415);
+        sim.init();
+
+        let stats = sim.run().generations();
+
+        assert!(stats.windows(2).all(|w| w[0].best <= w[1].best));
+    }
+
+    #[test]
+    fn generational_replacement_keeps_the_elites() {
+        let mut sim = Simulation::with_seed(21, 8, 50, 0.05, 0.4, num_ts_fitness, 415);
+        sim.set_replacement(Replacement::Generational { elites: 2 });
+        sim.init();
+
+        sim.run();
+
+        let stats = sim.history().generations();
+        assert_eq!(sim.population.len(), 21);
+        assert!(stats.windows(2).all(|w| w[0].best <= w[1].best));
+    }
+
+    #[test]
+    fn simulations_with_the_same_seed_are_identical() {
+        let mut sims = [
+            Simulation::with_seed(20, 8, 30, 0.05, 0.4, num_ts_fitness, 415),
+            Simulation::with_seed(20, 8, 30, 0.05, 0.4, num_ts_fitness, 415),
+        ];
+        for sim in sims.iter_mut() {
+            sim.init();
+        }
+
+        assert_eq!(sims[0].run().clone(), *sims[1].run());
+        assert!(sims[0]
+            .population
+            .iter()
+            .zip(sims[1].population.iter())
+            .all(|(a, b)| a.genome == b.genome));
+    }
+
+    #[test]
+    fn simulation_evolves_binary_genomes() {
+        let one_max = |genome: &[bool]| genome.iter().filter(|bit| **bit).count() as u32;
+        let mut sim = Simulation::with_seed(20, 16, 200, 0.05, 0.4, one_max, 415);
+        sim.set_replacement(Replacement::Generational { elites: 2 });
+        sim.init();
+
+        let stats = sim.run().generations();
+
+        assert!(stats.last().unwrap().best > stats.first().unwrap().best);
+    }
+
+    #[test]
+    fn simulation_evolves_integer_genomes() {
+        let target = 200u8;
+        let closeness = move |genome: &[u8]| {
+            genome.iter().map(|x| 255 - x.abs_diff(target) as u32).sum()
+        };
+        let mut sim = Simulation::with_seed(20, 4, 200, 0.1, 0.4, closeness, 415);
+        sim.init();
+
+        let stats = sim.run().generations();
+
+        assert!(stats.last().unwrap().best > stats.first().unwrap().best);
+    }
+
+    #[test]
+    fn builder_builds_the_default_simulation() {
+        let mut sim = SimulationBuilder::new(num_ts_fitness).seed(415).build().unwrap();
+        sim.init();
+
+        assert_eq!(sim.population.len(), 20);
+        assert_eq!(sim.population[0].genome.len(), 8);
+        assert_eq!(sim.run().generations().len(), 50);
+    }
+
+    #[test]
+    fn builder_rejects_mutation_rates_outside_0_to_1() {
+        for rate in [-0.1, 1.5, f32::NAN] {
+            let result = SimulationBuilder::new(num_ts_fitness).mutation_rate(rate).build();
+
+            assert!(matches!(result, Err(BuildError::MutationRateOutOfRange(_))));
+        }
+    }
+
+    #[test]
+    fn builder_rejects_selection_pressures_outside_0_to_1() {
+        for pressure in [-0.1, 1.5, f32::NAN] {
+            let result = SimulationBuilder::new(num_ts_fitness)
+                .selection_pressure(pressure)
+                .build();
+
+            assert!(matches!(result, Err(BuildError::SelectionPressureOutOfRange(_))));
+        }
+    }
+
+    #[test]
+    fn builder_rejects_populations_of_less_than_2() {
+        let result = SimulationBuilder::new(num_ts_fitness).population_size(1).build();
+
+        assert!(matches!(result, Err(BuildError::PopulationTooSmall(1))));
+    }
+
+    #[test]
+    fn builder_with_the_same_seed_builds_the_same_simulation() {
+        let build = || {
+            SimulationBuilder::new(num_ts_fitness)
+                .replacement(Replacement::Generational { elites: 2 })
+                .seed(415)
+                .build()
+                .unwrap()
+        };
+        let mut sims = [build(), build()];
+        for sim in sims.iter_mut() {
+            sim.init();
+        }
+
+        assert_eq!(sims[0].run().clone(), *sims[1].run());
+    }
+}
@@ -1,16 +1,40 @@
 mod libs;
-use libs::{Simulation};
-
+use ga_lab::{fitness::num_ts, genome::Nucleotide};
+use libs::{Replacement, SimulationBuilder};
+use std::fs::create_dir_all;
 
+// Output file path of the convergence curve
+const OUT_CONVERGENCE: &str = "output/convergence.png";
 
 fn main() {
-    let mut sim1 = Simulation::new(
-        20,
-        8,
-        1,
-        0.0,
-        0.4
-    );
+    // Count the Ts of DNA strands
+    let fitness = |genome: &[Nucleotide]| num_ts(genome) as u32;
+
+    let mut builder = SimulationBuilder::new(fitness)
+        .population_size(20)
+        .genome_size(8)
+        .generations(50)
+        .mutation_rate(0.05)
+        .selection_pressure(0.4)
+        .replacement(Replacement::Generational { elites: 2 });
+    // Pass a seed as the first argument to reproduce a run
+    if let Some(seed) = std::env::args().nth(1).and_then(|arg| arg.parse().ok()) {
+        builder = builder.seed(seed);
+    }
+    let mut sim1 = builder.build().unwrap();
     sim1.init();
     sim1.run();
+    for (gen, stats) in sim1.history().generations().iter().enumerate() {
+        println!(
+            "Generation #{}: best fitness {}, mean fitness {:.2}, worst fitness {}",
+            gen + 1,
+            stats.best,
+            stats.mean,
+            stats.worst
+        );
+    }
+    sim1.print();
+
+    create_dir_all("output").unwrap();
+    sim1.plot(OUT_CONVERGENCE).unwrap();
 }
@@ -0,0 +1,183 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion};
+use genevo::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Children, Parents},
+    operator::{prelude::*, CrossoverOp, MutationOp, SelectionOp},
+    population::{build_population, BinaryEncodedGenomeBuilder},
+    random::{get_rng, random_n_cut_points, Rng},
+};
+use std::rc::Rc;
+
+type Bits = Vec<bool>;
+
+const GENOME_LENGTHS: [usize; 3] = [16, 128, 1024];
+
+fn genomes(genome_length: usize, population_size: usize) -> Vec<Bits> {
+    build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(genome_length))
+        .of_size(population_size)
+        .using_seed([42; 32])
+        .individuals()
+        .to_vec()
+}
+
+fn bench_crossover_op<C>(c: &mut Criterion, name: &str, breeder: C)
+where
+    C: CrossoverOp<Bits>,
+{
+    let mut rng = get_rng([42; 32]);
+
+    let mut group = c.benchmark_group(format!("crossover {}", name));
+    for length in GENOME_LENGTHS {
+        let parents = genomes(length, 2);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(length),
+            &parents,
+            |b, parents| b.iter(|| breeder.crossover(parents.clone(), &mut rng)),
+        );
+    }
+}
+
+fn bench_crossover(c: &mut Criterion) {
+    bench_crossover_op(c, "single point", SinglePointCrossBreeder::new());
+    bench_crossover_op(
+        c,
+        "multi point (4 cut points)",
+        MultiPointCrossBreeder::new(4),
+    );
+    bench_crossover_op(c, "uniform", UniformCrossBreeder::new());
+}
+
+/// The multi point crossover of `Vec`s as it was before it copied whole
+/// slices, the reference for `bench_multi_point_crossover_of_long_genomes`.
+fn gene_by_gene_multi_point_crossover<R>(
+    parents: Parents<Bits>,
+    num_cut_points: usize,
+    rng: &mut R,
+) -> Children<Bits>
+where
+    R: Rng + Sized,
+{
+    let genome_length = parents[0].len();
+    let num_parents = parents.len();
+    let mut offspring: Vec<Bits> = Vec::with_capacity(num_parents);
+    while num_parents > offspring.len() {
+        let mut genome = Vec::with_capacity(genome_length);
+        let mut cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
+        cutpoints.push(genome_length);
+        let mut start = 0;
+        let mut end = cutpoints.remove(0);
+        let mut p_index = num_parents;
+        loop {
+            loop {
+                let index = rng.gen_range(0..num_parents);
+                if index != p_index {
+                    p_index = index;
+                    break;
+                }
+            }
+            let partner = &parents[p_index];
+            for partner in partner.iter().take(end).skip(start) {
+                genome.push(*partner)
+            }
+            if cutpoints.is_empty() {
+                break;
+            }
+            start = end;
+            end = cutpoints.remove(0);
+        }
+        offspring.push(genome);
+    }
+    offspring
+}
+
+fn bench_multi_point_crossover_of_long_genomes(c: &mut Criterion) {
+    let mut rng = get_rng([42; 32]);
+    let breeder = MultiPointCrossBreeder::new(16);
+
+    let mut group = c.benchmark_group("crossover multi point (16 cut points) of long genomes");
+    for length in [1_000, 10_000, 100_000] {
+        let parents = genomes(length, 2);
+        group.bench_with_input(
+            BenchmarkId::new("gene by gene", length),
+            &parents,
+            |b, parents| {
+                b.iter(|| gene_by_gene_multi_point_crossover(parents.clone(), 16, &mut rng))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("slices", length),
+            &parents,
+            |b, parents| b.iter(|| breeder.crossover(parents.clone(), &mut rng)),
+        );
+    }
+}
+
+fn bench_mutation(c: &mut Criterion) {
+    let mut rng = get_rng([42; 32]);
+    let mutator = RandomValueMutator::new(0.05, false, true);
+
+    let mut group = c.benchmark_group("mutation random value");
+    for length in GENOME_LENGTHS {
+        let genome = genomes(length, 1).remove(0);
+        group.bench_with_input(BenchmarkId::from_parameter(length), &genome, |b, genome| {
+            b.iter(|| mutator.mutate(genome.clone(), &mut rng))
+        });
+    }
+}
+
+fn evaluated_population(population_size: usize) -> EvaluatedPopulation<Bits, usize> {
+    let individuals = genomes(64, population_size);
+    let fitness: Vec<usize> = individuals
+        .iter()
+        .map(|genome| genome.iter().filter(|bit| **bit).count())
+        .collect();
+    let highest = *fitness.iter().max().unwrap();
+    let lowest = *fitness.iter().min().unwrap();
+    let average = fitness.iter().sum::<usize>() / fitness.len();
+    EvaluatedPopulation::new(Rc::new(individuals), fitness, highest, lowest, average)
+}
+
+fn bench_selection_op<S>(c: &mut Criterion, name: &str, selector: S)
+where
+    S: SelectionOp<Bits, usize>,
+{
+    let mut rng = get_rng([42; 32]);
+
+    let mut group = c.benchmark_group(format!("selection {}", name));
+    for population_size in [64, 256, 1024] {
+        let population = evaluated_population(population_size);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(population_size),
+            &population,
+            |b, population| b.iter(|| selector.select_from(population, &mut rng)),
+        );
+    }
+}
+
+fn bench_selection(c: &mut Criterion) {
+    bench_selection_op(c, "maximize", MaximizeSelector::new(0.7, 2));
+    bench_selection_op(
+        c,
+        "tournament",
+        TournamentSelector::new(0.7, 2, 4, 1., false),
+    );
+    bench_selection_op(c, "roulette wheel", RouletteWheelSelector::new(0.7, 2));
+    bench_selection_op(
+        c,
+        "universal sampling",
+        UniversalSamplingSelector::new(0.7, 2),
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_crossover,
+    bench_multi_point_crossover_of_long_genomes,
+    bench_mutation,
+    bench_selection
+);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion};
+use genevo::{
+    operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*, random::get_rng,
+};
+
+type Bits = Vec<bool>;
+
+/// Counts the ones of a genome the hard way, to simulate an expensive fitness
+/// function.
+#[derive(Clone, Debug)]
+struct ExpensiveCountOnes;
+
+impl FitnessFunction<Bits, usize> for ExpensiveCountOnes {
+    fn fitness_of(&self, genome: &Bits) -> usize {
+        let mut fitness = 0;
+        for round in 0..1_000 {
+            let ones = genome.iter().filter(|bit| **bit).count();
+            fitness = criterion::black_box(ones + round) - round;
+        }
+        fitness
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        64
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+fn bench_evaluation(c: &mut Criterion) {
+    let seed = [42; 32];
+    let population: Population<Bits> = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(64))
+        .of_size(256)
+        .using_seed(seed);
+
+    let mut group = c.benchmark_group("generation with expensive fitness");
+    for parallel in [false, true] {
+        let mut alg = genetic_algorithm()
+            .with_evaluation(ExpensiveCountOnes)
+            .with_selection(MaximizeSelector::new(0.7, 2))
+            .with_crossover(SinglePointCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.01, false, true))
+            .with_reinsertion(ElitistReinserter::new(ExpensiveCountOnes, true, 0.7))
+            .with_initial_population(population.clone())
+            .with_parallel_evaluation(parallel)
+            .build();
+        let mut rng = get_rng(seed);
+        let name = if parallel { "parallel" } else { "serial" };
+        group.bench_with_input(BenchmarkId::from_parameter(name), &parallel, |b, _| {
+            let mut iteration = 0;
+            b.iter(|| {
+                iteration += 1;
+                alg.next(iteration, &mut rng).unwrap()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_evaluation);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion};
+use genevo::{
+    operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*, random::get_rng,
+};
+
+type Bits = Vec<bool>;
+
+#[derive(Clone, Debug)]
+struct CountOnes;
+
+impl FitnessFunction<Bits, usize> for CountOnes {
+    fn fitness_of(&self, genome: &Bits) -> usize {
+        genome.iter().filter(|bit| **bit).count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        64
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+/// Runs a small simulation for a fixed number of generations, so every
+/// iteration does the same amount of work.
+fn bench_small_simulation(c: &mut Criterion) {
+    let seed = [42; 32];
+    let population: Population<Bits> = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(64))
+        .of_size(100)
+        .using_seed(seed);
+
+    c.bench_function("small simulation of 50 generations", |b| {
+        b.iter(|| {
+            let mut sim = simulate(
+                genetic_algorithm()
+                    .with_evaluation(CountOnes)
+                    .with_selection(MaximizeSelector::new(0.7, 2))
+                    .with_crossover(MultiPointCrossBreeder::new(2))
+                    .with_mutation(RandomValueMutator::new(0.01, false, true))
+                    .with_reinsertion(ElitistReinserter::new(CountOnes, true, 0.7))
+                    .with_initial_population(population.clone())
+                    .build(),
+            )
+            .until(GenerationLimit::new(50))
+            .build_with_seed(seed);
+            sim.run().unwrap()
+        })
+    });
+}
+
+/// Runs single generations of a large population of long genomes, where the
+/// breeding stage allocates a lot of genomes.
+fn bench_generation_of_large_population(c: &mut Criterion) {
+    let seed = [42; 32];
+
+    let mut group = c.benchmark_group("generation of 1000 genomes");
+    group.sample_size(20);
+    for genome_length in [1_000, 10_000] {
+        let population: Population<Bits> = build_population()
+            .with_genome_builder(BinaryEncodedGenomeBuilder::new(genome_length))
+            .of_size(1_000)
+            .using_seed(seed);
+        let mut alg = genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.7, 2))
+            .with_crossover(MultiPointCrossBreeder::new(4))
+            .with_mutation(RandomValueMutator::new(0.001, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, true, 0.7))
+            .with_initial_population(population)
+            .build();
+        let mut rng = get_rng(seed);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(genome_length),
+            &genome_length,
+            |b, _| {
+                let mut iteration = 0;
+                b.iter(|| {
+                    iteration += 1;
+                    alg.next(iteration, &mut rng).unwrap()
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_small_simulation,
+    bench_generation_of_large_population
+);
+criterion_main!(benches);
@@ -0,0 +1,241 @@
+//! The `regression` example evolves an expression for the quartic polynomial
+//! x⁴ + x³ + x² + x from samples of its values by
+//! [symbolic regression](https://en.wikipedia.org/wiki/Symbolic_regression),
+//! the classic problem of genetic programming.
+
+use genevo::{
+    encoding::{Primitive, Primitives, TreeGenome},
+    operator::prelude::*,
+    population::TreeGenomeBuilder,
+    prelude::*,
+    types::fmt::Display,
+};
+
+const NUM_SAMPLES: usize = 20;
+
+const HIGHEST_FITNESS: usize = 1_000_000;
+
+#[derive(Debug)]
+struct Parameter {
+    population_size: usize,
+    generation_limit: u64,
+    num_individuals_per_parents: usize,
+    selection_ratio: f64,
+    min_initial_depth: usize,
+    max_initial_depth: usize,
+    max_depth: usize,
+    subtree_mutation_rate: f64,
+    point_mutation_rate: f64,
+    reinsertion_ratio: f64,
+}
+
+impl Default for Parameter {
+    fn default() -> Self {
+        Parameter {
+            population_size: 500,
+            generation_limit: 200,
+            num_individuals_per_parents: 2,
+            selection_ratio: 0.7,
+            min_initial_depth: 2,
+            max_initial_depth: 6,
+            max_depth: 10,
+            subtree_mutation_rate: 0.1,
+            point_mutation_rate: 0.05,
+            reinsertion_ratio: 0.7,
+        }
+    }
+}
+
+/// The primitives of the expressions
+#[derive(Clone, Debug, PartialEq)]
+enum Op {
+    X,
+    One,
+    Add,
+    Sub,
+    Mul,
+    /// Protected division, which is 1 for a divisor of 0
+    Div,
+}
+
+impl Primitive for Op {
+    fn arity(&self) -> usize {
+        match self {
+            Op::X | Op::One => 0,
+            Op::Add | Op::Sub | Op::Mul | Op::Div => 2,
+        }
+    }
+}
+
+/// The genotype
+type Expression = TreeGenome<Op>;
+
+/// How do the genes of the genotype show up in the phenotype
+trait AsPhenotype {
+    fn value_at(&self, x: f64) -> f64;
+
+    fn as_formula(&self) -> String;
+}
+
+impl AsPhenotype for Expression {
+    fn value_at(&self, x: f64) -> f64 {
+        self.evaluate(|op, args: &[f64]| match op {
+            Op::X => x,
+            Op::One => 1.,
+            Op::Add => args[0] + args[1],
+            Op::Sub => args[0] - args[1],
+            Op::Mul => args[0] * args[1],
+            Op::Div if args[1] == 0. => 1.,
+            Op::Div => args[0] / args[1],
+        })
+    }
+
+    fn as_formula(&self) -> String {
+        self.evaluate(|op, args: &[String]| match op {
+            Op::X => "x".to_string(),
+            Op::One => "1".to_string(),
+            Op::Add => format!("({} + {})", args[0], args[1]),
+            Op::Sub => format!("({} - {})", args[0], args[1]),
+            Op::Mul => format!("{} * {}", args[0], args[1]),
+            Op::Div => format!("{} / {}", args[0], args[1]),
+        })
+    }
+}
+
+fn target(x: f64) -> f64 {
+    x * x * x * x + x * x * x + x * x + x
+}
+
+/// The fitness function for `Expression`s, which decreases with the sum of
+/// the absolute errors at the samples.
+#[derive(Clone, Debug)]
+struct FitnessCalc {
+    samples: Vec<(f64, f64)>,
+}
+
+impl FitnessCalc {
+    fn new() -> Self {
+        let samples = (0..NUM_SAMPLES)
+            .map(|index| {
+                let x = -1. + 2. * index as f64 / (NUM_SAMPLES - 1) as f64;
+                (x, target(x))
+            })
+            .collect();
+        FitnessCalc { samples }
+    }
+}
+
+impl FitnessFunction<Expression, usize> for FitnessCalc {
+    fn fitness_of(&self, genome: &Expression) -> usize {
+        let error: f64 = self
+            .samples
+            .iter()
+            .map(|(x, y)| (genome.value_at(*x) - y).abs())
+            .sum();
+        if error.is_finite() {
+            (HIGHEST_FITNESS as f64 / (1. + error)).round() as usize
+        } else {
+            0
+        }
+    }
+
+    fn average(&self, fitness_values: &[usize]) -> usize {
+        fitness_values.iter().sum::<usize>() / fitness_values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        HIGHEST_FITNESS
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+fn main() {
+    let params = Parameter::default();
+    let primitives = Primitives::new(
+        vec![Op::X, Op::One],
+        vec![Op::Add, Op::Sub, Op::Mul, Op::Div],
+    );
+
+    let initial_population: Population<Expression> = build_population()
+        .with_genome_builder(TreeGenomeBuilder::new(
+            primitives.clone(),
+            params.min_initial_depth,
+            params.max_initial_depth,
+        ))
+        .of_size(params.population_size)
+        .uniform_at_random();
+
+    let mut regression_sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(FitnessCalc::new())
+            .with_selection(MaximizeSelector::new(
+                params.selection_ratio,
+                params.num_individuals_per_parents,
+            ))
+            .with_crossover(SubtreeCrossBreeder::new().with_max_depth(params.max_depth))
+            .with_mutation(EnsembleMutator::new(
+                (
+                    SubtreeMutator::new(params.subtree_mutation_rate, primitives.clone(), 3)
+                        .with_max_depth(params.max_depth),
+                    PointMutator::new(params.point_mutation_rate, primitives),
+                ),
+                OperatorWeights::equal(2),
+            ))
+            .with_reinsertion(ElitistReinserter::new(
+                FitnessCalc::new(),
+                true,
+                params.reinsertion_ratio,
+            ))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(or(
+        FitnessLimit::new(HIGHEST_FITNESS),
+        GenerationLimit::new(params.generation_limit),
+    ))
+    .build();
+
+    println!("Starting symbolic regression with: {:?}", params);
+
+    loop {
+        let result = regression_sim.step();
+        match result {
+            Ok(SimResult::Intermediate(step)) => {
+                let evaluated_population = step.result.evaluated_population;
+                let best_solution = step.result.best_solution;
+                println!(
+                    "Step: generation: {}, average_fitness: {}, \
+                     best fitness: {}, duration: {}, processing_time: {}",
+                    step.iteration,
+                    evaluated_population.average_fitness(),
+                    best_solution.solution.fitness,
+                    step.duration.fmt(),
+                    step.processing_time.fmt()
+                );
+                println!("      {}", best_solution.solution.genome.as_formula());
+            },
+            Ok(SimResult::Final(step, processing_time, duration, stop_reason)) => {
+                let best_solution = step.result.best_solution;
+                println!("{}", stop_reason);
+                println!(
+                    "Final result after {}: generation: {}, \
+                     best solution with fitness {} found in generation {}, processing_time: {}",
+                    duration.fmt(),
+                    step.iteration,
+                    best_solution.solution.fitness,
+                    best_solution.generation,
+                    processing_time.fmt()
+                );
+                println!("      {}", best_solution.solution.genome.as_formula());
+                break;
+            },
+            Err(error) => {
+                println!("{}", error);
+                break;
+            },
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! This module provides an `algorithm::Algorithm` which implements ant colony
+//! optimization (ACO) for the travelling salesman problem (TSP), the Ant
+//! System of Dorigo, optionally with elitist ants.
+//!
+//! The tours are permutation encoded `genetic::Genotype`s of the indices of
+//! the cities, the distances between which are given as a matrix. In each
+//! iteration:
+//!
+//! 1. **Construction**: Each ant builds a tour from a random city. At each
+//!    step it moves to an unvisited city j from city i with a probability
+//!    proportional to τ(i, j)^α η(i, j)^β, where τ is the pheromone on the
+//!    edge and η = 1 / d(i, j) is the heuristic desirability of the edge.
+//! 2. **Evaluation**: The fitness of each tour is evaluated.
+//! 3. **Pheromone update**: The pheromone of every edge evaporates by the rate
+//!    ρ, then each ant deposits 1 / L on the edges of its tour of length L.
+//!    The elitist ants deposit as much on the best tour found so far.
+//!
+//! Each iteration outputs a `ga::State` of the tours of the ants and the best
+//! tour found so far, so the `simulation::Simulator`, the `termination`s and
+//! the `simulation::SimulationObserver`s of the genetic algorithm work with
+//! the ant colony as well.
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation},
+    ga::{GeneticAlgorithmError, State},
+    genetic::{Fitness, FitnessFunction},
+    random::{random_index, Prng, Rng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{marker::PhantomData, rc::Rc};
+
+/// A tour visiting each city once, by the indices of the cities.
+pub type Tour = Vec<usize>;
+
+/// The default weight α of the pheromone.
+pub const DEFAULT_PHEROMONE_WEIGHT: f64 = 1.;
+
+/// The default weight β of the heuristic desirability.
+pub const DEFAULT_HEURISTIC_WEIGHT: f64 = 2.;
+
+/// The default evaporation rate ρ.
+pub const DEFAULT_EVAPORATION_RATE: f64 = 0.5;
+
+/// Returns the length of the closed tour through the cities by the given
+/// distances.
+pub fn tour_length(tour: &[usize], distances: &[Vec<f64>]) -> f64 {
+    tour.iter()
+        .zip(tour.iter().cycle().skip(1))
+        .map(|(from, to)| distances[*from][*to])
+        .sum()
+}
+
+/// `AntColony` implements the Ant System for the travelling salesman problem.
+/// The pheromone of every edge starts at m / Lₙₙ for m ants and the length Lₙₙ
+/// of the nearest neighbour tour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AntColony<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Tour, F>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    distances: Vec<Vec<f64>>,
+    ants: usize,
+    alpha: f64,
+    beta: f64,
+    rho: f64,
+    elitist_ants: usize,
+    pheromone: Vec<Vec<f64>>,
+    best_solution: Option<BestSolution<Tour, F>>,
+    processing_time: ProcessingTime,
+}
+
+impl<F, E> AntColony<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Tour, F>,
+{
+    /// Creates a colony of the given number of ants on the cities of the given
+    /// distance matrix, moving by the default weights and evaporation rate.
+    pub fn new(evaluator: E, distances: Vec<Vec<f64>>, ants: usize) -> Self {
+        assert!(
+            distances.iter().all(|row| row.len() == distances.len()),
+            "the distance matrix must be square"
+        );
+        let mut colony = AntColony {
+            _f: PhantomData,
+            evaluator,
+            distances,
+            ants,
+            alpha: DEFAULT_PHEROMONE_WEIGHT,
+            beta: DEFAULT_HEURISTIC_WEIGHT,
+            rho: DEFAULT_EVAPORATION_RATE,
+            elitist_ants: 0,
+            pheromone: Vec::new(),
+            best_solution: None,
+            processing_time: ProcessingTime::zero(),
+        };
+        colony.restart();
+        colony
+    }
+
+    /// Sets the weight of the pheromone (`alpha`), the weight of the heuristic
+    /// desirability (`beta`) and the evaporation rate (`rho`).
+    pub fn with_coefficients(mut self, alpha: f64, beta: f64, rho: f64) -> Self {
+        self.alpha = alpha;
+        self.beta = beta;
+        self.rho = rho;
+        self
+    }
+
+    /// Adds the given number of elitist ants, which deposit pheromone on the
+    /// best tour found so far in every iteration.
+    pub fn with_elitist_ants(mut self, elitist_ants: usize) -> Self {
+        self.elitist_ants = elitist_ants;
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn distances(&self) -> &[Vec<f64>] {
+        &self.distances
+    }
+
+    /// Returns the number of ants building a tour in each iteration.
+    pub fn ants(&self) -> usize {
+        self.ants
+    }
+
+    /// Returns the weight of the pheromone, the weight of the heuristic
+    /// desirability and the evaporation rate.
+    pub fn coefficients(&self) -> (f64, f64, f64) {
+        (self.alpha, self.beta, self.rho)
+    }
+
+    pub fn elitist_ants(&self) -> usize {
+        self.elitist_ants
+    }
+
+    /// Returns the current pheromone on the edges between the cities.
+    pub fn pheromone(&self) -> &[Vec<f64>] {
+        &self.pheromone
+    }
+
+    fn restart(&mut self) {
+        let n = self.distances.len();
+        let nearest_neighbour_length = tour_length(&self.nearest_neighbour_tour(), &self.distances);
+        let initial = if nearest_neighbour_length > 0. {
+            self.ants.max(1) as f64 / nearest_neighbour_length
+        } else {
+            1.
+        };
+        self.pheromone = vec![vec![initial; n]; n];
+        self.best_solution = None;
+    }
+
+    /// The tour from the first city to its nearest unvisited city and so on.
+    fn nearest_neighbour_tour(&self) -> Tour {
+        let n = self.distances.len();
+        let mut tour = Vec::with_capacity(n);
+        let mut visited = vec![false; n];
+        let mut city = 0;
+        while tour.len() < n {
+            tour.push(city);
+            visited[city] = true;
+            city = (0..n)
+                .filter(|next| !visited[*next])
+                .min_by(|a, b| {
+                    self.distances[city][*a]
+                        .partial_cmp(&self.distances[city][*b])
+                        .unwrap()
+                })
+                .unwrap_or(city);
+        }
+        tour
+    }
+
+    /// Builds a tour of a single ant, by the attractiveness τ^α η^β of each
+    /// edge.
+    fn build_tour(&self, attractiveness: &[Vec<f64>], rng: &mut Prng) -> Tour {
+        let n = self.distances.len();
+        let mut tour = Vec::with_capacity(n);
+        let mut visited = vec![false; n];
+        let mut city = random_index(rng, n);
+        loop {
+            tour.push(city);
+            visited[city] = true;
+            let unvisited: Vec<usize> = (0..n).filter(|next| !visited[*next]).collect();
+            if unvisited.is_empty() {
+                return tour;
+            }
+            let total: f64 = unvisited
+                .iter()
+                .map(|next| attractiveness[city][*next])
+                .sum();
+            // Moves to a random unvisited city if the attractiveness underflows
+            city = if total > 0. {
+                let mut pointer = rng.gen::<f64>() * total;
+                *unvisited
+                    .iter()
+                    .find(|next| {
+                        pointer -= attractiveness[city][**next];
+                        pointer <= 0.
+                    })
+                    .unwrap_or(&unvisited[unvisited.len() - 1])
+            } else {
+                unvisited[random_index(rng, unvisited.len())]
+            };
+        }
+    }
+
+    fn deposit(&mut self, tour: &[usize], amount: f64) {
+        for (from, to) in tour.iter().zip(tour.iter().cycle().skip(1)) {
+            self.pheromone[*from][*to] += amount;
+            self.pheromone[*to][*from] += amount;
+        }
+    }
+
+    fn update_pheromone(&mut self, tours: &[Tour]) {
+        for row in &mut self.pheromone {
+            for tau in row.iter_mut() {
+                *tau *= 1. - self.rho;
+            }
+        }
+        for tour in tours {
+            let length = tour_length(tour, &self.distances);
+            self.deposit(tour, 1. / length.max(f64::EPSILON));
+        }
+        if self.elitist_ants > 0 {
+            if let Some(best) = self.best_solution.clone() {
+                let length = tour_length(&best.solution.genome, &self.distances);
+                let amount = self.elitist_ants as f64 / length.max(f64::EPSILON);
+                self.deposit(&best.solution.genome, amount);
+            }
+        }
+    }
+}
+
+impl<F, E> TrackProcessingTime for AntColony<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Tour, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<F, E> Algorithm for AntColony<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Tour, F>,
+{
+    type Output = State<Tour, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.ants == 0 {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "The ant colony of generation {} has no ants.",
+                iteration
+            )));
+        }
+        if self.distances.len() < 2 {
+            return Err(GeneticAlgorithmError::PopulationTooSmall(format!(
+                "The ant colony has {} cities to visit, it needs at least 2.",
+                self.distances.len()
+            )));
+        }
+        let generation = timed(|| {
+            let attractiveness: Vec<Vec<f64>> = self
+                .pheromone
+                .iter()
+                .zip(&self.distances)
+                .map(|(taus, distances)| {
+                    taus.iter()
+                        .zip(distances)
+                        .map(|(tau, d)| {
+                            tau.powf(self.alpha) * (1. / d.max(f64::EPSILON)).powf(self.beta)
+                        })
+                        .collect()
+                })
+                .collect();
+            let mut tours: Vec<Evaluated<Tour, F>> = (0..self.ants)
+                .map(|_| {
+                    let genome = self.build_tour(&attractiveness, rng);
+                    Evaluated {
+                        fitness: self.evaluator.fitness_of(&genome),
+                        genome,
+                    }
+                })
+                .collect();
+            tours.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+
+            let improved = match &self.best_solution {
+                Some(best_solution) => tours[0].fitness > best_solution.solution.fitness,
+                None => true,
+            };
+            if improved {
+                self.best_solution = Some(BestSolution {
+                    found_at: Local::now(),
+                    generation: iteration,
+                    solution: tours[0].clone(),
+                });
+            }
+            let genomes: Vec<Tour> = tours.iter().map(|tour| tour.genome.clone()).collect();
+            self.update_pheromone(&genomes);
+            tours
+        })
+        .run();
+        self.processing_time = generation.time;
+
+        let tours = generation.result;
+        let fitness_values: Vec<F> = tours.iter().map(|tour| tour.fitness.clone()).collect();
+        let evaluated_population = EvaluatedPopulation::new(
+            Rc::new(tours.into_iter().map(|tour| tour.genome).collect()),
+            fitness_values.clone(),
+            fitness_values[0].clone(),
+            fitness_values[fitness_values.len() - 1].clone(),
+            self.evaluator.average(&fitness_values),
+        );
+        Ok(State {
+            evaluated_population,
+            best_solution: self.best_solution.clone().unwrap(),
+            restarted: false,
+            processing_time: self.processing_time,
+            population_snapshot: None,
+            operator_statistics: None,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.restart();
+        Ok(true)
+    }
+}
@@ -7,6 +7,8 @@ use crate::{
     random::Prng,
 };
 use chrono::{DateTime, Local};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{error::Error, fmt::Debug, rc::Rc};
 
 /// An `Algorithm` defines the steps to be processed in a
@@ -21,6 +23,22 @@ pub trait Algorithm {
     fn reset(&mut self) -> Result<bool, Self::Error>;
 }
 
+/// A `PopulationAlgorithm` is an `Algorithm` that evolves a population of
+/// `genetic::Genotype`s. Between two iterations the population can be
+/// inspected and replaced, e.g. to seed hand-crafted individuals into a
+/// running `simulation::Simulation`.
+pub trait PopulationAlgorithm: Algorithm {
+    type Genotype: Genotype;
+    type Fitness: Fitness;
+
+    /// Returns an owned copy of the population that is processed by the next
+    /// iteration, with the fitness value of each individual.
+    fn population_snapshot(&self) -> Vec<Evaluated<Self::Genotype, Self::Fitness>>;
+
+    /// Replaces the population that is processed by the next iteration.
+    fn replace_population(&mut self, population: Vec<Self::Genotype>);
+}
+
 pub trait OptimizationResult<G, F>
 where
     G: Genotype,
@@ -37,6 +55,7 @@ where
 /// simulation with more sophisticated fitness calculations this can improve
 /// performance.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Evaluated<G, F>
 where
     G: Genotype,
@@ -53,6 +72,7 @@ where
 /// If the solution is finished this is the overall best solution found by the
 /// simulation.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BestSolution<G, F>
 where
     G: Genotype,
@@ -80,6 +100,7 @@ where
 /// data structures. To be able to change the fields internally later when
 /// new optimization are found the fields are kept private.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EvaluatedPopulation<G, F>
 where
     G: Genotype,
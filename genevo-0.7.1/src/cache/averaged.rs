@@ -0,0 +1,258 @@
+//! The `averaged` module provides the `AveragedFitness` function for
+//! stochastic fitness functions, e.g. one that runs a noisy simulation.
+//!
+//! A single evaluation of a noisy fitness function may rank a poor genotype
+//! above a good one by chance. The `AveragedFitness` evaluates each genotype
+//! several times and takes the mean as its fitness, which it caches, so
+//! that a genotype keeps its fitness while it stays in the population.
+//! Optionally the genotypes that are evaluated again, e.g. the elites that
+//! survive into the next generation, get more samples each time.
+
+use super::{CacheStats, LruCache};
+use crate::genetic::{AsScalar, Fitness, FitnessFunction, FromScalar, Genotype};
+use std::{
+    hash::Hash,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// The sum and the number of the fitness samples of a genotype.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Samples {
+    sum: f64,
+    count: usize,
+}
+
+impl Samples {
+    fn mean(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+}
+
+/// The `AveragedFitness` wraps a stochastic `genetic::FitnessFunction` and
+/// evaluates each genotype `samples` times, taking the mean as its fitness.
+/// The means of up to `capacity` genotypes are cached, the least recently
+/// used genotype is evicted when the cache is full.
+///
+/// With `with_resampling` a cached genotype gets `resamples` more samples
+/// each time it is evaluated again, up to `max_samples`, so the longer an
+/// individual survives the more reliable its fitness is. Without it the
+/// cached mean is returned unchanged.
+///
+/// Clones share the same cache, like the ones of a `cache::CachedFitness`.
+#[derive(Clone, Debug)]
+pub struct AveragedFitness<G, F, E> {
+    fitness_function: E,
+    samples: usize,
+    resamples: usize,
+    max_samples: usize,
+    cache: Arc<Mutex<LruCache<G, Samples>>>,
+    evaluations: Arc<AtomicU64>,
+    _fitness: PhantomData<fn() -> F>,
+}
+
+impl<G, F, E> AveragedFitness<G, F, E>
+where
+    G: Genotype + Eq + Hash,
+    F: Fitness + AsScalar + FromScalar,
+    E: FitnessFunction<G, F>,
+{
+    /// Returns a new `AveragedFitness` which evaluates each genotype
+    /// `samples` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is 0.
+    pub fn new(fitness_function: E, samples: usize, capacity: usize) -> Self {
+        assert!(samples > 0, "a genotype must be evaluated at least once");
+        AveragedFitness {
+            fitness_function,
+            samples,
+            resamples: 0,
+            max_samples: samples,
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
+            evaluations: Arc::new(AtomicU64::new(0)),
+            _fitness: PhantomData,
+        }
+    }
+
+    /// Takes `resamples` more samples of a cached genotype each time it is
+    /// evaluated again, until it has got `max_samples` samples.
+    pub fn with_resampling(mut self, resamples: usize, max_samples: usize) -> Self {
+        self.resamples = resamples;
+        self.max_samples = max_samples.max(self.samples);
+        self
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn fitness_function(&self) -> &E {
+        &self.fitness_function
+    }
+
+    /// Returns the number of samples of a genotype evaluated for the first
+    /// time.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Returns the number of samples added on each evaluation of a cached
+    /// genotype.
+    pub fn resamples(&self) -> usize {
+        self.resamples
+    }
+
+    /// Returns the highest number of samples of a genotype.
+    pub fn max_samples(&self) -> usize {
+        self.max_samples
+    }
+
+    /// Returns the number of samples the cached mean of the given genotype
+    /// is based on, 0 if it is not cached.
+    pub fn samples_of(&self, genome: &G) -> usize {
+        self.cache
+            .lock()
+            .expect("fitness cache poisoned")
+            .value_mut(genome)
+            .map_or(0, |samples| samples.count)
+    }
+
+    /// Returns the number of evaluations of the wrapped fitness function so
+    /// far.
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Returns the hits and misses of all lookups so far.
+    pub fn stats(&self) -> CacheStats {
+        self.cache.lock().expect("fitness cache poisoned").stats
+    }
+
+    fn sample(&self, genome: &G, count: usize) -> Samples {
+        self.evaluations.fetch_add(count as u64, Ordering::Relaxed);
+        Samples {
+            sum: (0..count)
+                .map(|_| self.fitness_function.fitness_of(genome).as_scalar())
+                .sum(),
+            count,
+        }
+    }
+}
+
+impl<G, F, E> FitnessFunction<G, F> for AveragedFitness<G, F, E>
+where
+    G: Genotype + Eq + Hash,
+    F: Fitness + AsScalar + FromScalar,
+    E: FitnessFunction<G, F>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        let cached = self
+            .cache
+            .lock()
+            .expect("fitness cache poisoned")
+            .get(genome);
+        let count = match cached {
+            Some(samples) if samples.count >= self.max_samples || self.resamples == 0 => {
+                return F::from_scalar(samples.mean());
+            }
+            Some(samples) => self.resamples.min(self.max_samples - samples.count),
+            None => self.samples,
+        };
+        // sample without holding the lock, so other threads can go on
+        let new_samples = self.sample(genome, count);
+        let mut cache = self.cache.lock().expect("fitness cache poisoned");
+        let samples = match cache.value_mut(genome) {
+            Some(samples) => {
+                samples.sum += new_samples.sum;
+                samples.count += new_samples.count;
+                *samples
+            }
+            None => {
+                cache.insert(genome.clone(), new_samples);
+                new_samples
+            }
+        };
+        F::from_scalar(samples.mean())
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        self.fitness_function.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.fitness_function.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.fitness_function.lowest_possible_fitness()
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.fitness_function.adapt(generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+    use std::sync::atomic::AtomicUsize;
+
+    // Ten times the number of ones, plus a noise cycling through -3, 0 and 3
+    #[derive(Clone, Debug)]
+    struct NoisyOnes(Arc<AtomicUsize>);
+
+    impl FitnessFunction<Vec<u8>, i64> for NoisyOnes {
+        fn fitness_of(&self, genome: &Vec<u8>) -> i64 {
+            let noise = (self.0.fetch_add(1, Ordering::SeqCst) % 3) as i64 * 3 - 3;
+            10 * genome.iter().filter(|v| **v == 1).count() as i64 + noise
+        }
+
+        fn average(&self, values: &[i64]) -> i64 {
+            values.iter().sum::<i64>() / values.len() as i64
+        }
+
+        fn highest_possible_fitness(&self) -> i64 {
+            43
+        }
+
+        fn lowest_possible_fitness(&self) -> i64 {
+            -3
+        }
+    }
+
+    #[test]
+    fn the_mean_of_the_samples_is_cached() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let averaged = AveragedFitness::new(NoisyOnes(calls.clone()), 3, 8);
+
+        expect_that!(&averaged.fitness_of(&vec![1, 0, 1, 1]), eq(30));
+        expect_that!(&averaged.clone().fitness_of(&vec![1, 0, 1, 1]), eq(30));
+        expect_that!(&averaged.fitness_of(&vec![0, 0, 0, 1]), eq(10));
+
+        expect_that!(&calls.load(Ordering::SeqCst), eq(6));
+        expect_that!(&averaged.evaluations(), eq(6));
+        expect_that!(&averaged.samples_of(&vec![1, 0, 1, 1]), eq(3));
+        expect_that!(&averaged.stats(), eq(CacheStats { hits: 1, misses: 2 }));
+    }
+
+    #[test]
+    fn a_genotype_evaluated_again_gets_more_samples_up_to_the_max() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let averaged = AveragedFitness::new(NoisyOnes(calls.clone()), 1, 8).with_resampling(2, 6);
+        let genome = vec![1, 1];
+
+        // the noise of the samples is -3, then 0 and 3, then -3 and 0
+        expect_that!(&averaged.fitness_of(&genome), eq(17));
+        expect_that!(&averaged.fitness_of(&genome), eq(20));
+        expect_that!(&averaged.fitness_of(&genome), eq(19));
+        expect_that!(&averaged.samples_of(&genome), eq(5));
+        // the last evaluation adds only one sample
+        expect_that!(&averaged.fitness_of(&genome), eq(20));
+        expect_that!(&averaged.fitness_of(&genome), eq(20));
+        expect_that!(&averaged.samples_of(&genome), eq(6));
+        expect_that!(&averaged.evaluations(), eq(6));
+    }
+}
@@ -0,0 +1,373 @@
+//! The `cache` module provides the `CachedFitness` function, which memoizes
+//! the `genetic::Fitness` values of another `genetic::FitnessFunction`.
+//!
+//! Late in a run the population often consists of many copies of the same
+//! genotypes. A `CachedFitness` evaluates each of them only once as long as
+//! it stays among the least recently used genotypes of the cache.
+//!
+//! For stochastic fitness functions the `averaged::AveragedFitness` caches
+//! the mean of several evaluations of each genotype instead.
+
+pub mod averaged;
+
+use crate::genetic::{Fitness, FitnessFunction, Genotype};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display},
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+/// The number of lookups of a `CachedFitness` that were answered from the
+/// cache (hits) or had to be evaluated (misses).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns the number of lookups.
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// Returns the share of lookups that were answered from the cache, or 0
+    /// if there has not been any lookup yet.
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, hit rate {:.2}%",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.
+        )
+    }
+}
+
+/// A least recently used cache of fitness values.
+#[derive(Debug)]
+struct LruCache<G, F> {
+    capacity: usize,
+    // the fitness of each cached genotype and the tick it was last used at
+    entries: HashMap<G, (F, u64)>,
+    // the cached genotypes by the tick they were last used at
+    recency: BTreeMap<u64, G>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl<G, F> LruCache<G, F>
+where
+    G: Clone + Eq + Hash,
+    F: Clone,
+{
+    fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    fn get(&mut self, genome: &G) -> Option<F> {
+        self.tick += 1;
+        match self.entries.get_mut(genome) {
+            Some((fitness, last_used)) => {
+                self.recency.remove(last_used);
+                self.recency.insert(self.tick, genome.clone());
+                *last_used = self.tick;
+                self.stats.hits += 1;
+                Some(fitness.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    // the cached value of the genotype, without counting a lookup
+    fn value_mut(&mut self, genome: &G) -> Option<&mut F> {
+        self.entries.get_mut(genome).map(|(fitness, _)| fitness)
+    }
+
+    fn insert(&mut self, genome: G, fitness: F) {
+        if self.capacity == 0 || self.entries.contains_key(&genome) {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        self.recency.insert(self.tick, genome.clone());
+        self.entries.insert(genome, (fitness, self.tick));
+    }
+}
+
+/// The `CachedFitness` wraps a `genetic::FitnessFunction` and remembers the
+/// fitness of up to `capacity` genotypes. When the cache is full the least
+/// recently used genotype is evicted.
+///
+/// Clones share the same cache, so the evaluation of a
+/// `ga::GeneticAlgorithm` and its `operator::ReinsertionOp` benefit from
+/// each other. The cache is locked for each lookup, which makes it worth it
+/// only for fitness functions that are more expensive than hashing the
+/// genotype.
+#[derive(Clone, Debug)]
+pub struct CachedFitness<G, F, E> {
+    fitness_function: E,
+    cache: Arc<Mutex<LruCache<G, F>>>,
+}
+
+impl<G, F, E> CachedFitness<G, F, E>
+where
+    G: Genotype + Eq + Hash,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    pub fn new(fitness_function: E, capacity: usize) -> Self {
+        CachedFitness {
+            fitness_function,
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn fitness_function(&self) -> &E {
+        &self.fitness_function
+    }
+
+    /// Returns the maximum number of cached genotypes.
+    pub fn capacity(&self) -> usize {
+        self.cache.lock().expect("fitness cache poisoned").capacity
+    }
+
+    /// Returns the number of currently cached genotypes.
+    pub fn len(&self) -> usize {
+        self.cache
+            .lock()
+            .expect("fitness cache poisoned")
+            .entries
+            .len()
+    }
+
+    /// Returns whether no genotype is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the hits and misses of all lookups so far.
+    pub fn stats(&self) -> CacheStats {
+        self.cache.lock().expect("fitness cache poisoned").stats
+    }
+}
+
+impl<G, F, E> FitnessFunction<G, F> for CachedFitness<G, F, E>
+where
+    G: Genotype + Eq + Hash,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        if let Some(fitness) = self
+            .cache
+            .lock()
+            .expect("fitness cache poisoned")
+            .get(genome)
+        {
+            return fitness;
+        }
+        // evaluate without holding the lock, so other threads can go on
+        let fitness = self.fitness_function.fitness_of(genome);
+        self.cache
+            .lock()
+            .expect("fitness cache poisoned")
+            .insert(genome.clone(), fitness.clone());
+        fitness
+    }
+
+    fn fitness_of_all(&self, genomes: &[G]) -> Vec<F> {
+        let mut cache = self.cache.lock().expect("fitness cache poisoned");
+        // the index of each genotype that is not cached among the misses
+        let mut miss_indices: HashMap<&G, usize> = HashMap::new();
+        let mut misses = Vec::new();
+        let lookups: Vec<Result<F, usize>> = genomes
+            .iter()
+            .map(|genome| match miss_indices.get(genome) {
+                // a repeated miss is answered by the evaluation of the first
+                Some(index) => {
+                    cache.stats.hits += 1;
+                    Err(*index)
+                }
+                None => cache.get(genome).ok_or_else(|| {
+                    miss_indices.insert(genome, misses.len());
+                    misses.push(genome.clone());
+                    misses.len() - 1
+                }),
+            })
+            .collect();
+        drop(cache);
+        // evaluate the misses in one batch without holding the lock
+        let evaluated = self.fitness_function.fitness_of_all(&misses);
+        let mut cache = self.cache.lock().expect("fitness cache poisoned");
+        for (genome, fitness) in misses.into_iter().zip(&evaluated) {
+            cache.insert(genome, fitness.clone());
+        }
+        lookups
+            .into_iter()
+            .map(|lookup| lookup.unwrap_or_else(|index| evaluated[index].clone()))
+            .collect()
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        self.fitness_function.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.fitness_function.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.fitness_function.lowest_possible_fitness()
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.fitness_function.adapt(generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Counts the ones and how often it has been asked to
+    #[derive(Clone, Debug)]
+    struct CountingOnes(Arc<AtomicUsize>);
+
+    impl FitnessFunction<Vec<u8>, usize> for CountingOnes {
+        fn fitness_of(&self, genome: &Vec<u8>) -> usize {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            genome.iter().filter(|v| **v == 1).count()
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            4
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn repeated_genomes_are_evaluated_once() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let cached = CachedFitness::new(CountingOnes(evaluations.clone()), 8);
+
+        expect_that!(&cached.fitness_of(&vec![1, 0, 1, 1]), eq(3));
+        expect_that!(&cached.clone().fitness_of(&vec![1, 0, 1, 1]), eq(3));
+        expect_that!(&cached.fitness_of(&vec![0, 0, 0, 1]), eq(1));
+
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(2));
+        expect_that!(&cached.stats(), eq(CacheStats { hits: 1, misses: 2 }));
+        expect_that!(&cached.stats().hit_rate(), eq(1. / 3.));
+    }
+
+    #[test]
+    fn a_batch_evaluates_each_missing_genome_once() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let cached = CachedFitness::new(CountingOnes(evaluations.clone()), 8);
+        cached.fitness_of(&vec![1, 1]);
+
+        let fitness_values = cached.fitness_of_all(&[vec![1], vec![1, 1], vec![0], vec![1]]);
+
+        expect_that!(&fitness_values, eq(vec![1, 2, 0, 1]));
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(3));
+        expect_that!(&cached.stats(), eq(CacheStats { hits: 2, misses: 3 }));
+    }
+
+    #[test]
+    fn a_batch_evicts_the_least_recently_used_batch() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let cached = CachedFitness::new(CountingOnes(evaluations.clone()), 4);
+        let older = [vec![0], vec![1], vec![0, 0], vec![0, 1]];
+        let latest = [vec![1, 0], vec![1, 1], vec![0, 0, 0], vec![0, 0, 1]];
+
+        cached.fitness_of_all(&older);
+        cached.fitness_of_all(&latest);
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(8));
+
+        cached.fitness_of_all(&latest);
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(8));
+        cached.fitness_of_all(&older);
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(12));
+        expect_that!(&cached.stats(), eq(CacheStats { hits: 4, misses: 12 }));
+    }
+
+    #[test]
+    fn the_least_recently_used_genome_is_evicted() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let cached = CachedFitness::new(CountingOnes(evaluations.clone()), 2);
+
+        cached.fitness_of(&vec![1]);
+        cached.fitness_of(&vec![0]);
+        // touch [1], so [0] is the least recently used
+        cached.fitness_of(&vec![1]);
+        cached.fitness_of(&vec![1, 1]);
+
+        expect_that!(&cached.len(), eq(2));
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(3));
+        cached.fitness_of(&vec![1]);
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(3));
+        cached.fitness_of(&vec![0]);
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(4));
+    }
+
+    #[test]
+    fn misses_inserted_in_a_row_evict_the_oldest_of_them() {
+        let mut cache = LruCache::new(2);
+
+        cache.insert(vec![1], 1);
+        cache.insert(vec![0], 0);
+        cache.insert(vec![1, 1], 2);
+
+        expect_that!(&cache.entries.len(), eq(2));
+        expect_that!(&cache.recency.len(), eq(2));
+        expect_that!(&cache.get(&vec![1]), eq(None));
+        expect_that!(&cache.get(&vec![0]), eq(Some(0)));
+        expect_that!(&cache.get(&vec![1, 1]), eq(Some(2)));
+    }
+
+    #[test]
+    fn a_cache_without_capacity_evaluates_every_time() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let cached = CachedFitness::new(CountingOnes(evaluations.clone()), 0);
+
+        cached.fitness_of(&vec![1]);
+        cached.fitness_of(&vec![1]);
+
+        expect_that!(&cached.is_empty(), eq(true));
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(2));
+    }
+}
@@ -0,0 +1,506 @@
+//! This module provides an `algorithm::Algorithm` which implements the
+//! covariance matrix adaptation evolution strategy (CMA-ES) of Hansen and
+//! Ostermeier for real-valued genotypes.
+//!
+//! The CMA-ES samples the candidates of each generation from a multivariate
+//! normal distribution. After the candidates are evaluated:
+//!
+//! 1. the **mean** of the distribution moves to the weighted mean of the μ
+//!    best candidates,
+//! 2. the **covariance matrix** learns the directions of the successful
+//!    steps, from the evolution path of the mean (rank-one update) and from
+//!    the μ best candidates (rank-μ update),
+//! 3. the **step size** grows when consecutive steps of the mean point the
+//!    same way and shrinks when they cancel out (cumulative step-size
+//!    adaptation).
+//!
+//! The default parameters are those of Hansen's tutorial "The CMA Evolution
+//! Strategy", which need no tuning for most problems.
+//!
+//! Each iteration outputs a `ga::State` of the candidates of the generation
+//! and the best candidate found so far, so the `simulation::Simulator`, the
+//! `termination`s and the `simulation::SimulationObserver`s of the genetic
+//! algorithm work with the CMA-ES as well.
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation},
+    encoding::RealValue,
+    ga::{GeneticAlgorithmError, State},
+    genetic::{Fitness, FitnessFunction},
+    random::{random_gaussian, Prng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{marker::PhantomData, rc::Rc};
+
+/// The strategy parameters of the CMA-ES, derived from the number of
+/// dimensions and the number of candidates per generation.
+#[derive(Clone, Debug, PartialEq)]
+struct Parameters {
+    weights: Vec<f64>,
+    mu_eff: f64,
+    c_c: f64,
+    c_sigma: f64,
+    c_1: f64,
+    c_mu: f64,
+    d_sigma: f64,
+    chi_n: f64,
+}
+
+impl Parameters {
+    fn new(dimensions: usize, lambda: usize) -> Self {
+        let n = dimensions as f64;
+        let mu = lambda / 2;
+        let weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let sum: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.iter().map(|w| w / sum).collect();
+        let mu_eff = 1. / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let c_c = (4. + mu_eff / n) / (n + 4. + 2. * mu_eff / n);
+        let c_sigma = (mu_eff + 2.) / (n + mu_eff + 5.);
+        let c_1 = 2. / ((n + 1.3).powi(2) + mu_eff);
+        let c_mu = (2. * (mu_eff - 2. + 1. / mu_eff) / ((n + 2.).powi(2) + mu_eff)).min(1. - c_1);
+        let d_sigma = 1. + 2. * (((mu_eff - 1.) / (n + 1.)).sqrt() - 1.).max(0.) + c_sigma;
+        let chi_n = n.sqrt() * (1. - 1. / (4. * n) + 1. / (21. * n * n));
+        Parameters {
+            weights,
+            mu_eff,
+            c_c,
+            c_sigma,
+            c_1,
+            c_mu,
+            d_sigma,
+            chi_n,
+        }
+    }
+}
+
+/// `CmaEs` implements the (μ/μ_w, λ) CMA-ES, starting from the given mean
+/// with the identity matrix as covariance matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CmaEs<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    _v: PhantomData<V>,
+    _f: PhantomData<F>,
+    evaluator: E,
+    lambda: usize,
+    bounds: Option<(f64, f64)>,
+    initial_mean: Vec<f64>,
+    initial_step_size: f64,
+    mean: Vec<f64>,
+    step_size: f64,
+    covariance: Vec<Vec<f64>>,
+    // The eigendecomposition C = B D² Bᵀ of the covariance matrix: the
+    // eigenvectors are the columns of B, D holds the square roots of the
+    // eigenvalues
+    eigenvectors: Vec<Vec<f64>>,
+    scales: Vec<f64>,
+    path_c: Vec<f64>,
+    path_sigma: Vec<f64>,
+    generations: u64,
+    best_solution: Option<BestSolution<Vec<V>, F>>,
+    processing_time: ProcessingTime,
+}
+
+impl<V, F, E> CmaEs<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    /// Creates a CMA-ES searching around `initial_mean` with the initial step
+    /// size `initial_step_size`, which should be about a third of the width of
+    /// the search space. The number of candidates per generation defaults to
+    /// 4 + ⌊3 ln n⌋ for n dimensions.
+    pub fn new(evaluator: E, initial_mean: Vec<V>, initial_step_size: f64) -> Self {
+        let initial_mean: Vec<f64> = initial_mean.into_iter().map(V::to_f64).collect();
+        let n = initial_mean.len();
+        let mut cma_es = CmaEs {
+            _v: PhantomData,
+            _f: PhantomData,
+            evaluator,
+            lambda: 4 + (3. * (n.max(1) as f64).ln()) as usize,
+            bounds: None,
+            mean: Vec::new(),
+            step_size: initial_step_size,
+            covariance: Vec::new(),
+            eigenvectors: Vec::new(),
+            scales: Vec::new(),
+            path_c: Vec::new(),
+            path_sigma: Vec::new(),
+            generations: 0,
+            best_solution: None,
+            processing_time: ProcessingTime::zero(),
+            initial_mean,
+            initial_step_size,
+        };
+        cma_es.restart();
+        cma_es
+    }
+
+    /// Sets the number of candidates sampled per generation (λ), the best half
+    /// of which move the mean.
+    pub fn with_population_size(mut self, lambda: usize) -> Self {
+        self.lambda = lambda;
+        self
+    }
+
+    /// Clamps the sampled candidates to the interval `[min_value, max_value]`
+    /// in each dimension.
+    pub fn with_bounds(mut self, min_value: f64, max_value: f64) -> Self {
+        assert!(
+            min_value <= max_value,
+            "min_value must not exceed max_value"
+        );
+        self.bounds = Some((min_value, max_value));
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the number of candidates sampled per generation.
+    pub fn population_size(&self) -> usize {
+        self.lambda
+    }
+
+    pub fn bounds(&self) -> Option<(f64, f64)> {
+        self.bounds
+    }
+
+    /// Returns the current mean of the sampling distribution.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Returns the current step size of the sampling distribution.
+    pub fn step_size(&self) -> f64 {
+        self.step_size
+    }
+
+    /// Returns the current covariance matrix of the sampling distribution.
+    pub fn covariance(&self) -> &[Vec<f64>] {
+        &self.covariance
+    }
+
+    fn restart(&mut self) {
+        let n = self.initial_mean.len();
+        self.mean = self.initial_mean.clone();
+        self.step_size = self.initial_step_size;
+        self.covariance = identity(n);
+        self.eigenvectors = identity(n);
+        self.scales = vec![1.; n];
+        self.path_c = vec![0.; n];
+        self.path_sigma = vec![0.; n];
+        self.generations = 0;
+        self.best_solution = None;
+    }
+
+    /// Samples λ candidates, each with the step y = B D z it was sampled by.
+    fn sample(&self, rng: &mut Prng) -> Vec<(Vec<f64>, Vec<f64>)> {
+        let n = self.mean.len();
+        (0..self.lambda)
+            .map(|_| {
+                let z: Vec<f64> = (0..n).map(|_| random_gaussian(rng, 0., 1.)).collect();
+                let mut step: Vec<f64> = (0..n)
+                    .map(|i| {
+                        (0..n)
+                            .map(|j| self.eigenvectors[i][j] * self.scales[j] * z[j])
+                            .sum()
+                    })
+                    .collect();
+                let mut x: Vec<f64> = self
+                    .mean
+                    .iter()
+                    .zip(&step)
+                    .map(|(m, y)| m + self.step_size * y)
+                    .collect();
+                // A clamped candidate counts as sampled by the step to where it is clamped
+                if let Some((min, max)) = self.bounds {
+                    for i in 0..n {
+                        if x[i] < min || x[i] > max {
+                            x[i] = x[i].clamp(min, max);
+                            step[i] = (x[i] - self.mean[i]) / self.step_size;
+                        }
+                    }
+                }
+                (x, step)
+            })
+            .collect()
+    }
+
+    /// Moves the sampling distribution towards the best of the evaluated
+    /// candidates, given by their steps from the mean, the best first.
+    fn update(&mut self, best_steps: &[&Vec<f64>]) {
+        let n = self.mean.len();
+        let p = Parameters::new(n, self.lambda);
+        self.generations += 1;
+
+        let step_w: Vec<f64> = (0..n)
+            .map(|i| {
+                p.weights
+                    .iter()
+                    .zip(best_steps)
+                    .map(|(w, y)| w * y[i])
+                    .sum()
+            })
+            .collect();
+        for (m, y) in self.mean.iter_mut().zip(&step_w) {
+            *m += self.step_size * y;
+        }
+
+        // C^(-1/2) y_w = B D⁻¹ Bᵀ y_w
+        let whitened: Vec<f64> = (0..n)
+            .map(|j| {
+                (0..n)
+                    .map(|i| self.eigenvectors[i][j] * step_w[i])
+                    .sum::<f64>()
+                    / self.scales[j]
+            })
+            .collect();
+        let whitened: Vec<f64> = (0..n)
+            .map(|i| (0..n).map(|j| self.eigenvectors[i][j] * whitened[j]).sum())
+            .collect();
+        let sigma_factor = (p.c_sigma * (2. - p.c_sigma) * p.mu_eff).sqrt();
+        for (p_sigma, w) in self.path_sigma.iter_mut().zip(&whitened) {
+            *p_sigma = (1. - p.c_sigma) * *p_sigma + sigma_factor * w;
+        }
+        let path_sigma_norm = norm(&self.path_sigma);
+
+        // Stall the rank-one update while the step size is growing fast
+        let h_sigma = path_sigma_norm
+            / (1. - (1. - p.c_sigma).powi(2 * self.generations as i32)).sqrt()
+            < (1.4 + 2. / (n as f64 + 1.)) * p.chi_n;
+        let h_sigma = if h_sigma { 1. } else { 0. };
+        let c_factor = h_sigma * (p.c_c * (2. - p.c_c) * p.mu_eff).sqrt();
+        for (p_c, y) in self.path_c.iter_mut().zip(&step_w) {
+            *p_c = (1. - p.c_c) * *p_c + c_factor * y;
+        }
+
+        let decay = 1. - p.c_1 - p.c_mu + (1. - h_sigma) * p.c_1 * p.c_c * (2. - p.c_c);
+        for i in 0..n {
+            for j in 0..=i {
+                let rank_mu: f64 = p
+                    .weights
+                    .iter()
+                    .zip(best_steps)
+                    .map(|(w, y)| w * y[i] * y[j])
+                    .sum();
+                let c_ij = decay * self.covariance[i][j]
+                    + p.c_1 * self.path_c[i] * self.path_c[j]
+                    + p.c_mu * rank_mu;
+                self.covariance[i][j] = c_ij;
+                self.covariance[j][i] = c_ij;
+            }
+        }
+
+        self.step_size *= ((p.c_sigma / p.d_sigma) * (path_sigma_norm / p.chi_n - 1.)).exp();
+
+        let (eigenvalues, eigenvectors) = symmetric_eigen(&self.covariance);
+        self.scales = eigenvalues
+            .iter()
+            .map(|e| e.max(f64::EPSILON).sqrt())
+            .collect();
+        self.eigenvectors = eigenvectors;
+    }
+}
+
+impl<V, F, E> TrackProcessingTime for CmaEs<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<V, F, E> Algorithm for CmaEs<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    type Output = State<Vec<V>, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.mean.is_empty() {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "The CMA-ES of generation {} has a mean of no dimensions.",
+                iteration
+            )));
+        }
+        if self.lambda < 2 {
+            return Err(GeneticAlgorithmError::PopulationTooSmall(format!(
+                "The CMA-ES samples {} candidates per generation, it needs at least 2.",
+                self.lambda
+            )));
+        }
+        let generation = timed(|| {
+            let samples = self.sample(rng);
+            let mut candidates: Vec<_> = samples
+                .into_iter()
+                .map(|(x, step)| {
+                    let genome: Vec<V> = x.into_iter().map(V::from_f64).collect();
+                    let evaluated = Evaluated {
+                        fitness: self.evaluator.fitness_of(&genome),
+                        genome,
+                    };
+                    (evaluated, step)
+                })
+                .collect();
+            candidates.sort_by(|a, b| b.0.fitness.cmp(&a.0.fitness));
+
+            let best_steps: Vec<&Vec<f64>> = candidates
+                .iter()
+                .take(self.lambda / 2)
+                .map(|(_, step)| step)
+                .collect();
+            self.update(&best_steps);
+
+            let improved = match &self.best_solution {
+                Some(best_solution) => candidates[0].0.fitness > best_solution.solution.fitness,
+                None => true,
+            };
+            if improved {
+                self.best_solution = Some(BestSolution {
+                    found_at: Local::now(),
+                    generation: iteration,
+                    solution: candidates[0].0.clone(),
+                });
+            }
+            candidates
+                .into_iter()
+                .map(|(evaluated, _)| evaluated)
+                .collect::<Vec<_>>()
+        })
+        .run();
+        self.processing_time = generation.time;
+
+        let candidates = generation.result;
+        let fitness_values: Vec<F> = candidates.iter().map(|c| c.fitness.clone()).collect();
+        let evaluated_population = EvaluatedPopulation::new(
+            Rc::new(candidates.into_iter().map(|c| c.genome).collect()),
+            fitness_values.clone(),
+            fitness_values[0].clone(),
+            fitness_values[fitness_values.len() - 1].clone(),
+            self.evaluator.average(&fitness_values),
+        );
+        Ok(State {
+            evaluated_population,
+            best_solution: self.best_solution.clone().unwrap(),
+            restarted: false,
+            processing_time: self.processing_time,
+            population_snapshot: None,
+            operator_statistics: None,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.restart();
+        Ok(true)
+    }
+}
+
+fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect()
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Returns the eigenvalues and the eigenvectors (as the columns of the matrix)
+/// of a symmetric matrix by the cyclic Jacobi method.
+fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut v = identity(n);
+    for _ in 0..100 {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off_diagonal < 1e-30 {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == 0. {
+                    continue;
+                }
+                // Rotate by the angle which zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let t = if theta == 0. { 1. } else { t };
+                let c = 1. / (t * t + 1.).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (a_kp, a_kq) = (row[p], row[q]);
+                    row[p] = c * a_kp - s * a_kq;
+                    row[q] = s * a_kp + c * a_kq;
+                }
+                let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                a[p] = row_p
+                    .iter()
+                    .zip(&row_q)
+                    .map(|(x, y)| c * x - s * y)
+                    .collect();
+                a[q] = row_p
+                    .iter()
+                    .zip(&row_q)
+                    .map(|(x, y)| s * x + c * y)
+                    .collect();
+                for row in v.iter_mut() {
+                    let (v_kp, v_kq) = (row[p], row[q]);
+                    row[p] = c * v_kp - s * v_kq;
+                    row[q] = s * v_kp + c * v_kq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_eigendecomposition_rebuilds_the_matrix() {
+        let matrix = vec![vec![4., 1., 0.5], vec![1., 3., -0.25], vec![0.5, -0.25, 2.]];
+
+        let (eigenvalues, eigenvectors) = symmetric_eigen(&matrix);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let rebuilt: f64 = (0..3)
+                    .map(|k| eigenvectors[i][k] * eigenvalues[k] * eigenvectors[j][k])
+                    .sum();
+                assert!((rebuilt - matrix[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn the_default_weights_sum_to_one_and_decrease() {
+        let parameters = Parameters::new(10, 10);
+
+        assert_eq!(parameters.weights.len(), 5);
+        assert!((parameters.weights.iter().sum::<f64>() - 1.).abs() < 1e-12);
+        assert!(parameters.weights.windows(2).all(|w| w[0] > w[1]));
+        assert!(parameters.mu_eff > 1. && parameters.mu_eff < 5.);
+    }
+}
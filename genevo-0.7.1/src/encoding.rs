@@ -21,7 +21,12 @@
 //! appropriate encoding trait. If an application is defining its own crossover
 //! and mutation operators then using these marker traits is optional.
 
-use crate::genetic::Genotype;
+use crate::{
+    genetic::Genotype,
+    random::{Rng, SliceRandom},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// Marker trait for declaring a `genetic::Genotype` as binary encoded.
@@ -36,6 +41,40 @@ pub trait PermutationEncoded {}
 /// Marker trait for declaring a tree encoded `genetic::Genotype`.
 pub trait TreeEncoded: Genotype {}
 
+/// A floating point value as used for the genes of real-valued
+/// `genetic::Genotype`s, e.g. `Vec<f64>`.
+///
+/// Operators for real-valued genotypes do their arithmetic in `f64` and
+/// convert the results back into the gene type.
+pub trait RealValue: Copy + Debug + PartialEq + PartialOrd + Send + Sync {
+    /// Converts this value into an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts the given `f64` into a value of this type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_real_value {
+    ($($t:ty),*) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl RealValue for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> $t {
+                    value as $t
+                }
+            }
+        )*
+    }
+}
+
+impl_real_value!(f32, f64);
+
 /// Implementation of a genotype using `Vec`.
 impl<V> Genotype for Vec<V>
 where
@@ -56,6 +95,620 @@ impl<V> ValueEncoded for Vec<V> {}
 /// using `Vec`.
 impl<V> PermutationEncoded for Vec<V> {}
 
+/// The number of 2-bit alleles packed into each word of a `PackedGenome`.
+const ALLELES_PER_WORD: usize = 32;
+
+/// A value encoded `genetic::Genotype` of 2-bit alleles, i.e. the values 0 to
+/// 3, like the nucleotides of a DNA strand.
+///
+/// 32 alleles are packed into each `u64`, which takes a quarter of the memory
+/// of a `Vec<u8>` and lets the crossover copy whole words. The unused bits of
+/// the last word are always 0.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawPackedGenome")
+)]
+pub struct PackedGenome {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PackedGenome {
+    /// Constructs a new `PackedGenome` of `len` alleles that are all 0.
+    pub fn new(len: usize) -> Self {
+        PackedGenome {
+            words: vec![0; len.div_ceil(ALLELES_PER_WORD)],
+            len,
+        }
+    }
+
+    /// Returns the number of alleles.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this genome has no alleles.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the allele at the given locus.
+    pub fn get(&self, locus: usize) -> u8 {
+        assert!(locus < self.len, "locus {} out of bounds", locus);
+        let shift = locus % ALLELES_PER_WORD * 2;
+        (self.words[locus / ALLELES_PER_WORD] >> shift & 0b11) as u8
+    }
+
+    /// Sets the allele at the given locus to the given value, which must be
+    /// less than 4.
+    pub fn set(&mut self, locus: usize, value: u8) {
+        assert!(locus < self.len, "locus {} out of bounds", locus);
+        assert!(value < 4, "allele {} does not fit into 2 bits", value);
+        let shift = locus % ALLELES_PER_WORD * 2;
+        let word = &mut self.words[locus / ALLELES_PER_WORD];
+        *word = *word & !(0b11 << shift) | u64::from(value) << shift;
+    }
+
+    /// Returns an iterator over the alleles.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..self.len).map(move |locus| self.get(locus))
+    }
+
+    /// Returns the words the alleles are packed into, the first allele in the
+    /// lowest 2 bits of the first word.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Copies the alleles of the loci `start..end` from the other genome,
+    /// whole words at a time wherever the range covers them.
+    pub fn copy_from(&mut self, other: &PackedGenome, start: usize, end: usize) {
+        assert!(start <= end && end <= self.len && end <= other.len);
+        let mut locus = start;
+        while locus < end && !locus.is_multiple_of(ALLELES_PER_WORD) {
+            self.set(locus, other.get(locus));
+            locus += 1;
+        }
+        while locus + ALLELES_PER_WORD <= end {
+            self.words[locus / ALLELES_PER_WORD] = other.words[locus / ALLELES_PER_WORD];
+            locus += ALLELES_PER_WORD;
+        }
+        while locus < end {
+            self.set(locus, other.get(locus));
+            locus += 1;
+        }
+    }
+}
+
+impl FromIterator<u8> for PackedGenome {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let alleles = iter.into_iter().collect::<Vec<_>>();
+        let mut genome = PackedGenome::new(alleles.len());
+        for (locus, allele) in alleles.into_iter().enumerate() {
+            genome.set(locus, allele);
+        }
+        genome
+    }
+}
+
+/// Implementation of a genotype using `PackedGenome`.
+impl Genotype for PackedGenome {
+    type Dna = u8;
+}
+
+/// Implementation of a value encoded `genetic::Genotype`
+/// using `PackedGenome`.
+impl ValueEncoded for PackedGenome {}
+
+/// A value encoded `genetic::Genotype` of `rows` × `cols` cells, for problems
+/// on a grid, e.g. the layout of bins or the rules of a cellular automaton.
+///
+/// The cells are stored row by row. The crossover operators of the
+/// `recombination::matrix` module keep the rows, columns or blocks of cells
+/// of the parents together, which a `Vec` of the cells would split up.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawMatrix<V>")
+)]
+pub struct Matrix<V> {
+    cells: Vec<V>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<V> Matrix<V> {
+    /// Constructs a new `Matrix` of `rows` × `cols` cells of the given value.
+    pub fn new(rows: usize, cols: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        Matrix {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Constructs a `Matrix` of `rows` × `cols` cells from the cells given
+    /// row by row.
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<V>) -> Self {
+        assert_eq!(
+            cells.len(),
+            rows * cols,
+            "a {}×{} matrix needs {} cells",
+            rows,
+            cols,
+            rows * cols
+        );
+        Matrix { cells, rows, cols }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the cell in the given row and column.
+    pub fn get(&self, row: usize, col: usize) -> &V {
+        &self.cells[self.index(row, col)]
+    }
+
+    /// Sets the cell in the given row and column to the given value.
+    pub fn set(&mut self, row: usize, col: usize, value: V) {
+        let index = self.index(row, col);
+        self.cells[index] = value;
+    }
+
+    /// Returns the cells of the given row.
+    pub fn row(&self, row: usize) -> &[V] {
+        assert!(row < self.rows, "row {} out of bounds", row);
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns all cells, row by row.
+    pub fn cells(&self) -> &[V] {
+        &self.cells
+    }
+
+    /// Returns all cells, row by row, giving up the matrix.
+    pub fn into_cells(self) -> Vec<V> {
+        self.cells
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        assert!(
+            row < self.rows && col < self.cols,
+            "cell ({}, {}) out of bounds",
+            row,
+            col
+        );
+        row * self.cols + col
+    }
+}
+
+/// Implementation of a genotype using `Matrix`.
+impl<V> Genotype for Matrix<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    type Dna = V;
+}
+
+/// Implementation of a value encoded `genetic::Genotype`
+/// using `Matrix`.
+impl<V> ValueEncoded for Matrix<V> {}
+
+/// A primitive of a `TreeGenome`: a function, which is applied to the values
+/// of the children of its node, or a terminal like a variable or a constant,
+/// whose node has no children.
+pub trait Primitive: Clone + Debug + PartialEq + Send + Sync {
+    /// Returns the number of children of a node of this primitive, 0 for a
+    /// terminal.
+    fn arity(&self) -> usize;
+}
+
+/// A `PrimitiveSet` provides the random primitives that `TreeGenome`s are
+/// built of and mutated with.
+///
+/// The `Primitives` implement it for fixed lists of terminals and functions.
+/// A custom implementation can e.g. create ephemeral random constants.
+pub trait PrimitiveSet: Clone + Debug + PartialEq + Send + Sync {
+    type Primitive: Primitive;
+
+    /// Returns a random terminal, i.e. a primitive of arity 0.
+    fn random_terminal<R>(&self, rng: &mut R) -> Self::Primitive
+    where
+        R: Rng + Sized;
+
+    /// Returns a random function, i.e. a primitive of arity 1 or more, or
+    /// `None` if there are no functions.
+    fn random_function<R>(&self, rng: &mut R) -> Option<Self::Primitive>
+    where
+        R: Rng + Sized;
+
+    /// Returns a random primitive of the given arity, or `None` if there is
+    /// no primitive of that arity.
+    fn random_of_arity<R>(&self, arity: usize, rng: &mut R) -> Option<Self::Primitive>
+    where
+        R: Rng + Sized;
+}
+
+/// A `PrimitiveSet` which picks the primitives uniformly at random from a
+/// list of terminals and a list of functions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Primitives<P> {
+    terminals: Vec<P>,
+    functions: Vec<P>,
+}
+
+impl<P> Primitives<P>
+where
+    P: Primitive,
+{
+    /// Constructs a new set of the given terminals, which must not be empty,
+    /// and functions.
+    pub fn new(terminals: Vec<P>, functions: Vec<P>) -> Self {
+        assert!(!terminals.is_empty(), "a primitive set needs terminals");
+        assert!(
+            terminals.iter().all(|terminal| terminal.arity() == 0),
+            "the terminals must have an arity of 0"
+        );
+        assert!(
+            functions.iter().all(|function| function.arity() > 0),
+            "the functions must have an arity of 1 or more"
+        );
+        Primitives {
+            terminals,
+            functions,
+        }
+    }
+
+    pub fn terminals(&self) -> &[P] {
+        &self.terminals
+    }
+
+    pub fn functions(&self) -> &[P] {
+        &self.functions
+    }
+}
+
+impl<P> PrimitiveSet for Primitives<P>
+where
+    P: Primitive,
+{
+    type Primitive = P;
+
+    fn random_terminal<R>(&self, rng: &mut R) -> P
+    where
+        R: Rng + Sized,
+    {
+        self.terminals.choose(rng).unwrap().clone()
+    }
+
+    fn random_function<R>(&self, rng: &mut R) -> Option<P>
+    where
+        R: Rng + Sized,
+    {
+        self.functions.choose(rng).cloned()
+    }
+
+    fn random_of_arity<R>(&self, arity: usize, rng: &mut R) -> Option<P>
+    where
+        R: Rng + Sized,
+    {
+        let candidates = if arity == 0 {
+            &self.terminals
+        } else {
+            &self.functions
+        };
+        let of_arity: Vec<&P> = candidates
+            .iter()
+            .filter(|primitive| primitive.arity() == arity)
+            .collect();
+        of_arity.choose(rng).map(|primitive| (*primitive).clone())
+    }
+}
+
+/// A tree encoded `genetic::Genotype`, e.g. the expression tree of a program
+/// evolved by genetic programming.
+///
+/// The nodes are stored in prefix order, each node followed by the subtrees
+/// of its children, so that every subtree is a contiguous range of nodes,
+/// which the operators of the `recombination::tree` and `mutation::tree`
+/// modules cut out and splice in. A tree of a single node has a depth of 0.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        try_from = "RawTreeGenome<P>",
+        bound(deserialize = "P: Primitive + Deserialize<'de>")
+    )
+)]
+pub struct TreeGenome<P> {
+    nodes: Vec<P>,
+}
+
+impl<P> TreeGenome<P>
+where
+    P: Primitive,
+{
+    /// Constructs a tree of a single terminal.
+    pub fn leaf(terminal: P) -> Self {
+        TreeGenome::node(terminal, Vec::new())
+    }
+
+    /// Constructs a tree of a node of the given primitive with the given
+    /// children, one for each argument of the primitive.
+    pub fn node(primitive: P, children: Vec<TreeGenome<P>>) -> Self {
+        assert_eq!(
+            primitive.arity(),
+            children.len(),
+            "a node needs one child for each argument of its primitive"
+        );
+        let mut nodes = vec![primitive];
+        for child in children {
+            nodes.extend(child.nodes);
+        }
+        TreeGenome { nodes }
+    }
+
+    /// Constructs a tree of the given nodes in prefix order.
+    pub fn from_nodes(nodes: Vec<P>) -> Self {
+        assert!(
+            Self::form_one_tree(&nodes),
+            "the nodes must form exactly one tree"
+        );
+        TreeGenome { nodes }
+    }
+
+    /// Returns whether the nodes in prefix order form exactly one tree.
+    fn form_one_tree(nodes: &[P]) -> bool {
+        let mut open = 1;
+        for node in nodes {
+            if open == 0 {
+                return false;
+            }
+            open += node.arity();
+            open -= 1;
+        }
+        open == 0
+    }
+
+    /// Builds a random tree by the grow method, which picks a terminal or a
+    /// function for each node, and only terminals at the given `max_depth`.
+    pub fn grow<S, R>(primitives: &S, max_depth: usize, rng: &mut R) -> Self
+    where
+        S: PrimitiveSet<Primitive = P>,
+        R: Rng + Sized,
+    {
+        let mut nodes = Vec::new();
+        build_nodes(primitives, max_depth, false, &mut nodes, rng);
+        TreeGenome { nodes }
+    }
+
+    /// Builds a random tree by the full method, which picks only functions
+    /// above the given `depth` and only terminals at it, so that all leaves
+    /// are at the same depth.
+    pub fn full<S, R>(primitives: &S, depth: usize, rng: &mut R) -> Self
+    where
+        S: PrimitiveSet<Primitive = P>,
+        R: Rng + Sized,
+    {
+        let mut nodes = Vec::new();
+        build_nodes(primitives, depth, true, &mut nodes, rng);
+        TreeGenome { nodes }
+    }
+
+    /// Returns the nodes in prefix order.
+    pub fn nodes(&self) -> &[P] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes.
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the depth of the tree, i.e. the number of edges on the
+    /// longest path from the root to a leaf.
+    pub fn depth(&self) -> usize {
+        depth_of(&self.nodes)
+    }
+
+    /// Returns the nodes of the subtree rooted at the node of the given
+    /// index.
+    pub fn subtree(&self, index: usize) -> &[P] {
+        &self.nodes[index..self.subtree_end(index)]
+    }
+
+    /// Returns a copy of this tree with the subtree rooted at the node of the
+    /// given index replaced by the given subtree.
+    pub fn replace_subtree(&self, index: usize, subtree: &[P]) -> Self {
+        let end = self.subtree_end(index);
+        let mut nodes = Vec::with_capacity(self.nodes.len() - (end - index) + subtree.len());
+        nodes.extend_from_slice(&self.nodes[..index]);
+        nodes.extend_from_slice(subtree);
+        nodes.extend_from_slice(&self.nodes[end..]);
+        TreeGenome::from_nodes(nodes)
+    }
+
+    /// Evaluates the tree bottom-up: `apply` is called for each node with
+    /// the values of its children and returns the value of the node.
+    pub fn evaluate<T, F>(&self, apply: F) -> T
+    where
+        F: FnMut(&P, &[T]) -> T,
+    {
+        fold_nodes(&self.nodes, apply)
+    }
+
+    // The index after the last node of the subtree rooted at `index`.
+    fn subtree_end(&self, index: usize) -> usize {
+        let mut open = 1;
+        let mut end = index;
+        while open > 0 {
+            open += self.nodes[end].arity();
+            open -= 1;
+            end += 1;
+        }
+        end
+    }
+}
+
+// Appends the nodes of a random tree of at most the given depth, of exactly
+// the depth if `full`.
+fn build_nodes<S, R>(
+    primitives: &S,
+    depth: usize,
+    full: bool,
+    nodes: &mut Vec<S::Primitive>,
+    rng: &mut R,
+) where
+    S: PrimitiveSet,
+    R: Rng + Sized,
+{
+    let function = if depth > 0 && (full || rng.gen_bool(0.5)) {
+        primitives.random_function(rng)
+    } else {
+        None
+    };
+    match function {
+        Some(function) => {
+            let arity = function.arity();
+            nodes.push(function);
+            for _ in 0..arity {
+                build_nodes(primitives, depth - 1, full, nodes, rng);
+            }
+        }
+        None => nodes.push(primitives.random_terminal(rng)),
+    }
+}
+
+// Folds the nodes of a tree from the leaves up to the root.
+fn fold_nodes<P, T, F>(nodes: &[P], mut apply: F) -> T
+where
+    P: Primitive,
+    F: FnMut(&P, &[T]) -> T,
+{
+    let mut values: Vec<T> = Vec::new();
+    for node in nodes.iter().rev() {
+        let mut children = values.split_off(values.len() - node.arity());
+        children.reverse();
+        values.push(apply(node, &children));
+    }
+    values.pop().expect("a tree has a root")
+}
+
+// The depth of the tree of the given nodes.
+fn depth_of<P>(nodes: &[P]) -> usize
+where
+    P: Primitive,
+{
+    fold_nodes(nodes, |_, children: &[usize]| {
+        children.iter().max().map_or(0, |depth| depth + 1)
+    })
+}
+
+/// Implementation of a genotype using `TreeGenome`.
+impl<P> Genotype for TreeGenome<P>
+where
+    P: Primitive,
+{
+    type Dna = P;
+}
+
+/// Implementation of a tree encoded `genetic::Genotype`
+/// using `TreeGenome`.
+impl<P> TreeEncoded for TreeGenome<P> where P: Primitive {}
+
+// The fields of the deserialized genotypes, which are checked before they
+// are taken over.
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawPackedGenome {
+    words: Vec<u64>,
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPackedGenome> for PackedGenome {
+    type Error = String;
+
+    fn try_from(raw: RawPackedGenome) -> Result<Self, Self::Error> {
+        let RawPackedGenome { words, len } = raw;
+        if words.len() != len.div_ceil(ALLELES_PER_WORD) {
+            return Err(format!(
+                "{} alleles are packed into {} words, not {}",
+                len,
+                len.div_ceil(ALLELES_PER_WORD),
+                words.len()
+            ));
+        }
+        let used = len % ALLELES_PER_WORD;
+        if used > 0 && words.last().is_some_and(|word| word >> (used * 2) != 0) {
+            return Err("the unused bits of the last word must be 0".to_string());
+        }
+        Ok(PackedGenome { words, len })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawMatrix<V> {
+    cells: Vec<V>,
+    rows: usize,
+    cols: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<V> TryFrom<RawMatrix<V>> for Matrix<V> {
+    type Error = String;
+
+    fn try_from(raw: RawMatrix<V>) -> Result<Self, Self::Error> {
+        let RawMatrix { cells, rows, cols } = raw;
+        if rows.checked_mul(cols) != Some(cells.len()) {
+            return Err(format!(
+                "a {}×{} matrix cannot have {} cells",
+                rows,
+                cols,
+                cells.len()
+            ));
+        }
+        Ok(Matrix { cells, rows, cols })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawTreeGenome<P> {
+    nodes: Vec<P>,
+}
+
+#[cfg(feature = "serde")]
+impl<P> TryFrom<RawTreeGenome<P>> for TreeGenome<P>
+where
+    P: Primitive,
+{
+    type Error = String;
+
+    fn try_from(raw: RawTreeGenome<P>) -> Result<Self, Self::Error> {
+        if !Self::form_one_tree(&raw.nodes) {
+            return Err("the nodes must form exactly one tree".to_string());
+        }
+        Ok(TreeGenome { nodes: raw.nodes })
+    }
+}
+
 #[cfg(feature = "fixedbitset")]
 mod fixedbitset_genotype {
     use super::{BinaryEncoded, Genotype};
@@ -100,3 +753,124 @@ mod smallvec_genotype {
     /// using `smallvec::SmallVec`.
     impl<A> PermutationEncoded for SmallVec<A> where A: Array {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn packed_genome_stores_each_allele_in_2_bits() {
+        let alleles = (0..70).map(|locus| (locus % 4) as u8).collect::<Vec<_>>();
+
+        let genome = alleles.iter().copied().collect::<PackedGenome>();
+
+        expect_that!(&genome.len(), eq(70));
+        expect_that!(&genome.words().len(), eq(3));
+        expect_that!(&genome.iter().collect::<Vec<_>>(), eq(alleles));
+    }
+
+    #[test]
+    fn matrix_stores_the_cells_row_by_row() {
+        let mut matrix = Matrix::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        matrix.set(1, 0, 9);
+
+        expect_that!(matrix.get(0, 2), eq(3));
+        expect_that!(&matrix.row(1).to_vec(), eq(vec![9, 5, 6]));
+        expect_that!(&matrix.into_cells(), eq(vec![1, 2, 3, 9, 5, 6]));
+    }
+
+    #[test]
+    fn packed_genome_copy_from_copies_only_the_given_loci() {
+        let ones = (0..100).map(|_| 1).collect::<PackedGenome>();
+        let mut genome = PackedGenome::new(100);
+
+        genome.copy_from(&ones, 10, 90);
+
+        for locus in 0..100 {
+            let expected = if (10..90).contains(&locus) { 1 } else { 0 };
+            expect_that!(&genome.get(locus), eq(expected));
+        }
+        // the untouched words stay equal to a freshly built genome
+        genome.copy_from(&PackedGenome::new(100), 0, 100);
+        expect_that!(&genome, eq(PackedGenome::new(100)));
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Op {
+        X,
+        One,
+        Add,
+        Neg,
+    }
+
+    impl Primitive for Op {
+        fn arity(&self) -> usize {
+            match self {
+                Op::X | Op::One => 0,
+                Op::Neg => 1,
+                Op::Add => 2,
+            }
+        }
+    }
+
+    #[test]
+    fn tree_genome_stores_the_nodes_in_prefix_order() {
+        // x + -(1 + x)
+        let tree = TreeGenome::node(
+            Op::Add,
+            vec![
+                TreeGenome::leaf(Op::X),
+                TreeGenome::node(
+                    Op::Neg,
+                    vec![TreeGenome::node(
+                        Op::Add,
+                        vec![TreeGenome::leaf(Op::One), TreeGenome::leaf(Op::X)],
+                    )],
+                ),
+            ],
+        );
+
+        expect_that!(
+            &tree.nodes().to_vec(),
+            eq(vec![Op::Add, Op::X, Op::Neg, Op::Add, Op::One, Op::X])
+        );
+        expect_that!(&tree.size(), eq(6));
+        expect_that!(&tree.depth(), eq(3));
+        expect_that!(
+            &tree.subtree(2).to_vec(),
+            eq(vec![Op::Neg, Op::Add, Op::One, Op::X])
+        );
+        let value = tree.evaluate(|op, children: &[i32]| match op {
+            Op::X => 2,
+            Op::One => 1,
+            Op::Add => children[0] + children[1],
+            Op::Neg => -children[0],
+        });
+        expect_that!(&value, eq(-1));
+        expect_that!(
+            &tree.replace_subtree(2, &[Op::One]),
+            eq(TreeGenome::from_nodes(vec![Op::Add, Op::X, Op::One]))
+        );
+    }
+
+    #[test]
+    fn full_trees_have_all_leaves_at_the_depth() {
+        let primitives = Primitives::new(vec![Op::X, Op::One], vec![Op::Add, Op::Neg]);
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..20 {
+            let full = TreeGenome::full(&primitives, 3, &mut rng);
+            let grown = TreeGenome::grow(&primitives, 3, &mut rng);
+
+            expect_that!(&full.depth(), eq(3));
+            expect_that!(
+                &full.evaluate(|_, children: &[usize]| children.iter().min().map_or(0, |d| d + 1)),
+                eq(3)
+            );
+            expect_that!(&grown.depth(), less_than_or_equal(3));
+        }
+    }
+}
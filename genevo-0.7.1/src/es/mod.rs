@@ -0,0 +1,250 @@
+//! This module provides an `algorithm::Algorithm` which implements the
+//! (μ+λ) evolution strategy (ES) and, as its simplest case, the (1+1)
+//! evolutionary algorithm (EA).
+//!
+//! The stages of the (μ+λ)-ES are:
+//!
+//! 1. **Variation**: Create λ offspring, each a mutated copy of one of the μ
+//!    parents chosen uniformly at random.
+//! 2. **Evaluation**: Evaluate the fitness of the offspring.
+//! 3. **Survival**: The μ fittest of the parents and the offspring together
+//!    become the parents of the next generation. An offspring wins a tie
+//!    with a parent, so the strategy can drift across plateaus.
+//!
+//! The (1+1)-EA keeps a single parent and creates a single offspring per
+//! generation, usually by mutating each locus with probability 1/n.
+//!
+//! Each iteration outputs a `ga::State` of the parents, so the
+//! `simulation::Simulator`, the `termination`s and the
+//! `simulation::SimulationObserver`s of the genetic algorithm work with the
+//! evolution strategy as well.
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation, PopulationAlgorithm},
+    ga::{GeneticAlgorithmError, State},
+    genetic::{Fitness, FitnessFunction, Genotype},
+    operator::MutationOp,
+    population::Population,
+    random::{random_index, Prng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{marker::PhantomData, rc::Rc};
+
+/// `EvolutionStrategy` implements the (μ+λ) evolution strategy. The first μ
+/// individuals of the initial population are the initial parents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvolutionStrategy<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    mutator: M,
+    mu: usize,
+    lambda: usize,
+    generations_per_iteration: u64,
+    initial_population: Population<G>,
+    parents: Vec<Evaluated<G, F>>,
+    processing_time: ProcessingTime,
+}
+
+impl<G, F, E, M> EvolutionStrategy<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    /// Creates a (μ+λ)-ES keeping `mu` parents and creating `lambda`
+    /// offspring per generation.
+    pub fn new(
+        evaluator: E,
+        mutator: M,
+        mu: usize,
+        lambda: usize,
+        initial_population: Population<G>,
+    ) -> Self {
+        EvolutionStrategy {
+            _f: PhantomData,
+            evaluator,
+            mutator,
+            mu,
+            lambda,
+            generations_per_iteration: 1,
+            initial_population,
+            parents: Vec::new(),
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    /// Creates a (1+1)-EA, the (μ+λ)-ES with a single parent and a single
+    /// offspring.
+    pub fn one_plus_one(evaluator: E, mutator: M, initial_population: Population<G>) -> Self {
+        EvolutionStrategy::new(evaluator, mutator, 1, 1, initial_population)
+    }
+
+    /// Runs the given number of generations per iteration of the simulation,
+    /// e.g. to compare the strategy with a genetic algorithm at the same
+    /// number of evaluations per iteration.
+    pub fn with_generations_per_iteration(mut self, generations: u64) -> Self {
+        self.generations_per_iteration = generations.max(1);
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the number of parents.
+    pub fn mu(&self) -> usize {
+        self.mu
+    }
+
+    /// Returns the number of offspring of each generation.
+    pub fn lambda(&self) -> usize {
+        self.lambda
+    }
+
+    pub fn generations_per_iteration(&self) -> u64 {
+        self.generations_per_iteration
+    }
+
+    fn evaluate(&self, genomes: Vec<G>) -> Vec<Evaluated<G, F>> {
+        genomes
+            .into_iter()
+            .map(|genome| Evaluated {
+                fitness: self.evaluator.fitness_of(&genome),
+                genome,
+            })
+            .collect()
+    }
+
+    /// Runs one generation and returns whether the best fitness improved.
+    fn generation(&mut self, rng: &mut Prng) -> bool {
+        let offspring = (0..self.lambda)
+            .map(|_| {
+                let parent = &self.parents[random_index(rng, self.parents.len())];
+                self.mutator.mutate(parent.genome.clone(), rng)
+            })
+            .collect();
+        let mut candidates = self.evaluate(offspring);
+        let highest = self.parents[0].fitness.clone();
+        candidates.append(&mut self.parents);
+        // The sort is stable, so the offspring stay ahead of equally fit parents
+        candidates.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+        candidates.truncate(self.mu);
+        self.parents = candidates;
+        self.parents[0].fitness > highest
+    }
+}
+
+impl<G, F, E, M> TrackProcessingTime for EvolutionStrategy<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, F, E, M> Algorithm for EvolutionStrategy<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    type Output = State<G, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.mu == 0 || self.lambda == 0 {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "The ({}+{})-ES of generation {} has no parents or no offspring.",
+                self.mu, self.lambda, iteration
+            )));
+        }
+        if self.initial_population.size() < self.mu {
+            return Err(GeneticAlgorithmError::PopulationTooSmall(format!(
+                "The initial population has a size of {} which is smaller than the {} \
+                 parents of the ({}+{})-ES.",
+                self.initial_population.size(),
+                self.mu,
+                self.mu,
+                self.lambda
+            )));
+        }
+        let generation = timed(|| {
+            if self.parents.is_empty() {
+                let initial = self.initial_population.individuals()[..self.mu].to_vec();
+                self.parents = self.evaluate(initial);
+                self.parents.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+            }
+            for _ in 0..self.generations_per_iteration {
+                let improved = self.generation(rng);
+                self.mutator.adapt(improved);
+            }
+        })
+        .run();
+        self.processing_time = generation.time;
+
+        let fitness_values: Vec<F> = self.parents.iter().map(|p| p.fitness.clone()).collect();
+        let evaluated_population = EvaluatedPopulation::new(
+            Rc::new(self.parents.iter().map(|p| p.genome.clone()).collect()),
+            fitness_values.clone(),
+            fitness_values[0].clone(),
+            fitness_values[fitness_values.len() - 1].clone(),
+            self.evaluator.average(&fitness_values),
+        );
+        Ok(State {
+            evaluated_population,
+            best_solution: BestSolution {
+                found_at: Local::now(),
+                generation: iteration,
+                solution: self.parents[0].clone(),
+            },
+            restarted: false,
+            processing_time: self.processing_time,
+            population_snapshot: None,
+            operator_statistics: None,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.parents.clear();
+        Ok(true)
+    }
+}
+
+impl<G, F, E, M> PopulationAlgorithm for EvolutionStrategy<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    type Genotype = G;
+    type Fitness = F;
+
+    fn population_snapshot(&self) -> Vec<Evaluated<G, F>> {
+        self.parents.clone()
+    }
+
+    fn replace_population(&mut self, population: Vec<G>) {
+        self.parents = self.evaluate(population);
+        self.parents.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+        self.parents.truncate(self.mu);
+    }
+}
@@ -1,8 +1,13 @@
 use super::GeneticAlgorithm;
 use crate::{
     genetic::{Fitness, FitnessFunction, Genotype},
-    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
+    lineage::{LineageHook, NoLineage},
+    local_search::NoLocalSearch,
+    mutation::noop::NoOpMutator,
+    operator::{CrossoverOp, LocalSearchOp, MutationOp, ReinsertionOp, RepairOp, SelectionOp},
     population::Population,
+    recombination::noop::NoOpCrossover,
+    repair::NoRepair,
     statistic::ProcessingTime,
 };
 use std::{marker::PhantomData, rc::Rc};
@@ -10,8 +15,18 @@ use std::{marker::PhantomData, rc::Rc};
 const DEFAULT_MIN_POPULATION_SIZE: usize = 6;
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct GeneticAlgorithmBuilder<G, F, E, S, C, M, R>
-where
+pub struct GeneticAlgorithmBuilder<
+    G,
+    F,
+    E,
+    S,
+    C,
+    M,
+    R,
+    L = NoLocalSearch,
+    H = NoLineage,
+    P = NoRepair,
+> where
     G: Genotype,
     F: Fitness,
     E: FitnessFunction<G, F>,
@@ -19,6 +34,9 @@ where
     C: CrossoverOp<G>,
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F>,
+    H: LineageHook<G>,
+    P: RepairOp<G>,
 {
     _f: PhantomData<F>,
     evaluator: E,
@@ -26,11 +44,17 @@ where
     breeder: C,
     mutator: M,
     reinserter: R,
+    local_search: L,
+    lineage: H,
+    repair: P,
     min_population_size: usize,
+    parallel_evaluation: bool,
+    population_snapshots: bool,
+    operator_statistics: bool,
     initial_population: Population<G>,
 }
 
-impl<G, F, E, S, C, M, R> GeneticAlgorithmBuilder<G, F, E, S, C, M, R>
+impl<G, F, E, S, C, M, R, L, H, P> GeneticAlgorithmBuilder<G, F, E, S, C, M, R, L, H, P>
 where
     G: Genotype,
     F: Fitness,
@@ -39,8 +63,11 @@ where
     C: CrossoverOp<G>,
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F>,
+    H: LineageHook<G>,
+    P: RepairOp<G>,
 {
-    pub fn build(self) -> GeneticAlgorithm<G, F, E, S, C, M, R> {
+    pub fn build(self) -> GeneticAlgorithm<G, F, E, S, C, M, R, L, H, P> {
         GeneticAlgorithm {
             _f: self._f,
             evaluator: self.evaluator,
@@ -48,9 +75,16 @@ where
             breeder: self.breeder,
             mutator: self.mutator,
             reinserter: self.reinserter,
+            local_search: self.local_search,
+            lineage: self.lineage,
+            repair: self.repair,
             min_population_size: self.min_population_size,
+            parallel_evaluation: self.parallel_evaluation,
+            population_snapshots: self.population_snapshots,
+            operator_statistics: self.operator_statistics,
             population: Rc::new(self.initial_population.individuals().to_vec()),
             initial_population: self.initial_population,
+            highest_fitness: None,
             processing_time: ProcessingTime::zero(),
         }
     }
@@ -59,6 +93,110 @@ where
         self.min_population_size = min_population_size;
         self
     }
+
+    /// Whether the fitness of the population is evaluated in parallel. This is
+    /// the default; turn it off for cheap fitness functions or when the
+    /// simulations themselves already run in parallel.
+    pub fn with_parallel_evaluation(mut self, parallel_evaluation: bool) -> Self {
+        self.parallel_evaluation = parallel_evaluation;
+        self
+    }
+
+    /// Whether the `State` of each generation holds a snapshot of its evaluated
+    /// population, e.g. for diversity metrics or histograms of the fitness.
+    /// Off by default, as it copies the whole population every generation.
+    pub fn with_population_snapshots(mut self, population_snapshots: bool) -> Self {
+        self.population_snapshots = population_snapshots;
+        self
+    }
+
+    /// Whether the `State` of each generation reports the time spent in each
+    /// stage and the number of children bred and mutated, to find the
+    /// bottlenecks of the algorithm. Off by default, as timing each child
+    /// takes time itself.
+    pub fn with_operator_statistics(mut self, operator_statistics: bool) -> Self {
+        self.operator_statistics = operator_statistics;
+        self
+    }
+
+    /// Adds a repair stage, which makes each child satisfy the constraints of
+    /// the problem after mutation. The initial population is not repaired.
+    pub fn with_repair<RP>(
+        self,
+        repair_op: RP,
+    ) -> GeneticAlgorithmBuilder<G, F, E, S, C, M, R, L, H, RP>
+    where
+        RP: RepairOp<G>,
+    {
+        GeneticAlgorithmBuilder {
+            _f: self._f,
+            evaluator: self.evaluator,
+            selector: self.selector,
+            breeder: self.breeder,
+            mutator: self.mutator,
+            reinserter: self.reinserter,
+            local_search: self.local_search,
+            lineage: self.lineage,
+            repair: repair_op,
+            min_population_size: self.min_population_size,
+            parallel_evaluation: self.parallel_evaluation,
+            population_snapshots: self.population_snapshots,
+            operator_statistics: self.operator_statistics,
+            initial_population: self.initial_population,
+        }
+    }
+
+    /// Adds a local search stage, which improves each child after mutation.
+    pub fn with_local_search<LS>(
+        self,
+        local_search_op: LS,
+    ) -> GeneticAlgorithmBuilder<G, F, E, S, C, M, R, LS, H, P>
+    where
+        LS: LocalSearchOp<G, F>,
+    {
+        GeneticAlgorithmBuilder {
+            _f: self._f,
+            evaluator: self.evaluator,
+            selector: self.selector,
+            breeder: self.breeder,
+            mutator: self.mutator,
+            reinserter: self.reinserter,
+            local_search: local_search_op,
+            lineage: self.lineage,
+            repair: self.repair,
+            min_population_size: self.min_population_size,
+            parallel_evaluation: self.parallel_evaluation,
+            population_snapshots: self.population_snapshots,
+            operator_statistics: self.operator_statistics,
+            initial_population: self.initial_population,
+        }
+    }
+
+    /// Adds a lineage hook, which is notified about the birth of each child.
+    pub fn with_lineage<LH>(
+        self,
+        lineage_hook: LH,
+    ) -> GeneticAlgorithmBuilder<G, F, E, S, C, M, R, L, LH, P>
+    where
+        LH: LineageHook<G>,
+    {
+        GeneticAlgorithmBuilder {
+            _f: self._f,
+            evaluator: self.evaluator,
+            selector: self.selector,
+            breeder: self.breeder,
+            mutator: self.mutator,
+            reinserter: self.reinserter,
+            local_search: self.local_search,
+            lineage: lineage_hook,
+            repair: self.repair,
+            min_population_size: self.min_population_size,
+            parallel_evaluation: self.parallel_evaluation,
+            population_snapshots: self.population_snapshots,
+            operator_statistics: self.operator_statistics,
+            initial_population: self.initial_population,
+        }
+    }
 }
 
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -166,6 +304,14 @@ where
             breeder: crossover_op,
         }
     }
+
+    /// Skips the crossover stage: the children are copies of their parents,
+    /// which are then only changed by the mutation (see `NoOpCrossover`).
+    pub fn without_crossover(
+        self,
+    ) -> GeneticAlgorithmWithEvalSeleAndBreeBuilder<G, F, E, S, NoOpCrossover> {
+        self.with_crossover(NoOpCrossover)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -208,6 +354,14 @@ where
             mutator: mutation_op,
         }
     }
+
+    /// Skips the mutation stage: the children are only made of the alleles of
+    /// their parents (see `NoOpMutator`).
+    pub fn without_mutation(
+        self,
+    ) -> GeneticAlgorithmWithEvalSeleBreeAndMutaBuilder<G, F, E, S, C, NoOpMutator> {
+        self.with_mutation(NoOpMutator)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -300,7 +454,13 @@ where
             breeder: self.breeder,
             mutator: self.mutator,
             reinserter: self.reinserter,
+            local_search: NoLocalSearch,
+            lineage: NoLineage,
+            repair: NoRepair,
             min_population_size: DEFAULT_MIN_POPULATION_SIZE,
+            parallel_evaluation: true,
+            population_snapshots: false,
+            operator_statistics: false,
             initial_population,
         }
     }
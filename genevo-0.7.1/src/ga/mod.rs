@@ -16,7 +16,12 @@
 //! 3.3. **Mutation**: With a mutation probability mutate new offspring at each
 //!      locus (position in genotype) by means of the configured
 //!      `operator::MutationOp`.
-//! 3.4. **Accepting**: Place new offspring in the new population.
+//! 3.4. **Repair**: Optionally make the new offspring satisfy the constraints
+//!      of the problem by means of the configured `operator::RepairOp`.
+//! 3.5. **Local search**: Optionally improve the new offspring by means of
+//!      the configured `operator::LocalSearchOp` (memetic algorithm).
+//! 3.6. **Accepting**: Place new offspring in the new population. An optional
+//!      `lineage::LineageHook` is notified about the birth of each child.
 //! 4. **Replace**: Use new generated population for a further run of the
 //!    algorithm.
 //! 5. **Termination**: If the end condition is satisfied, stop, and return the
@@ -27,26 +32,36 @@ pub mod builder;
 
 use self::builder::EmptyGeneticAlgorithmBuilder;
 use crate::{
-    algorithm::{Algorithm, BestSolution, EvaluatedPopulation},
+    algorithm::{
+        Algorithm, BestSolution, Evaluated, EvaluatedPopulation, OptimizationResult,
+        PopulationAlgorithm,
+    },
     genetic::{Fitness, FitnessFunction, Genotype, Offspring, Parents},
-    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
+    lineage::{Birth, LineageHook, NoLineage},
+    local_search::NoLocalSearch,
+    operator::{CrossoverOp, LocalSearchOp, MutationOp, ReinsertionOp, RepairOp, SelectionOp},
     population::Population,
     random::Prng,
-    statistic::{timed, ProcessingTime, TimedResult, TrackProcessingTime},
+    repair::NoRepair,
+    statistic::{timed, OperatorStatistics, ProcessingTime, TimedResult, TrackProcessingTime},
 };
 use chrono::Local;
 #[cfg(not(target_arch = "wasm32"))]
 use rayon;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Display},
     marker::PhantomData,
     rc::Rc,
+    sync::Arc,
 };
 
 /// The `State` struct holds the results of one pass of the genetic algorithm
 /// loop, i.e. the processing of the evolution from one generation to the next
 /// generation.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct State<G, F>
 where
     G: Genotype,
@@ -56,9 +71,31 @@ where
     pub evaluated_population: EvaluatedPopulation<G, F>,
     /// Best solution of this generation.
     pub best_solution: BestSolution<G, F>,
+    /// Whether the `operator::ReinsertionOp` has restarted the population
+    /// of the next generation instead of combining it as usual.
+    pub restarted: bool,
     /// Processing time for this generation. In case of parallel processing it
     /// is the accumulated time spent by each thread.
     pub processing_time: ProcessingTime,
+    /// A snapshot of the evaluated population of the current generation, each
+    /// individual with its fitness value, if the algorithm is built
+    /// `with_population_snapshots`. Unlike the `evaluated_population` it can
+    /// be kept beyond the generation and sent to other threads.
+    pub population_snapshot: Option<Arc<Vec<Evaluated<G, F>>>>,
+    /// The time spent in each stage of this generation and the number of
+    /// children bred and mutated, if the algorithm is built
+    /// `with_operator_statistics`.
+    pub operator_statistics: Option<OperatorStatistics>,
+}
+
+impl<G, F> OptimizationResult<G, F> for State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<G, F> {
+        &self.best_solution
+    }
 }
 
 /// An error that can occur during execution of a `GeneticAlgorithm`.
@@ -92,8 +129,13 @@ where
 
 /// A `GeneticAlgorithm` declares the building blocks that make up the actual
 /// algorithm for a specific optimization problem.
+///
+/// The repair and the local search stages are optional, by default
+/// `NoRepair` and `NoLocalSearch` are used. Likewise the lineage of the
+/// individuals is only tracked by a configured `lineage::LineageHook`, by
+/// default `NoLineage` is used.
 #[derive(Clone, Debug, PartialEq)]
-pub struct GeneticAlgorithm<G, F, E, S, C, M, R>
+pub struct GeneticAlgorithm<G, F, E, S, C, M, R, L = NoLocalSearch, H = NoLineage, P = NoRepair>
 where
     G: Genotype,
     F: Fitness,
@@ -102,6 +144,9 @@ where
     C: CrossoverOp<G>,
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F>,
+    H: LineageHook<G>,
+    P: RepairOp<G>,
 {
     _f: PhantomData<F>,
     evaluator: E,
@@ -109,13 +154,20 @@ where
     breeder: C,
     mutator: M,
     reinserter: R,
+    local_search: L,
+    lineage: H,
+    repair: P,
     min_population_size: usize,
+    parallel_evaluation: bool,
+    population_snapshots: bool,
+    operator_statistics: bool,
     initial_population: Population<G>,
     population: Rc<Vec<G>>,
+    highest_fitness: Option<F>,
     processing_time: ProcessingTime,
 }
 
-impl<G, F, E, S, C, M, R> GeneticAlgorithm<G, F, E, S, C, M, R>
+impl<G, F, E, S, C, M, R, L, H, P> GeneticAlgorithm<G, F, E, S, C, M, R, L, H, P>
 where
     G: Genotype,
     F: Fitness,
@@ -124,6 +176,9 @@ where
     C: CrossoverOp<G>,
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F>,
+    H: LineageHook<G>,
+    P: RepairOp<G>,
 {
     pub fn evaluator(&self) -> &E {
         &self.evaluator
@@ -145,12 +200,37 @@ where
         &self.reinserter
     }
 
+    pub fn local_search(&self) -> &L {
+        &self.local_search
+    }
+
+    pub fn lineage(&self) -> &H {
+        &self.lineage
+    }
+
+    pub fn repair(&self) -> &P {
+        &self.repair
+    }
+
     pub fn min_population_size(&self) -> usize {
         self.min_population_size
     }
+
+    pub fn parallel_evaluation(&self) -> bool {
+        self.parallel_evaluation
+    }
+
+    pub fn population_snapshots(&self) -> bool {
+        self.population_snapshots
+    }
+
+    pub fn operator_statistics(&self) -> bool {
+        self.operator_statistics
+    }
 }
 
-impl<G, F, E, S, C, M, R> TrackProcessingTime for GeneticAlgorithm<G, F, E, S, C, M, R>
+impl<G, F, E, S, C, M, R, L, H, P> TrackProcessingTime
+    for GeneticAlgorithm<G, F, E, S, C, M, R, L, H, P>
 where
     G: Genotype,
     F: Fitness,
@@ -159,13 +239,16 @@ where
     C: CrossoverOp<G>,
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F>,
+    H: LineageHook<G>,
+    P: RepairOp<G>,
 {
     fn processing_time(&self) -> ProcessingTime {
         self.processing_time
     }
 }
 
-impl<G, F, E, S, C, M, R> Algorithm for GeneticAlgorithm<G, F, E, S, C, M, R>
+impl<G, F, E, S, C, M, R, L, H, P> Algorithm for GeneticAlgorithm<G, F, E, S, C, M, R, L, H, P>
 where
     G: Genotype,
     F: Fitness + Send + Sync,
@@ -174,6 +257,9 @@ where
     C: CrossoverOp<G> + Sync,
     M: MutationOp<G> + Sync,
     R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F> + Sync,
+    H: LineageHook<G>,
+    P: RepairOp<G> + Sync,
 {
     type Output = State<G, F>;
     type Error = GeneticAlgorithmError;
@@ -197,15 +283,47 @@ where
         }
 
         // Stage 2: The fitness check:
-        let evaluation = evaluate_fitness(self.population.clone(), &self.evaluator);
+        self.evaluator.adapt(iteration);
+        let evaluation = evaluate_fitness(
+            self.population.clone(),
+            &self.evaluator,
+            self.parallel_evaluation,
+        );
         let best_solution = determine_best_solution(iteration, &evaluation.result);
+        let fitness = &best_solution.result.solution.fitness;
+        let improved = self
+            .highest_fitness
+            .as_ref()
+            .is_none_or(|highest| fitness > highest);
+        if improved {
+            self.highest_fitness = Some(fitness.clone());
+        }
+        self.selector.adapt(improved);
+        self.breeder.adapt(improved);
+        self.mutator.adapt(improved);
+        self.reinserter.adapt(improved);
+        let restarted = self.reinserter.is_restarting();
 
         // Stage 3: The making of a new population:
         let selection = timed(|| self.selector.select_from(&evaluation.result, rng)).run();
-        let mut breeding = par_breed_offspring(selection.result, &self.breeder, &self.mutator, rng);
+        let breeding_operators = Breeding {
+            breeder: &self.breeder,
+            mutator: &self.mutator,
+            repair: &self.repair,
+            local_search: &self.local_search,
+            evaluator: &self.evaluator,
+            lineage: &self.lineage,
+            generation: iteration,
+        };
+        let mut breeding = par_breed_offspring(
+            selection.result,
+            &breeding_operators,
+            self.operator_statistics,
+            rng,
+        );
         let reinsertion = timed(|| {
             self.reinserter
-                .combine(&mut breeding.result, &evaluation.result, rng)
+                .combine(&mut breeding.result.0, &evaluation.result, rng)
         })
         .run();
 
@@ -217,30 +335,103 @@ where
             + reinsertion.time;
         let next_generation = reinsertion.result;
         self.population = Rc::new(next_generation);
+        let operator_statistics = if self.operator_statistics {
+            Some(OperatorStatistics {
+                evaluation: evaluation.time + best_solution.time,
+                selection: selection.time,
+                reinsertion: reinsertion.time,
+                ..breeding.result.1
+            })
+        } else {
+            None
+        };
+        let population_snapshot = if self.population_snapshots {
+            Some(Arc::new(snapshot_of(&evaluation.result)))
+        } else {
+            None
+        };
         Ok(State {
             evaluated_population: evaluation.result,
             best_solution: best_solution.result,
+            restarted,
             processing_time: self.processing_time,
+            population_snapshot,
+            operator_statistics,
         })
     }
 
     fn reset(&mut self) -> Result<bool, Self::Error> {
         self.processing_time = ProcessingTime::zero();
         self.population = Rc::new(self.initial_population.individuals().to_vec());
+        self.highest_fitness = None;
         Ok(true)
     }
 }
 
+impl<G, F, E, S, C, M, R, L, H, P> PopulationAlgorithm
+    for GeneticAlgorithm<G, F, E, S, C, M, R, L, H, P>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+    L: LocalSearchOp<G, F> + Sync,
+    H: LineageHook<G>,
+    P: RepairOp<G> + Sync,
+{
+    type Genotype = G;
+    type Fitness = F;
+
+    fn population_snapshot(&self) -> Vec<Evaluated<G, F>> {
+        self.population
+            .iter()
+            .map(|genome| Evaluated {
+                genome: genome.clone(),
+                fitness: self.evaluator.fitness_of(genome),
+            })
+            .collect()
+    }
+
+    fn replace_population(&mut self, population: Vec<G>) {
+        self.population = Rc::new(population);
+    }
+}
+
+/// Pairs each individual of the evaluated population with its fitness value.
+fn snapshot_of<G, F>(evaluated_population: &EvaluatedPopulation<G, F>) -> Vec<Evaluated<G, F>>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    evaluated_population
+        .individuals()
+        .iter()
+        .zip(evaluated_population.fitness_values())
+        .map(|(genome, fitness)| Evaluated {
+            genome: genome.clone(),
+            fitness: fitness.clone(),
+        })
+        .collect()
+}
+
 fn evaluate_fitness<G, F, E>(
     population: Rc<Vec<G>>,
     evaluator: &E,
+    parallel: bool,
 ) -> TimedResult<EvaluatedPopulation<G, F>>
 where
     G: Genotype + Sync,
     F: Fitness + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
 {
-    let evaluation = par_evaluate_fitness(&population, evaluator);
+    let evaluation = if parallel {
+        par_evaluate_fitness(&population, evaluator)
+    } else {
+        seq_evaluate_fitness(&population, evaluator)
+    };
     let average = timed(|| evaluator.average(&evaluation.result.0)).run();
     let evaluated = EvaluatedPopulation::new(
         population,
@@ -265,23 +456,7 @@ where
     E: FitnessFunction<G, F> + Sync,
 {
     if population.len() < 50 {
-        timed(|| {
-            let mut fitness = Vec::with_capacity(population.len());
-            let mut highest = evaluator.lowest_possible_fitness();
-            let mut lowest = evaluator.highest_possible_fitness();
-            for genome in population.iter() {
-                let score = evaluator.fitness_of(genome);
-                if score > highest {
-                    highest = score.clone();
-                }
-                if score < lowest {
-                    lowest = score.clone();
-                }
-                fitness.push(score);
-            }
-            (fitness, highest, lowest)
-        })
-        .run()
+        seq_evaluate_fitness(population, evaluator)
     } else {
         let mid_point = population.len() / 2;
         let (l_slice, r_slice) = population.split_at(mid_point);
@@ -315,20 +490,29 @@ where
     G: Genotype + Sync,
     F: Fitness + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
+{
+    seq_evaluate_fitness(population, evaluator)
+}
+
+/// Calculates the `genetic::Fitness` value of each `genetic::Genotype` one
+/// after the other and records the highest and lowest values.
+fn seq_evaluate_fitness<G, F, E>(population: &[G], evaluator: &E) -> TimedResult<(Vec<F>, F, F)>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
 {
     timed(|| {
-        let mut fitness = Vec::with_capacity(population.len());
+        let fitness = evaluator.fitness_of_all(population);
         let mut highest = evaluator.lowest_possible_fitness();
         let mut lowest = evaluator.highest_possible_fitness();
-        for genome in population.iter() {
-            let score = evaluator.fitness_of(genome);
-            if score > highest {
+        for score in &fitness {
+            if *score > highest {
                 highest = score.clone();
             }
-            if score < lowest {
+            if *score < lowest {
                 lowest = score.clone();
             }
-            fitness.push(score);
         }
         (fitness, highest, lowest)
     })
@@ -362,31 +546,57 @@ where
     .run()
 }
 
-/// Lets the parents breed their offspring and mutate its children. And
-/// finally combines the offspring of all parents into one big offspring.
+/// The operators that breed the offspring of one generation and the
+/// `LineageHook` that is notified about the birth of each child.
+struct Breeding<'a, C, M, P, L, E, H> {
+    breeder: &'a C,
+    mutator: &'a M,
+    repair: &'a P,
+    local_search: &'a L,
+    evaluator: &'a E,
+    lineage: &'a H,
+    generation: u64,
+}
+
+/// Lets the parents breed their offspring, mutate its children, repair them
+/// and improve them by local search. And finally combines the offspring of
+/// all parents into one big offspring. The time spent in the crossover, the
+/// mutation, the repair and the local search is only measured if
+/// `statistics` are collected.
 #[cfg(not(target_arch = "wasm32"))]
-fn par_breed_offspring<G, C, M>(
+fn par_breed_offspring<G, F, C, M, P, L, E, H>(
     parents: Vec<Parents<G>>,
-    breeder: &C,
-    mutator: &M,
+    breeding: &Breeding<'_, C, M, P, L, E, H>,
+    statistics: bool,
     rng: &mut Prng,
-) -> TimedResult<Offspring<G>>
+) -> TimedResult<(Offspring<G>, OperatorStatistics)>
 where
     G: Genotype + Send,
+    F: Fitness,
     C: CrossoverOp<G> + Sync,
     M: MutationOp<G> + Sync,
+    P: RepairOp<G> + Sync,
+    L: LocalSearchOp<G, F> + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    H: LineageHook<G>,
 {
     if parents.len() < 50 {
         timed(|| {
             let mut offspring: Offspring<G> = Vec::with_capacity(parents.len() * parents[0].len());
+            let mut operator_statistics = OperatorStatistics::zero();
+            // the genomes of the parents that have been bred are reused for the next children
+            let mut recycled = Vec::new();
             for parents in parents {
-                let children = breeder.crossover(parents, rng);
-                for child in children {
-                    let mutated = mutator.mutate(child, rng);
-                    offspring.push(mutated);
-                }
+                breed_children(
+                    parents,
+                    &mut recycled,
+                    &mut offspring,
+                    breeding,
+                    statistics.then_some(&mut operator_statistics),
+                    rng,
+                );
             }
-            offspring
+            (offspring, operator_statistics)
         })
         .run()
     } else {
@@ -400,40 +610,183 @@ where
         let r_slice = parents.drain(mid_point..).collect();
         let l_slice = parents;
         let (mut left, mut right) = rayon::join(
-            || par_breed_offspring(l_slice, breeder, mutator, &mut rng1),
-            || par_breed_offspring(r_slice, breeder, mutator, &mut rng2),
+            || {
+                par_breed_offspring(l_slice, breeding, statistics, &mut rng1)
+            },
+            || {
+                par_breed_offspring(r_slice, breeding, statistics, &mut rng2)
+            },
         );
-        offspring.append(&mut left.result);
-        offspring.append(&mut right.result);
+        offspring.append(&mut left.result.0);
+        offspring.append(&mut right.result.0);
         TimedResult {
-            result: offspring,
+            result: (offspring, left.result.1 + right.result.1),
             time: left.time + right.time,
         }
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-fn par_breed_offspring<G, C, M>(
+fn par_breed_offspring<G, F, C, M, P, L, E, H>(
     parents: Vec<Parents<G>>,
-    breeder: &C,
-    mutator: &M,
+    breeding: &Breeding<'_, C, M, P, L, E, H>,
+    statistics: bool,
     rng: &mut Prng,
-) -> TimedResult<Offspring<G>>
+) -> TimedResult<(Offspring<G>, OperatorStatistics)>
 where
     G: Genotype + Send,
+    F: Fitness,
     C: CrossoverOp<G> + Sync,
     M: MutationOp<G> + Sync,
+    P: RepairOp<G> + Sync,
+    L: LocalSearchOp<G, F> + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    H: LineageHook<G>,
 {
     timed(|| {
         let mut offspring: Offspring<G> = Vec::with_capacity(parents.len() * parents[0].len());
+        let mut operator_statistics = OperatorStatistics::zero();
+        // the genomes of the parents that have been bred are reused for the next children
+        let mut recycled = Vec::new();
         for parents in parents {
-            let children = breeder.crossover(parents, rng);
-            for child in children {
-                let mutated = mutator.mutate(child, rng);
-                offspring.push(mutated);
-            }
+            breed_children(
+                parents,
+                &mut recycled,
+                &mut offspring,
+                breeding,
+                statistics.then_some(&mut operator_statistics),
+                rng,
+            );
         }
-        offspring
+        (offspring, operator_statistics)
     })
     .run()
 }
+
+/// Lets one tuple of parents breed its children, mutates, repairs and
+/// improves them and places them into the offspring. A tracking
+/// `LineageHook` is notified about the birth of each child. The stages are
+/// timed and the children counted into the `statistics`, if given.
+fn breed_children<G, F, C, M, P, L, E, H>(
+    parents: Parents<G>,
+    recycled: &mut Vec<G>,
+    offspring: &mut Offspring<G>,
+    breeding: &Breeding<'_, C, M, P, L, E, H>,
+    statistics: Option<&mut OperatorStatistics>,
+    rng: &mut Prng,
+) where
+    G: Genotype,
+    F: Fitness,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+    P: RepairOp<G>,
+    L: LocalSearchOp<G, F>,
+    E: FitnessFunction<G, F>,
+    H: LineageHook<G>,
+{
+    let Breeding {
+        breeder,
+        mutator,
+        repair,
+        local_search,
+        evaluator,
+        lineage,
+        generation,
+    } = *breeding;
+    if let Some(statistics) = statistics {
+        breed_children_timed(parents, recycled, offspring, breeding, statistics, rng);
+        return;
+    }
+    if !lineage.is_tracking() {
+        for child in breeder.crossover_recycling(parents, recycled, rng) {
+            let mutated = mutator.mutate(child, rng);
+            let repaired = repair.repair(mutated, rng);
+            offspring.push(local_search.improve(repaired, evaluator, rng));
+        }
+        return;
+    }
+    // the crossover recycles the parents, so the hook gets to see copies of them
+    let tracked_parents = parents.clone();
+    for crossed in breeder.crossover_recycling(parents, recycled, rng) {
+        let mutated = mutator.mutate(crossed.clone(), rng);
+        let repaired = repair.repair(mutated.clone(), rng);
+        let child = local_search.improve(repaired, evaluator, rng);
+        lineage.on_birth(&Birth {
+            generation,
+            parents: &tracked_parents,
+            crossed: &crossed,
+            mutated: &mutated,
+            child: &child,
+        });
+        offspring.push(child);
+    }
+}
+
+/// Like `breed_children`, timing each stage and counting the children. The
+/// children are bred exactly as they are without the statistics.
+fn breed_children_timed<G, F, C, M, P, L, E, H>(
+    parents: Parents<G>,
+    recycled: &mut Vec<G>,
+    offspring: &mut Offspring<G>,
+    breeding: &Breeding<'_, C, M, P, L, E, H>,
+    statistics: &mut OperatorStatistics,
+    rng: &mut Prng,
+) where
+    G: Genotype,
+    F: Fitness,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+    P: RepairOp<G>,
+    L: LocalSearchOp<G, F>,
+    E: FitnessFunction<G, F>,
+    H: LineageHook<G>,
+{
+    let Breeding {
+        breeder,
+        mutator,
+        repair,
+        local_search,
+        evaluator,
+        lineage,
+        generation,
+    } = *breeding;
+    // the crossover recycles the parents, so the hook gets to see copies of them
+    let tracked_parents = lineage.is_tracking().then(|| parents.clone());
+    let crossover = timed(|| breeder.crossover_recycling(parents, recycled, rng)).run();
+    statistics.crossover += crossover.time;
+    for crossed in crossover.result {
+        let mutation = timed(|| mutator.mutate(crossed.clone(), rng)).run();
+        statistics.mutation += mutation.time;
+        statistics.offspring += 1;
+        if mutation.result != crossed {
+            statistics.mutated += 1;
+        }
+        let repairing = timed(|| repair.repair(mutation.result.clone(), rng)).run();
+        statistics.repair += repairing.time;
+        if repairing.result != mutation.result {
+            statistics.repaired += 1;
+        }
+        let child = match &tracked_parents {
+            Some(tracked_parents) => {
+                let improvement =
+                    timed(|| local_search.improve(repairing.result, evaluator, rng)).run();
+                lineage.on_birth(&Birth {
+                    generation,
+                    parents: tracked_parents,
+                    crossed: &crossed,
+                    mutated: &mutation.result,
+                    child: &improvement.result,
+                });
+                statistics.local_search += improvement.time;
+                improvement.result
+            }
+            None => {
+                let improvement =
+                    timed(|| local_search.improve(repairing.result, evaluator, rng)).run();
+                statistics.local_search += improvement.time;
+                improvement.result
+            }
+        };
+        offspring.push(child);
+    }
+}
@@ -100,6 +100,14 @@ pub trait AsScalar {
     fn as_scalar(&self) -> f64;
 }
 
+/// The inverse of `AsScalar`: converts a float value back into this type,
+/// e.g. after a penalty has been subtracted from the scalar form of a
+/// `Fitness` value. Values out of the range of the type saturate.
+pub trait FromScalar {
+    /// Returns the value of this type nearest to the given float value.
+    fn from_scalar(value: f64) -> Self;
+}
+
 /// Defines the evaluation function to calculate the `Fitness` value of a
 /// `Genotype` based on its properties.
 pub trait FitnessFunction<G, F>: Clone
@@ -110,6 +118,21 @@ where
     /// Calculates the `Fitness` value of the given `Genotype`.
     fn fitness_of(&self, a: &G) -> F;
 
+    /// Calculates the `Fitness` values of the given `Genotype`s, in their
+    /// order. The `ga::GeneticAlgorithm` and the `operator::ReinsertionOp`s
+    /// evaluate their genotypes by this function.
+    ///
+    /// Fitness functions with a high cost per call, such as a
+    /// `process::ProcessFitness` that runs an external command, evaluate the
+    /// genotypes in batches. The default implementation calls `fitness_of`
+    /// for each genotype.
+    fn fitness_of_all(&self, genomes: &[G]) -> Vec<F> {
+        genomes
+            .iter()
+            .map(|genome| self.fitness_of(genome))
+            .collect()
+    }
+
     /// Calculates the average `Fitness` value of the given `Fitness` values.
     fn average(&self, a: &[F]) -> F;
 
@@ -119,4 +142,16 @@ where
     /// Returns the worst of all theoretically possible `Fitness` values.
     /// This is usually a value equivalent to zero.
     fn lowest_possible_fitness(&self) -> F;
+
+    /// Informs the fitness function about the generation whose population is
+    /// evaluated next. The `ga::GeneticAlgorithm` calls it once per
+    /// generation, before the fitness check. The first generation is
+    /// generation 1.
+    ///
+    /// Fitness functions that change over the course of a run, such as a
+    /// `penalty::PenalizedFitness` with scheduled weights, use this to follow
+    /// the generation. Their clones, e.g. the one of an
+    /// `operator::ReinsertionOp`, should share the generation, as it takes
+    /// `&self`. The default implementation does nothing.
+    fn adapt(&self, _generation: u64) {}
 }
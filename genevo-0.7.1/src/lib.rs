@@ -105,10 +105,31 @@ pub mod genetic;
 
 pub mod algorithm;
 
+pub mod cache;
+
+pub mod penalty;
+
+pub mod scalarization;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod process;
+
 pub mod ga;
 
+pub mod es;
+
+pub mod pso;
+
+pub mod cmaes;
+
+pub mod aco;
+
+pub mod nsga2;
+
 pub mod population;
 
+pub mod schedule;
+
 pub mod encoding;
 
 pub mod operator;
@@ -121,6 +142,12 @@ pub mod recombination;
 
 pub mod mutation;
 
+pub mod local_search;
+
+pub mod repair;
+
+pub mod lineage;
+
 pub mod reinsertion;
 
 pub mod termination;
@@ -0,0 +1,234 @@
+//! The `lineage` module provides hooks into the breeding stage of the
+//! `ga::GeneticAlgorithm` which get to see how each child came to be, e.g.
+//! to trace which operator produced a new best individual.
+//!
+//! The provided `LineageHook`s are:
+//! * `NoLineage` which ignores the births. It is the default of the
+//!   `ga::GeneticAlgorithm`.
+//! * `Ancestry` which tags the individuals with IDs and the IDs of their
+//!   parents, so the family tree of an individual can be reconstructed and
+//!   exported in the GraphViz DOT format.
+
+use crate::genetic::Genotype;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    sync::Mutex,
+};
+
+/// The `Birth` of a child: the parents it has been bred from and its genome
+/// after each step of the breeding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Birth<'a, G>
+where
+    G: Genotype,
+{
+    /// The generation the parents have been selected from.
+    pub generation: u64,
+    /// The parents the child has been bred from.
+    pub parents: &'a [G],
+    /// The genome of the child as it came out of the crossover.
+    pub crossed: &'a G,
+    /// The genome of the child after the mutation.
+    pub mutated: &'a G,
+    /// The genome of the child after the repair and the local search, as it
+    /// has been placed into the offspring.
+    pub child: &'a G,
+}
+
+/// A `LineageHook` is notified about the `Birth` of each child of the
+/// offspring. The children of one generation may be bred in parallel, so the
+/// hook is shared between threads.
+///
+/// Tracking does not change the course of the evolution: the breeding draws
+/// the same random numbers whether a hook is tracking or not.
+pub trait LineageHook<G>: Debug + Sync
+where
+    G: Genotype,
+{
+    /// Whether the hook looks at the births at all. The breeding only keeps
+    /// copies of the parents and of the intermediate genomes for a hook that
+    /// is tracking.
+    fn is_tracking(&self) -> bool {
+        true
+    }
+
+    /// Called for each child of the offspring.
+    fn on_birth(&self, birth: &Birth<G>);
+}
+
+/// The `NoLineage` hook ignores all births, i.e. the genetic algorithm does
+/// not track the lineage of its individuals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoLineage;
+
+impl<G> LineageHook<G> for NoLineage
+where
+    G: Genotype,
+{
+    fn is_tracking(&self) -> bool {
+        false
+    }
+
+    fn on_birth(&self, _: &Birth<G>) {}
+}
+
+/// An `Individual` as tagged by the `Ancestry` hook.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Individual<G> {
+    /// The ID of the individual. The IDs are handed out in the order the
+    /// individuals are first seen.
+    pub id: u64,
+    /// The IDs of the parents, without duplicates. The individuals of the
+    /// initial population have no parents.
+    pub parent_ids: Vec<u64>,
+    /// The generation the individual is a member of, the initial population
+    /// being generation 1.
+    pub generation: u64,
+    pub genome: G,
+}
+
+#[derive(Debug)]
+struct Records<G> {
+    // The ID of the latest individual with each genome
+    ids: HashMap<G, u64>,
+    // The individuals by their ID
+    individuals: Vec<Individual<G>>,
+}
+
+impl<G> Records<G>
+where
+    G: Genotype + Eq + Hash,
+{
+    fn tag(&mut self, genome: &G, parent_ids: Vec<u64>, generation: u64) -> u64 {
+        let id = self.individuals.len() as u64;
+        self.ids.insert(genome.clone(), id);
+        self.individuals.push(Individual {
+            id,
+            parent_ids,
+            generation,
+            genome: genome.clone(),
+        });
+        id
+    }
+}
+
+/// The `Ancestry` hook tags each child with a new ID and the IDs of its
+/// parents. The individuals of the initial population are tagged when they
+/// are first selected as parents.
+///
+/// The individuals are told apart by their genomes: a parent is identified
+/// as the latest individual with its genome. As every individual ever born
+/// is kept, the `Ancestry` is meant for single diagnostic runs.
+#[derive(Debug)]
+pub struct Ancestry<G> {
+    records: Mutex<Records<G>>,
+}
+
+impl<G> Default for Ancestry<G> {
+    fn default() -> Self {
+        Ancestry {
+            records: Mutex::new(Records {
+                ids: HashMap::new(),
+                individuals: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl<G> Ancestry<G>
+where
+    G: Genotype + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of individuals tagged so far.
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap().individuals.len()
+    }
+
+    /// Whether no individual has been tagged so far.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the ID of the latest individual with the given genome.
+    pub fn id_of(&self, genome: &G) -> Option<u64> {
+        self.records.lock().unwrap().ids.get(genome).copied()
+    }
+
+    /// Returns the latest individual with the given genome and all of its
+    /// ancestors, ordered by their IDs. The family tree is empty if the
+    /// genome has never been seen.
+    pub fn family_tree(&self, genome: &G) -> Vec<Individual<G>> {
+        let records = self.records.lock().unwrap();
+        let mut ids = HashSet::new();
+        let mut open = records
+            .ids
+            .get(genome)
+            .copied()
+            .into_iter()
+            .collect::<Vec<_>>();
+        while let Some(id) = open.pop() {
+            if ids.insert(id) {
+                open.extend(&records.individuals[id as usize].parent_ids);
+            }
+        }
+        let mut tree = ids
+            .into_iter()
+            .map(|id| records.individuals[id as usize].clone())
+            .collect::<Vec<_>>();
+        tree.sort_by_key(|individual| individual.id);
+        tree
+    }
+}
+
+impl<G> LineageHook<G> for Ancestry<G>
+where
+    G: Genotype + Eq + Hash,
+{
+    fn on_birth(&self, birth: &Birth<G>) {
+        let mut records = self.records.lock().unwrap();
+        let mut parent_ids = Vec::with_capacity(birth.parents.len());
+        for parent in birth.parents {
+            let id = match records.ids.get(parent) {
+                Some(id) => *id,
+                // The parents of the first generation are the initial population
+                None => records.tag(parent, Vec::new(), birth.generation),
+            };
+            if !parent_ids.contains(&id) {
+                parent_ids.push(id);
+            }
+        }
+        records.tag(birth.child, parent_ids, birth.generation + 1);
+    }
+}
+
+/// Writes a family tree in the GraphViz DOT format, with an edge from each
+/// parent to its child. Each individual is labeled by the given function.
+pub fn family_tree_to_dot<G, L>(tree: &[Individual<G>], label: L) -> String
+where
+    L: Fn(&Individual<G>) -> String,
+{
+    let mut dot = String::from("digraph ancestry {\n    node [shape=box];\n");
+    for individual in tree {
+        dot.push_str(&format!(
+            "    n{} [label={:?}];\n",
+            individual.id,
+            label(individual)
+        ));
+    }
+    for individual in tree {
+        for parent_id in &individual.parent_ids {
+            dot.push_str(&format!("    n{} -> n{};\n", parent_id, individual.id));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
@@ -0,0 +1,183 @@
+//! The `local_search` module provides `operator::LocalSearchOp`s which turn
+//! the genetic algorithm into a memetic algorithm.
+//!
+//! The provided `operator::LocalSearchOp`s are:
+//! * `NoLocalSearch` which leaves the offspring unchanged. It is the default
+//!   of the `ga::GeneticAlgorithm`.
+//! * `HillClimber` which repeatedly moves to a neighbour that is not worse.
+
+use crate::{
+    genetic::{Fitness, FitnessFunction, Genotype},
+    operator::{GeneticOperator, LocalSearchOp, MutationOp},
+    random::Rng,
+};
+
+/// The `NoLocalSearch` operator returns each genome unchanged, i.e. the
+/// genetic algorithm does not apply any local search.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoLocalSearch;
+
+impl GeneticOperator for NoLocalSearch {
+    fn name() -> String {
+        "No-Local-Search".to_string()
+    }
+}
+
+impl<G, F> LocalSearchOp<G, F> for NoLocalSearch
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn improve<E, R>(&self, genome: G, _: &E, _: &mut R) -> G
+    where
+        E: FitnessFunction<G, F>,
+        R: Rng + Sized,
+    {
+        genome
+    }
+}
+
+/// The `HillClimber` performs a stochastic hill climbing for a fixed number
+/// of steps. In each step a neighbour of the current genome is created by
+/// the given `operator::MutationOp`, e.g. one that changes a single locus.
+/// The neighbour replaces the current genome if its fitness is not lower, so
+/// the search can also move across plateaus of equal fitness.
+///
+/// Each step evaluates the fitness of one neighbour. A `HillClimber` with 0
+/// steps leaves the genomes unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HillClimber<M> {
+    neighbour_op: M,
+    steps: usize,
+}
+
+impl<M> HillClimber<M> {
+    pub fn new(neighbour_op: M, steps: usize) -> Self {
+        HillClimber {
+            neighbour_op,
+            steps,
+        }
+    }
+
+    /// Returns the operator that creates the neighbours of a genome.
+    pub fn neighbour_op(&self) -> &M {
+        &self.neighbour_op
+    }
+
+    /// Returns the number of steps.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Sets the number of steps to the given value.
+    pub fn set_steps(&mut self, value: usize) {
+        self.steps = value;
+    }
+}
+
+impl<M> GeneticOperator for HillClimber<M>
+where
+    M: Clone,
+{
+    fn name() -> String {
+        "Hill-Climbing-Local-Search".to_string()
+    }
+}
+
+impl<G, F, M> LocalSearchOp<G, F> for HillClimber<M>
+where
+    G: Genotype,
+    F: Fitness,
+    M: MutationOp<G>,
+{
+    fn improve<E, R>(&self, genome: G, evaluator: &E, rng: &mut R) -> G
+    where
+        E: FitnessFunction<G, F>,
+        R: Rng + Sized,
+    {
+        if self.steps == 0 {
+            return genome;
+        }
+        let mut current = genome;
+        let mut current_fitness = evaluator.fitness_of(&current);
+        for _ in 0..self.steps {
+            let neighbour = self.neighbour_op.mutate(current.clone(), rng);
+            let neighbour_fitness = evaluator.fitness_of(&neighbour);
+            if neighbour_fitness >= current_fitness {
+                current = neighbour;
+                current_fitness = neighbour_fitness;
+            }
+        }
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[derive(Clone, Debug)]
+    struct CountOnes;
+
+    impl FitnessFunction<Vec<u8>, usize> for CountOnes {
+        fn fitness_of(&self, genome: &Vec<u8>) -> usize {
+            genome.iter().filter(|v| **v == 1).count()
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            8
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    // Flips a single random locus
+    #[derive(Clone, Debug)]
+    struct FlipOne;
+
+    impl GeneticOperator for FlipOne {
+        fn name() -> String {
+            "Flip-One".to_string()
+        }
+    }
+
+    impl MutationOp<Vec<u8>> for FlipOne {
+        fn mutate<R>(&self, mut genome: Vec<u8>, rng: &mut R) -> Vec<u8>
+        where
+            R: Rng + Sized,
+        {
+            let locus = rng.gen_range(0..genome.len());
+            genome[locus] = 1 - genome[locus];
+            genome
+        }
+    }
+
+    #[test]
+    fn hill_climber_climbs_to_the_optimum() {
+        let mut rng = get_rng(random_seed());
+        let climber = HillClimber::new(FlipOne, 256);
+
+        let improved = climber.improve(vec![0; 8], &CountOnes, &mut rng);
+
+        expect_that!(&CountOnes.fitness_of(&improved), eq(8));
+    }
+
+    #[test]
+    fn hill_climber_with_zero_steps_returns_the_genome_unchanged() {
+        let mut rng = get_rng(random_seed());
+        let climber = HillClimber::new(FlipOne, 0);
+
+        let genome = vec![0, 1, 0, 1, 0, 1, 0, 1];
+        let improved = climber.improve(genome.clone(), &CountOnes, &mut rng);
+
+        expect_that!(&improved, eq(genome));
+    }
+}
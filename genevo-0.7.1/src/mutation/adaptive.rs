@@ -0,0 +1,417 @@
+//! The `adaptive` module provides `operator::MutationOp`s that wrap another
+//! `operator::MutationOp` and adjust its mutation rate in response to the
+//! progress of the search.
+//!
+//! The provided `operator::MutationOp`s are:
+//! * `StagnationBoostMutator` which temporarily increases the mutation rate
+//!   when the best fitness has not improved for a number of generations.
+//! * `SelfAdaptiveMutator` which evolves a mutation rate per individual
+//!   along with the solution, see `SelfAdaptive`.
+
+use crate::{
+    genetic::Genotype,
+    mutation::{
+        order::{InsertOrderMutator, SwapOrderMutator},
+        real::{GaussianMutator, PolynomialMutator},
+        tree::{PointMutator, SubtreeMutator},
+        value::{
+            BreederGenomeMutation, BreederValueMutator, RandomGenomeMutation, RandomValueMutator,
+        },
+        variable::{GeneDeletionMutator, GeneInsertionMutator},
+    },
+    operator::{GeneticOperator, MutationOp},
+    random::{random_gaussian, Rng},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A `MutationRate` gives access to the mutation rate of an
+/// `operator::MutationOp`, so that it can be adjusted by an adaptive wrapper.
+pub trait MutationRate {
+    /// Returns the current mutation rate.
+    fn mutation_rate(&self) -> f64;
+
+    /// Sets the mutation rate to the given value.
+    fn set_mutation_rate(&mut self, value: f64);
+}
+
+macro_rules! impl_mutation_rate {
+    ($($mutator:ty),*) => {
+        $(
+            impl MutationRate for $mutator {
+                fn mutation_rate(&self) -> f64 {
+                    self.mutation_rate()
+                }
+
+                fn set_mutation_rate(&mut self, value: f64) {
+                    self.set_mutation_rate(value);
+                }
+            }
+        )*
+    }
+}
+
+impl_mutation_rate!(
+    InsertOrderMutator,
+    SwapOrderMutator,
+    GaussianMutator,
+    PolynomialMutator,
+    GeneDeletionMutator
+);
+
+impl<G> MutationRate for RandomValueMutator<G>
+where
+    G: Genotype + RandomGenomeMutation,
+{
+    fn mutation_rate(&self) -> f64 {
+        self.mutation_rate()
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.set_mutation_rate(value);
+    }
+}
+
+impl<V> MutationRate for GeneInsertionMutator<V> {
+    fn mutation_rate(&self) -> f64 {
+        self.mutation_rate()
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.set_mutation_rate(value);
+    }
+}
+
+impl<S> MutationRate for SubtreeMutator<S> {
+    fn mutation_rate(&self) -> f64 {
+        self.mutation_rate()
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.set_mutation_rate(value);
+    }
+}
+
+impl<S> MutationRate for PointMutator<S> {
+    fn mutation_rate(&self) -> f64 {
+        self.mutation_rate()
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.set_mutation_rate(value);
+    }
+}
+
+impl<G> MutationRate for BreederValueMutator<G>
+where
+    G: Genotype + BreederGenomeMutation,
+{
+    fn mutation_rate(&self) -> f64 {
+        self.mutation_rate()
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.set_mutation_rate(value);
+    }
+}
+
+/// The `StagnationBoostMutator` wraps another `operator::MutationOp` and
+/// multiplies its mutation rate by the boost factor for a number of
+/// generations when the best fitness has not improved for `stagnation_limit`
+/// generations in a row. Afterwards the mutation rate returns to the rate
+/// the wrapped operator has been created with.
+///
+/// Increasing the mutation rate during stagnation helps the population to
+/// escape local optima, while keeping it low otherwise avoids disrupting
+/// good solutions. A stagnation limit of 0 disables the boost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StagnationBoostMutator<M> {
+    mutator: M,
+    base_rate: f64,
+    stagnation_limit: u64,
+    boost_factor: f64,
+    boost_duration: u64,
+    stagnant_generations: u64,
+    remaining_boost: u64,
+}
+
+impl<M> StagnationBoostMutator<M>
+where
+    M: MutationRate,
+{
+    pub fn new(mutator: M, stagnation_limit: u64, boost_factor: f64, boost_duration: u64) -> Self {
+        StagnationBoostMutator {
+            base_rate: mutator.mutation_rate(),
+            mutator,
+            stagnation_limit,
+            boost_factor,
+            boost_duration,
+            stagnant_generations: 0,
+            remaining_boost: 0,
+        }
+    }
+
+    /// Returns the wrapped `operator::MutationOp`.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the number of generations without improvement after which the
+    /// mutation rate is boosted.
+    pub fn stagnation_limit(&self) -> u64 {
+        self.stagnation_limit
+    }
+
+    /// Returns the factor the mutation rate is multiplied by while boosted.
+    pub fn boost_factor(&self) -> f64 {
+        self.boost_factor
+    }
+
+    /// Returns the number of generations a boost lasts.
+    pub fn boost_duration(&self) -> u64 {
+        self.boost_duration
+    }
+
+    /// Returns whether the mutation rate is currently boosted.
+    pub fn is_boosted(&self) -> bool {
+        self.remaining_boost > 0
+    }
+}
+
+/// The mutation rate of a `StagnationBoostMutator` is the base rate it
+/// returns to after a boost. Setting it takes effect at once unless the
+/// mutation rate is boosted, in which case the boost applies to the new base
+/// rate from the next generation on.
+impl<M> MutationRate for StagnationBoostMutator<M>
+where
+    M: MutationRate,
+{
+    fn mutation_rate(&self) -> f64 {
+        self.base_rate
+    }
+
+    fn set_mutation_rate(&mut self, value: f64) {
+        self.base_rate = value;
+        if self.remaining_boost == 0 {
+            self.mutator.set_mutation_rate(value);
+        }
+    }
+}
+
+impl<M> GeneticOperator for StagnationBoostMutator<M>
+where
+    M: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Stagnation-Boost-{}", M::name())
+    }
+}
+
+impl<G, M> MutationOp<G> for StagnationBoostMutator<M>
+where
+    G: Genotype,
+    M: MutationOp<G> + MutationRate,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutator.mutate(genome, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.mutator.adapt(improved);
+        if self.remaining_boost > 0 {
+            self.remaining_boost -= 1;
+        }
+        if improved {
+            self.stagnant_generations = 0;
+        } else {
+            self.stagnant_generations += 1;
+            if self.stagnation_limit > 0
+                && self.stagnant_generations >= self.stagnation_limit
+                && self.remaining_boost == 0
+            {
+                self.stagnant_generations = 0;
+                self.remaining_boost = self.boost_duration;
+            }
+        }
+        let rate = if self.remaining_boost > 0 {
+            (self.base_rate * self.boost_factor).min(1.)
+        } else {
+            self.base_rate
+        };
+        self.mutator.set_mutation_rate(rate);
+    }
+}
+
+/// A `SelfAdaptive` genotype extends another `genetic::Genotype` by a
+/// strategy parameter: each individual carries its own mutation rate, which
+/// is inherited and mutated along with the solution, as known from evolution
+/// strategies (ES). Individuals with a rate that suits the current stage of
+/// the search produce better offspring, so good rates are selected together
+/// with good solutions.
+///
+/// `SelfAdaptive` genotypes are mutated by the `SelfAdaptiveMutator` and
+/// recombined by the `recombination::adaptive::SelfAdaptiveCrossBreeder`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SelfAdaptive<G> {
+    /// The encoded solution.
+    pub genome: G,
+    /// The mutation rate this individual mutates its offspring with.
+    pub mutation_rate: f64,
+}
+
+impl<G> SelfAdaptive<G> {
+    pub fn new(genome: G, mutation_rate: f64) -> Self {
+        SelfAdaptive {
+            genome,
+            mutation_rate,
+        }
+    }
+}
+
+impl<G> Genotype for SelfAdaptive<G>
+where
+    G: Genotype,
+{
+    type Dna = <G as Genotype>::Dna;
+}
+
+/// The `SelfAdaptiveMutator` mutates `SelfAdaptive` genotypes. First the
+/// mutation rate of the individual is mutated log-normally, i.e. multiplied
+/// by `exp(learning_rate * N(0, 1))` and kept within the given bounds. Then
+/// the genome is mutated by the wrapped `operator::MutationOp` using the new
+/// mutation rate.
+///
+/// A common choice for the learning rate is `1 / sqrt(n)` where `n` is the
+/// length of the genome.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfAdaptiveMutator<M> {
+    mutator: M,
+    learning_rate: f64,
+    min_rate: f64,
+    max_rate: f64,
+}
+
+impl<M> SelfAdaptiveMutator<M>
+where
+    M: MutationRate,
+{
+    pub fn new(mutator: M, learning_rate: f64, min_rate: f64, max_rate: f64) -> Self {
+        assert!(min_rate <= max_rate, "min_rate must not exceed max_rate");
+        SelfAdaptiveMutator {
+            mutator,
+            learning_rate,
+            min_rate,
+            max_rate,
+        }
+    }
+
+    /// Returns the wrapped `operator::MutationOp`.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the learning rate the mutation rates are mutated with.
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    /// Returns the bounds of the mutation rates as `(min_rate, max_rate)`.
+    pub fn rate_bounds(&self) -> (f64, f64) {
+        (self.min_rate, self.max_rate)
+    }
+}
+
+impl<M> GeneticOperator for SelfAdaptiveMutator<M>
+where
+    M: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Self-Adaptive-{}", M::name())
+    }
+}
+
+impl<G, M> MutationOp<SelfAdaptive<G>> for SelfAdaptiveMutator<M>
+where
+    G: Genotype,
+    M: MutationOp<G> + MutationRate,
+{
+    fn mutate<R>(&self, genome: SelfAdaptive<G>, rng: &mut R) -> SelfAdaptive<G>
+    where
+        R: Rng + Sized,
+    {
+        let factor = (self.learning_rate * random_gaussian(rng, 0., 1.)).exp();
+        let mutation_rate = (genome.mutation_rate * factor)
+            .max(self.min_rate)
+            .min(self.max_rate);
+        let mut mutator = self.mutator.clone();
+        mutator.set_mutation_rate(mutation_rate);
+        SelfAdaptive {
+            genome: mutator.mutate(genome.genome, rng),
+            mutation_rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    fn adapt(mutator: &mut StagnationBoostMutator<SwapOrderMutator>, improved: bool) {
+        MutationOp::<Vec<usize>>::adapt(mutator, improved);
+    }
+
+    #[test]
+    fn stagnation_boost_increases_rate_after_stagnation_limit() {
+        let mut mutator = StagnationBoostMutator::new(SwapOrderMutator::new(0.1), 3, 4., 2);
+
+        adapt(&mut mutator, true);
+        adapt(&mut mutator, false);
+        adapt(&mut mutator, false);
+        expect_that!(&mutator.is_boosted(), eq(false));
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.1));
+
+        adapt(&mut mutator, false);
+        expect_that!(&mutator.is_boosted(), eq(true));
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.4));
+
+        adapt(&mut mutator, false);
+        expect_that!(&mutator.is_boosted(), eq(true));
+
+        adapt(&mut mutator, false);
+        expect_that!(&mutator.is_boosted(), eq(false));
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.1));
+    }
+
+    #[test]
+    fn stagnation_boost_is_disabled_with_stagnation_limit_of_zero() {
+        let mut mutator = StagnationBoostMutator::new(SwapOrderMutator::new(0.1), 0, 4., 2);
+
+        for _ in 0..10 {
+            adapt(&mut mutator, false);
+        }
+
+        expect_that!(&mutator.is_boosted(), eq(false));
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.1));
+    }
+
+    #[test]
+    fn self_adaptive_mutation_keeps_mutation_rate_within_bounds() {
+        let mut rng = get_rng(random_seed());
+        let mutator = SelfAdaptiveMutator::new(SwapOrderMutator::new(0.1), 1., 0.01, 0.5);
+        let mut genome = SelfAdaptive::new(vec![1, 2, 3, 4, 5, 6, 7, 8], 0.1);
+
+        for _ in 0..100 {
+            genome = mutator.mutate(genome, &mut rng);
+
+            expect_that!(&genome.mutation_rate, is(greater_than_or_equal(0.01)));
+            expect_that!(&genome.mutation_rate, is(less_than_or_equal(0.5)));
+        }
+        expect_that!(&genome.genome.len(), eq(8));
+    }
+}
@@ -0,0 +1,101 @@
+//! The `ensemble` module provides the `EnsembleMutator`, which holds several
+//! mutation operators and picks one of them for each mutation.
+
+use crate::{
+    genetic::Genotype,
+    operator::{
+        ensemble::{MutationOps, OperatorSet, OperatorWeights},
+        GeneticOperator, MutationOp,
+    },
+    random::Rng,
+};
+
+/// The `EnsembleMutator` holds several `operator::MutationOp`s, e.g. a tuple
+/// of operators of different types, and picks one of them by the
+/// `operator::ensemble::OperatorWeights` each time a genome is mutated.
+///
+/// With adaptive weights the operators that mutated the offspring of the
+/// generations improving on the best fitness are picked more often, see
+/// `operator::ensemble::WeightAdaptation`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnsembleMutator<O> {
+    operators: O,
+    weights: OperatorWeights,
+}
+
+impl<O> EnsembleMutator<O>
+where
+    O: OperatorSet,
+{
+    /// Creates an ensemble of the operators, picked by the given weights,
+    /// one for each of the operators.
+    pub fn new(operators: O, weights: OperatorWeights) -> Self {
+        assert_eq!(
+            operators.len(),
+            weights.len(),
+            "an ensemble needs one weight for each of its operators"
+        );
+        EnsembleMutator { operators, weights }
+    }
+
+    /// Returns the operators of the ensemble.
+    pub fn operators(&self) -> &O {
+        &self.operators
+    }
+
+    /// Returns the weights the operators are picked by.
+    pub fn weights(&self) -> &OperatorWeights {
+        &self.weights
+    }
+}
+
+impl<O> GeneticOperator for EnsembleMutator<O>
+where
+    O: OperatorSet,
+{
+    fn name() -> String {
+        "Ensemble-Mutation".to_string()
+    }
+}
+
+impl<G, O> MutationOp<G> for EnsembleMutator<O>
+where
+    G: Genotype,
+    O: MutationOps<G>,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        let index = self.weights.pick(rng);
+        self.operators.mutate_by(index, genome, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.operators.adapt_each(improved);
+        self.weights.adapt(improved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mutation::{noop::NoOpMutator, value::RandomValueMutator},
+        random::{get_rng, random_seed},
+    };
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn the_genome_is_mutated_by_the_picked_operator() {
+        let ensemble = EnsembleMutator::new(
+            (NoOpMutator, RandomValueMutator::new(1., 5u8, 6)),
+            OperatorWeights::new(vec![0., 1.]),
+        );
+
+        let mutated = ensemble.mutate(vec![0u8; 8], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated.contains(&5), eq(true));
+        expect_that!(&ensemble.weights().uses(), eq(vec![0, 1]));
+    }
+}
@@ -0,0 +1,106 @@
+//! The `masked` module provides `operator::MutationOp`s that mutate each
+//! locus of a `genetic::Genotype` with its own probability.
+
+use crate::{
+    mutation::value::RandomValueMutation,
+    operator::{GeneticOperator, MutationOp},
+    random::Rng,
+};
+use std::fmt::Debug;
+
+/// The `MaskedMutator` mutates value encoded `genetic::Genotype`s using a
+/// mask of mutation probabilities, one for each locus. Each locus is replaced
+/// by a random value between `min_value` and `max_value` with the probability
+/// given for its position.
+///
+/// This allows some regions of a genome to be mutated more aggressively than
+/// others, e.g. positions at the boundaries of building blocks. A probability
+/// of 0 protects a locus from mutation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaskedMutator<V> {
+    probabilities: Vec<f64>,
+    min_value: V,
+    max_value: V,
+}
+
+impl<V> MaskedMutator<V> {
+    /// Creates a `MaskedMutator` with the given mutation probability for each
+    /// locus. The number of probabilities must match the length of the
+    /// genomes to be mutated.
+    pub fn new(probabilities: Vec<f64>, min_value: V, max_value: V) -> Self {
+        MaskedMutator {
+            probabilities,
+            min_value,
+            max_value,
+        }
+    }
+
+    /// Returns the mutation probability of each locus.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Sets the mutation probability of each locus to the given values.
+    pub fn set_probabilities(&mut self, values: Vec<f64>) {
+        self.probabilities = values;
+    }
+}
+
+impl<V> GeneticOperator for MaskedMutator<V>
+where
+    V: Clone,
+{
+    fn name() -> String {
+        "Masked-Mutation".to_string()
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for MaskedMutator<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync + RandomValueMutation,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        assert_eq!(
+            self.probabilities.len(),
+            genome.len(),
+            "the number of probabilities must match the genome length"
+        );
+        let mut mutated = genome;
+        for (value, probability) in mutated.iter_mut().zip(self.probabilities.iter()) {
+            if rng.gen::<f64>() < *probability {
+                *value = RandomValueMutation::random_mutated(
+                    value.clone(),
+                    &self.min_value,
+                    &self.max_value,
+                    rng,
+                );
+            }
+        }
+        mutated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn masked_mutation_mutates_loci_according_to_their_probability() {
+        let mut rng = get_rng(random_seed());
+        let mutator = MaskedMutator::new(vec![0., 1., 0., 1.], 10u8, 20u8);
+
+        for _ in 0..100 {
+            let mutated = mutator.mutate(vec![0u8; 4], &mut rng);
+
+            expect_that!(&mutated[0], eq(0));
+            expect_that!(&mutated[1], is(greater_than_or_equal(10)));
+            expect_that!(&mutated[2], eq(0));
+            expect_that!(&mutated[3], is(greater_than_or_equal(10)));
+        }
+    }
+}
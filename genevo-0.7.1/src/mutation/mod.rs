@@ -1,7 +1,32 @@
 //! The `mutation` module provides `operator::MutationOp`s implementation
 //! of various mutation schemes for binary encoded, value encoded, permutation
 //! encoded and tree encoded `genetic::Genotype`s.
+//!
+//! The `adaptive` module provides wrappers that adjust the mutation rate of
+//! another `operator::MutationOp` while the simulation is running, the
+//! `masked` module mutation schemes with a mutation probability per locus.
+//! The `variable` module provides mutation schemes which insert and delete
+//! values, so that the length of the genomes evolves.
+//! The `tree` module provides mutation schemes for the expression trees of
+//! genetic programming.
+//! The `noop` module provides an operator that does not mutate at all, the
+//! `ensemble` module one that picks one of several operators for each
+//! mutation.
 
 pub mod value;
 
 pub mod order;
+
+pub mod real;
+
+pub mod adaptive;
+
+pub mod masked;
+
+pub mod variable;
+
+pub mod tree;
+
+pub mod noop;
+
+pub mod ensemble;
@@ -0,0 +1,33 @@
+//! The `noop` module provides the `NoOpMutator`, which skips the mutation
+//! stage of the genetic algorithm, e.g. to find out what the mutation
+//! contributes to a run in an ablation experiment.
+
+use crate::{
+    genetic::Genotype,
+    operator::{GeneticOperator, MutationOp},
+    random::Rng,
+};
+
+/// The `NoOpMutator` returns each genome unchanged. The offspring then only
+/// differs from the selected parents by crossover, so no new alleles enter
+/// the population.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoOpMutator;
+
+impl GeneticOperator for NoOpMutator {
+    fn name() -> String {
+        "No-Op-Mutator".to_string()
+    }
+}
+
+impl<G> MutationOp<G> for NoOpMutator
+where
+    G: Genotype,
+{
+    fn mutate<R>(&self, genome: G, _: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        genome
+    }
+}
@@ -0,0 +1,293 @@
+//! The `real` module provides `operator::MutationOp`s for real-valued
+//! `genetic::Genotype`s, such as `Vec<f64>`, that perturb values instead of
+//! replacing them.
+
+use crate::{
+    encoding::RealValue,
+    operator::{GeneticOperator, MutationOp},
+    random::{random_gaussian, random_index, Rng},
+};
+
+/// The `GaussianMutator` mutates real-valued `genetic::Genotype`s by adding
+/// a normally distributed random value with a mean of 0 to the values of
+/// randomly chosen loci.
+///
+/// The standard deviation of the perturbation can either be the same for all
+/// loci or be given per locus, e.g. to account for differently scaled
+/// dimensions of the search space. If bounds are set, mutated values are
+/// clamped to them.
+///
+/// It is the standard mutation operator for real-valued genetic algorithms.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaussianMutator {
+    mutation_rate: f64,
+    /// Either a single standard deviation for all loci or one per locus.
+    std_devs: Vec<f64>,
+    bounds: Option<(f64, f64)>,
+}
+
+impl GaussianMutator {
+    /// Creates a `GaussianMutator` that uses the same standard deviation for
+    /// all loci.
+    pub fn new(mutation_rate: f64, std_dev: f64) -> Self {
+        GaussianMutator {
+            mutation_rate,
+            std_devs: vec![std_dev],
+            bounds: None,
+        }
+    }
+
+    /// Creates a `GaussianMutator` with a standard deviation for each locus.
+    /// The number of standard deviations must match the length of the
+    /// genomes to be mutated.
+    pub fn with_std_devs(mutation_rate: f64, std_devs: Vec<f64>) -> Self {
+        assert!(
+            !std_devs.is_empty(),
+            "at least one standard deviation is required"
+        );
+        GaussianMutator {
+            mutation_rate,
+            std_devs,
+            bounds: None,
+        }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    /// Returns the standard deviation of the perturbations at the given locus.
+    pub fn std_dev_at(&self, locus: usize) -> f64 {
+        if self.std_devs.len() == 1 {
+            self.std_devs[0]
+        } else {
+            self.std_devs[locus]
+        }
+    }
+
+    /// Sets the standard deviation of the perturbations of all loci to the
+    /// given value.
+    pub fn set_std_dev(&mut self, value: f64) {
+        self.std_devs = vec![value];
+    }
+
+    /// Sets the standard deviations of the perturbations per locus.
+    pub fn set_std_devs(&mut self, values: Vec<f64>) {
+        assert!(
+            !values.is_empty(),
+            "at least one standard deviation is required"
+        );
+        self.std_devs = values;
+    }
+
+    /// Returns the bounds mutated values are clamped to, if any.
+    pub fn bounds(&self) -> Option<(f64, f64)> {
+        self.bounds
+    }
+
+    /// Clamps mutated values to the interval `[min_value, max_value]`.
+    pub fn set_bounds(&mut self, min_value: f64, max_value: f64) {
+        assert!(
+            min_value <= max_value,
+            "min_value must not exceed max_value"
+        );
+        self.bounds = Some((min_value, max_value));
+    }
+}
+
+impl GeneticOperator for GaussianMutator {
+    fn name() -> String {
+        "Gaussian-Mutation".to_string()
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for GaussianMutator
+where
+    V: RealValue,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = genome.len();
+        assert!(
+            self.std_devs.len() == 1 || self.std_devs.len() == genome_length,
+            "the number of standard deviations must match the genome length"
+        );
+        let num_mutations =
+            ((genome_length as f64 * self.mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, genome_length);
+            let value = mutated[index].to_f64();
+            let mut value = random_gaussian(rng, value, self.std_dev_at(index));
+            if let Some((min_value, max_value)) = self.bounds {
+                value = value.max(min_value).min(max_value);
+            }
+            mutated[index] = V::from_f64(value);
+        }
+        mutated
+    }
+}
+
+/// The `PolynomialMutator` implements the polynomial mutation as used by
+/// NSGA-II. The values of randomly chosen loci are perturbed following a
+/// polynomial distribution that is scaled to the bounds of the search space
+/// and never leaves them.
+///
+/// A large distribution index creates small perturbations, a small one large
+/// perturbations. Values between 20 and 100 are common.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolynomialMutator {
+    mutation_rate: f64,
+    distribution_index: f64,
+    min_value: f64,
+    max_value: f64,
+}
+
+impl PolynomialMutator {
+    pub fn new(
+        mutation_rate: f64,
+        distribution_index: f64,
+        min_value: f64,
+        max_value: f64,
+    ) -> Self {
+        assert!(
+            min_value < max_value,
+            "min_value must be less than max_value"
+        );
+        PolynomialMutator {
+            mutation_rate,
+            distribution_index,
+            min_value,
+            max_value,
+        }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    /// Returns the distribution index of this operator.
+    pub fn distribution_index(&self) -> f64 {
+        self.distribution_index
+    }
+
+    /// Sets the distribution index of this operator to the given value.
+    pub fn set_distribution_index(&mut self, value: f64) {
+        self.distribution_index = value;
+    }
+
+    /// Returns the bounds of the values as `(min_value, max_value)`.
+    pub fn bounds(&self) -> (f64, f64) {
+        (self.min_value, self.max_value)
+    }
+}
+
+impl GeneticOperator for PolynomialMutator {
+    fn name() -> String {
+        "Polynomial-Mutation".to_string()
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for PolynomialMutator
+where
+    V: RealValue,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = genome.len();
+        let num_mutations =
+            ((genome_length as f64 * self.mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let range = self.max_value - self.min_value;
+        let exponent = 1. / (self.distribution_index + 1.);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, genome_length);
+            let value = mutated[index]
+                .to_f64()
+                .max(self.min_value)
+                .min(self.max_value);
+            let delta1 = (value - self.min_value) / range;
+            let delta2 = (self.max_value - value) / range;
+            let u = rng.gen::<f64>();
+            let delta = if u <= 0.5 {
+                let base =
+                    2. * u + (1. - 2. * u) * (1. - delta1).powf(self.distribution_index + 1.);
+                base.powf(exponent) - 1.
+            } else {
+                let base = 2. * (1. - u)
+                    + 2. * (u - 0.5) * (1. - delta2).powf(self.distribution_index + 1.);
+                1. - base.powf(exponent)
+            };
+            let value = (value + delta * range)
+                .max(self.min_value)
+                .min(self.max_value);
+            mutated[index] = V::from_f64(value);
+        }
+        mutated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn gaussian_mutation_clamps_values_to_bounds() {
+        let mut rng = get_rng(random_seed());
+        let mut mutator = GaussianMutator::new(1., 10.);
+        mutator.set_bounds(-1., 1.);
+
+        for _ in 0..100 {
+            let mutated = mutator.mutate(vec![0f32; 8], &mut rng);
+
+            for value in mutated {
+                expect_that!(&value, is(greater_than_or_equal(-1.)));
+                expect_that!(&value, is(less_than_or_equal(1.)));
+            }
+        }
+    }
+
+    #[test]
+    fn gaussian_mutation_does_not_change_loci_with_zero_std_dev() {
+        let mut rng = get_rng(random_seed());
+        let mutator = GaussianMutator::with_std_devs(1., vec![0., 1., 0.]);
+
+        for _ in 0..100 {
+            let mutated = mutator.mutate(vec![5., 5., 5.], &mut rng);
+
+            expect_that!(&mutated[0], eq(5.));
+            expect_that!(&mutated[2], eq(5.));
+        }
+    }
+
+    #[test]
+    fn polynomial_mutation_keeps_values_within_bounds() {
+        let mut rng = get_rng(random_seed());
+        let mutator = PolynomialMutator::new(1., 5., -2., 2.);
+
+        for _ in 0..100 {
+            let mutated = mutator.mutate(vec![-2., -1.9, 0., 1.9, 2.], &mut rng);
+
+            for value in mutated {
+                expect_that!(&value, is(greater_than_or_equal(-2.)));
+                expect_that!(&value, is(less_than_or_equal(2.)));
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! The `tree` module provides `operator::MutationOp`s for tree encoded
+//! genotypes as used by genetic programming.
+//!
+//! The provided `operator::MutationOp`s are:
+//! * `SubtreeMutator` which replaces a subtree by a random one.
+//! * `PointMutator` which replaces single nodes by random primitives of the
+//!   same arity.
+
+use crate::{
+    encoding::{Primitive, PrimitiveSet, TreeGenome},
+    operator::{GeneticOperator, MutationOp},
+    random::{random_index, Rng},
+};
+
+/// The `SubtreeMutator` replaces the subtree at a random node of a genome by
+/// a random tree grown of its `encoding::PrimitiveSet`, see
+/// `encoding::TreeGenome::grow`. The `mutation_rate` is the probability that
+/// a genome is mutated at all.
+///
+/// The depth of the mutated genomes can be limited, see `with_max_depth`. A
+/// genome that would get deeper is not mutated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtreeMutator<S> {
+    mutation_rate: f64,
+    primitives: S,
+    max_subtree_depth: usize,
+    max_depth: usize,
+}
+
+impl<S> SubtreeMutator<S> {
+    /// Returns a new `SubtreeMutator` which grows subtrees of at most the
+    /// given `max_subtree_depth` of the given primitives.
+    pub fn new(mutation_rate: f64, primitives: S, max_subtree_depth: usize) -> Self {
+        SubtreeMutator {
+            mutation_rate,
+            primitives,
+            max_subtree_depth,
+            max_depth: usize::MAX,
+        }
+    }
+
+    /// Limits the depth of the mutated genomes to the given `max_depth`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+impl<S> GeneticOperator for SubtreeMutator<S>
+where
+    S: PrimitiveSet,
+{
+    fn name() -> String {
+        "Subtree-Mutator".to_string()
+    }
+}
+
+impl<S, P> MutationOp<TreeGenome<P>> for SubtreeMutator<S>
+where
+    S: PrimitiveSet<Primitive = P>,
+    P: Primitive,
+{
+    fn mutate<R>(&self, genome: TreeGenome<P>, rng: &mut R) -> TreeGenome<P>
+    where
+        R: Rng + Sized,
+    {
+        if rng.gen::<f64>() >= self.mutation_rate {
+            return genome;
+        }
+        let node = random_index(rng, genome.size());
+        let subtree = TreeGenome::grow(&self.primitives, self.max_subtree_depth, rng);
+        let mutated = genome.replace_subtree(node, subtree.nodes());
+        if mutated.depth() > self.max_depth {
+            genome
+        } else {
+            mutated
+        }
+    }
+}
+
+/// The `PointMutator` replaces each node of a genome with the probability of
+/// the `mutation_rate` by a random primitive of the same arity from its
+/// `encoding::PrimitiveSet`, so that the shape of the tree stays the same.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointMutator<S> {
+    mutation_rate: f64,
+    primitives: S,
+}
+
+impl<S> PointMutator<S> {
+    pub fn new(mutation_rate: f64, primitives: S) -> Self {
+        PointMutator {
+            mutation_rate,
+            primitives,
+        }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+}
+
+impl<S> GeneticOperator for PointMutator<S>
+where
+    S: PrimitiveSet,
+{
+    fn name() -> String {
+        "Point-Mutator".to_string()
+    }
+}
+
+impl<S, P> MutationOp<TreeGenome<P>> for PointMutator<S>
+where
+    S: PrimitiveSet<Primitive = P>,
+    P: Primitive,
+{
+    fn mutate<R>(&self, genome: TreeGenome<P>, rng: &mut R) -> TreeGenome<P>
+    where
+        R: Rng + Sized,
+    {
+        let nodes = genome
+            .nodes()
+            .iter()
+            .map(|node| {
+                if rng.gen::<f64>() < self.mutation_rate {
+                    self.primitives
+                        .random_of_arity(node.arity(), rng)
+                        .unwrap_or_else(|| node.clone())
+                } else {
+                    node.clone()
+                }
+            })
+            .collect();
+        TreeGenome::from_nodes(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encoding::Primitives,
+        random::{get_rng, random_seed},
+    };
+    use galvanic_assert::matchers::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Op {
+        X,
+        Y,
+        Add,
+        Mul,
+    }
+
+    impl Primitive for Op {
+        fn arity(&self) -> usize {
+            match self {
+                Op::X | Op::Y => 0,
+                Op::Add | Op::Mul => 2,
+            }
+        }
+    }
+
+    fn primitives() -> Primitives<Op> {
+        Primitives::new(vec![Op::X, Op::Y], vec![Op::Add, Op::Mul])
+    }
+
+    #[test]
+    fn subtree_mutation_keeps_the_genome_within_the_max_depth() {
+        let mutator = SubtreeMutator::new(1., primitives(), 3).with_max_depth(4);
+        let mut rng = get_rng(random_seed());
+        let mut genome = TreeGenome::full(&primitives(), 3, &mut rng);
+
+        for _ in 0..50 {
+            genome = mutator.mutate(genome, &mut rng);
+
+            expect_that!(&genome.depth(), less_than_or_equal(4));
+        }
+    }
+
+    #[test]
+    fn point_mutation_keeps_the_shape_of_the_tree() {
+        let mutator = PointMutator::new(1., primitives());
+        let mut rng = get_rng(random_seed());
+        let genome = TreeGenome::full(&primitives(), 3, &mut rng);
+
+        let mutated = mutator.mutate(genome.clone(), &mut rng);
+
+        let arities = |tree: &TreeGenome<Op>| {
+            tree.nodes()
+                .iter()
+                .map(|node| node.arity())
+                .collect::<Vec<_>>()
+        };
+        expect_that!(&arities(&mutated), eq(arities(&genome)));
+    }
+}
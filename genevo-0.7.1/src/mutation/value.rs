@@ -1,4 +1,5 @@
 use crate::{
+    encoding::{Matrix, PackedGenome},
     genetic::Genotype,
     operator::{GeneticOperator, MutationOp},
     random::{random_index, Rng},
@@ -31,6 +32,14 @@ where
             max_value,
         }
     }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
 }
 
 impl<G> GeneticOperator for RandomValueMutator<G>
@@ -107,6 +116,63 @@ where
     }
 }
 
+impl RandomGenomeMutation for PackedGenome {
+    type Dna = u8;
+
+    fn mutate_genome<R>(
+        genome: Self,
+        mutation_rate: f64,
+        min_value: &u8,
+        max_value: &u8,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = genome.len();
+        let num_mutations =
+            ((genome_length as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, genome_length);
+            let value =
+                RandomValueMutation::random_mutated(mutated.get(index), min_value, max_value, rng);
+            mutated.set(index, value);
+        }
+        mutated
+    }
+}
+
+/// The cells of a `Matrix` are mutated like the values of a `Vec`, each cell
+/// as likely as any other.
+impl<V> RandomGenomeMutation for Matrix<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync + RandomValueMutation,
+{
+    type Dna = V;
+
+    fn mutate_genome<R>(
+        genome: Self,
+        mutation_rate: f64,
+        min_value: &V,
+        max_value: &V,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let (rows, cols) = (genome.rows(), genome.cols());
+        let cells = <Vec<V> as RandomGenomeMutation>::mutate_genome(
+            genome.into_cells(),
+            mutation_rate,
+            min_value,
+            max_value,
+            rng,
+        );
+        Matrix::from_cells(rows, cols, cells)
+    }
+}
+
 #[cfg(feature = "fixedbitset")]
 mod fixedbitset_random_genome_mutation {
     use super::{random_index, RandomGenomeMutation};
@@ -247,6 +313,14 @@ where
             max_value,
         }
     }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
 }
 
 impl<G> GeneticOperator for BreederValueMutator<G>
@@ -369,3 +443,33 @@ macro_rules! impl_breeder_mutation {
 }
 
 impl_breeder_mutation!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn random_value_mutation_of_packed_genomes_keeps_the_alleles_in_range() {
+        let mutator = RandomValueMutator::new(0.5, 1, 3);
+        let mut rng = get_rng(random_seed());
+
+        let mutated = mutator.mutate(PackedGenome::new(200), &mut rng);
+
+        expect_that!(&mutated.len(), eq(200));
+        expect_that!(&mutated.iter().all(|allele| allele < 3), eq(true));
+        expect_that!(&mutated.iter().any(|allele| allele != 0), eq(true));
+    }
+
+    #[test]
+    fn random_value_mutation_of_matrices_keeps_their_size() {
+        let mutator = RandomValueMutator::new(0.5, 1u8, 3);
+        let mut rng = get_rng(random_seed());
+
+        let mutated = mutator.mutate(Matrix::new(10, 20, 0u8), &mut rng);
+
+        expect_that!(&(mutated.rows(), mutated.cols()), eq((10, 20)));
+        expect_that!(&mutated.cells().iter().any(|cell| *cell != 0), eq(true));
+    }
+}
@@ -0,0 +1,209 @@
+//! The `variable` module provides `operator::MutationOp`s which change the
+//! length of value encoded genotypes, so that the size of a solution can
+//! evolve along with its values.
+//!
+//! The provided `operator::MutationOp`s are:
+//! * `GeneInsertionMutator` which inserts random values into a `Vec`.
+//! * `GeneDeletionMutator` which removes values from a `Vec`.
+//!
+//! To mutate the length and the values of the genomes, combine them with
+//! e.g. the `RandomValueMutator` in an `EnsembleMutator`.
+
+use crate::{
+    mutation::value::RandomValueMutation,
+    operator::{GeneticOperator, MutationOp},
+    random::{random_index, Rng},
+};
+use std::fmt::Debug;
+
+// The number of loci mutated in a genome of the given length, at least one
+// in average, so that empty genomes can grow as well.
+fn num_mutations<R>(genome_length: usize, mutation_rate: f64, rng: &mut R) -> usize
+where
+    R: Rng + Sized,
+{
+    ((genome_length.max(1) as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize
+}
+
+/// The `GeneInsertionMutator` inserts random values at random positions of
+/// a genome. The inserted values are in the range between the given
+/// `min_value` (inclusive) and `max_value` (exclusive), like the ones of the
+/// `RandomValueMutator`.
+///
+/// The genome grows by about `mutation_rate` times its length, but not
+/// beyond the max length, see `with_max_length`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneInsertionMutator<V> {
+    mutation_rate: f64,
+    min_value: V,
+    max_value: V,
+    max_length: usize,
+}
+
+impl<V> GeneInsertionMutator<V> {
+    pub fn new(mutation_rate: f64, min_value: V, max_value: V) -> Self {
+        GeneInsertionMutator {
+            mutation_rate,
+            min_value,
+            max_value,
+            max_length: usize::MAX,
+        }
+    }
+
+    /// Limits the length of the mutated genomes to the given `max_length`.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl<V> GeneticOperator for GeneInsertionMutator<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn name() -> String {
+        "Gene-Insertion-Mutator".to_string()
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for GeneInsertionMutator<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync + RandomValueMutation,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let num_insertions = num_mutations(genome.len(), self.mutation_rate, rng)
+            .min(self.max_length.saturating_sub(genome.len()));
+        let mut mutated = genome;
+        for _ in 0..num_insertions {
+            let index = random_index(rng, mutated.len() + 1);
+            let value = RandomValueMutation::random_mutated(
+                self.min_value.clone(),
+                &self.min_value,
+                &self.max_value,
+                rng,
+            );
+            mutated.insert(index, value);
+        }
+        mutated
+    }
+}
+
+/// The `GeneDeletionMutator` removes the values at random positions of a
+/// genome.
+///
+/// The genome shrinks by about `mutation_rate` times its length, but not
+/// below the min length, see `with_min_length`.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneDeletionMutator {
+    mutation_rate: f64,
+    min_length: usize,
+}
+
+impl GeneDeletionMutator {
+    pub fn new(mutation_rate: f64) -> Self {
+        GeneDeletionMutator {
+            mutation_rate,
+            min_length: 0,
+        }
+    }
+
+    /// Keeps the length of the mutated genomes at the given `min_length` at
+    /// least.
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    pub fn min_length(&self) -> usize {
+        self.min_length
+    }
+}
+
+impl GeneticOperator for GeneDeletionMutator {
+    fn name() -> String {
+        "Gene-Deletion-Mutator".to_string()
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for GeneDeletionMutator
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let num_deletions = num_mutations(genome.len(), self.mutation_rate, rng)
+            .min(genome.len().saturating_sub(self.min_length));
+        let mut mutated = genome;
+        for _ in 0..num_deletions {
+            let index = random_index(rng, mutated.len());
+            mutated.remove(index);
+        }
+        mutated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn insertion_grows_the_genome_up_to_the_max_length() {
+        let mutator = GeneInsertionMutator::new(0.5, 5u8, 6).with_max_length(12);
+        let mut rng = get_rng(random_seed());
+
+        let mutated = mutator.mutate(vec![0u8; 8], &mut rng);
+        expect_that!(&mutated.len(), greater_than(8));
+        expect_that!(&mutated.len(), less_than_or_equal(12));
+        expect_that!(&mutated.iter().filter(|value| **value == 0).count(), eq(8));
+        expect_that!(&mutator.mutate(vec![0u8; 12], &mut rng).len(), eq(12));
+    }
+
+    #[test]
+    fn insertion_grows_empty_genomes() {
+        let mutator = GeneInsertionMutator::new(1., 5u8, 6);
+
+        let mutated = mutator.mutate(Vec::new(), &mut get_rng(random_seed()));
+
+        expect_that!(&mutated.is_empty(), eq(false));
+    }
+
+    #[test]
+    fn deletion_shrinks_the_genome_down_to_the_min_length() {
+        let mutator = GeneDeletionMutator::new(0.5).with_min_length(4);
+        let mut rng = get_rng(random_seed());
+
+        let mutated = mutator.mutate((0..8u8).collect(), &mut rng);
+        expect_that!(&mutated.len(), less_than(8));
+        expect_that!(&mutated.len(), greater_than_or_equal(4));
+        expect_that!(&mutated.windows(2).all(|pair| pair[0] < pair[1]), eq(true));
+        expect_that!(&mutator.mutate(vec![0u8; 4], &mut rng).len(), eq(4));
+    }
+}
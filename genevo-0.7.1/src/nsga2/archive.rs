@@ -0,0 +1,115 @@
+//! The `archive` module provides the `ParetoArchive`, which keeps the
+//! non-dominated solutions found over the whole run of a multi-objective
+//! optimization.
+//!
+//! The population of `Nsga2` only holds the non-dominated solutions of the
+//! current generation, and good solutions may be lost again when the
+//! population is cut by crowding distance. The archive never loses a
+//! solution unless it is dominated by another one.
+
+use crate::{
+    genetic::Genotype,
+    nsga2::{dominates, ParetoSolution},
+};
+
+/// The `ParetoArchive` holds the set of mutually non-dominated solutions
+/// seen so far. Solutions with the same objectives as an archived solution
+/// are not added again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoArchive<G>
+where
+    G: Genotype,
+{
+    solutions: Vec<ParetoSolution<G>>,
+}
+
+impl<G> Default for ParetoArchive<G>
+where
+    G: Genotype,
+{
+    fn default() -> Self {
+        ParetoArchive::new()
+    }
+}
+
+impl<G> ParetoArchive<G>
+where
+    G: Genotype,
+{
+    pub fn new() -> Self {
+        ParetoArchive {
+            solutions: Vec::new(),
+        }
+    }
+
+    /// Returns the archived solutions.
+    pub fn solutions(&self) -> &[ParetoSolution<G>] {
+        &self.solutions
+    }
+
+    /// Returns the number of archived solutions.
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Returns whether the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    /// Adds the given solution if it is not dominated by an archived
+    /// solution, and removes all archived solutions it dominates. Returns
+    /// whether the solution has been added.
+    pub fn insert(&mut self, solution: &ParetoSolution<G>) -> bool {
+        if self.solutions.iter().any(|archived| {
+            archived.objectives == solution.objectives
+                || dominates(&archived.objectives, &solution.objectives)
+        }) {
+            return false;
+        }
+        self.solutions
+            .retain(|archived| !dominates(&solution.objectives, &archived.objectives));
+        self.solutions.push(solution.clone());
+        true
+    }
+
+    /// Adds all given solutions, see `insert`. Returns the number of
+    /// solutions that have been added.
+    pub fn update<'a, I>(&mut self, solutions: I) -> usize
+    where
+        G: 'a,
+        I: IntoIterator<Item = &'a ParetoSolution<G>>,
+    {
+        solutions
+            .into_iter()
+            .filter(|solution| self.insert(solution))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    fn solution(objectives: Vec<f64>) -> ParetoSolution<Vec<u8>> {
+        ParetoSolution {
+            genome: vec![],
+            objectives,
+        }
+    }
+
+    #[test]
+    fn archive_keeps_only_non_dominated_solutions() {
+        let mut archive = ParetoArchive::new();
+
+        expect_that!(&archive.insert(&solution(vec![2., 2.])), eq(true));
+        expect_that!(&archive.insert(&solution(vec![3., 3.])), eq(false));
+        expect_that!(&archive.insert(&solution(vec![1., 4.])), eq(true));
+        expect_that!(&archive.insert(&solution(vec![1., 4.])), eq(false));
+        expect_that!(&archive.insert(&solution(vec![1., 1.])), eq(true));
+
+        expect_that!(&archive.len(), eq(1));
+        expect_that!(&archive.solutions()[0].objectives, eq(vec![1., 1.]));
+    }
+}
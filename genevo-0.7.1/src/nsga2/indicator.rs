@@ -0,0 +1,128 @@
+//! The `indicator` module provides quality indicators, which measure how
+//! well a set of solutions approximates the Pareto front, so that
+//! multi-objective runs can be compared quantitatively.
+//!
+//! The provided indicators are:
+//! * `hypervolume`, the volume of the objective space dominated by the
+//!   solutions (higher is better).
+//! * `inverted_generational_distance` (IGD), the mean distance of the points
+//!   of a known reference front to the nearest solution (lower is better).
+
+use crate::nsga2::Objectives;
+use std::cmp::Ordering;
+
+/// Calculates the hypervolume of the given objectives with respect to the
+/// reference point, i.e. the volume of the region that is dominated by at
+/// least one of the points and bounded by the reference point. All
+/// objectives are minimized, so the reference point must be worse than the
+/// points in every objective. Points that do not dominate the reference
+/// point do not contribute.
+///
+/// The hypervolume is calculated exactly by slicing the objective space
+/// along the last objective, which is fast for two or three objectives and
+/// fronts of moderate size.
+pub fn hypervolume(points: &[Objectives], reference: &[f64]) -> f64 {
+    let points: Vec<&[f64]> = points
+        .iter()
+        .map(|point| point.as_slice())
+        .filter(|point| point.iter().zip(reference.iter()).all(|(p, r)| p < r))
+        .collect();
+    sliced_volume(points, reference)
+}
+
+fn sliced_volume(mut points: Vec<&[f64]>, reference: &[f64]) -> f64 {
+    if points.is_empty() {
+        return 0.;
+    }
+    let last = reference.len() - 1;
+    if last == 0 {
+        let min = points.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+        return reference[0] - min;
+    }
+    points.sort_by(|a, b| a[last].partial_cmp(&b[last]).unwrap_or(Ordering::Equal));
+    let mut volume = 0.;
+    for i in 0..points.len() {
+        let upper = points.get(i + 1).map_or(reference[last], |p| p[last]);
+        let depth = upper - points[i][last];
+        if depth > 0. {
+            let slice = points[..=i].iter().map(|p| &p[..last]).collect();
+            volume += sliced_volume(slice, &reference[..last]) * depth;
+        }
+    }
+    volume
+}
+
+/// Calculates the inverted generational distance (IGD) of the given
+/// objectives to the reference front, i.e. the mean Euclidean distance of
+/// each point of the reference front to its nearest point in `points`.
+///
+/// Returns infinity if `points` is empty.
+pub fn inverted_generational_distance(
+    points: &[Objectives],
+    reference_front: &[Objectives],
+) -> f64 {
+    if reference_front.is_empty() {
+        return 0.;
+    }
+    let total: f64 = reference_front
+        .iter()
+        .map(|reference| {
+            points
+                .iter()
+                .map(|point| {
+                    point
+                        .iter()
+                        .zip(reference.iter())
+                        .map(|(p, r)| (p - r) * (p - r))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum();
+    total / reference_front.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn hypervolume_of_two_dimensional_front() {
+        let points = vec![vec![1., 3.], vec![2., 2.], vec![3., 1.], vec![3., 3.]];
+
+        let volume = hypervolume(&points, &[4., 4.]);
+
+        expect_that!(&(volume - 6.).abs(), is(less_than(1e-9)));
+    }
+
+    #[test]
+    fn hypervolume_of_three_dimensional_front() {
+        let points = vec![vec![0., 1., 1.], vec![1., 0., 1.], vec![1., 1., 0.]];
+
+        let volume = hypervolume(&points, &[2., 2., 2.]);
+
+        // three 2x1x1 boxes overlapping in three 1x1x1 cubes, which again
+        // overlap in the unit cube [1, 2]^3
+        expect_that!(&(volume - 4.).abs(), is(less_than(1e-9)));
+    }
+
+    #[test]
+    fn hypervolume_ignores_points_beyond_the_reference_point() {
+        let points = vec![vec![5., 1.]];
+
+        expect_that!(&hypervolume(&points, &[4., 4.]), eq(0.));
+    }
+
+    #[test]
+    fn igd_is_zero_when_the_reference_front_is_found() {
+        let front = vec![vec![0., 1.], vec![1., 0.]];
+
+        expect_that!(&inverted_generational_distance(&front, &front), eq(0.));
+        expect_that!(
+            &inverted_generational_distance(&[vec![0., 1.]], &front),
+            eq(2f64.sqrt() / 2.)
+        );
+    }
+}
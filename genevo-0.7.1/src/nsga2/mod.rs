@@ -0,0 +1,410 @@
+//! This module provides an `algorithm::Algorithm` which implements the
+//! non-dominated sorting genetic algorithm II (NSGA-II) for multi-objective
+//! optimization.
+//!
+//! Instead of a single `genetic::Fitness` value each individual is evaluated
+//! for several objectives, which are all minimized. An individual dominates
+//! another one if it is not worse in any objective and better in at least
+//! one. As there is usually no single best solution, NSGA-II approximates the
+//! Pareto front, the set of solutions not dominated by any other solution.
+//!
+//! The stages of NSGA-II are:
+//!
+//! 1. **Variation**: Create an offspring population of the same size as the
+//!    population by binary tournament selection, crossover and mutation.
+//! 2. **Evaluation**: Evaluate the objectives of the offspring.
+//! 3. **Non-dominated sorting**: Sort the union of population and offspring
+//!    into fronts, the first front being the non-dominated individuals.
+//! 4. **Survival**: Fill the next population front by front. The last front
+//!    that does not fit completely is cut by crowding distance, preferring
+//!    individuals in less crowded regions of the objective space.
+//!
+//! The `archive` module keeps the non-dominated solutions across
+//! generations, and the `indicator` module measures the quality of a front.
+
+pub mod archive;
+
+pub mod indicator;
+
+use crate::{
+    algorithm::Algorithm,
+    ga::GeneticAlgorithmError,
+    genetic::Genotype,
+    operator::{CrossoverOp, MutationOp},
+    population::Population,
+    random::{random_index, Prng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt::Debug};
+
+/// The values of all objectives of an individual. All objectives are
+/// minimized.
+pub type Objectives = Vec<f64>;
+
+/// An `ObjectiveFunction` evaluates the objectives of a `genetic::Genotype`
+/// for the multi-objective optimization with `Nsga2`.
+pub trait ObjectiveFunction<G>: Clone + Debug
+where
+    G: Genotype,
+{
+    /// Calculates the values of the objectives of the given genome. All
+    /// objectives are minimized, so objectives to be maximized must be
+    /// negated. The number of objectives must be the same for all genomes.
+    fn objectives_of(&self, genome: &G) -> Objectives;
+}
+
+/// An individual together with the values of its objectives.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParetoSolution<G>
+where
+    G: Genotype,
+{
+    /// The evaluated `genetic::Genotype`.
+    pub genome: G,
+    /// The values of the objectives of the genome.
+    pub objectives: Objectives,
+}
+
+/// The `State` holds the result of one generation of `Nsga2`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct State<G>
+where
+    G: Genotype,
+{
+    /// The population selected for the next generation.
+    pub population: Vec<ParetoSolution<G>>,
+    /// The non-dominated individuals of the population.
+    pub pareto_front: Vec<ParetoSolution<G>>,
+    /// Processing time for this generation.
+    pub processing_time: ProcessingTime,
+}
+
+/// Returns whether the objectives `a` dominate the objectives `b`, i.e. `a`
+/// is not worse than `b` in any objective and better in at least one.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    let mut better = false;
+    for (a, b) in a.iter().zip(b.iter()) {
+        if a > b {
+            return false;
+        }
+        if a < b {
+            better = true;
+        }
+    }
+    better
+}
+
+/// Sorts the given objectives into fronts of non-dominated individuals and
+/// returns the indices of the individuals of each front. The first front
+/// holds the individuals that are not dominated by any other individual, the
+/// second front those that are only dominated by the first front and so on.
+pub fn non_dominated_sort(objectives: &[Objectives]) -> Vec<Vec<usize>> {
+    let size = objectives.len();
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); size];
+    let mut domination_count = vec![0usize; size];
+    let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+    for p in 0..size {
+        for q in 0..size {
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated[p].push(q);
+            } else if dominates(&objectives[q], &objectives[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            fronts[0].push(p);
+        }
+    }
+    let mut current = 0;
+    while !fronts[current].is_empty() {
+        let mut next = Vec::new();
+        for &p in &fronts[current] {
+            for &q in &dominated[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next.push(q);
+                }
+            }
+        }
+        fronts.push(next);
+        current += 1;
+    }
+    fronts.pop();
+    fronts
+}
+
+/// Calculates the crowding distance of each individual of the given front.
+/// The crowding distance is the normalized perimeter of the cuboid spanned
+/// by the nearest neighbours in the objective space. The individuals at the
+/// boundaries of each objective get an infinite distance.
+///
+/// The returned distances are in the same order as the indices in `front`.
+pub fn crowding_distances(objectives: &[Objectives], front: &[usize]) -> Vec<f64> {
+    let size = front.len();
+    let mut distances = vec![0.; size];
+    if size == 0 {
+        return distances;
+    }
+    let num_objectives = objectives[front[0]].len();
+    let mut order: Vec<usize> = (0..size).collect();
+    let value = |i: usize, objective: usize| objectives[front[i]][objective];
+    for objective in 0..num_objectives {
+        order.sort_by(|&a, &b| {
+            value(a, objective)
+                .partial_cmp(&value(b, objective))
+                .unwrap_or(Ordering::Equal)
+        });
+        let min = value(order[0], objective);
+        let max = value(order[size - 1], objective);
+        distances[order[0]] = f64::INFINITY;
+        distances[order[size - 1]] = f64::INFINITY;
+        if max - min <= 0. {
+            continue;
+        }
+        for neighbours in order.windows(3) {
+            distances[neighbours[1]] +=
+                (value(neighbours[2], objective) - value(neighbours[0], objective)) / (max - min);
+        }
+    }
+    distances
+}
+
+/// `Nsga2` implements the non-dominated sorting genetic algorithm II. It
+/// keeps the size of the initial population throughout the run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nsga2<G, E, C, M>
+where
+    G: Genotype,
+    E: ObjectiveFunction<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    evaluator: E,
+    breeder: C,
+    mutator: M,
+    initial_population: Population<G>,
+    population: Vec<ParetoSolution<G>>,
+    ranks: Vec<usize>,
+    crowding: Vec<f64>,
+    processing_time: ProcessingTime,
+}
+
+impl<G, E, C, M> Nsga2<G, E, C, M>
+where
+    G: Genotype,
+    E: ObjectiveFunction<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    pub fn new(evaluator: E, breeder: C, mutator: M, initial_population: Population<G>) -> Self {
+        Nsga2 {
+            evaluator,
+            breeder,
+            mutator,
+            initial_population,
+            population: Vec::new(),
+            ranks: Vec::new(),
+            crowding: Vec::new(),
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the number of individuals of the population.
+    pub fn population_size(&self) -> usize {
+        self.initial_population.size()
+    }
+
+    fn evaluate(&self, genomes: Vec<G>) -> Vec<ParetoSolution<G>> {
+        genomes
+            .into_iter()
+            .map(|genome| ParetoSolution {
+                objectives: self.evaluator.objectives_of(&genome),
+                genome,
+            })
+            .collect()
+    }
+
+    /// Selects the individuals that survive into the next population and
+    /// stores their rank and crowding distance for the tournaments.
+    fn select_survivors(&mut self, candidates: Vec<ParetoSolution<G>>) {
+        let size = self.population_size();
+        let objectives: Vec<Objectives> = candidates
+            .iter()
+            .map(|solution| solution.objectives.clone())
+            .collect();
+        let mut survivors = Vec::with_capacity(size);
+        let mut ranks = Vec::with_capacity(size);
+        let mut crowding = Vec::with_capacity(size);
+        for (rank, front) in non_dominated_sort(&objectives).into_iter().enumerate() {
+            if survivors.len() >= size {
+                break;
+            }
+            let distances = crowding_distances(&objectives, &front);
+            let mut members: Vec<(usize, f64)> = front.into_iter().zip(distances).collect();
+            if survivors.len() + members.len() > size {
+                members.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+                members.truncate(size - survivors.len());
+            }
+            for (index, distance) in members {
+                survivors.push(index);
+                ranks.push(rank);
+                crowding.push(distance);
+            }
+        }
+        let mut candidates: Vec<Option<ParetoSolution<G>>> =
+            candidates.into_iter().map(Some).collect();
+        self.population = survivors
+            .into_iter()
+            .map(|index| candidates[index].take().expect("each survivor is unique"))
+            .collect();
+        self.ranks = ranks;
+        self.crowding = crowding;
+    }
+
+    /// Binary tournament by rank first and crowding distance second.
+    fn tournament(&self, rng: &mut Prng) -> &G {
+        let a = random_index(rng, self.population.len());
+        let b = random_index(rng, self.population.len());
+        let winner = match self.ranks[a].cmp(&self.ranks[b]) {
+            Ordering::Less => a,
+            Ordering::Greater => b,
+            Ordering::Equal if self.crowding[a] >= self.crowding[b] => a,
+            Ordering::Equal => b,
+        };
+        &self.population[winner].genome
+    }
+
+    fn breed_offspring(&self, rng: &mut Prng) -> Vec<G> {
+        let size = self.population_size();
+        let mut offspring = Vec::with_capacity(size + 1);
+        while offspring.len() < size {
+            let parents = vec![self.tournament(rng).clone(), self.tournament(rng).clone()];
+            for child in self.breeder.crossover(parents, rng) {
+                offspring.push(self.mutator.mutate(child, rng));
+            }
+        }
+        offspring.truncate(size);
+        offspring
+    }
+}
+
+impl<G, E, C, M> TrackProcessingTime for Nsga2<G, E, C, M>
+where
+    G: Genotype,
+    E: ObjectiveFunction<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, E, C, M> Algorithm for Nsga2<G, E, C, M>
+where
+    G: Genotype,
+    E: ObjectiveFunction<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    type Output = State<G>;
+    type Error = GeneticAlgorithmError;
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.initial_population.size() == 0 {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "Population of generation {} is empty.",
+                iteration
+            )));
+        }
+        let generation = timed(|| {
+            if self.population.is_empty() {
+                let initial = self.evaluate(self.initial_population.individuals().to_vec());
+                self.select_survivors(initial);
+            }
+            let offspring = self.breed_offspring(rng);
+            let mut candidates = self.evaluate(offspring);
+            candidates.append(&mut self.population);
+            self.select_survivors(candidates);
+        })
+        .run();
+
+        self.processing_time = generation.time;
+        let pareto_front = self
+            .population
+            .iter()
+            .zip(self.ranks.iter())
+            .filter(|(_, rank)| **rank == 0)
+            .map(|(solution, _)| solution.clone())
+            .collect();
+        Ok(State {
+            population: self.population.clone(),
+            pareto_front,
+            processing_time: self.processing_time,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.population.clear();
+        self.ranks.clear();
+        self.crowding.clear();
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn dominates_requires_being_better_in_at_least_one_objective() {
+        expect_that!(&dominates(&[1., 2.], &[1., 3.]), eq(true));
+        expect_that!(&dominates(&[1., 2.], &[1., 2.]), eq(false));
+        expect_that!(&dominates(&[0., 3.], &[1., 2.]), eq(false));
+    }
+
+    #[test]
+    fn non_dominated_sort_sorts_into_fronts() {
+        let objectives = vec![
+            vec![1., 4.],
+            vec![2., 2.],
+            vec![4., 1.],
+            vec![3., 3.],
+            vec![4., 4.],
+        ];
+
+        let fronts = non_dominated_sort(&objectives);
+
+        expect_that!(&fronts, eq(vec![vec![0, 1, 2], vec![3], vec![4]]));
+    }
+
+    #[test]
+    fn crowding_distance_is_infinite_at_the_boundaries() {
+        let objectives = vec![vec![0., 4.], vec![1., 2.], vec![2., 1.], vec![4., 0.]];
+
+        let distances = crowding_distances(&objectives, &[0, 1, 2, 3]);
+
+        expect_that!(&distances[0], eq(f64::INFINITY));
+        expect_that!(&distances[3], eq(f64::INFINITY));
+        expect_that!(&(distances[1] - 1.25).abs(), is(less_than(1e-9)));
+        expect_that!(&(distances[2] - 1.25).abs(), is(less_than(1e-9)));
+    }
+}
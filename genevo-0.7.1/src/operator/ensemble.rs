@@ -0,0 +1,479 @@
+//! The `ensemble` module provides the building blocks of the operators that
+//! hold several operators of the same kind and pick one of them each time
+//! they are applied, i.e. the `recombination::ensemble::EnsembleCrossover`
+//! and the `mutation::ensemble::EnsembleMutator`.
+//!
+//! The operators of an ensemble are either a `Vec` of operators of one type
+//! or a tuple of up to 6 operators of different types. Which one is applied
+//! is picked by `OperatorWeights`, which may adapt the weights to the success
+//! of each operator while the simulation is running (adaptive operator
+//! selection).
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    operator::{CrossoverOp, GeneticOperator, MutationOp},
+    random::{random_probability, Rng},
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The operators of an ensemble.
+pub trait OperatorSet: Clone {
+    /// Returns the number of operators in the set.
+    fn len(&self) -> usize;
+
+    /// Returns whether the set holds no operators.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the names of the operators in the set, in their order.
+    fn names(&self) -> Vec<String>;
+}
+
+/// A set of `operator::CrossoverOp`s of an ensemble.
+pub trait CrossoverOps<G>: OperatorSet
+where
+    G: Genotype,
+{
+    /// Performs the crossover of the operator at the given index, see
+    /// `operator::CrossoverOp::crossover_recycling`.
+    fn crossover_by<R>(
+        &self,
+        index: usize,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized;
+
+    /// Informs each operator of the set about the progress of the search, see
+    /// `operator::CrossoverOp::adapt`.
+    fn adapt_each(&mut self, improved: bool);
+}
+
+/// A set of `operator::MutationOp`s of an ensemble.
+pub trait MutationOps<G>: OperatorSet
+where
+    G: Genotype,
+{
+    /// Mutates the genome by the operator at the given index.
+    fn mutate_by<R>(&self, index: usize, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized;
+
+    /// Informs each operator of the set about the progress of the search, see
+    /// `operator::MutationOp::adapt`.
+    fn adapt_each(&mut self, improved: bool);
+}
+
+impl<O> OperatorSet for Vec<O>
+where
+    O: GeneticOperator,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn names(&self) -> Vec<String> {
+        vec![O::name(); self.len()]
+    }
+}
+
+impl<G, C> CrossoverOps<G> for Vec<C>
+where
+    G: Genotype,
+    C: CrossoverOp<G>,
+{
+    fn crossover_by<R>(
+        &self,
+        index: usize,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        self[index].crossover_recycling(parents, recycled, rng)
+    }
+
+    fn adapt_each(&mut self, improved: bool) {
+        for operator in self {
+            operator.adapt(improved);
+        }
+    }
+}
+
+impl<G, M> MutationOps<G> for Vec<M>
+where
+    G: Genotype,
+    M: MutationOp<G>,
+{
+    fn mutate_by<R>(&self, index: usize, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self[index].mutate(genome, rng)
+    }
+
+    fn adapt_each(&mut self, improved: bool) {
+        for operator in self {
+            operator.adapt(improved);
+        }
+    }
+}
+
+macro_rules! impl_operator_sets_for_tuple {
+    ($len:expr; $($index:tt: $operator:ident),+) => {
+        impl<$($operator),+> OperatorSet for ($($operator,)+)
+        where
+            $($operator: GeneticOperator),+
+        {
+            fn len(&self) -> usize {
+                $len
+            }
+
+            fn names(&self) -> Vec<String> {
+                vec![$($operator::name()),+]
+            }
+        }
+
+        impl<G, $($operator),+> CrossoverOps<G> for ($($operator,)+)
+        where
+            G: Genotype,
+            $($operator: CrossoverOp<G>),+
+        {
+            fn crossover_by<R>(
+                &self,
+                index: usize,
+                parents: Parents<G>,
+                recycled: &mut Vec<G>,
+                rng: &mut R,
+            ) -> Children<G>
+            where
+                R: Rng + Sized,
+            {
+                match index {
+                    $($index => self.$index.crossover_recycling(parents, recycled, rng),)+
+                    _ => panic!("no crossover operator at index {} of {}", index, $len),
+                }
+            }
+
+            fn adapt_each(&mut self, improved: bool) {
+                $(self.$index.adapt(improved);)+
+            }
+        }
+
+        impl<G, $($operator),+> MutationOps<G> for ($($operator,)+)
+        where
+            G: Genotype,
+            $($operator: MutationOp<G>),+
+        {
+            fn mutate_by<R>(&self, index: usize, genome: G, rng: &mut R) -> G
+            where
+                R: Rng + Sized,
+            {
+                match index {
+                    $($index => self.$index.mutate(genome, rng),)+
+                    _ => panic!("no mutation operator at index {} of {}", index, $len),
+                }
+            }
+
+            fn adapt_each(&mut self, improved: bool) {
+                $(self.$index.adapt(improved);)+
+            }
+        }
+    };
+}
+
+impl_operator_sets_for_tuple!(2; 0: O0, 1: O1);
+impl_operator_sets_for_tuple!(3; 0: O0, 1: O1, 2: O2);
+impl_operator_sets_for_tuple!(4; 0: O0, 1: O1, 2: O2, 3: O3);
+impl_operator_sets_for_tuple!(5; 0: O0, 1: O1, 2: O2, 3: O3, 4: O4);
+impl_operator_sets_for_tuple!(6; 0: O0, 1: O1, 2: O2, 3: O3, 4: O4, 5: O5);
+
+/// How `OperatorWeights` adapt to the success of the operators, by
+/// probability matching: each operator has a quality, the running average of
+/// the credit it got in each generation, and is picked with a probability
+/// proportional to its quality, but at least `min_probability`. Operators
+/// with an initial weight of 0 are still never picked.
+///
+/// The credit is assigned per generation: if the offspring of a generation
+/// improved on the best fitness so far, each operator gets the share of the
+/// offspring it bred as its credit, otherwise none of them gets any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeightAdaptation {
+    /// The weight of the credit of the latest generation in the quality,
+    /// between 0 (the weights never change) and 1 (only the latest generation
+    /// counts).
+    pub learning_rate: f64,
+    /// The probability each operator is at least picked with, so that an
+    /// operator that failed early gets a chance again later. It must not be
+    /// higher than 1 divided by the number of operators with a weight.
+    pub min_probability: f64,
+}
+
+/// The `OperatorWeights` pick the operator of an ensemble each time it is
+/// applied, by fixed weights or, with a `WeightAdaptation`, by weights
+/// adapted to the success of the operators. They count how often each
+/// operator was picked since the last generation, which is safe while the
+/// offspring is bred in parallel.
+#[derive(Debug)]
+pub struct OperatorWeights {
+    weights: Vec<f64>,
+    adaptation: Option<WeightAdaptation>,
+    qualities: Vec<f64>,
+    uses: Vec<AtomicU64>,
+}
+
+impl OperatorWeights {
+    /// Creates fixed weights of the operators, in their order. The weights
+    /// do not have to add up to 1, an operator with a weight of 0 is never
+    /// picked.
+    pub fn new(weights: Vec<f64>) -> Self {
+        assert!(
+            !weights.is_empty(),
+            "an ensemble needs at least one operator"
+        );
+        assert!(
+            weights.iter().all(|weight| *weight >= 0.) && weights.iter().sum::<f64>() > 0.,
+            "the weights of the operators must not be negative and not all 0"
+        );
+        let sum: f64 = weights.iter().sum();
+        OperatorWeights {
+            qualities: weights.iter().map(|weight| weight / sum).collect(),
+            uses: weights.iter().map(|_| AtomicU64::new(0)).collect(),
+            weights,
+            adaptation: None,
+        }
+    }
+
+    /// Creates equal weights of the given number of operators.
+    pub fn equal(num_operators: usize) -> Self {
+        OperatorWeights::new(vec![1.; num_operators])
+    }
+
+    /// Adapts the weights to the success of the operators, starting from the
+    /// weights they have been created with.
+    pub fn with_adaptation(mut self, adaptation: WeightAdaptation) -> Self {
+        assert!(
+            adaptation.min_probability * self.active_operators().count() as f64 <= 1.,
+            "the minimum probabilities of the operators add up to more than 1"
+        );
+        self.adaptation = Some(adaptation);
+        self
+    }
+
+    /// Returns the number of operators.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns whether there are no operators, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns the adaptation of the weights, if they adapt.
+    pub fn adaptation(&self) -> Option<WeightAdaptation> {
+        self.adaptation
+    }
+
+    // The indices of the operators with a weight, the only ones ever picked
+    fn active_operators(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.weights.len()).filter(|index| self.weights[*index] > 0.)
+    }
+
+    /// Returns the probability of each operator to be picked.
+    pub fn probabilities(&self) -> Vec<f64> {
+        match self.adaptation {
+            Some(adaptation) => {
+                let sum: f64 = self.qualities.iter().sum();
+                let num_active = self.active_operators().count() as f64;
+                let share = 1. - num_active * adaptation.min_probability;
+                self.weights
+                    .iter()
+                    .zip(&self.qualities)
+                    .map(|(weight, quality)| {
+                        if *weight <= 0. {
+                            0.
+                        } else if sum > 0. {
+                            adaptation.min_probability + share * quality / sum
+                        } else {
+                            1. / num_active
+                        }
+                    })
+                    .collect()
+            }
+            None => {
+                let sum: f64 = self.weights.iter().sum();
+                self.weights.iter().map(|weight| weight / sum).collect()
+            }
+        }
+    }
+
+    /// Returns how often each operator has been picked since the last
+    /// generation.
+    pub fn uses(&self) -> Vec<u64> {
+        self.uses
+            .iter()
+            .map(|uses| uses.load(Ordering::Relaxed))
+            .collect()
+    }
+
+    /// Picks the index of the operator to apply next. The only operator with
+    /// a weight is picked without drawing a random number, so that it runs
+    /// exactly like on its own.
+    pub fn pick<R>(&self, rng: &mut R) -> usize
+    where
+        R: Rng + Sized,
+    {
+        let last_active = self.active_operators().last().unwrap_or_default();
+        let index = if self.active_operators().count() == 1 {
+            last_active
+        } else {
+            let mut remaining = random_probability(rng);
+            self.probabilities()
+                .iter()
+                .position(|probability| {
+                    remaining -= probability;
+                    remaining < 0.
+                })
+                .unwrap_or(last_active)
+        };
+        self.uses[index].fetch_add(1, Ordering::Relaxed);
+        index
+    }
+
+    /// Assigns the credit of the operators picked since the last generation
+    /// if the weights adapt, see `WeightAdaptation`, and starts counting
+    /// their uses anew.
+    pub fn adapt(&mut self, improved: bool) {
+        let uses = self.uses();
+        for count in &mut self.uses {
+            *count.get_mut() = 0;
+        }
+        let Some(adaptation) = self.adaptation else {
+            return;
+        };
+        let total: u64 = uses.iter().sum();
+        if total == 0 {
+            return;
+        }
+        for (quality, uses) in self.qualities.iter_mut().zip(uses) {
+            let credit = if improved {
+                uses as f64 / total as f64
+            } else {
+                0.
+            };
+            *quality += adaptation.learning_rate * (credit - *quality);
+        }
+    }
+}
+
+impl Clone for OperatorWeights {
+    fn clone(&self) -> Self {
+        OperatorWeights {
+            weights: self.weights.clone(),
+            adaptation: self.adaptation,
+            qualities: self.qualities.clone(),
+            uses: self.uses().into_iter().map(AtomicU64::new).collect(),
+        }
+    }
+}
+
+impl PartialEq for OperatorWeights {
+    fn eq(&self, other: &Self) -> bool {
+        self.weights == other.weights
+            && self.adaptation == other.adaptation
+            && self.qualities == other.qualities
+            && self.uses() == other.uses()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn the_operators_are_picked_by_their_weights() {
+        let weights = OperatorWeights::new(vec![1., 3.]);
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..10_000 {
+            weights.pick(&mut rng);
+        }
+
+        let uses = weights.uses();
+        expect_that!(&uses[0], greater_than(2_000));
+        expect_that!(&uses[0], less_than(3_000));
+        expect_that!(&(uses[0] + uses[1]), eq(10_000));
+    }
+
+    #[test]
+    fn fixed_weights_do_not_adapt() {
+        let mut weights = OperatorWeights::new(vec![1., 1.]);
+        let mut rng = get_rng(random_seed());
+        for _ in 0..100 {
+            weights.pick(&mut rng);
+        }
+
+        weights.adapt(true);
+
+        expect_that!(&weights.probabilities(), eq(vec![0.5, 0.5]));
+        expect_that!(&weights.uses(), eq(vec![0, 0]));
+    }
+
+    #[test]
+    fn the_operators_of_improving_generations_gain_weight() {
+        let mut weights = OperatorWeights::equal(2).with_adaptation(WeightAdaptation {
+            learning_rate: 0.5,
+            min_probability: 0.1,
+        });
+
+        weights.uses[0].store(30, Ordering::Relaxed);
+        weights.uses[1].store(10, Ordering::Relaxed);
+        weights.adapt(true);
+        let after_improvement = weights.probabilities();
+        weights.uses[1].store(40, Ordering::Relaxed);
+        weights.adapt(false);
+
+        expect_that!(&after_improvement[0], greater_than(0.5));
+        expect_that!(&weights.probabilities(), eq(after_improvement));
+    }
+
+    #[test]
+    fn operators_without_a_weight_are_never_picked() {
+        let weights = OperatorWeights::new(vec![0., 1., 0.]).with_adaptation(WeightAdaptation {
+            learning_rate: 0.5,
+            min_probability: 0.2,
+        });
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..100 {
+            weights.pick(&mut rng);
+        }
+
+        expect_that!(&weights.probabilities(), eq(vec![0., 1., 0.]));
+        expect_that!(&weights.uses(), eq(vec![0, 100, 0]));
+    }
+
+    #[test]
+    fn the_operators_keep_their_minimum_probability() {
+        let mut weights = OperatorWeights::equal(3).with_adaptation(WeightAdaptation {
+            learning_rate: 1.,
+            min_probability: 0.05,
+        });
+
+        weights.uses[0].store(10, Ordering::Relaxed);
+        weights.adapt(true);
+
+        let probabilities = weights.probabilities();
+        expect_that!(&probabilities[1], eq(0.05));
+        expect_that!(&probabilities[2], eq(0.05));
+        expect_that!(&(probabilities[0] - 0.9).abs(), less_than(1e-12));
+    }
+}
@@ -9,9 +9,11 @@
 
 pub mod prelude;
 
+pub mod ensemble;
+
 use crate::{
     algorithm::EvaluatedPopulation,
-    genetic::{Children, Fitness, Genotype, Offspring, Parents},
+    genetic::{Children, Fitness, FitnessFunction, Genotype, Offspring, Parents},
     random::Rng,
 };
 
@@ -58,6 +60,15 @@ where
     ) -> Vec<Parents<G>>
     where
         R: Rng + Sized;
+
+    /// Informs the operator about the progress of the search once per
+    /// generation, before the parents of that generation are selected.
+    /// `improved` is true if the best fitness of the current generation is
+    /// higher than the best fitness of all previous generations.
+    ///
+    /// Scheduled operators use this to count the generations. The default
+    /// implementation does nothing.
+    fn adapt(&mut self, _improved: bool) {}
 }
 
 /// A `CrossoverOp` defines a function of how to crossover two
@@ -74,6 +85,35 @@ where
     fn crossover<R>(&self, parents: Parents<G>, rng: &mut R) -> Children<G>
     where
         R: Rng + Sized;
+
+    /// Performs the crossover like `crossover`, but may build the children
+    /// in genomes taken from `recycled` instead of allocating new ones, and
+    /// may put the parents into `recycled` once they are no longer needed.
+    /// This way the breeding stage of a generation reuses the buffers of the
+    /// genomes instead of allocating new ones for each child.
+    ///
+    /// The default implementation ignores `recycled`.
+    fn crossover_recycling<R>(
+        &self,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        let _ = recycled;
+        self.crossover(parents, rng)
+    }
+
+    /// Informs the operator about the progress of the search once per
+    /// generation, before the parents of that generation are crossed over.
+    /// `improved` is true if the best fitness of the current generation is
+    /// higher than the best fitness of all previous generations.
+    ///
+    /// Adaptive operators use this to adjust their parameters. The default
+    /// implementation does nothing.
+    fn adapt(&mut self, _improved: bool) {}
 }
 
 /// A `MutationOp` defines a function of how a `genetic::Genotype` mutates. It
@@ -93,6 +133,53 @@ where
     fn mutate<R>(&self, genome: G, rng: &mut R) -> G
     where
         R: Rng + Sized;
+
+    /// Informs the operator about the progress of the search once per
+    /// generation, before the offspring of that generation is mutated.
+    /// `improved` is true if the best fitness of the current generation is
+    /// higher than the best fitness of all previous generations.
+    ///
+    /// Adaptive operators use this to adjust their parameters. The default
+    /// implementation does nothing.
+    fn adapt(&mut self, _improved: bool) {}
+}
+
+/// A `RepairOp` defines a function of how to turn an invalid
+/// `genetic::Genotype` into a valid one, e.g. one that violates a capacity
+/// or the bounds of a content ratio. It is applied to each child after
+/// mutation, so that the crossover and the mutation operators do not need to
+/// know about the constraints of the problem.
+///
+/// A `RepairOp` should return valid genomes unchanged.
+pub trait RepairOp<G>: GeneticOperator
+where
+    G: Genotype,
+{
+    /// Repairs the given `genetic::Genotype` and returns the valid one.
+    fn repair<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized;
+}
+
+/// A `LocalSearchOp` defines a function of how to improve a single
+/// `genetic::Genotype` by searching its neighbourhood, e.g. by hill climbing.
+/// It is applied to each child after mutation. A genetic algorithm with a
+/// local search stage is often called a memetic algorithm.
+///
+/// Local search usually evaluates the fitness of many neighbours, so it adds
+/// considerably to the processing time of a generation.
+pub trait LocalSearchOp<G, F>: GeneticOperator
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Improves the given `genetic::Genotype` and returns the improved one.
+    /// The given fitness function is used to compare the genome with its
+    /// neighbours.
+    fn improve<E, R>(&self, genome: G, evaluator: &E, rng: &mut R) -> G
+    where
+        E: FitnessFunction<G, F>,
+        R: Rng + Sized;
 }
 
 /// A `ReinsertionOp` defines a function that combines the offspring with the
@@ -135,4 +222,24 @@ where
     ) -> Vec<G>
     where
         R: Rng + Sized;
+
+    /// Informs the operator about the progress of the search once per
+    /// generation, before the offspring of that generation is combined with
+    /// the population. `improved` is true if the best fitness of the current
+    /// generation is higher than the best fitness of all previous generations.
+    ///
+    /// Scheduled operators use this to count the generations. Operators that
+    /// wrap another `ReinsertionOp` pass it on. The default implementation
+    /// does nothing.
+    fn adapt(&mut self, _improved: bool) {}
+
+    /// Whether the `combine` of the current generation restarts the
+    /// population, i.e. replaces most of it with new individuals. The genetic
+    /// algorithm asks after the `adapt` hook and reports it in its state.
+    ///
+    /// Operators that wrap another `ReinsertionOp` pass it on. The default
+    /// implementation returns false.
+    fn is_restarting(&self) -> bool {
+        false
+    }
 }
@@ -1,7 +1,38 @@
+pub use crate::operator::ensemble::{
+    CrossoverOps, MutationOps, OperatorSet, OperatorWeights, WeightAdaptation,
+};
+
 pub use crate::selection::{proportionate::*, ranking::*, tournament::*, truncation::*, *};
 
-pub use crate::recombination::{discrete::*, order::*, *};
+pub use crate::recombination::{
+    adaptive::SelfAdaptiveCrossBreeder,
+    discrete::*,
+    ensemble::EnsembleCrossover,
+    matrix::*,
+    noop::NoOpCrossover,
+    order::*,
+    probabilistic::*,
+    real::{ArithmeticCrossBreeder, BlendCrossBreeder, SimulatedBinaryCrossBreeder},
+    tree::SubtreeCrossBreeder,
+    variable::CutAndSpliceCrossBreeder,
+};
+
+pub use crate::mutation::{
+    adaptive::{MutationRate, SelfAdaptive, SelfAdaptiveMutator, StagnationBoostMutator},
+    ensemble::EnsembleMutator,
+    masked::*,
+    noop::NoOpMutator,
+    order::*,
+    real::{GaussianMutator, PolynomialMutator},
+    tree::{PointMutator, SubtreeMutator},
+    value::*,
+    variable::{GeneDeletionMutator, GeneInsertionMutator},
+};
+
+pub use crate::local_search::*;
+
+pub use crate::repair::*;
 
-pub use crate::mutation::{order::*, value::*, *};
+pub use crate::schedule::*;
 
-pub use crate::reinsertion::{elitist::*, random::*, *};
+pub use crate::reinsertion::{elitism::*, elitist::*, immigrants::*, random::*, restart::*, *};
@@ -0,0 +1,288 @@
+//! The `penalty` module provides the `PenalizedFitness` function, which
+//! subtracts weighted penalties for the violated constraints of a problem
+//! from the `genetic::Fitness` values of another `genetic::FitnessFunction`.
+//!
+//! Each constraint is given as a function returning by how much a genotype
+//! violates it, 0 if the genotype satisfies it. The penalty of a genotype is
+//! the sum of its violations multiplied by the weights of their constraints.
+//! A weight is either static or follows a `schedule::Schedule`, e.g. to let
+//! the search cross infeasible regions early and to push it towards feasible
+//! genotypes late in the run.
+
+use crate::{
+    genetic::{AsScalar, Fitness, FitnessFunction, FromScalar, Genotype},
+    schedule::Schedule,
+};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+type Violation<G> = Arc<dyn Fn(&G) -> f64 + Send + Sync>;
+
+/// A constraint and the `Schedule` of its weight.
+struct Constraint<G> {
+    violation: Violation<G>,
+    weight: Schedule,
+}
+
+impl<G> Clone for Constraint<G> {
+    fn clone(&self) -> Self {
+        Constraint {
+            violation: self.violation.clone(),
+            weight: self.weight.clone(),
+        }
+    }
+}
+
+/// The `PenalizedFitness` wraps a `genetic::FitnessFunction` and subtracts
+/// the weighted violations of the constraints from its fitness values. The
+/// penalized fitness is never lower than the lowest possible fitness of the
+/// wrapped function.
+///
+/// The weights of a `Schedule` follow the generation, which the
+/// `ga::GeneticAlgorithm` sets through `FitnessFunction::adapt`. Clones
+/// share the generation, so an `operator::ReinsertionOp` evaluates the
+/// offspring with the same weights as the algorithm. As the weights change,
+/// the fitness of the best solution found in an earlier generation is not
+/// comparable to the current one, so check it with `is_feasible`.
+///
+/// To cache the fitness values, wrap the base fitness function into a
+/// `cache::CachedFitness` and not the `PenalizedFitness`, whose values
+/// change with the generation.
+pub struct PenalizedFitness<G, F, E> {
+    fitness_function: E,
+    constraints: Vec<Constraint<G>>,
+    generation: Arc<AtomicU64>,
+    _fitness: PhantomData<F>,
+}
+
+impl<G, F, E> Clone for PenalizedFitness<G, F, E>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        PenalizedFitness {
+            fitness_function: self.fitness_function.clone(),
+            constraints: self.constraints.clone(),
+            generation: self.generation.clone(),
+            _fitness: PhantomData,
+        }
+    }
+}
+
+impl<G, F, E> Debug for PenalizedFitness<G, F, E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PenalizedFitness")
+            .field("fitness_function", &self.fitness_function)
+            .field(
+                "weights",
+                &self
+                    .constraints
+                    .iter()
+                    .map(|constraint| &constraint.weight)
+                    .collect::<Vec<_>>(),
+            )
+            .field("generation", &self.generation.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+impl<G, F, E> PenalizedFitness<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar,
+    E: FitnessFunction<G, F>,
+{
+    pub fn new(fitness_function: E) -> Self {
+        PenalizedFitness {
+            fitness_function,
+            constraints: Vec::new(),
+            generation: Arc::new(AtomicU64::new(0)),
+            _fitness: PhantomData,
+        }
+    }
+
+    /// Adds a constraint whose violation is penalized with the same weight
+    /// in every generation.
+    pub fn with_penalty<V>(self, violation: V, weight: f64) -> Self
+    where
+        V: Fn(&G) -> f64 + Send + Sync + 'static,
+    {
+        self.with_scheduled_penalty(violation, Schedule::Constant(weight))
+    }
+
+    /// Adds a constraint whose violation is penalized with the weight of the
+    /// given `Schedule` in the current generation.
+    pub fn with_scheduled_penalty<V>(mut self, violation: V, weight: Schedule) -> Self
+    where
+        V: Fn(&G) -> f64 + Send + Sync + 'static,
+    {
+        self.constraints.push(Constraint {
+            violation: Arc::new(violation),
+            weight,
+        });
+        self
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn fitness_function(&self) -> &E {
+        &self.fitness_function
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns the generation the weights are currently taken from, 0 before
+    /// the first generation.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    /// Returns the weights of the constraints in the current generation.
+    pub fn weights(&self) -> Vec<f64> {
+        let generation = self.generation();
+        self.constraints
+            .iter()
+            .map(|constraint| constraint.weight.value_at(generation))
+            .collect()
+    }
+
+    /// Returns the sum of the weighted violations of the given genome in the
+    /// current generation.
+    pub fn penalty_of(&self, genome: &G) -> f64 {
+        let generation = self.generation();
+        self.constraints
+            .iter()
+            .map(|constraint| {
+                (constraint.violation)(genome) * constraint.weight.value_at(generation)
+            })
+            .sum()
+    }
+
+    /// Returns whether the given genome violates none of the constraints.
+    pub fn is_feasible(&self, genome: &G) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| (constraint.violation)(genome) <= 0.)
+    }
+
+    // the fitness of the wrapped function minus the penalty of the genome
+    fn penalized(&self, genome: &G, fitness: F) -> F {
+        let penalty = self.penalty_of(genome);
+        if penalty <= 0. {
+            return fitness;
+        }
+        let lowest = self.fitness_function.lowest_possible_fitness();
+        F::from_scalar((fitness.as_scalar() - penalty).max(lowest.as_scalar()))
+    }
+}
+
+impl<G, F, E> FitnessFunction<G, F> for PenalizedFitness<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar,
+    E: FitnessFunction<G, F>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        self.penalized(genome, self.fitness_function.fitness_of(genome))
+    }
+
+    fn fitness_of_all(&self, genomes: &[G]) -> Vec<F> {
+        genomes
+            .iter()
+            .zip(self.fitness_function.fitness_of_all(genomes))
+            .map(|(genome, fitness)| self.penalized(genome, fitness))
+            .collect()
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        self.fitness_function.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.fitness_function.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.fitness_function.lowest_possible_fitness()
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.generation.store(generation, Ordering::Relaxed);
+        self.fitness_function.adapt(generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    // The sum of the values, which should not exceed 10
+    #[derive(Clone, Debug)]
+    struct Sum;
+
+    impl FitnessFunction<Vec<usize>, usize> for Sum {
+        fn fitness_of(&self, genome: &Vec<usize>) -> usize {
+            genome.iter().sum()
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            100
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    fn excess(values: &[usize]) -> f64 {
+        values.iter().sum::<usize>().saturating_sub(10) as f64
+    }
+
+    #[test]
+    fn the_weighted_violations_are_subtracted_down_to_the_lowest_fitness() {
+        let penalized = PenalizedFitness::new(Sum)
+            .with_penalty(|genome: &Vec<usize>| excess(genome), 2.)
+            .with_penalty(|genome: &Vec<usize>| genome.len() as f64 - 3., 0.5);
+
+        expect_that!(&penalized.fitness_of(&vec![2, 3]), eq(5));
+        expect_that!(&penalized.fitness_of(&vec![4, 4, 4]), eq(8));
+        // 24 - 2 * 14 - 0.5 * 1
+        expect_that!(&penalized.fitness_of(&vec![6, 6, 6, 6]), eq(0));
+        expect_that!(&penalized.is_feasible(&vec![2, 3]), eq(true));
+        expect_that!(&penalized.is_feasible(&vec![4, 4, 4]), eq(false));
+        expect_that!(&penalized.penalty_of(&vec![6, 6, 6, 6]), eq(28.5));
+    }
+
+    #[test]
+    fn scheduled_weights_follow_the_generation_of_all_clones() {
+        let penalized = PenalizedFitness::new(Sum).with_scheduled_penalty(
+            |genome: &Vec<usize>| excess(genome),
+            Schedule::Piecewise(vec![(1, 0.), (11, 1.)]),
+        );
+        let reinserter_copy = penalized.clone();
+
+        penalized.adapt(1);
+        expect_that!(&reinserter_copy.fitness_of(&vec![20]), eq(20));
+        penalized.adapt(6);
+        expect_that!(&reinserter_copy.fitness_of(&vec![20]), eq(15));
+        penalized.adapt(11);
+        expect_that!(&reinserter_copy.fitness_of(&vec![20]), eq(10));
+        expect_that!(&reinserter_copy.weights(), eq(vec![1.]));
+    }
+}
@@ -8,7 +8,15 @@
 //!
 //! Default implementations of `GenomeBuilder` are provided for the binary
 //! encoded types `fixedbitset::FixedBitSet` and `Vec<bool>` and for the
-//! value encoded type `Vec<T>`.
+//! value encoded type `Vec<T>`. The `LatinHypercubeGenomeBuilder` spreads the
+//! values of `Vec<T>` genomes of integers or floats evenly over their range,
+//! the `VariableLengthGenomeBuilder` builds `Vec<T>` genomes of random length.
+//! The `TreeGenomeBuilder` builds the `encoding::TreeGenome`s of genetic
+//! programming.
+//!
+//! A population of genomes that implement `HammingDistance` can be built
+//! spread out, i.e. with a minimum distance between any two individuals, by
+//! `PopulationWithGenomeBuilderAndSizeBuilder::with_min_distance`.
 //!
 //! ## Examples
 //!
@@ -98,17 +106,22 @@
 //! ```
 
 use crate::{
+    encoding::{Matrix, PackedGenome, PrimitiveSet, TreeGenome},
     genetic::Genotype,
-    random::{get_rng, random_seed, Prng, Rng, Seed},
+    mutation::adaptive::SelfAdaptive,
+    random::{get_rng, random_seed, Prng, Rng, Seed, SliceRandom},
 };
 use rand::distributions::uniform::SampleUniform;
 #[cfg(not(target_arch = "wasm32"))]
 use rayon;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// The `Population` defines a set of possible solutions to the optimization
 /// or search problem.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Population<G>
 where
     G: Genotype,
@@ -151,14 +164,29 @@ pub struct PopulationBuilder;
 
 #[cfg(not(target_arch = "wasm32"))]
 impl PopulationBuilder {
-    fn build_population<B, G>(genome_builder: &B, size: usize, mut rng: Prng) -> Population<G>
+    fn build_population<B, G>(genome_builder: &B, size: usize, rng: Prng) -> Population<G>
+    where
+        B: GenomeBuilder<G>,
+        G: Genotype,
+    {
+        Self::build_individuals(genome_builder, 0, size, rng)
+    }
+
+    // Builds the individuals with the indices `first_index..first_index + size`,
+    // splitting the larger populations between threads.
+    fn build_individuals<B, G>(
+        genome_builder: &B,
+        first_index: usize,
+        size: usize,
+        mut rng: Prng,
+    ) -> Population<G>
     where
         B: GenomeBuilder<G>,
         G: Genotype,
     {
         if size < 50 {
             Population {
-                individuals: (0..size)
+                individuals: (first_index..first_index + size)
                     .map(|index| genome_builder.build_genome(index, &mut rng))
                     .collect(),
             }
@@ -170,8 +198,15 @@ impl PopulationBuilder {
             let left_size = size / 2;
             let right_size = size - left_size;
             let (left_population, right_population) = rayon::join(
-                || Self::build_population(genome_builder, left_size, rng1),
-                || Self::build_population(genome_builder, right_size, rng2),
+                || Self::build_individuals(genome_builder, first_index, left_size, rng1),
+                || {
+                    Self::build_individuals(
+                        genome_builder,
+                        first_index + left_size,
+                        right_size,
+                        rng2,
+                    )
+                },
             );
             let mut right_individuals = right_population.individuals;
             let mut individuals = left_population.individuals;
@@ -292,6 +327,156 @@ where
             get_rng(seed),
         )
     }
+
+    /// Rejects each new genome that is closer than the given `min_distance`
+    /// to a genome already accepted into the population, see
+    /// `DiversePopulationBuilder`.
+    pub fn with_min_distance(self, min_distance: usize) -> DiversePopulationBuilder<B, G>
+    where
+        G: HammingDistance,
+    {
+        DiversePopulationBuilder {
+            _g: self._g,
+            genome_builder: self.genome_builder,
+            population_size: self.population_size,
+            min_distance,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// The `HammingDistance` of two genomes is the number of loci they differ
+/// at. Genomes of different lengths also differ at each locus only one of
+/// them has.
+pub trait HammingDistance {
+    /// Returns the Hamming distance between this genome and the `other` one.
+    fn hamming_distance(&self, other: &Self) -> usize;
+}
+
+impl<V> HammingDistance for Vec<V>
+where
+    V: PartialEq,
+{
+    fn hamming_distance(&self, other: &Self) -> usize {
+        let differing = self.iter().zip(other).filter(|(a, b)| a != b).count();
+        differing + self.len().max(other.len()) - self.len().min(other.len())
+    }
+}
+
+impl HammingDistance for PackedGenome {
+    fn hamming_distance(&self, other: &Self) -> usize {
+        let differing = self
+            .iter()
+            .zip(other.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        differing + self.len().max(other.len()) - self.len().min(other.len())
+    }
+}
+
+/// Matrices of the same size differ at each cell they hold different values
+/// in, matrices of different sizes are compared cell by cell row by row.
+impl<V> HammingDistance for Matrix<V>
+where
+    V: PartialEq,
+{
+    fn hamming_distance(&self, other: &Self) -> usize {
+        let differing = self
+            .cells()
+            .iter()
+            .zip(other.cells())
+            .filter(|(a, b)| a != b)
+            .count();
+        let (len, other_len) = (self.cells().len(), other.cells().len());
+        differing + len.max(other_len) - len.min(other_len)
+    }
+}
+
+/// The distance of `SelfAdaptive` genotypes is the one of their genomes, the
+/// mutation rates are not compared.
+impl<G> HammingDistance for SelfAdaptive<G>
+where
+    G: HammingDistance,
+{
+    fn hamming_distance(&self, other: &Self) -> usize {
+        self.genome.hamming_distance(&other.genome)
+    }
+}
+
+/// The number of genomes built for each individual of a
+/// `DiversePopulationBuilder` unless set otherwise.
+pub const DEFAULT_MAX_ATTEMPTS: usize = 100;
+
+/// The `DiversePopulationBuilder` builds a population whose individuals are
+/// spread out over the search space: a new genome is rejected if its Hamming
+/// distance to any genome already accepted is less than the `min_distance`,
+/// and another one is built for the same index instead.
+///
+/// If none of the `max_attempts` genomes built for an individual is far
+/// enough from the accepted ones, the one with the largest distance to its
+/// closest accepted genome is taken, so that building a population always
+/// terminates. A `GenomeBuilder` that builds the same genome for an index
+/// every time, e.g. the seeds of a `SeededGenomeBuilder`, has it accepted
+/// after the attempts.
+///
+/// The individuals are built one after another, as each one depends on the
+/// ones before it. It is meant for small populations, for which a uniformly
+/// random population may be clustered by chance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversePopulationBuilder<B, G>
+where
+    B: GenomeBuilder<G>,
+    G: Genotype + HammingDistance,
+{
+    _g: PhantomData<G>,
+    genome_builder: B,
+    population_size: usize,
+    min_distance: usize,
+    max_attempts: usize,
+}
+
+impl<B, G> DiversePopulationBuilder<B, G>
+where
+    B: GenomeBuilder<G>,
+    G: Genotype + HammingDistance,
+{
+    /// Sets the number of genomes built at most for each individual. It must
+    /// be at least 1.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn uniform_at_random(self) -> Population<G> {
+        self.build_population(get_rng(random_seed()))
+    }
+
+    pub fn using_seed(self, seed: Seed) -> Population<G> {
+        self.build_population(get_rng(seed))
+    }
+
+    fn build_population(&self, mut rng: Prng) -> Population<G> {
+        let mut individuals: Vec<G> = Vec::with_capacity(self.population_size);
+        for index in 0..self.population_size {
+            let mut best: Option<(G, usize)> = None;
+            for _ in 0..self.max_attempts {
+                let genome = self.genome_builder.build_genome(index, &mut rng);
+                let distance = individuals
+                    .iter()
+                    .map(|accepted| accepted.hamming_distance(&genome))
+                    .min()
+                    .unwrap_or(usize::MAX);
+                if best.as_ref().is_none_or(|(_, best)| distance > *best) {
+                    best = Some((genome, distance));
+                }
+                if distance >= self.min_distance {
+                    break;
+                }
+            }
+            individuals.extend(best.map(|(genome, _)| genome));
+        }
+        Population { individuals }
+    }
 }
 
 pub fn build_population() -> EmptyPopulationBuilder {
@@ -300,6 +485,55 @@ pub fn build_population() -> EmptyPopulationBuilder {
     }
 }
 
+/// A `GenomeBuilder` that seeds a population with given genomes, e.g. the
+/// best solutions of earlier runs, and fills the rest of it with the genomes
+/// built by another `GenomeBuilder`.
+///
+/// The individual with index `i` is the `i`th seed genome. If there are more
+/// seed genomes than individuals the surplus ones are left out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeededGenomeBuilder<G, B> {
+    seeds: Vec<G>,
+    genome_builder: B,
+}
+
+impl<G, B> SeededGenomeBuilder<G, B> {
+    /// Returns a new instance of the `SeededGenomeBuilder` that builds the
+    /// given `seeds` first and then the genomes of the given `genome_builder`.
+    pub fn new(seeds: Vec<G>, genome_builder: B) -> Self {
+        SeededGenomeBuilder {
+            seeds,
+            genome_builder,
+        }
+    }
+
+    /// Returns the seed genomes.
+    pub fn seeds(&self) -> &[G] {
+        &self.seeds
+    }
+
+    /// Returns the `GenomeBuilder` the rest of the population is built by.
+    pub fn genome_builder(&self) -> &B {
+        &self.genome_builder
+    }
+}
+
+impl<G, B> GenomeBuilder<G> for SeededGenomeBuilder<G, B>
+where
+    G: Genotype,
+    B: GenomeBuilder<G>,
+{
+    fn build_genome<R>(&self, index: usize, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        match self.seeds.get(index) {
+            Some(seed) => seed.clone(),
+            None => self.genome_builder.build_genome(index, rng),
+        }
+    }
+}
+
 /// A `GenomeBuilder` that builds binary encoded `genetic::Genotype`s.
 ///
 /// The default implementation can build `fixedbitset::FixedBitSet` genomes
@@ -329,9 +563,9 @@ impl GenomeBuilder<Vec<bool>> for BinaryEncodedGenomeBuilder {
 
 /// A `GenomeBuilder` that builds value encoded `genetic::Genotype`s.
 ///
-/// The default implementation can build `Vec<T>` genomes. The values of
-/// `T` are generated randomly in the range between a min value and a max
-/// value.
+/// The default implementation can build `Vec<T>` genomes, and
+/// `encoding::PackedGenome`s for values of `u8`. The values of `T` are
+/// generated randomly in the range between a min value and a max value.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ValueEncodedGenomeBuilder<V> {
     genome_length: usize,
@@ -368,6 +602,267 @@ where
     }
 }
 
+impl GenomeBuilder<PackedGenome> for ValueEncodedGenomeBuilder<u8> {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> PackedGenome
+    where
+        R: Rng + Sized,
+    {
+        (0..self.genome_length)
+            .map(|_| rng.gen_range(self.min_value..self.max_value))
+            .collect()
+    }
+}
+
+/// A `GenomeBuilder` that builds `encoding::Matrix` genomes of `rows` ×
+/// `cols` cells. The values of the cells are generated randomly in the range
+/// between a min value (inclusive) and a max value (exclusive).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixGenomeBuilder<V> {
+    rows: usize,
+    cols: usize,
+    min_value: V,
+    max_value: V,
+}
+
+impl<V> MatrixGenomeBuilder<V> {
+    pub fn new(rows: usize, cols: usize, min_value: V, max_value: V) -> Self {
+        MatrixGenomeBuilder {
+            rows,
+            cols,
+            min_value,
+            max_value,
+        }
+    }
+}
+
+impl<V> GenomeBuilder<Matrix<V>> for MatrixGenomeBuilder<V>
+where
+    V: Clone + Debug + PartialEq + PartialOrd + SampleUniform + Send + Sync,
+{
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Matrix<V>
+    where
+        R: Rng + Sized,
+    {
+        let cells = (0..self.rows * self.cols)
+            .map(|_| rng.gen_range(self.min_value.clone()..self.max_value.clone()))
+            .collect();
+        Matrix::from_cells(self.rows, self.cols, cells)
+    }
+}
+
+/// A `GenomeBuilder` that builds value encoded `Vec<T>` genomes of random
+/// length, for genotypes whose length evolves. The length of the genomes is
+/// in the range between a min length and a max length (both inclusive), the
+/// values are in the range between a min value (inclusive) and a max value
+/// (exclusive).
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableLengthGenomeBuilder<V> {
+    min_length: usize,
+    max_length: usize,
+    min_value: V,
+    max_value: V,
+}
+
+impl<V> VariableLengthGenomeBuilder<V> {
+    pub fn new(min_length: usize, max_length: usize, min_value: V, max_value: V) -> Self {
+        assert!(
+            min_length <= max_length,
+            "the min length must not be greater than the max length"
+        );
+        VariableLengthGenomeBuilder {
+            min_length,
+            max_length,
+            min_value,
+            max_value,
+        }
+    }
+}
+
+impl<V> GenomeBuilder<Vec<V>> for VariableLengthGenomeBuilder<V>
+where
+    V: Clone + Debug + PartialEq + PartialOrd + SampleUniform + Send + Sync,
+{
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let length = rng.gen_range(self.min_length..=self.max_length);
+        (0..length)
+            .map(|_| rng.gen_range(self.min_value.clone()..self.max_value.clone()))
+            .collect()
+    }
+}
+
+/// A `GenomeBuilder` that builds `encoding::TreeGenome`s of the primitives
+/// of an `encoding::PrimitiveSet` by the ramped half-and-half method: the
+/// depths of the trees are spread evenly between a min depth and a max depth
+/// (both inclusive), and for each depth half of the trees are built by the
+/// full method and half of them by the grow method, see
+/// `encoding::TreeGenome::full` and `encoding::TreeGenome::grow`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeGenomeBuilder<S> {
+    primitives: S,
+    min_depth: usize,
+    max_depth: usize,
+}
+
+impl<S> TreeGenomeBuilder<S> {
+    pub fn new(primitives: S, min_depth: usize, max_depth: usize) -> Self {
+        assert!(
+            min_depth <= max_depth,
+            "the min depth must not be greater than the max depth"
+        );
+        TreeGenomeBuilder {
+            primitives,
+            min_depth,
+            max_depth,
+        }
+    }
+}
+
+impl<S> GenomeBuilder<TreeGenome<S::Primitive>> for TreeGenomeBuilder<S>
+where
+    S: PrimitiveSet,
+{
+    fn build_genome<R>(&self, index: usize, rng: &mut R) -> TreeGenome<S::Primitive>
+    where
+        R: Rng + Sized,
+    {
+        let depth = self.min_depth + (index / 2) % (self.max_depth - self.min_depth + 1);
+        if index.is_multiple_of(2) {
+            TreeGenome::full(&self.primitives, depth, rng)
+        } else {
+            TreeGenome::grow(&self.primitives, depth, rng)
+        }
+    }
+}
+
+/// A `GenomeBuilder` that builds value encoded `genetic::Genotype`s by Latin
+/// hypercube sampling (LHS), so that the initial population covers the search
+/// space more evenly than uniformly random values do.
+///
+/// The range of the values is split into as many equally wide strata as the
+/// population has individuals. At each locus every stratum is taken by exactly
+/// one individual, which draws its value uniformly at random from within its
+/// stratum. The strata are assigned to the individuals by an independent
+/// random permutation per locus, drawn when the builder is created.
+///
+/// The default implementation can build `Vec<T>` genomes of the primitive
+/// integer and float types. The values are generated in the range between a
+/// min value (inclusive) and a max value (exclusive). Integer strata that are
+/// narrower than a single value hold the lowest value of the stratum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LatinHypercubeGenomeBuilder<V> {
+    min_value: V,
+    max_value: V,
+    // The stratum of each individual at each locus
+    strata: Vec<Vec<usize>>,
+}
+
+impl<V> LatinHypercubeGenomeBuilder<V> {
+    /// Returns a new instance of the `LatinHypercubeGenomeBuilder` that builds
+    /// genomes of length specified by the given `genome_length` for a
+    /// population of `population_size` individuals. The strata are assigned
+    /// to the individuals using the given random number generator `rng`.
+    ///
+    /// The individual with index `i` is assigned the strata of the individual
+    /// with index `i % population_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `population_size` is 0.
+    pub fn new<R>(
+        genome_length: usize,
+        min_value: V,
+        max_value: V,
+        population_size: usize,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized,
+    {
+        assert!(population_size > 0, "the population must not be empty");
+        let strata = (0..genome_length)
+            .map(|_| {
+                let mut strata: Vec<usize> = (0..population_size).collect();
+                strata.shuffle(rng);
+                strata
+            })
+            .collect();
+        LatinHypercubeGenomeBuilder {
+            min_value,
+            max_value,
+            strata,
+        }
+    }
+
+    /// Returns the number of strata the range of the values is split into,
+    /// which is the size of the population.
+    pub fn num_strata(&self) -> usize {
+        self.strata.first().map_or(0, Vec::len)
+    }
+}
+
+macro_rules! impl_latin_hypercube_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl GenomeBuilder<Vec<$t>> for LatinHypercubeGenomeBuilder<$t> {
+                #[allow(trivial_numeric_casts)]
+                fn build_genome<R>(&self, index: usize, rng: &mut R) -> Vec<$t>
+                where
+                    R: Rng + Sized,
+                {
+                    let min_value = self.min_value as i128;
+                    let width = self.max_value as i128 - min_value;
+                    self.strata
+                        .iter()
+                        .map(|strata| {
+                            let num_strata = strata.len() as i128;
+                            let stratum = strata[index % strata.len()] as i128;
+                            let low = min_value + width * stratum / num_strata;
+                            let high = min_value + width * (stratum + 1) / num_strata;
+                            if high > low {
+                                rng.gen_range(low..high) as $t
+                            } else {
+                                low as $t
+                            }
+                        })
+                        .collect()
+                }
+            }
+        )*
+    }
+}
+
+impl_latin_hypercube_for_integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_latin_hypercube_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl GenomeBuilder<Vec<$t>> for LatinHypercubeGenomeBuilder<$t> {
+                #[allow(trivial_numeric_casts)]
+                fn build_genome<R>(&self, index: usize, rng: &mut R) -> Vec<$t>
+                where
+                    R: Rng + Sized,
+                {
+                    let min_value = self.min_value as f64;
+                    let width = self.max_value as f64 - min_value;
+                    self.strata
+                        .iter()
+                        .map(|strata| {
+                            let stratum = strata[index % strata.len()] as f64;
+                            let offset = (stratum + rng.gen::<f64>()) / strata.len() as f64;
+                            (min_value + width * offset) as $t
+                        })
+                        .collect()
+                }
+            }
+        )*
+    }
+}
+
+impl_latin_hypercube_for_floats!(f32, f64);
+
 #[cfg(feature = "fixedbitset")]
 mod fixedbitset_genome_builder {
     use super::{BinaryEncodedGenomeBuilder, GenomeBuilder};
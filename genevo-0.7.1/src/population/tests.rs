@@ -62,3 +62,193 @@ mod population_builder {
         }
     }
 }
+
+mod seeded_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed, Rng};
+    use proptest::prelude::*;
+
+    // Builds genomes holding just their index
+    #[derive(Clone, Debug, PartialEq)]
+    struct IndexBuilder;
+
+    impl GenomeBuilder<Vec<usize>> for IndexBuilder {
+        fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<usize>
+        where
+            R: Rng + Sized,
+        {
+            vec![index]
+        }
+    }
+
+    proptest! {
+
+        #[test]
+        fn each_individual_of_a_population_is_built_with_its_own_index(
+            size in 0usize..999,
+        ) {
+            let population: Population<Vec<usize>> = PopulationBuilder::build_population(
+                &IndexBuilder,
+                size,
+                get_rng(random_seed()),
+            );
+
+            let indices: Vec<usize> = population.individuals().iter().map(|g| g[0]).collect();
+            prop_assert_eq!(indices, (0..size).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn the_seeds_are_built_first_and_the_rest_is_filled_in(
+            num_seeds in 0usize..99,
+            size in 0usize..199,
+        ) {
+            let seeds: Vec<Vec<usize>> = (0..num_seeds).map(|seed| vec![1000 + seed]).collect();
+
+            let population: Population<Vec<usize>> = PopulationBuilder::build_population(
+                &SeededGenomeBuilder::new(seeds, IndexBuilder),
+                size,
+                get_rng(random_seed()),
+            );
+
+            let expected: Vec<Vec<usize>> = (0..size)
+                .map(|index| vec![if index < num_seeds { 1000 + index } else { index }])
+                .collect();
+            prop_assert_eq!(population.individuals().to_vec(), expected);
+        }
+    }
+}
+
+mod latin_hypercube_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn each_stratum_of_a_float_locus_is_taken_by_one_individual(
+            size in 1usize..200,
+            genome_length in 1usize..8,
+        ) {
+            let mut rng = get_rng(random_seed());
+            let builder = LatinHypercubeGenomeBuilder::new(genome_length, -2., 2., size, &mut rng);
+
+            let population: Population<Vec<f64>> =
+                PopulationBuilder::build_population(&builder, size, rng);
+
+            for locus in 0..genome_length {
+                let mut strata: Vec<usize> = population
+                    .individuals()
+                    .iter()
+                    .map(|genome| ((genome[locus] + 2.) / 4. * size as f64) as usize)
+                    .collect();
+                strata.sort_unstable();
+                prop_assert_eq!(strata, (0..size).collect::<Vec<_>>());
+            }
+        }
+
+        #[test]
+        fn integer_values_are_spread_over_their_range(
+            size in 1usize..100,
+        ) {
+            let mut rng = get_rng(random_seed());
+            let builder = LatinHypercubeGenomeBuilder::new(3, -50i32, 50, size, &mut rng);
+
+            let population: Population<Vec<i32>> =
+                PopulationBuilder::build_population(&builder, size, rng);
+
+            for locus in 0..3 {
+                let mut values: Vec<i32> =
+                    population.individuals().iter().map(|genome| genome[locus]).collect();
+                values.sort_unstable();
+                for (stratum, value) in values.into_iter().enumerate() {
+                    let low = -50 + (100 * stratum / size) as i32;
+                    let high = -50 + (100 * (stratum + 1) / size) as i32;
+                    prop_assert!(value >= low && (value < high || value == low));
+                }
+            }
+        }
+    }
+}
+
+mod diverse_population_builder {
+
+    use super::*;
+    use crate::random::{random_seed, Rng};
+    use galvanic_assert::matchers::*;
+    use proptest::prelude::*;
+
+    // Builds the same genome every time
+    #[derive(Clone, Debug, PartialEq)]
+    struct ConstantBuilder;
+
+    impl GenomeBuilder<Vec<bool>> for ConstantBuilder {
+        fn build_genome<R>(&self, _: usize, _: &mut R) -> Vec<bool>
+        where
+            R: Rng + Sized,
+        {
+            vec![true; 8]
+        }
+    }
+
+    #[test]
+    fn hamming_distance_counts_the_differing_loci() {
+        expect_that!(&vec![1, 2, 3, 4].hamming_distance(&vec![1, 0, 3, 0]), eq(2));
+        expect_that!(&vec![1, 2].hamming_distance(&vec![1, 2, 3, 4]), eq(2));
+        let packed: PackedGenome = vec![0u8, 1, 2, 3].into_iter().collect();
+        let other: PackedGenome = vec![0u8, 1, 3, 3].into_iter().collect();
+        expect_that!(&packed.hamming_distance(&other), eq(1));
+    }
+
+    #[test]
+    fn a_population_is_built_even_if_no_genome_is_far_enough() {
+        let population: Population<Vec<bool>> = build_population()
+            .with_genome_builder(ConstantBuilder)
+            .of_size(5)
+            .with_min_distance(1)
+            .with_max_attempts(3)
+            .using_seed(random_seed());
+
+        expect_that!(&population.size(), eq(5));
+    }
+
+    proptest! {
+
+        #[test]
+        fn all_individuals_keep_the_min_distance(
+            size in 0usize..30,
+        ) {
+            let population: Population<Vec<bool>> = build_population()
+                .with_genome_builder(BinaryEncodedGenomeBuilder::new(32))
+                .of_size(size)
+                .with_min_distance(6)
+                .using_seed(random_seed());
+
+            prop_assert_eq!(population.size(), size);
+            let individuals = population.individuals();
+            for (i, genome) in individuals.iter().enumerate() {
+                for other in &individuals[i + 1..] {
+                    prop_assert!(genome.hamming_distance(other) >= 6);
+                }
+            }
+        }
+
+        #[test]
+        fn a_diverse_population_is_the_same_for_the_same_seed(
+            size in 0usize..30,
+        ) {
+            let seed = random_seed();
+            let build = || -> Population<Vec<bool>> {
+                build_population()
+                    .with_genome_builder(BinaryEncodedGenomeBuilder::new(16))
+                    .of_size(size)
+                    .with_min_distance(4)
+                    .using_seed(seed)
+            };
+
+            prop_assert_eq!(build(), build());
+        }
+    }
+}
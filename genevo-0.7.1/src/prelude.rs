@@ -4,10 +4,25 @@ pub use crate::genetic::{Fitness, FitnessFunction, Genotype, Phenotype};
 
 // Algorithm
 //
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::process::{PhenotypeInput, ProcessFitness};
 pub use crate::{
-    algorithm::Algorithm,
+    aco::AntColony,
+    algorithm::{Algorithm, PopulationAlgorithm},
+    cache::{averaged::AveragedFitness, CacheStats, CachedFitness},
+    cmaes::CmaEs,
+    es::EvolutionStrategy,
     ga::{genetic_algorithm, GeneticAlgorithm},
+    lineage::{family_tree_to_dot, Ancestry, Birth, Individual, LineageHook, NoLineage},
+    nsga2::{
+        archive::ParetoArchive,
+        indicator::{hypervolume, inverted_generational_distance},
+        Nsga2, ObjectiveFunction, Objectives, ParetoSolution,
+    },
+    penalty::PenalizedFitness,
+    pso::ParticleSwarm,
     random::{Prng, Rng, Seed},
+    scalarization::{ChebyshevFitness, WeightedSumFitness},
 };
 
 // Population
@@ -16,11 +31,19 @@ pub use crate::population::{build_population, GenomeBuilder, Population};
 
 // Simulation
 //
-pub use crate::simulation::{simulator::simulate, SimResult, Simulation, SimulationBuilder};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::simulation::island::{IslandModel, IslandResult, Topology};
+pub use crate::simulation::{
+    metrics::{MetricsFormat, MetricsWriter},
+    observer::{observe, Observed, SimulationObserver},
+    simulator::simulate,
+    AnytimeResult, AnytimeStop, SimResult, Simulation, SimulationBuilder,
+};
 
 // Termination
 //
 pub use crate::termination::{
     combinator::{and, or, And, Or},
     limit::*,
+    predicate::{stop_when, StopWhen},
 };
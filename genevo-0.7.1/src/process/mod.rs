@@ -0,0 +1,337 @@
+//! The `process` module provides the `ProcessFitness` function, which scores
+//! the genotypes by an external command, e.g. a Python script or a game
+//! engine that simulates the phenotype.
+//!
+//! The command is run once per batch of genotypes. It gets the phenotypes of
+//! the batch either one per line on its stdin or one per argument, and has
+//! to print one fitness value per line on its stdout, in the same order.
+//! The batches are evaluated by a pool of worker threads, each running one
+//! command at a time.
+
+use crate::genetic::{AsScalar, Fitness, FitnessFunction, FromScalar, Genotype};
+use std::{
+    fmt::{self, Debug},
+    io::Write,
+    process::{Command, Stdio},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// How the phenotypes of a batch are passed on to the command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhenotypeInput {
+    /// One phenotype per line on the stdin of the command. The phenotypes
+    /// must not contain line breaks.
+    Stdin,
+    /// One phenotype per argument, after the arguments of the command.
+    Arguments,
+}
+
+/// The `ProcessFitness` evaluates the genotypes by running an external
+/// command on their phenotypes, which the `render` function turns the
+/// genotypes into. It splits the genotypes given to `fitness_of_all` into
+/// batches of `batch_size` and evaluates them by `workers` commands in
+/// parallel, one batch each.
+///
+/// The `ga::GeneticAlgorithm` evaluates the population in parallel chunks
+/// already, so switch it off by `with_parallel_evaluation(false)` to hand
+/// the whole population to the `ProcessFitness` at once. Wrap it into a
+/// `cache::CachedFitness` to evaluate repeated genotypes only once.
+///
+/// # Panics
+///
+/// The evaluation panics if the command cannot be run, fails, or does not
+/// print a valid fitness value for each phenotype.
+pub struct ProcessFitness<G, F> {
+    program: String,
+    args: Vec<String>,
+    input: PhenotypeInput,
+    render: Arc<dyn Fn(&G) -> String + Send + Sync>,
+    lowest: F,
+    highest: F,
+    batch_size: usize,
+    workers: usize,
+    runs: Arc<AtomicU64>,
+}
+
+impl<G, F> Clone for ProcessFitness<G, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        ProcessFitness {
+            program: self.program.clone(),
+            args: self.args.clone(),
+            input: self.input,
+            render: self.render.clone(),
+            lowest: self.lowest.clone(),
+            highest: self.highest.clone(),
+            batch_size: self.batch_size,
+            workers: self.workers,
+            runs: self.runs.clone(),
+        }
+    }
+}
+
+impl<G, F> Debug for ProcessFitness<G, F>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProcessFitness")
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .field("input", &self.input)
+            .field("lowest", &self.lowest)
+            .field("highest", &self.highest)
+            .field("batch_size", &self.batch_size)
+            .field("workers", &self.workers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G, F> ProcessFitness<G, F>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar + FromStr + Send + Sync,
+    <F as FromStr>::Err: Debug,
+{
+    /// Returns a new `ProcessFitness` which runs the given program on the
+    /// phenotypes rendered by `render`, whose fitness values range from
+    /// `lowest` to `highest`. By default each phenotype is passed on stdin
+    /// to its own run of the program, with one worker per available CPU.
+    pub fn new<R>(program: &str, render: R, lowest: F, highest: F) -> Self
+    where
+        R: Fn(&G) -> String + Send + Sync + 'static,
+    {
+        ProcessFitness {
+            program: program.to_string(),
+            args: Vec::new(),
+            input: PhenotypeInput::Stdin,
+            render: Arc::new(render),
+            lowest,
+            highest,
+            batch_size: 1,
+            workers: thread::available_parallelism().map_or(1, |workers| workers.get()),
+            runs: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Sets the arguments of the program, which come before the phenotypes
+    /// passed as `PhenotypeInput::Arguments`.
+    pub fn with_args<S>(mut self, args: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.args = args.iter().map(|arg| arg.as_ref().to_string()).collect();
+        self
+    }
+
+    pub fn with_input(mut self, input: PhenotypeInput) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the number of phenotypes passed on to each run of the program.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets the number of runs of the program in parallel.
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    pub fn input(&self) -> PhenotypeInput {
+        self.input
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Returns the number of runs of the program so far.
+    pub fn runs(&self) -> u64 {
+        self.runs.load(Ordering::Relaxed)
+    }
+
+    /// Runs the program once on the phenotypes of the given batch.
+    fn run_batch(&self, batch: &[G]) -> Vec<F> {
+        let phenotypes: Vec<String> = batch.iter().map(|genome| (self.render)(genome)).collect();
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if self.input == PhenotypeInput::Arguments {
+            command.args(&phenotypes);
+        }
+        let mut child = command
+            .spawn()
+            .unwrap_or_else(|error| panic!("cannot run `{}`: {}", self.program, error));
+        let mut stdin = child
+            .stdin
+            .take()
+            .expect("the stdin of the command is piped");
+        let mut lines = String::new();
+        if self.input == PhenotypeInput::Stdin {
+            lines = phenotypes.join("\n");
+            lines.push('\n');
+        }
+        // write on another thread, so a command printing a lot before it has
+        // read all of its input does not block
+        let writer = thread::spawn(move || stdin.write_all(lines.as_bytes()));
+        let output = child
+            .wait_with_output()
+            .unwrap_or_else(|error| panic!("cannot run `{}`: {}", self.program, error));
+        // a command that stops reading early shows by its status or output
+        let _ = writer
+            .join()
+            .expect("the writer of the phenotypes panicked");
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        if !output.status.success() {
+            panic!(
+                "`{}` failed with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let fitness_values: Vec<F> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.parse().unwrap_or_else(|error| {
+                    panic!(
+                        "`{}` printed {:?}, which is no fitness value: {:?}",
+                        self.program, line, error
+                    )
+                })
+            })
+            .collect();
+        if fitness_values.len() != batch.len() {
+            panic!(
+                "`{}` printed {} fitness values for {} phenotypes",
+                self.program,
+                fitness_values.len(),
+                batch.len()
+            );
+        }
+        fitness_values
+    }
+}
+
+impl<G, F> FitnessFunction<G, F> for ProcessFitness<G, F>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar + FromStr + Send + Sync,
+    <F as FromStr>::Err: Debug,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        let mut fitness_values = self.run_batch(std::slice::from_ref(genome));
+        fitness_values.remove(0)
+    }
+
+    fn fitness_of_all(&self, genomes: &[G]) -> Vec<F> {
+        let batches: Vec<&[G]> = genomes.chunks(self.batch_size).collect();
+        let results: Mutex<Vec<Option<Vec<F>>>> =
+            Mutex::new(batches.iter().map(|_| None).collect());
+        // the workers take the batches in turn until all are evaluated
+        let next_batch = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..self.workers.min(batches.len()) {
+                scope.spawn(|| loop {
+                    let index = next_batch.fetch_add(1, Ordering::Relaxed);
+                    let Some(batch) = batches.get(index) else {
+                        break;
+                    };
+                    let fitness_values = self.run_batch(batch);
+                    results.lock().expect("fitness results poisoned")[index] = Some(fitness_values);
+                });
+            }
+        });
+        results
+            .into_inner()
+            .expect("fitness results poisoned")
+            .into_iter()
+            .flat_map(|fitness_values| fitness_values.expect("every batch is evaluated"))
+            .collect()
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        F::from_scalar(values.iter().map(AsScalar::as_scalar).sum::<f64>() / values.len() as f64)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.highest.clone()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.lowest.clone()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    fn render(genome: &[u8]) -> String {
+        genome.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn the_batches_are_passed_on_stdin() {
+        // counts the ones of each line
+        let fitness = ProcessFitness::new("sh", |genome: &Vec<u8>| render(genome), 0usize, 4)
+            .with_args(&["-c", "awk '{ print gsub(/1/, \"\") }'"])
+            .with_batch_size(2)
+            .with_workers(2);
+
+        let genomes = vec![
+            vec![1, 0, 1, 1],
+            vec![0; 4],
+            vec![1; 4],
+            vec![0, 1, 0, 0],
+            vec![1],
+        ];
+
+        expect_that!(&fitness.fitness_of_all(&genomes), eq(vec![3, 0, 4, 1, 1]));
+        expect_that!(&fitness.runs(), eq(3));
+        expect_that!(&fitness.fitness_of(&vec![1, 1, 0, 0]), eq(2));
+    }
+
+    #[test]
+    fn the_batches_are_passed_as_arguments() {
+        // prints the length of each argument
+        let fitness = ProcessFitness::new("sh", |genome: &Vec<u8>| render(genome), 0i64, 8)
+            .with_args(&["-c", "for p in \"$@\"; do echo ${#p}; done", "sh"])
+            .with_input(PhenotypeInput::Arguments)
+            .with_batch_size(10);
+
+        let genomes = vec![vec![1, 0, 1], vec![0; 8], vec![2, 2]];
+
+        expect_that!(&fitness.fitness_of_all(&genomes), eq(vec![3, 8, 2]));
+        expect_that!(&fitness.runs(), eq(1));
+    }
+}
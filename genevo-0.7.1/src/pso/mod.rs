@@ -0,0 +1,336 @@
+//! This module provides an `algorithm::Algorithm` which implements particle
+//! swarm optimization (PSO) for real-valued genotypes.
+//!
+//! Each individual of the population is a particle which moves through the
+//! search space at its own velocity. In every iteration the velocity of each
+//! particle is pulled towards
+//!
+//! 1. the best position the particle has visited itself (**cognitive** part),
+//! 2. the best position any particle of the swarm has visited (**social**
+//!    part),
+//!
+//! each by a random factor, while the previous velocity is kept by the
+//! **inertia** weight. The particle then moves by its velocity and the fitness
+//! of its new position is evaluated.
+//!
+//! The default coefficients are the constriction coefficients of Clerc and
+//! Kennedy, which let the swarm converge without clamping the velocities.
+//!
+//! Each iteration outputs a `ga::State` of the current positions of the
+//! particles and the best position found so far, so the
+//! `simulation::Simulator`, the `termination`s and the
+//! `simulation::SimulationObserver`s of the genetic algorithm work with the
+//! particle swarm as well.
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation, PopulationAlgorithm},
+    encoding::RealValue,
+    ga::{GeneticAlgorithmError, State},
+    genetic::{Fitness, FitnessFunction},
+    population::Population,
+    random::{Prng, Rng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{marker::PhantomData, rc::Rc};
+
+/// The default inertia weight, the constriction coefficient of Clerc and
+/// Kennedy.
+pub const DEFAULT_INERTIA: f64 = 0.7298;
+
+/// The default weight of the pull towards the best position of a particle and
+/// towards the best position of the swarm.
+pub const DEFAULT_ACCELERATION: f64 = 1.49618;
+
+/// A particle of the swarm.
+#[derive(Clone, Debug, PartialEq)]
+struct Particle<V, F>
+where
+    V: RealValue,
+    F: Fitness,
+{
+    current: Evaluated<Vec<V>, F>,
+    velocity: Vec<f64>,
+    best: Evaluated<Vec<V>, F>,
+}
+
+/// `ParticleSwarm` implements the global best particle swarm optimization.
+/// The individuals of the initial population are the initial positions of the
+/// particles, all of which start at rest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParticleSwarm<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    _v: PhantomData<V>,
+    evaluator: E,
+    inertia: f64,
+    cognitive: f64,
+    social: f64,
+    bounds: Option<(f64, f64)>,
+    max_velocity: Option<f64>,
+    initial_population: Population<Vec<V>>,
+    particles: Vec<Particle<V, F>>,
+    best_solution: Option<BestSolution<Vec<V>, F>>,
+    generation: u64,
+    processing_time: ProcessingTime,
+}
+
+impl<V, F, E> ParticleSwarm<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    /// Creates a swarm of as many particles as the initial population has
+    /// individuals, moving by the default coefficients.
+    pub fn new(evaluator: E, initial_population: Population<Vec<V>>) -> Self {
+        ParticleSwarm {
+            _v: PhantomData,
+            evaluator,
+            inertia: DEFAULT_INERTIA,
+            cognitive: DEFAULT_ACCELERATION,
+            social: DEFAULT_ACCELERATION,
+            bounds: None,
+            max_velocity: None,
+            initial_population,
+            particles: Vec::new(),
+            best_solution: None,
+            generation: 0,
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    /// Sets the inertia weight and the weights of the pull towards the best
+    /// position of a particle (`cognitive`) and of the swarm (`social`).
+    pub fn with_coefficients(mut self, inertia: f64, cognitive: f64, social: f64) -> Self {
+        self.inertia = inertia;
+        self.cognitive = cognitive;
+        self.social = social;
+        self
+    }
+
+    /// Keeps the particles within the interval `[min_value, max_value]` in
+    /// each dimension. A particle that hits a bound stops in that dimension.
+    /// Unless limited by `with_max_velocity`, the velocity is limited to the
+    /// width of the interval.
+    pub fn with_bounds(mut self, min_value: f64, max_value: f64) -> Self {
+        assert!(
+            min_value <= max_value,
+            "min_value must not exceed max_value"
+        );
+        self.bounds = Some((min_value, max_value));
+        self
+    }
+
+    /// Limits the velocity of the particles in each dimension.
+    pub fn with_max_velocity(mut self, max_velocity: f64) -> Self {
+        self.max_velocity = Some(max_velocity.abs());
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the inertia weight, the cognitive and the social weight.
+    pub fn coefficients(&self) -> (f64, f64, f64) {
+        (self.inertia, self.cognitive, self.social)
+    }
+
+    pub fn bounds(&self) -> Option<(f64, f64)> {
+        self.bounds
+    }
+
+    /// Returns the limit of the velocity in each dimension, if any.
+    pub fn max_velocity(&self) -> Option<f64> {
+        self.max_velocity
+            .or_else(|| self.bounds.map(|(min, max)| max - min))
+    }
+
+    fn evaluate(&self, genome: Vec<V>) -> Evaluated<Vec<V>, F> {
+        Evaluated {
+            fitness: self.evaluator.fitness_of(&genome),
+            genome,
+        }
+    }
+
+    fn start(&mut self, positions: Vec<Vec<V>>) {
+        self.particles = positions
+            .into_iter()
+            .map(|position| {
+                let current = self.evaluate(position);
+                Particle {
+                    velocity: vec![0.; current.genome.len()],
+                    best: current.clone(),
+                    current,
+                }
+            })
+            .collect();
+        self.update_best_solution();
+    }
+
+    /// Moves each particle by its new velocity and evaluates its new position.
+    fn move_particles(&mut self, rng: &mut Prng) {
+        let swarm_best = match &self.best_solution {
+            Some(best) => best.solution.genome.clone(),
+            None => return,
+        };
+        let max_velocity = self.max_velocity();
+        let mut particles = std::mem::take(&mut self.particles);
+        for particle in &mut particles {
+            let position = particle
+                .current
+                .genome
+                .iter()
+                .zip(particle.velocity.iter_mut())
+                .zip(particle.best.genome.iter().zip(swarm_best.iter()))
+                .map(|((x, v), (own_best, swarm_best))| {
+                    let x = x.to_f64();
+                    *v = self.inertia * *v
+                        + self.cognitive * rng.gen::<f64>() * (own_best.to_f64() - x)
+                        + self.social * rng.gen::<f64>() * (swarm_best.to_f64() - x);
+                    if let Some(max_velocity) = max_velocity {
+                        *v = v.clamp(-max_velocity, max_velocity);
+                    }
+                    let mut x = x + *v;
+                    if let Some((min, max)) = self.bounds {
+                        if x < min || x > max {
+                            x = x.clamp(min, max);
+                            *v = 0.;
+                        }
+                    }
+                    V::from_f64(x)
+                })
+                .collect();
+            particle.current = self.evaluate(position);
+            if particle.current.fitness > particle.best.fitness {
+                particle.best = particle.current.clone();
+            }
+        }
+        self.particles = particles;
+    }
+
+    fn update_best_solution(&mut self) {
+        let best = match self
+            .particles
+            .iter()
+            .map(|particle| &particle.best)
+            .max_by(|a, b| a.fitness.cmp(&b.fitness))
+        {
+            Some(best) => best,
+            None => return,
+        };
+        let improved = match &self.best_solution {
+            Some(best_solution) => best.fitness > best_solution.solution.fitness,
+            None => true,
+        };
+        if improved {
+            self.best_solution = Some(BestSolution {
+                found_at: Local::now(),
+                generation: self.generation,
+                solution: best.clone(),
+            });
+        }
+    }
+}
+
+impl<V, F, E> TrackProcessingTime for ParticleSwarm<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<V, F, E> Algorithm for ParticleSwarm<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    type Output = State<Vec<V>, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.initial_population.size() == 0 {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "The swarm of generation {} has no particles.",
+                iteration
+            )));
+        }
+        self.generation = iteration;
+        let generation = timed(|| {
+            if self.particles.is_empty() {
+                let positions = self.initial_population.individuals().to_vec();
+                self.start(positions);
+            } else {
+                self.move_particles(rng);
+                self.update_best_solution();
+            }
+        })
+        .run();
+        self.processing_time = generation.time;
+
+        let fitness_values: Vec<F> = self
+            .particles
+            .iter()
+            .map(|particle| particle.current.fitness.clone())
+            .collect();
+        let evaluated_population = EvaluatedPopulation::new(
+            Rc::new(
+                self.particles
+                    .iter()
+                    .map(|particle| particle.current.genome.clone())
+                    .collect(),
+            ),
+            fitness_values.clone(),
+            fitness_values.iter().max().unwrap().clone(),
+            fitness_values.iter().min().unwrap().clone(),
+            self.evaluator.average(&fitness_values),
+        );
+        Ok(State {
+            evaluated_population,
+            best_solution: self.best_solution.clone().unwrap(),
+            restarted: false,
+            processing_time: self.processing_time,
+            population_snapshot: None,
+            operator_statistics: None,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.particles.clear();
+        self.best_solution = None;
+        Ok(true)
+    }
+}
+
+impl<V, F, E> PopulationAlgorithm for ParticleSwarm<V, F, E>
+where
+    V: RealValue,
+    F: Fitness,
+    E: FitnessFunction<Vec<V>, F>,
+{
+    type Genotype = Vec<V>;
+    type Fitness = F;
+
+    fn population_snapshot(&self) -> Vec<Evaluated<Vec<V>, F>> {
+        self.particles
+            .iter()
+            .map(|particle| particle.current.clone())
+            .collect()
+    }
+
+    /// Restarts the swarm from the given positions, at rest. The best position
+    /// of the swarm found so far is kept.
+    fn replace_population(&mut self, population: Vec<Vec<V>>) {
+        self.start(population);
+    }
+}
@@ -4,7 +4,7 @@
 pub use rand::{
     distributions::{uniform::SampleUniform, Open01},
     seq::SliceRandom,
-    Rng, SeedableRng,
+    Rng, RngCore, SeedableRng,
 };
 
 use crate::genetic::AsScalar;
@@ -136,6 +136,20 @@ where
     rng.sample(Open01)
 }
 
+/// Generates a normally distributed random value with the given mean and
+/// standard deviation using the given `Prng`.
+///
+/// The value is generated by the Box-Muller transform.
+pub fn random_gaussian<R>(rng: &mut R, mean: f64, std_dev: f64) -> f64
+where
+    R: Rng + Sized,
+{
+    let u1 = random_probability(rng);
+    let u2 = random_probability(rng);
+    let standard = (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos();
+    mean + std_dev * standard
+}
+
 /// The `WeightedDistribution` is used to select values proportional to their
 /// weighted values.
 ///
@@ -159,3 +159,23 @@ mod weighted_distribution {
         expect_that!(&counter[3], is(less_than(60)));
     }
 }
+
+mod random_gaussian {
+
+    use super::*;
+
+    #[test]
+    fn random_gaussian_has_given_mean_and_std_dev() {
+        let mut rng = Prng::from_seed([42; 32]);
+
+        let n = 10_000;
+        let values: Vec<f64> = (0..n).map(|_| random_gaussian(&mut rng, 3., 2.)).collect();
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+
+        expect_that!(&mean, is(greater_than(2.9)));
+        expect_that!(&mean, is(less_than(3.1)));
+        expect_that!(&variance.sqrt(), is(greater_than(1.9)));
+        expect_that!(&variance.sqrt(), is(less_than(2.1)));
+    }
+}
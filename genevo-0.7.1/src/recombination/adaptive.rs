@@ -0,0 +1,96 @@
+//! The `adaptive` module provides `operator::CrossoverOp`s for genotypes that
+//! carry strategy parameters, such as `mutation::adaptive::SelfAdaptive`.
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    mutation::adaptive::SelfAdaptive,
+    operator::{CrossoverOp, GeneticOperator},
+    random::Rng,
+};
+
+/// The `SelfAdaptiveCrossBreeder` recombines `SelfAdaptive` genotypes. The
+/// genomes are recombined by the wrapped `operator::CrossoverOp`, while each
+/// child inherits the geometric mean of the mutation rates of its parents
+/// (intermediate recombination of the strategy parameters).
+///
+/// This crossover operator creates as many child individuals as the wrapped
+/// `operator::CrossoverOp` does.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfAdaptiveCrossBreeder<C> {
+    breeder: C,
+}
+
+impl<C> SelfAdaptiveCrossBreeder<C> {
+    pub fn new(breeder: C) -> Self {
+        SelfAdaptiveCrossBreeder { breeder }
+    }
+
+    /// Returns the wrapped `operator::CrossoverOp`.
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+}
+
+impl<C> GeneticOperator for SelfAdaptiveCrossBreeder<C>
+where
+    C: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Self-Adaptive-{}", C::name())
+    }
+}
+
+impl<G, C> CrossoverOp<SelfAdaptive<G>> for SelfAdaptiveCrossBreeder<C>
+where
+    G: Genotype,
+    C: CrossoverOp<G>,
+{
+    fn crossover<R>(
+        &self,
+        parents: Parents<SelfAdaptive<G>>,
+        rng: &mut R,
+    ) -> Children<SelfAdaptive<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len() as f64;
+        let log_rate_sum: f64 = parents.iter().map(|p| p.mutation_rate.ln()).sum();
+        let mutation_rate = (log_rate_sum / num_parents).exp();
+        let genomes = parents.into_iter().map(|p| p.genome).collect();
+        self.breeder
+            .crossover(genomes, rng)
+            .into_iter()
+            .map(|genome| SelfAdaptive::new(genome, mutation_rate))
+            .collect()
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.breeder.adapt(improved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::{get_rng, random_seed},
+        recombination::discrete::UniformCrossBreeder,
+    };
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn self_adaptive_crossover_children_inherit_geometric_mean_of_rates() {
+        let mut rng = get_rng(random_seed());
+        let p1 = SelfAdaptive::new(vec![true, true, true], 0.01);
+        let p2 = SelfAdaptive::new(vec![false, false, false], 0.16);
+
+        let children = SelfAdaptiveCrossBreeder::new(UniformCrossBreeder::new())
+            .crossover(vec![p1, p2], &mut rng);
+
+        expect_that!(&children.len(), eq(2));
+        for child in children {
+            expect_that!(&(child.mutation_rate - 0.04).abs(), is(less_than(1e-9)));
+            expect_that!(&child.genome.len(), eq(3));
+        }
+    }
+}
@@ -7,11 +7,12 @@
 //! * `UniformCrossBreeder` for `fixedbitset::FixedBitSet`,
 //!   `smallvec::SmallVec` and `Vec` of any type.
 //! * `SinglePointCrossBreeder` for `fixedbitset::FixedBitSet`,
-//!   `smallvec::SmallVec` and `Vec` of any type.
+//!   `smallvec::SmallVec`, `encoding::PackedGenome` and `Vec` of any type.
 //! * `MultiPointCrossBreeder` for `fixedbitset::FixedBitSet`,
-//!   `smallvec::SmallVec` and `Vec` of any type.
+//!   `smallvec::SmallVec`, `encoding::PackedGenome` and `Vec` of any type.
 
 use crate::{
+    encoding::PackedGenome,
     genetic::{Children, Genotype, Parents},
     operator::{CrossoverOp, GeneticOperator},
     random::{random_n_cut_points, Rng},
@@ -173,6 +174,18 @@ where
     {
         MultiPointCrossover::crossover(parents, 1, rng)
     }
+
+    fn crossover_recycling<R>(
+        &self,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_recycling(parents, 1, recycled, rng)
+    }
 }
 
 /// The `MultiPointCrossBreeder` operator combines binary or value encoded
@@ -221,6 +234,18 @@ where
     {
         MultiPointCrossover::crossover(parents, self.num_cut_points, rng)
     }
+
+    fn crossover_recycling<R>(
+        &self,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_recycling(parents, self.num_cut_points, recycled, rng)
+    }
 }
 
 pub trait MultiPointCrossover: Genotype {
@@ -229,6 +254,23 @@ pub trait MultiPointCrossover: Genotype {
     fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
     where
         R: Rng + Sized;
+
+    /// Performs the crossover like `crossover`, building the children in
+    /// genomes taken from `recycled` where possible and putting the parents
+    /// into `recycled` afterwards. The default implementation ignores
+    /// `recycled`.
+    fn crossover_recycling<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        recycled: &mut Vec<Self>,
+        rng: &mut R,
+    ) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        let _ = recycled;
+        Self::crossover(parents, num_cut_points, rng)
+    }
 }
 
 impl<V> MultiPointCrossover for Vec<V>
@@ -238,6 +280,18 @@ where
     type Dna = V;
 
     fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        Self::crossover_recycling(parents, num_cut_points, &mut vec![], rng)
+    }
+
+    fn crossover_recycling<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        recycled: &mut Vec<Self>,
+        rng: &mut R,
+    ) -> Children<Self>
     where
         R: Rng + Sized,
     {
@@ -246,13 +300,19 @@ where
         // breed one child for each partner in parents
         let mut offspring: Vec<Vec<V>> = Vec::with_capacity(num_parents);
         while num_parents > offspring.len() {
-            let mut genome = Vec::with_capacity(genome_length);
+            let mut genome = match recycled.pop() {
+                Some(mut genome) => {
+                    genome.clear();
+                    genome
+                }
+                None => Vec::with_capacity(genome_length),
+            };
+            // the cut points are sorted, each one ends the slice taken from a partner
             let mut cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
             cutpoints.push(genome_length);
             let mut start = 0;
-            let mut end = cutpoints.remove(0);
             let mut p_index = num_parents;
-            loop {
+            for &end in &cutpoints {
                 loop {
                     let index = rng.gen_range(0..num_parents);
                     if index != p_index {
@@ -260,18 +320,64 @@ where
                         break;
                     }
                 }
-                let partner = &parents[p_index];
-                for partner in partner.iter().take(end).skip(start) {
-                    genome.push(partner.clone())
-                }
-                if cutpoints.is_empty() {
-                    break;
+                genome.extend_from_slice(&parents[p_index][start..end]);
+                start = end;
+            }
+            offspring.push(genome);
+        }
+        recycled.extend(parents);
+        offspring
+    }
+}
+
+impl MultiPointCrossover for PackedGenome {
+    type Dna = u8;
+
+    fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        Self::crossover_recycling(parents, num_cut_points, &mut vec![], rng)
+    }
+
+    fn crossover_recycling<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        recycled: &mut Vec<Self>,
+        rng: &mut R,
+    ) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = parents[0].len();
+        let num_parents = parents.len();
+        // breed one child for each partner in parents
+        let mut offspring: Vec<PackedGenome> = Vec::with_capacity(num_parents);
+        while num_parents > offspring.len() {
+            // all loci are copied from the partners, so a recycled genome need not be cleared
+            let mut genome = match recycled.pop() {
+                Some(genome) if genome.len() == genome_length => genome,
+                _ => PackedGenome::new(genome_length),
+            };
+            // the cut points are sorted, each one ends the slice taken from a partner
+            let mut cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
+            cutpoints.push(genome_length);
+            let mut start = 0;
+            let mut p_index = num_parents;
+            for &end in &cutpoints {
+                loop {
+                    let index = rng.gen_range(0..num_parents);
+                    if index != p_index {
+                        p_index = index;
+                        break;
+                    }
                 }
+                genome.copy_from(&parents[p_index], start, end);
                 start = end;
-                end = cutpoints.remove(0);
             }
             offspring.push(genome);
         }
+        recycled.extend(parents);
         offspring
     }
 }
@@ -305,12 +411,12 @@ mod smallvec_multipoint_crossover {
             let mut offspring: Vec<SmallVec<A>> = Vec::with_capacity(num_parents);
             while num_parents > offspring.len() {
                 let mut genome = SmallVec::with_capacity(genome_length);
+                // the cut points are sorted, each one ends the slice taken from a partner
                 let mut cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
                 cutpoints.push(genome_length);
                 let mut start = 0;
-                let mut end = cutpoints.remove(0);
                 let mut p_index = num_parents;
-                loop {
+                for &end in &cutpoints {
                     loop {
                         let index = rng.gen_range(0..num_parents);
                         if index != p_index {
@@ -318,15 +424,8 @@ mod smallvec_multipoint_crossover {
                             break;
                         }
                     }
-                    let partner = &parents[p_index];
-                    for partner in partner.iter().take(end).skip(start) {
-                        genome.push(partner.clone())
-                    }
-                    if cutpoints.is_empty() {
-                        break;
-                    }
+                    genome.extend(parents[p_index][start..end].iter().cloned());
                     start = end;
-                    end = cutpoints.remove(0);
                 }
                 offspring.push(genome);
             }
@@ -388,3 +487,36 @@ mod fixedbitset_multipoint_crossover {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn multi_point_crossover_of_packed_genomes_takes_a_slice_between_each_cut_point() {
+        let parents = vec![PackedGenome::new(1000), (0..1000).map(|_| 3).collect()];
+        let mut rng = get_rng(random_seed());
+
+        for child in MultiPointCrossover::crossover(parents, 4, &mut rng) {
+            let alleles = child.iter().collect::<Vec<_>>();
+            let switches = alleles.windows(2).filter(|w| w[0] != w[1]).count();
+            expect_that!(&child.len(), eq(1000));
+            expect_that!(&switches, eq(4));
+        }
+    }
+
+    #[test]
+    fn multi_point_crossover_of_vec_takes_a_slice_between_each_cut_point() {
+        let parents = vec![vec![0u8; 1000], vec![1u8; 1000]];
+        let mut rng = get_rng(random_seed());
+
+        for child in MultiPointCrossover::crossover(parents, 4, &mut rng) {
+            let switches = child.windows(2).filter(|w| w[0] != w[1]).count();
+            expect_that!(&child.len(), eq(1000));
+            // with two parents the partner changes at every cut point
+            expect_that!(&switches, eq(4));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! The `ensemble` module provides the `EnsembleCrossover`, which holds
+//! several crossover operators and picks one of them for each crossover.
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    operator::{
+        ensemble::{CrossoverOps, OperatorSet, OperatorWeights},
+        CrossoverOp, GeneticOperator,
+    },
+    random::Rng,
+};
+
+/// The `EnsembleCrossover` holds several `operator::CrossoverOp`s, e.g. a
+/// tuple of operators of different types, and picks one of them by the
+/// `operator::ensemble::OperatorWeights` each time parents are crossed over.
+///
+/// With adaptive weights the operators that bred the offspring of the
+/// generations improving on the best fitness are picked more often, see
+/// `operator::ensemble::WeightAdaptation`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnsembleCrossover<O> {
+    operators: O,
+    weights: OperatorWeights,
+}
+
+impl<O> EnsembleCrossover<O>
+where
+    O: OperatorSet,
+{
+    /// Creates an ensemble of the operators, picked by the given weights,
+    /// one for each of the operators.
+    pub fn new(operators: O, weights: OperatorWeights) -> Self {
+        assert_eq!(
+            operators.len(),
+            weights.len(),
+            "an ensemble needs one weight for each of its operators"
+        );
+        EnsembleCrossover { operators, weights }
+    }
+
+    /// Returns the operators of the ensemble.
+    pub fn operators(&self) -> &O {
+        &self.operators
+    }
+
+    /// Returns the weights the operators are picked by.
+    pub fn weights(&self) -> &OperatorWeights {
+        &self.weights
+    }
+}
+
+impl<O> GeneticOperator for EnsembleCrossover<O>
+where
+    O: OperatorSet,
+{
+    fn name() -> String {
+        "Ensemble-Crossover".to_string()
+    }
+}
+
+impl<G, O> CrossoverOp<G> for EnsembleCrossover<O>
+where
+    G: Genotype,
+    O: CrossoverOps<G>,
+{
+    fn crossover<R>(&self, parents: Parents<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        self.crossover_recycling(parents, &mut Vec::new(), rng)
+    }
+
+    fn crossover_recycling<R>(
+        &self,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        let index = self.weights.pick(rng);
+        self.operators.crossover_by(index, parents, recycled, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.operators.adapt_each(improved);
+        self.weights.adapt(improved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operator::ensemble::WeightAdaptation,
+        random::{get_rng, random_seed},
+        recombination::{discrete::UniformCrossBreeder, noop::NoOpCrossover},
+    };
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn operators_of_different_types_are_picked() {
+        let ensemble = EnsembleCrossover::new(
+            (NoOpCrossover, UniformCrossBreeder::new()),
+            OperatorWeights::equal(2),
+        );
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..100 {
+            ensemble.crossover(vec![vec![0u8; 8], vec![1u8; 8]], &mut rng);
+        }
+
+        let uses = ensemble.weights().uses();
+        expect_that!(&uses[0], greater_than(0));
+        expect_that!(&uses[1], greater_than(0));
+        expect_that!(
+            &ensemble.operators().names(),
+            eq(vec![
+                "No-Op-Crossover".to_string(),
+                "Uniform-Cross-Breeder".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn the_operator_of_an_improvement_gains_weight() {
+        let mut ensemble = EnsembleCrossover::new(
+            vec![NoOpCrossover; 2],
+            OperatorWeights::equal(2).with_adaptation(WeightAdaptation {
+                learning_rate: 0.5,
+                min_probability: 0.1,
+            }),
+        );
+        let mut rng = get_rng(random_seed());
+
+        ensemble.crossover(vec![vec![0u8; 8], vec![1u8; 8]], &mut rng);
+        let picked = ensemble.weights().uses().iter().position(|uses| *uses == 1);
+        CrossoverOp::<Vec<u8>>::adapt(&mut ensemble, true);
+
+        let probabilities = ensemble.weights().probabilities();
+        expect_that!(&ensemble.weights().uses(), eq(vec![0, 0]));
+        expect_that!(
+            &(probabilities[picked.unwrap()] - 0.7).abs(),
+            less_than(1e-12)
+        );
+    }
+}
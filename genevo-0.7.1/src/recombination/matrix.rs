@@ -0,0 +1,223 @@
+//! The `matrix` module provides implementations of `operator::CrossoverOp`
+//! for `encoding::Matrix` genotypes. They keep neighbouring cells of the
+//! parents together along the rows, the columns or in rectangular blocks.
+//!
+//! The provided `operator::CrossoverOp`s for matrices are:
+//! * `RowCrossBreeder`
+//! * `ColumnCrossBreeder`
+//! * `BlockCrossBreeder`
+
+use crate::{
+    encoding::Matrix,
+    genetic::{Children, Genotype, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::{random_index_from_range, Rng},
+};
+
+// Breeds one child for each parent, which takes the cells `from_next` of the
+// row and the column returns true for from the next parent and the other
+// cells from its own parent.
+fn combine<V, F>(parents: &[Matrix<V>], from_next: F) -> Children<Matrix<V>>
+where
+    V: Clone,
+    F: Fn(usize, usize) -> bool,
+{
+    let (rows, cols) = (parents[0].rows(), parents[0].cols());
+    assert!(
+        parents
+            .iter()
+            .all(|parent| parent.rows() == rows && parent.cols() == cols),
+        "the parents must be matrices of the same size"
+    );
+    (0..parents.len())
+        .map(|index| {
+            let own = &parents[index];
+            let next = &parents[(index + 1) % parents.len()];
+            let cells = (0..rows)
+                .flat_map(|row| (0..cols).map(move |col| (row, col)))
+                .map(|(row, col)| {
+                    let parent = if from_next(row, col) { next } else { own };
+                    parent.get(row, col).clone()
+                })
+                .collect();
+            Matrix::from_cells(rows, cols, cells)
+        })
+        .collect()
+}
+
+/// The `RowCrossBreeder` operator cuts the matrices between two rows. Each
+/// child takes the rows above the cut from one parent and the rows below it
+/// from the next one.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct RowCrossBreeder {}
+
+impl RowCrossBreeder {
+    pub fn new() -> Self {
+        RowCrossBreeder {}
+    }
+}
+
+impl GeneticOperator for RowCrossBreeder {
+    fn name() -> String {
+        "Row-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Matrix<V>> for RowCrossBreeder
+where
+    Matrix<V>: Genotype,
+    V: Clone,
+{
+    fn crossover<R>(&self, parents: Parents<Matrix<V>>, rng: &mut R) -> Children<Matrix<V>>
+    where
+        R: Rng + Sized,
+    {
+        let rows = parents[0].rows();
+        if rows < 2 {
+            return parents;
+        }
+        let cut = random_index_from_range(rng, 1, rows);
+        combine(&parents, |row, _| row >= cut)
+    }
+}
+
+/// The `ColumnCrossBreeder` operator cuts the matrices between two columns.
+/// Each child takes the columns left of the cut from one parent and the
+/// columns right of it from the next one.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct ColumnCrossBreeder {}
+
+impl ColumnCrossBreeder {
+    pub fn new() -> Self {
+        ColumnCrossBreeder {}
+    }
+}
+
+impl GeneticOperator for ColumnCrossBreeder {
+    fn name() -> String {
+        "Column-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Matrix<V>> for ColumnCrossBreeder
+where
+    Matrix<V>: Genotype,
+    V: Clone,
+{
+    fn crossover<R>(&self, parents: Parents<Matrix<V>>, rng: &mut R) -> Children<Matrix<V>>
+    where
+        R: Rng + Sized,
+    {
+        let cols = parents[0].cols();
+        if cols < 2 {
+            return parents;
+        }
+        let cut = random_index_from_range(rng, 1, cols);
+        combine(&parents, |_, col| col >= cut)
+    }
+}
+
+/// The `BlockCrossBreeder` operator picks a random rectangular block of
+/// cells. Each child takes the block from the next parent and the cells
+/// around it from its own parent, i.e. it is the two-point crossover in two
+/// dimensions.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BlockCrossBreeder {}
+
+impl BlockCrossBreeder {
+    pub fn new() -> Self {
+        BlockCrossBreeder {}
+    }
+}
+
+impl GeneticOperator for BlockCrossBreeder {
+    fn name() -> String {
+        "Block-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Matrix<V>> for BlockCrossBreeder
+where
+    Matrix<V>: Genotype,
+    V: Clone,
+{
+    fn crossover<R>(&self, parents: Parents<Matrix<V>>, rng: &mut R) -> Children<Matrix<V>>
+    where
+        R: Rng + Sized,
+    {
+        let (rows, cols) = (parents[0].rows(), parents[0].cols());
+        if rows == 0 || cols == 0 {
+            return parents;
+        }
+        let top = random_index_from_range(rng, 0, rows);
+        let bottom = random_index_from_range(rng, top + 1, rows + 1);
+        let left = random_index_from_range(rng, 0, cols);
+        let right = random_index_from_range(rng, left + 1, cols + 1);
+        combine(&parents, |row, col| {
+            (top..bottom).contains(&row) && (left..right).contains(&col)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    fn parents() -> Vec<Matrix<u8>> {
+        vec![Matrix::new(4, 5, 0), Matrix::new(4, 5, 1)]
+    }
+
+    #[test]
+    fn row_crossover_keeps_the_rows_together() {
+        let children = RowCrossBreeder::new().crossover(parents(), &mut get_rng(random_seed()));
+
+        for child in &children {
+            for row in 0..child.rows() {
+                let first = child.row(row)[0];
+                expect_that!(&child.row(row).iter().all(|cell| *cell == first), eq(true));
+            }
+            expect_that!(&child.row(0)[0], not(eq(child.row(3)[0])));
+        }
+    }
+
+    #[test]
+    fn column_crossover_keeps_the_columns_together() {
+        let children = ColumnCrossBreeder::new().crossover(parents(), &mut get_rng(random_seed()));
+
+        for child in &children {
+            for col in 0..child.cols() {
+                let first = *child.get(0, col);
+                expect_that!(
+                    &(0..child.rows()).all(|row| *child.get(row, col) == first),
+                    eq(true)
+                );
+            }
+            expect_that!(child.get(0, 0), not(eq(*child.get(0, 4))));
+        }
+    }
+
+    #[test]
+    fn block_crossover_swaps_a_block_between_the_parents() {
+        let children = BlockCrossBreeder::new().crossover(parents(), &mut get_rng(random_seed()));
+
+        let swapped = |child: &Matrix<u8>, parent: u8| {
+            child.cells().iter().filter(|cell| **cell != parent).count()
+        };
+        expect_that!(&swapped(&children[0], 0), greater_than(0));
+        expect_that!(&swapped(&children[0], 0), eq(swapped(&children[1], 1)));
+    }
+}
@@ -4,7 +4,38 @@
 //! * `discrete` - crossover schemes working on discrete values of a bitset or
 //!                or list of values.
 //! * `order` - crossover schemes for permutation encoded values.
+//! * `real` - crossover schemes computing new values for real-valued
+//!            genotypes.
+//! * `variable` - crossover schemes for value encoded genotypes of variable
+//!                length.
+//! * `tree` - crossover schemes for tree encoded genotypes of genetic
+//!            programming.
+//! * `matrix` - crossover schemes for two-dimensional genotypes that keep
+//!              rows, columns or blocks of cells together.
+//! * `adaptive` - crossover schemes for genotypes carrying strategy
+//!                parameters.
+//! * `noop` - an operator that does not cross over at all.
+//! * `probabilistic` - an operator that applies another one to only a share
+//!                     of the parents.
+//! * `ensemble` - an operator that picks one of several operators for each
+//!                crossover.
+
+pub mod adaptive;
 
 pub mod discrete;
 
 pub mod order;
+
+pub mod real;
+
+pub mod variable;
+
+pub mod tree;
+
+pub mod matrix;
+
+pub mod noop;
+
+pub mod probabilistic;
+
+pub mod ensemble;
@@ -0,0 +1,49 @@
+//! The `noop` module provides the `NoOpCrossover`, which skips the crossover
+//! stage of the genetic algorithm, e.g. to find out what the crossover
+//! contributes to a run in an ablation experiment.
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::Rng,
+};
+
+/// The `NoOpCrossover` returns the parents unchanged as the children, i.e.
+/// each child is a copy of one of its parents. The offspring then only
+/// differs from the selected parents by mutation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoOpCrossover;
+
+impl GeneticOperator for NoOpCrossover {
+    fn name() -> String {
+        "No-Op-Crossover".to_string()
+    }
+}
+
+impl<G> CrossoverOp<G> for NoOpCrossover
+where
+    G: Genotype,
+{
+    fn crossover<R>(&self, parents: Parents<G>, _: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        parents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn the_children_are_the_parents() {
+        let parents = vec![vec![1u8, 2, 3], vec![4u8, 5, 6]];
+
+        let children = NoOpCrossover.crossover(parents.clone(), &mut get_rng(random_seed()));
+
+        expect_that!(&children, eq(parents));
+    }
+}
@@ -0,0 +1,174 @@
+//! The `probabilistic` module provides the `ProbabilisticCrossover`, which
+//! applies another crossover operator to only a share of the selected
+//! parents, as the crossover probability `pc` of the canonical genetic
+//! algorithm.
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::{random_probability, Rng},
+};
+
+/// The `ProbabilisticCrossover` wraps another `operator::CrossoverOp` and
+/// applies it to the parents with the probability `crossover_rate`.
+/// Otherwise the parents are returned unchanged as the children, i.e. each
+/// child is a copy of one of its parents, which may still be mutated.
+///
+/// A crossover rate of 1 applies the wrapped operator to all parents and a
+/// rate of 0 none. In both cases no random number is drawn, so with a rate of
+/// 1 the operator runs exactly like the wrapped one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbabilisticCrossover<C> {
+    breeder: C,
+    crossover_rate: f64,
+}
+
+impl<C> ProbabilisticCrossover<C> {
+    pub fn new(breeder: C, crossover_rate: f64) -> Self {
+        ProbabilisticCrossover {
+            breeder,
+            crossover_rate,
+        }
+    }
+
+    /// Returns the wrapped `operator::CrossoverOp`.
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    /// Returns the probability of the parents to be crossed over.
+    pub fn crossover_rate(&self) -> f64 {
+        self.crossover_rate
+    }
+
+    /// Sets the probability of the parents to be crossed over.
+    pub fn set_crossover_rate(&mut self, value: f64) {
+        self.crossover_rate = value;
+    }
+
+    fn crosses_over<R>(&self, rng: &mut R) -> bool
+    where
+        R: Rng + Sized,
+    {
+        if self.crossover_rate >= 1. {
+            true
+        } else if self.crossover_rate <= 0. {
+            false
+        } else {
+            random_probability(rng) < self.crossover_rate
+        }
+    }
+}
+
+impl<C> GeneticOperator for ProbabilisticCrossover<C>
+where
+    C: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Probabilistic-{}", C::name())
+    }
+}
+
+impl<G, C> CrossoverOp<G> for ProbabilisticCrossover<C>
+where
+    G: Genotype,
+    C: CrossoverOp<G>,
+{
+    fn crossover<R>(&self, parents: Parents<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        if self.crosses_over(rng) {
+            self.breeder.crossover(parents, rng)
+        } else {
+            parents
+        }
+    }
+
+    fn crossover_recycling<R>(
+        &self,
+        parents: Parents<G>,
+        recycled: &mut Vec<G>,
+        rng: &mut R,
+    ) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        if self.crosses_over(rng) {
+            self.breeder.crossover_recycling(parents, recycled, rng)
+        } else {
+            parents
+        }
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.breeder.adapt(improved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::{get_rng, random_seed},
+        recombination::discrete::SinglePointCrossBreeder,
+    };
+    use galvanic_assert::matchers::*;
+
+    fn parents() -> Vec<Vec<u8>> {
+        vec![vec![0; 16], vec![1; 16]]
+    }
+
+    // Swaps the parents, so that it is told apart from not crossing over
+    #[derive(Clone, Debug, PartialEq)]
+    struct SwapBreeder;
+
+    impl GeneticOperator for SwapBreeder {
+        fn name() -> String {
+            "Swap-Breeder".to_string()
+        }
+    }
+
+    impl CrossoverOp<Vec<u8>> for SwapBreeder {
+        fn crossover<R>(&self, mut parents: Parents<Vec<u8>>, _: &mut R) -> Children<Vec<u8>>
+        where
+            R: Rng + Sized,
+        {
+            parents.reverse();
+            parents
+        }
+    }
+
+    #[test]
+    fn a_rate_of_0_never_crosses_over() {
+        let breeder = ProbabilisticCrossover::new(SinglePointCrossBreeder::new(), 0.);
+
+        let children = breeder.crossover(parents(), &mut get_rng(random_seed()));
+
+        expect_that!(&children, eq(parents()));
+    }
+
+    #[test]
+    fn a_rate_of_1_runs_like_the_wrapped_operator() {
+        let seed = random_seed();
+        let breeder = ProbabilisticCrossover::new(SinglePointCrossBreeder::new(), 1.);
+
+        let children = breeder.crossover(parents(), &mut get_rng(seed));
+        let expected = SinglePointCrossBreeder::new().crossover(parents(), &mut get_rng(seed));
+
+        expect_that!(&children, eq(expected));
+    }
+
+    #[test]
+    fn the_parents_are_crossed_over_at_the_rate() {
+        let breeder = ProbabilisticCrossover::new(SwapBreeder, 0.3);
+        let mut rng = get_rng(random_seed());
+
+        let crossed = (0..10_000)
+            .filter(|_| breeder.crossover(parents(), &mut rng) != parents())
+            .count();
+
+        expect_that!(&crossed, greater_than(2_500));
+        expect_that!(&crossed, less_than(3_500));
+    }
+}
@@ -0,0 +1,308 @@
+//! The `real` module provides `operator::CrossoverOp`s that recombine
+//! real-valued `genetic::Genotype`s, such as `Vec<f64>`, by computing new
+//! values from the values of the parents instead of just exchanging them.
+//!
+//! The provided `operator::CrossoverOp`s are:
+//! * `ArithmeticCrossBreeder` for `Vec` of `encoding::RealValue`s.
+//! * `BlendCrossBreeder` (BLX-α) for `Vec` of `encoding::RealValue`s.
+//! * `SimulatedBinaryCrossBreeder` (SBX) for `Vec` of `encoding::RealValue`s.
+
+use crate::{
+    encoding::RealValue,
+    genetic::{Children, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::Rng,
+};
+
+/// The `ArithmeticCrossBreeder` operator combines real-valued
+/// `genetic::Genotype`s by computing each child as a weighted average of
+/// its parents. The weights are chosen uniformly at random for each child
+/// and sum up to 1, so each child lies within the hull spanned by the
+/// parents.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct ArithmeticCrossBreeder {}
+
+impl ArithmeticCrossBreeder {
+    pub fn new() -> Self {
+        ArithmeticCrossBreeder {}
+    }
+}
+
+impl GeneticOperator for ArithmeticCrossBreeder {
+    fn name() -> String {
+        "Arithmetic-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Vec<V>> for ArithmeticCrossBreeder
+where
+    V: RealValue,
+{
+    fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = parents[0].len();
+        let num_parents = parents.len();
+        // breed one child for each partner in parents
+        let mut offspring: Vec<Vec<V>> = Vec::with_capacity(num_parents);
+        while num_parents > offspring.len() {
+            let mut weights: Vec<f64> = (0..num_parents).map(|_| rng.gen()).collect();
+            let weight_sum: f64 = weights.iter().sum();
+            for weight in weights.iter_mut() {
+                *weight /= weight_sum;
+            }
+            let genome = (0..genome_length)
+                .map(|locus| {
+                    V::from_f64(
+                        parents
+                            .iter()
+                            .zip(weights.iter())
+                            .map(|(parent, weight)| parent[locus].to_f64() * weight)
+                            .sum(),
+                    )
+                })
+                .collect();
+            offspring.push(genome);
+        }
+        offspring
+    }
+}
+
+/// The `BlendCrossBreeder` operator implements the blend crossover, also
+/// known as BLX-α. For each locus the value of a child is chosen uniformly
+/// at random from the interval spanned by the values of the parents, which
+/// is extended on both sides by `alpha` times its width.
+///
+/// With an `alpha` of 0 the children stay within the hull of the parents,
+/// an `alpha` of 0.5 is the most common choice as it preserves the variance
+/// of the population.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlendCrossBreeder {
+    /// The fraction by which the interval spanned by the parents is extended
+    /// on each side.
+    alpha: f64,
+}
+
+impl BlendCrossBreeder {
+    pub fn new(alpha: f64) -> Self {
+        BlendCrossBreeder { alpha }
+    }
+
+    /// Returns the alpha value of this operator.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Sets the alpha value of this operator to the given value.
+    pub fn set_alpha(&mut self, value: f64) {
+        self.alpha = value;
+    }
+}
+
+impl GeneticOperator for BlendCrossBreeder {
+    fn name() -> String {
+        "Blend-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Vec<V>> for BlendCrossBreeder
+where
+    V: RealValue,
+{
+    fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = parents[0].len();
+        let num_parents = parents.len();
+        // breed one child for each partner in parents
+        let mut offspring: Vec<Vec<V>> = Vec::with_capacity(num_parents);
+        while num_parents > offspring.len() {
+            let genome = (0..genome_length)
+                .map(|locus| {
+                    let (min, max) = parents
+                        .iter()
+                        .map(|parent| parent[locus].to_f64())
+                        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                            (min.min(value), max.max(value))
+                        });
+                    let extension = self.alpha * (max - min);
+                    let value = min - extension + rng.gen::<f64>() * (max - min + 2. * extension);
+                    V::from_f64(value)
+                })
+                .collect();
+            offspring.push(genome);
+        }
+        offspring
+    }
+}
+
+/// The `SimulatedBinaryCrossBreeder` operator implements the simulated binary
+/// crossover (SBX) as used by NSGA-II. Two parents create two children that
+/// are spread symmetrically around the parents, where the spread follows a
+/// polynomial distribution controlled by the distribution index. Each locus
+/// is recombined with a probability of 0.5, otherwise the values of the
+/// parents are passed on unchanged.
+///
+/// A large distribution index creates children close to their parents, a
+/// small one children that are far away. Values between 2 and 20 are common.
+///
+/// The parents are paired in order, the last parent of an odd number of
+/// parents is paired with the first one. This crossover operator always
+/// creates as many child individuals as there are individuals in the given
+/// `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulatedBinaryCrossBreeder {
+    distribution_index: f64,
+}
+
+impl SimulatedBinaryCrossBreeder {
+    pub fn new(distribution_index: f64) -> Self {
+        SimulatedBinaryCrossBreeder { distribution_index }
+    }
+
+    /// Returns the distribution index of this operator.
+    pub fn distribution_index(&self) -> f64 {
+        self.distribution_index
+    }
+
+    /// Sets the distribution index of this operator to the given value.
+    pub fn set_distribution_index(&mut self, value: f64) {
+        self.distribution_index = value;
+    }
+}
+
+impl GeneticOperator for SimulatedBinaryCrossBreeder {
+    fn name() -> String {
+        "Simulated-Binary-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Vec<V>> for SimulatedBinaryCrossBreeder
+where
+    V: RealValue,
+{
+    fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = parents[0].len();
+        let num_parents = parents.len();
+        let exponent = 1. / (self.distribution_index + 1.);
+        // breed two children for each pair of parents
+        let mut offspring: Vec<Vec<V>> = Vec::with_capacity(num_parents + 1);
+        let mut index = 0;
+        while num_parents > offspring.len() {
+            let parent1 = &parents[index % num_parents];
+            let parent2 = &parents[(index + 1) % num_parents];
+            let mut child1 = parent1.clone();
+            let mut child2 = parent2.clone();
+            for locus in 0..genome_length {
+                if rng.gen::<f64>() > 0.5 {
+                    continue;
+                }
+                let value1 = parent1[locus].to_f64();
+                let value2 = parent2[locus].to_f64();
+                let u = rng.gen::<f64>();
+                let beta = if u <= 0.5 {
+                    (2. * u).powf(exponent)
+                } else {
+                    (1. / (2. * (1. - u))).powf(exponent)
+                };
+                child1[locus] = V::from_f64(0.5 * ((1. + beta) * value1 + (1. - beta) * value2));
+                child2[locus] = V::from_f64(0.5 * ((1. - beta) * value1 + (1. + beta) * value2));
+            }
+            offspring.push(child1);
+            offspring.push(child2);
+            index += 2;
+        }
+        offspring.truncate(num_parents);
+        offspring
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn arithmetic_crossover_children_lie_between_parents() {
+        let mut rng = get_rng(random_seed());
+        let p1 = vec![0., 10., -4.];
+        let p2 = vec![1., 20., 4.];
+
+        let children = ArithmeticCrossBreeder::new().crossover(vec![p1, p2], &mut rng);
+
+        expect_that!(&children.len(), eq(2));
+        for child in children {
+            expect_that!(&child[0], is(greater_than_or_equal(0.)));
+            expect_that!(&child[0], is(less_than_or_equal(1.)));
+            expect_that!(&child[1], is(greater_than_or_equal(10.)));
+            expect_that!(&child[1], is(less_than_or_equal(20.)));
+            expect_that!(&child[2], is(greater_than_or_equal(-4.)));
+            expect_that!(&child[2], is(less_than_or_equal(4.)));
+        }
+    }
+
+    #[test]
+    fn blend_crossover_children_lie_within_extended_interval() {
+        let mut rng = get_rng(random_seed());
+        let p1: Vec<f32> = vec![0., 10.];
+        let p2: Vec<f32> = vec![2., 10.];
+
+        for _ in 0..100 {
+            let children =
+                BlendCrossBreeder::new(0.5).crossover(vec![p1.clone(), p2.clone()], &mut rng);
+
+            expect_that!(&children.len(), eq(2));
+            for child in children {
+                expect_that!(&child[0], is(greater_than_or_equal(-1.)));
+                expect_that!(&child[0], is(less_than_or_equal(3.)));
+                expect_that!(&child[1], eq(10.));
+            }
+        }
+    }
+
+    #[test]
+    fn simulated_binary_crossover_preserves_the_mean_of_the_parents() {
+        let mut rng = get_rng(random_seed());
+        let p1: Vec<f64> = vec![1., -3., 0.5];
+        let p2 = vec![3., 5., 0.5];
+
+        for _ in 0..100 {
+            let children = SimulatedBinaryCrossBreeder::new(2.)
+                .crossover(vec![p1.clone(), p2.clone()], &mut rng);
+
+            expect_that!(&children.len(), eq(2));
+            for locus in 0..3 {
+                let mean = (children[0][locus] + children[1][locus]) / 2.;
+                let parents_mean = (p1[locus] + p2[locus]) / 2.;
+                expect_that!(&(mean - parents_mean).abs(), is(less_than(1e-9)));
+            }
+            expect_that!(&(children[0][2] - 0.5).abs(), is(less_than(1e-9)));
+            expect_that!(&(children[1][2] - 0.5).abs(), is(less_than(1e-9)));
+        }
+    }
+
+    #[test]
+    fn simulated_binary_crossover_creates_as_many_children_as_parents() {
+        let mut rng = get_rng(random_seed());
+        let parents = vec![vec![0f32, 1.], vec![2., 3.], vec![4., 5.]];
+
+        let children = SimulatedBinaryCrossBreeder::new(15.).crossover(parents, &mut rng);
+
+        expect_that!(&children.len(), eq(3));
+    }
+}
@@ -0,0 +1,156 @@
+//! The `tree` module provides `operator::CrossoverOp`s for tree encoded
+//! genotypes as used by genetic programming.
+//!
+//! The provided `operator::CrossoverOp`s are:
+//! * `SubtreeCrossBreeder` for `encoding::TreeGenome`.
+
+use crate::{
+    encoding::{Primitive, TreeGenome},
+    genetic::{Children, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::{random_index, Rng},
+};
+
+/// The `SubtreeCrossBreeder` operator picks a random node in each parent and
+/// in the next one. The child is a copy of its own parent with the subtree at
+/// its node replaced by the subtree at the node of the next parent.
+///
+/// The nodes are picked uniformly at random, so in trees of binary functions
+/// about half of the crossovers swap a leaf.
+///
+/// The depth of the children can be limited, see `with_max_depth`, which
+/// keeps the trees from bloating. A child that would be deeper is a copy of
+/// its own parent.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtreeCrossBreeder {
+    max_depth: usize,
+}
+
+impl SubtreeCrossBreeder {
+    /// Returns a new `SubtreeCrossBreeder` which breeds children of any
+    /// depth.
+    pub fn new() -> Self {
+        SubtreeCrossBreeder {
+            max_depth: usize::MAX,
+        }
+    }
+
+    /// Limits the depth of the children to the given `max_depth`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+impl Default for SubtreeCrossBreeder {
+    fn default() -> Self {
+        SubtreeCrossBreeder::new()
+    }
+}
+
+impl GeneticOperator for SubtreeCrossBreeder {
+    fn name() -> String {
+        "Subtree-Cross-Breeder".to_string()
+    }
+}
+
+impl<P> CrossoverOp<TreeGenome<P>> for SubtreeCrossBreeder
+where
+    P: Primitive,
+{
+    fn crossover<R>(&self, parents: Parents<TreeGenome<P>>, rng: &mut R) -> Children<TreeGenome<P>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len();
+        (0..num_parents)
+            .map(|index| {
+                let own = &parents[index];
+                let next = &parents[(index + 1) % num_parents];
+                let own_node = random_index(rng, own.size());
+                let next_node = random_index(rng, next.size());
+                let child = own.replace_subtree(own_node, next.subtree(next_node));
+                if child.depth() > self.max_depth {
+                    own.clone()
+                } else {
+                    child
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Op {
+        Zero,
+        One,
+        Add,
+    }
+
+    impl Primitive for Op {
+        fn arity(&self) -> usize {
+            match self {
+                Op::Zero | Op::One => 0,
+                Op::Add => 2,
+            }
+        }
+    }
+
+    // a complete tree of additions of the given depth with leaves of the given terminal
+    fn full_tree(depth: usize, leaf: Op) -> TreeGenome<Op> {
+        if depth == 0 {
+            TreeGenome::leaf(leaf)
+        } else {
+            TreeGenome::node(
+                Op::Add,
+                vec![
+                    full_tree(depth - 1, leaf.clone()),
+                    full_tree(depth - 1, leaf),
+                ],
+            )
+        }
+    }
+
+    #[test]
+    fn the_children_take_a_subtree_of_the_next_parent() {
+        let parents = vec![full_tree(3, Op::Zero), full_tree(3, Op::One)];
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..20 {
+            let children = SubtreeCrossBreeder::new().crossover(parents.clone(), &mut rng);
+
+            for (index, child) in children.iter().enumerate() {
+                let own_leaf = if index == 0 { Op::Zero } else { Op::One };
+                let own_leaves = child.nodes().iter().filter(|node| **node == own_leaf);
+                expect_that!(&own_leaves.count(), less_than(8));
+            }
+        }
+    }
+
+    #[test]
+    fn children_deeper_than_the_max_depth_are_copies_of_their_parent() {
+        let parents = vec![full_tree(2, Op::Zero), full_tree(2, Op::One)];
+        let breeder = SubtreeCrossBreeder::new().with_max_depth(2);
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..50 {
+            for child in breeder.crossover(parents.clone(), &mut rng) {
+                expect_that!(&child.depth(), less_than_or_equal(2));
+            }
+        }
+    }
+}
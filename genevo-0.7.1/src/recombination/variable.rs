@@ -0,0 +1,162 @@
+//! The `variable` module provides `operator::CrossoverOp`s for value encoded
+//! genotypes of variable length. The parents may differ in their length and
+//! the length of the children evolves along with their values.
+//!
+//! The provided `operator::CrossoverOp`s are:
+//! * `CutAndSpliceCrossBreeder` for `Vec` of any type.
+
+use crate::{
+    genetic::{Children, Parents},
+    operator::{CrossoverOp, GeneticOperator},
+    random::{random_index_from_range, Rng},
+};
+use std::fmt::Debug;
+
+/// The `CutAndSpliceCrossBreeder` operator cuts each parent and the next one
+/// at two independently chosen points. The child takes the values of its own
+/// parent before the first cut point and the values of the next parent after
+/// the second cut point, so it may be longer or shorter than both parents.
+///
+/// The length of the children can be kept within a minimum and a maximum
+/// length, see `with_length_limits`. A child whose parents are too short or
+/// too long to be cut into a child of a valid length is a copy of its own
+/// parent.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CutAndSpliceCrossBreeder {
+    min_length: usize,
+    max_length: usize,
+}
+
+impl CutAndSpliceCrossBreeder {
+    /// Returns a new `CutAndSpliceCrossBreeder` which breeds children of any
+    /// length.
+    pub fn new() -> Self {
+        CutAndSpliceCrossBreeder {
+            min_length: 0,
+            max_length: usize::MAX,
+        }
+    }
+
+    /// Limits the length of the children to the range between the given
+    /// `min_length` and `max_length` (both inclusive).
+    pub fn with_length_limits(mut self, min_length: usize, max_length: usize) -> Self {
+        assert!(
+            min_length <= max_length,
+            "the min length must not be greater than the max length"
+        );
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn min_length(&self) -> usize {
+        self.min_length
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl Default for CutAndSpliceCrossBreeder {
+    fn default() -> Self {
+        CutAndSpliceCrossBreeder::new()
+    }
+}
+
+impl GeneticOperator for CutAndSpliceCrossBreeder {
+    fn name() -> String {
+        "Cut-And-Splice-Cross-Breeder".to_string()
+    }
+}
+
+impl<V> CrossoverOp<Vec<V>> for CutAndSpliceCrossBreeder
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len();
+        (0..num_parents)
+            .map(|index| {
+                let own = &parents[index];
+                let next = &parents[(index + 1) % num_parents];
+                // the child takes `own[..head]` and `next[tail..]`, so the
+                // cut points must leave a length between the limits
+                let min_head = self.min_length.saturating_sub(next.len());
+                let max_head = own.len().min(self.max_length);
+                if min_head > max_head {
+                    return own.clone();
+                }
+                let head = random_index_from_range(rng, min_head, max_head + 1);
+                let min_tail = (head + next.len()).saturating_sub(self.max_length);
+                let max_tail = (head + next.len() - self.min_length).min(next.len());
+                let tail = random_index_from_range(rng, min_tail, max_tail + 1);
+                let mut child = Vec::with_capacity(head + next.len() - tail);
+                child.extend_from_slice(&own[..head]);
+                child.extend_from_slice(&next[tail..]);
+                child
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn the_children_are_spliced_from_the_head_and_the_tail_of_two_parents() {
+        let parents = vec![vec![0u8; 4], vec![1u8; 9]];
+
+        let children =
+            CutAndSpliceCrossBreeder::new().crossover(parents, &mut get_rng(random_seed()));
+
+        expect_that!(&children.len(), eq(2));
+        for (index, child) in children.iter().enumerate() {
+            let head = child
+                .iter()
+                .take_while(|value| **value == index as u8)
+                .count();
+            expect_that!(
+                &child[head..].iter().all(|value| *value != index as u8),
+                eq(true)
+            );
+        }
+    }
+
+    #[test]
+    fn the_length_of_the_children_stays_within_the_limits() {
+        let breeder = CutAndSpliceCrossBreeder::new().with_length_limits(3, 6);
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..100 {
+            let children = breeder.crossover(vec![vec![0u8; 2], vec![1u8; 12]], &mut rng);
+
+            for child in &children {
+                expect_that!(&child.len(), greater_than_or_equal(3));
+                expect_that!(&child.len(), less_than_or_equal(6));
+            }
+        }
+    }
+
+    #[test]
+    fn parents_too_short_for_the_min_length_are_copied() {
+        let breeder = CutAndSpliceCrossBreeder::new().with_length_limits(8, 10);
+
+        let children = breeder.crossover(
+            vec![vec![0u8; 2], vec![1u8; 3]],
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&children, eq(vec![vec![0u8; 2], vec![1u8; 3]]));
+    }
+}
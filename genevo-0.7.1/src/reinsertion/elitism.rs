@@ -0,0 +1,173 @@
+//! The `elitism` module provides an `operator::ReinsertionOp` that wraps
+//! another `operator::ReinsertionOp` and guarantees that the best individuals
+//! of the old population survive unchanged.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Fitness, Genotype, Offspring},
+    operator::{GeneticOperator, MultiObjective, ReinsertionOp, SingleObjective},
+    random::Rng,
+};
+
+/// The `ElitismReinserter` carries the `n_elites` best individuals of the old
+/// population over into the new population, whatever the replace ratio of
+/// the wrapped `operator::ReinsertionOp` is. The remaining spots are filled
+/// with the first individuals of the population combined by the wrapped
+/// reinserter, leaving out one copy of each elite it already contains.
+///
+/// The `ElitistReinserter` without offspring precedence puts the best
+/// individuals first, so those are the ones that are kept. With offspring
+/// precedence the last individuals of the old population are dropped.
+///
+/// An `ElitismReinserter` with 0 elites returns the population combined by
+/// the wrapped reinserter unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElitismReinserter<R> {
+    reinserter: R,
+    n_elites: usize,
+}
+
+impl<R> ElitismReinserter<R> {
+    pub fn new(reinserter: R, n_elites: usize) -> Self {
+        ElitismReinserter {
+            reinserter,
+            n_elites,
+        }
+    }
+
+    /// Returns the wrapped `operator::ReinsertionOp`.
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    /// Returns the number of elites.
+    pub fn n_elites(&self) -> usize {
+        self.n_elites
+    }
+
+    /// Sets the number of elites to the given value.
+    pub fn set_n_elites(&mut self, value: usize) {
+        self.n_elites = value;
+    }
+}
+
+impl<R> GeneticOperator for ElitismReinserter<R>
+where
+    R: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Elitism-{}", R::name())
+    }
+}
+
+impl<R> SingleObjective for ElitismReinserter<R> where R: SingleObjective {}
+
+impl<R> MultiObjective for ElitismReinserter<R> where R: MultiObjective {}
+
+impl<G, F, R> ReinsertionOp<G, F> for ElitismReinserter<R>
+where
+    G: Genotype,
+    F: Fitness,
+    R: ReinsertionOp<G, F>,
+{
+    fn combine<RNG>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut RNG,
+    ) -> Vec<G>
+    where
+        RNG: Rng + Sized,
+    {
+        let combined = self.reinserter.combine(offspring, evaluated, rng);
+        if self.n_elites == 0 {
+            return combined;
+        }
+
+        let old_individuals = evaluated.individuals();
+        let old_fitness_values = evaluated.fitness_values();
+        let mut old_population_indices: Vec<usize> = (0..old_fitness_values.len()).collect();
+        // sort fitness indices from best performing to worst performing index
+        old_population_indices.sort_by(|x, y| old_fitness_values[*y].cmp(&old_fitness_values[*x]));
+
+        let population_size = combined.len();
+        let mut new_population: Vec<G> = old_population_indices
+            .iter()
+            .take(self.n_elites.min(population_size))
+            .map(|index| old_individuals[*index].clone())
+            .collect();
+        // the elites the wrapped reinserter has already kept are not taken twice
+        let mut kept_elites = vec![false; new_population.len()];
+        for genome in combined {
+            if new_population.len() == population_size {
+                break;
+            }
+            match (0..kept_elites.len()).find(|i| !kept_elites[*i] && new_population[*i] == genome)
+            {
+                Some(elite) => kept_elites[elite] = true,
+                None => new_population.push(genome),
+            }
+        }
+        new_population
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.reinserter.adapt(improved);
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.reinserter.is_restarting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::{get_rng, random_seed},
+        reinsertion::test_util::OffspringOnly,
+    };
+    use galvanic_assert::matchers::*;
+    use std::rc::Rc;
+
+    fn population(fitness_values: Vec<usize>) -> EvaluatedPopulation<Vec<usize>, usize> {
+        let individuals = fitness_values.iter().map(|f| vec![*f]).collect();
+        let highest = *fitness_values.iter().max().unwrap();
+        let lowest = *fitness_values.iter().min().unwrap();
+        EvaluatedPopulation::new(Rc::new(individuals), fitness_values, highest, lowest, 0)
+    }
+
+    fn combine(n_elites: usize, offspring: Vec<usize>, fitness_values: Vec<usize>) -> Vec<usize> {
+        let mut offspring = offspring.into_iter().map(|f| vec![f]).collect();
+        ElitismReinserter::new(OffspringOnly, n_elites)
+            .combine(
+                &mut offspring,
+                &population(fitness_values),
+                &mut get_rng(random_seed()),
+            )
+            .into_iter()
+            .map(|genome| genome[0])
+            .collect()
+    }
+
+    #[test]
+    fn the_elites_survive_a_complete_replacement() {
+        let new_population = combine(2, vec![0, 2, 4, 6, 8], vec![3, 9, 1, 7, 5]);
+
+        expect_that!(&new_population, eq(vec![9, 7, 0, 2, 4]));
+    }
+
+    #[test]
+    fn elites_kept_by_the_wrapped_reinserter_are_not_duplicated() {
+        let new_population = combine(2, vec![9, 9, 0], vec![3, 9, 1]);
+
+        expect_that!(&new_population, eq(vec![9, 3, 9]));
+    }
+
+    #[test]
+    fn no_elites_leave_the_combined_population_unchanged() {
+        let new_population = combine(0, vec![0, 2, 4], vec![3, 9, 1]);
+
+        expect_that!(&new_population, eq(vec![0, 2, 4]));
+    }
+}
@@ -149,11 +149,9 @@ where
             // first pick individuals from offspring
             if num_offspring < offspring.len() {
                 // evaluate fitness of the offspring individuals
-                let mut offspring_fitness: Vec<(G, F)> = Vec::with_capacity(offspring.len());
-                while let Some(child) = offspring.pop() {
-                    let fitness = self.fitness_evaluator.fitness_of(&child);
-                    offspring_fitness.push((child, fitness));
-                }
+                let fitness_values = self.fitness_evaluator.fitness_of_all(offspring);
+                let mut offspring_fitness: Vec<(G, F)> =
+                    offspring.drain(..).zip(fitness_values).rev().collect();
                 // sort offspring from worst to best performing performing
                 offspring_fitness.sort_by(|x, y| x.1.cmp(&y.1));
                 // pick only the best individuals from the offspring
@@ -173,11 +171,9 @@ where
             }
         } else {
             // evaluate fitness of the offspring individuals
-            let mut offspring_fitness: Vec<(G, F)> = Vec::with_capacity(offspring.len());
-            while let Some(child) = offspring.pop() {
-                let fitness = self.fitness_evaluator.fitness_of(&child);
-                offspring_fitness.push((child, fitness));
-            }
+            let fitness_values = self.fitness_evaluator.fitness_of_all(offspring);
+            let mut offspring_fitness: Vec<(G, F)> =
+                offspring.drain(..).zip(fitness_values).rev().collect();
             // sort offspring from worst to best performing performing
             offspring_fitness.sort_by(|x, y| x.1.cmp(&y.1));
             for _ in 0..population_size {
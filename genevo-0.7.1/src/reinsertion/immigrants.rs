@@ -0,0 +1,321 @@
+//! The `immigrants` module provides `operator::ReinsertionOp`s that wrap
+//! another `operator::ReinsertionOp` and bring random immigrants into the
+//! population: the `ImmigrantReinserter` in place of exact duplicates in the
+//! offspring, the `ImmigrationReinserter` in place of the worst individuals
+//! of each generation.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Fitness, FitnessFunction, Genotype, Offspring},
+    operator::{GeneticOperator, MultiObjective, ReinsertionOp, SingleObjective},
+    population::GenomeBuilder,
+    random::Rng,
+};
+
+/// The `ImmigrantReinserter` rejects each child of the offspring that is an
+/// exact duplicate of an individual of the old population or of another
+/// child, and replaces it with a random immigrant built by the given
+/// `population::GenomeBuilder`. Then the offspring is combined with the old
+/// population by the wrapped `operator::ReinsertionOp`.
+///
+/// Duplicates waste the evaluations of a generation, and a population that
+/// has collapsed to a few genomes hardly recombines into anything new. The
+/// immigrants bring fresh genetic material into the population instead.
+///
+/// Finding the duplicates compares each child with the whole old population,
+/// which takes time quadratic in the population size. An inactive
+/// `ImmigrantReinserter` leaves the offspring unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImmigrantReinserter<R, B> {
+    reinserter: R,
+    immigrant_builder: B,
+    active: bool,
+}
+
+impl<R, B> ImmigrantReinserter<R, B> {
+    /// Constructs a new, active instance of the `ImmigrantReinserter`.
+    pub fn new(reinserter: R, immigrant_builder: B) -> Self {
+        ImmigrantReinserter {
+            reinserter,
+            immigrant_builder,
+            active: true,
+        }
+    }
+
+    /// Returns the wrapped `operator::ReinsertionOp`.
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    /// Returns true if the duplicates are replaced by immigrants.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Sets whether the duplicates are replaced by immigrants.
+    pub fn set_active(&mut self, value: bool) {
+        self.active = value;
+    }
+}
+
+impl<R, B> GeneticOperator for ImmigrantReinserter<R, B>
+where
+    R: GeneticOperator,
+    B: Clone,
+{
+    fn name() -> String {
+        format!("Immigrant-{}", R::name())
+    }
+}
+
+impl<R, B> SingleObjective for ImmigrantReinserter<R, B> where R: SingleObjective {}
+
+impl<R, B> MultiObjective for ImmigrantReinserter<R, B> where R: MultiObjective {}
+
+impl<G, F, R, B> ReinsertionOp<G, F> for ImmigrantReinserter<R, B>
+where
+    G: Genotype,
+    F: Fitness,
+    R: ReinsertionOp<G, F>,
+    B: GenomeBuilder<G> + Clone,
+{
+    fn combine<RNG>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut RNG,
+    ) -> Vec<G>
+    where
+        RNG: Rng + Sized,
+    {
+        if self.active {
+            let old_individuals = evaluated.individuals();
+            for index in 0..offspring.len() {
+                let child = &offspring[index];
+                if old_individuals.contains(child) || offspring[..index].contains(child) {
+                    offspring[index] = self.immigrant_builder.build_genome(index, rng);
+                }
+            }
+        }
+        self.reinserter.combine(offspring, evaluated, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.reinserter.adapt(improved);
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.reinserter.is_restarting()
+    }
+}
+
+/// The `ImmigrationReinserter` replaces the worst individuals of the
+/// population combined by the wrapped `operator::ReinsertionOp` with random
+/// immigrants built by the given `population::GenomeBuilder`. The
+/// `immigrant_rate` is the fraction of the population that is replaced each
+/// generation.
+///
+/// Like mutation, the immigrants keep the population diverse, but they bring
+/// in whole new genomes instead of changing single loci of the existing ones.
+///
+/// The combined population is ranked by the given `FitnessFunction`. An
+/// `ImmigrationReinserter` with an immigrant rate of 0 returns the combined
+/// population unchanged, without evaluating it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImmigrationReinserter<R, B, E> {
+    reinserter: R,
+    immigrant_builder: B,
+    fitness_evaluator: E,
+    immigrant_rate: f64,
+}
+
+impl<R, B, E> ImmigrationReinserter<R, B, E> {
+    pub fn new(
+        reinserter: R,
+        immigrant_builder: B,
+        fitness_evaluator: E,
+        immigrant_rate: f64,
+    ) -> Self {
+        ImmigrationReinserter {
+            reinserter,
+            immigrant_builder,
+            fitness_evaluator,
+            immigrant_rate,
+        }
+    }
+
+    /// Returns the wrapped `operator::ReinsertionOp`.
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    /// Returns the `immigrant_rate` of this `ImmigrationReinserter`.
+    pub fn immigrant_rate(&self) -> f64 {
+        self.immigrant_rate
+    }
+
+    /// Set the `immigrant_rate` of this `ImmigrationReinserter` to the given
+    /// value. The value must be between 0 and 1.0 (inclusive).
+    pub fn set_immigrant_rate(&mut self, value: f64) {
+        self.immigrant_rate = value;
+    }
+}
+
+impl<R, B, E> GeneticOperator for ImmigrationReinserter<R, B, E>
+where
+    R: GeneticOperator,
+    B: Clone,
+    E: Clone,
+{
+    fn name() -> String {
+        format!("Immigration-{}", R::name())
+    }
+}
+
+impl<R, B, E> SingleObjective for ImmigrationReinserter<R, B, E> where R: SingleObjective {}
+
+impl<G, F, R, B, E> ReinsertionOp<G, F> for ImmigrationReinserter<R, B, E>
+where
+    G: Genotype,
+    F: Fitness,
+    R: ReinsertionOp<G, F>,
+    B: GenomeBuilder<G> + Clone,
+    E: FitnessFunction<G, F>,
+{
+    fn combine<RNG>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut RNG,
+    ) -> Vec<G>
+    where
+        RNG: Rng + Sized,
+    {
+        let mut new_population = self.reinserter.combine(offspring, evaluated, rng);
+        let num_immigrants =
+            (new_population.len() as f64 * self.immigrant_rate + 0.5).floor() as usize;
+        if num_immigrants == 0 {
+            return new_population;
+        }
+
+        let fitness_values = self.fitness_evaluator.fitness_of_all(&new_population);
+        let mut indices: Vec<usize> = (0..new_population.len()).collect();
+        // sort indices from worst performing to best performing individual
+        indices.sort_by(|x, y| fitness_values[*x].cmp(&fitness_values[*y]));
+        for index in indices.into_iter().take(num_immigrants) {
+            new_population[index] = self.immigrant_builder.build_genome(index, rng);
+        }
+        new_population
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.reinserter.adapt(improved);
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.reinserter.is_restarting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::{get_rng, random_seed},
+        reinsertion::test_util::OffspringOnly,
+    };
+    use galvanic_assert::matchers::*;
+    use std::rc::Rc;
+
+    // Builds immigrants that tell the index they replace
+    #[derive(Clone, Debug, PartialEq)]
+    struct IndexBuilder;
+
+    impl GenomeBuilder<Vec<usize>> for IndexBuilder {
+        fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<usize>
+        where
+            R: Rng + Sized,
+        {
+            vec![100 + index]
+        }
+    }
+
+    fn combine(active: bool, offspring: Vec<usize>, old_population: Vec<usize>) -> Vec<usize> {
+        let mut offspring = offspring.into_iter().map(|f| vec![f]).collect();
+        let individuals = old_population.iter().map(|f| vec![*f]).collect();
+        let evaluated = EvaluatedPopulation::new(Rc::new(individuals), old_population, 0, 0, 0);
+        let mut reinserter = ImmigrantReinserter::new(OffspringOnly, IndexBuilder);
+        reinserter.set_active(active);
+        reinserter
+            .combine(&mut offspring, &evaluated, &mut get_rng(random_seed()))
+            .into_iter()
+            .map(|genome| genome[0])
+            .collect()
+    }
+
+    #[test]
+    fn duplicates_of_the_old_population_are_replaced_by_immigrants() {
+        let new_population = combine(true, vec![1, 5, 2, 6], vec![1, 2, 3, 4]);
+
+        expect_that!(&new_population, eq(vec![100, 5, 102, 6]));
+    }
+
+    #[test]
+    fn duplicates_within_the_offspring_are_replaced_by_immigrants() {
+        let new_population = combine(true, vec![5, 5, 6, 5], vec![1, 2, 3, 4]);
+
+        expect_that!(&new_population, eq(vec![5, 101, 6, 103]));
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Identity;
+
+    impl FitnessFunction<Vec<usize>, usize> for Identity {
+        fn fitness_of(&self, genome: &Vec<usize>) -> usize {
+            genome[0]
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            usize::MAX
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    fn immigrate(immigrant_rate: f64, offspring: Vec<usize>) -> Vec<usize> {
+        let mut offspring = offspring.into_iter().map(|f| vec![f]).collect();
+        let evaluated = EvaluatedPopulation::new(Rc::new(vec![]), vec![], 0, 0, 0);
+        ImmigrationReinserter::new(OffspringOnly, IndexBuilder, Identity, immigrant_rate)
+            .combine(&mut offspring, &evaluated, &mut get_rng(random_seed()))
+            .into_iter()
+            .map(|genome| genome[0])
+            .collect()
+    }
+
+    #[test]
+    fn the_worst_individuals_are_replaced_by_immigrants() {
+        let new_population = immigrate(0.5, vec![7, 3, 9, 1]);
+
+        expect_that!(&new_population, eq(vec![7, 101, 9, 103]));
+    }
+
+    #[test]
+    fn an_immigrant_rate_of_zero_keeps_the_population() {
+        let new_population = immigrate(0., vec![7, 3, 9, 1]);
+
+        expect_that!(&new_population, eq(vec![7, 3, 9, 1]));
+    }
+
+    #[test]
+    fn an_inactive_reinserter_keeps_the_duplicates() {
+        let new_population = combine(false, vec![1, 5, 5, 6], vec![1, 2, 3, 4]);
+
+        expect_that!(&new_population, eq(vec![1, 5, 5, 6]));
+    }
+}
@@ -4,7 +4,20 @@
 //! The provided implementations of the `operator:ReinsertionOp` are:
 //! * `random::UniformReinserter`
 //! * `elitist::ElitistReinserter`
+//! * `elitism::ElitismReinserter` which wraps another reinserter
+//! * `immigrants::ImmigrantReinserter` which wraps another reinserter
+//! * `immigrants::ImmigrationReinserter` which wraps another reinserter
+//! * `restart::RestartReinserter` which wraps another reinserter
 
 pub mod random;
 
 pub mod elitist;
+
+pub mod elitism;
+
+pub mod immigrants;
+
+pub mod restart;
+
+#[cfg(test)]
+pub(crate) mod test_util;
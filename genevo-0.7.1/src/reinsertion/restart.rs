@@ -0,0 +1,238 @@
+//! The `restart` module provides an `operator::ReinsertionOp` that wraps
+//! another `operator::ReinsertionOp` and restarts the population when the
+//! search stagnates.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Fitness, Genotype, Offspring},
+    operator::{GeneticOperator, MultiObjective, ReinsertionOp, SingleObjective},
+    population::GenomeBuilder,
+    random::Rng,
+};
+
+/// The `RestartReinserter` restarts the population once the best fitness
+/// has not improved for `stagnation_limit` generations: the `n_elites` best
+/// individuals of the old population are kept and all others are replaced
+/// with new individuals built by the given `population::GenomeBuilder`.
+/// In all other generations the population combined by the wrapped
+/// `operator::ReinsertionOp` is returned unchanged.
+///
+/// A population that has converged to a local optimum hardly gets away from
+/// it by mutation alone. The restart spreads the search again while the
+/// elites keep the best solutions found so far. After a restart the
+/// generations without improvement are counted anew.
+///
+/// The generations are counted by the calls of the `adapt` hook. A
+/// `stagnation_limit` of 0 never restarts the population.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestartReinserter<R, B> {
+    reinserter: R,
+    genome_builder: B,
+    stagnation_limit: u64,
+    n_elites: usize,
+    generation: u64,
+    stagnant_generations: u64,
+    restarts: Vec<u64>,
+}
+
+impl<R, B> RestartReinserter<R, B> {
+    pub fn new(reinserter: R, genome_builder: B, stagnation_limit: u64, n_elites: usize) -> Self {
+        RestartReinserter {
+            reinserter,
+            genome_builder,
+            stagnation_limit,
+            n_elites,
+            generation: 0,
+            stagnant_generations: 0,
+            restarts: Vec::new(),
+        }
+    }
+
+    /// Returns the wrapped `operator::ReinsertionOp`.
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    /// Returns the number of generations without improvement after which
+    /// the population is restarted.
+    pub fn stagnation_limit(&self) -> u64 {
+        self.stagnation_limit
+    }
+
+    /// Returns the number of elites kept by a restart.
+    pub fn n_elites(&self) -> usize {
+        self.n_elites
+    }
+
+    /// Returns the generations whose offspring has been combined into a
+    /// restarted population, in ascending order.
+    pub fn restarts(&self) -> &[u64] {
+        &self.restarts
+    }
+
+    fn is_restarting_now(&self) -> bool {
+        self.restarts.last() == Some(&self.generation)
+    }
+}
+
+impl<R, B> GeneticOperator for RestartReinserter<R, B>
+where
+    R: GeneticOperator,
+    B: Clone,
+{
+    fn name() -> String {
+        format!("Restart-{}", R::name())
+    }
+}
+
+impl<R, B> SingleObjective for RestartReinserter<R, B> where R: SingleObjective {}
+
+impl<R, B> MultiObjective for RestartReinserter<R, B> where R: MultiObjective {}
+
+impl<G, F, R, B> ReinsertionOp<G, F> for RestartReinserter<R, B>
+where
+    G: Genotype,
+    F: Fitness,
+    R: ReinsertionOp<G, F>,
+    B: GenomeBuilder<G> + Clone,
+{
+    fn combine<RNG>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut RNG,
+    ) -> Vec<G>
+    where
+        RNG: Rng + Sized,
+    {
+        let combined = self.reinserter.combine(offspring, evaluated, rng);
+        if !self.is_restarting_now() {
+            return combined;
+        }
+
+        let old_individuals = evaluated.individuals();
+        let old_fitness_values = evaluated.fitness_values();
+        let mut old_population_indices: Vec<usize> = (0..old_fitness_values.len()).collect();
+        // sort fitness indices from best performing to worst performing index
+        old_population_indices.sort_by(|x, y| old_fitness_values[*y].cmp(&old_fitness_values[*x]));
+
+        let population_size = combined.len();
+        let mut new_population: Vec<G> = old_population_indices
+            .iter()
+            .take(self.n_elites.min(population_size))
+            .map(|index| old_individuals[*index].clone())
+            .collect();
+        for index in new_population.len()..population_size {
+            new_population.push(self.genome_builder.build_genome(index, rng));
+        }
+        new_population
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.reinserter.adapt(improved);
+        self.generation += 1;
+        if improved {
+            self.stagnant_generations = 0;
+        } else {
+            self.stagnant_generations += 1;
+        }
+        if self.stagnation_limit > 0 && self.stagnant_generations >= self.stagnation_limit {
+            self.stagnant_generations = 0;
+            self.restarts.push(self.generation);
+        }
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.is_restarting_now() || self.reinserter.is_restarting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::{get_rng, random_seed},
+        reinsertion::test_util::OffspringOnly,
+    };
+    use galvanic_assert::matchers::*;
+    use std::rc::Rc;
+
+    // Builds genomes that tell the restarted individuals apart from the offspring
+    #[derive(Clone, Debug, PartialEq)]
+    struct Newcomer;
+
+    impl GenomeBuilder<Vec<usize>> for Newcomer {
+        fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<usize>
+        where
+            R: Rng + Sized,
+        {
+            vec![100 + index]
+        }
+    }
+
+    fn population(fitness_values: Vec<usize>) -> EvaluatedPopulation<Vec<usize>, usize> {
+        let individuals = fitness_values.iter().map(|f| vec![*f]).collect();
+        let highest = *fitness_values.iter().max().unwrap();
+        let lowest = *fitness_values.iter().min().unwrap();
+        EvaluatedPopulation::new(Rc::new(individuals), fitness_values, highest, lowest, 0)
+    }
+
+    fn combine(reinserter: &RestartReinserter<OffspringOnly, Newcomer>) -> Vec<usize> {
+        let mut offspring = vec![vec![0], vec![2], vec![4], vec![6]];
+        reinserter
+            .combine(
+                &mut offspring,
+                &population(vec![3, 9, 1, 7]),
+                &mut get_rng(random_seed()),
+            )
+            .into_iter()
+            .map(|genome| genome[0])
+            .collect()
+    }
+
+    fn adapt(reinserter: &mut RestartReinserter<OffspringOnly, Newcomer>, improved: bool) {
+        ReinsertionOp::<Vec<usize>, usize>::adapt(reinserter, improved);
+    }
+
+    fn is_restarting(reinserter: &RestartReinserter<OffspringOnly, Newcomer>) -> bool {
+        ReinsertionOp::<Vec<usize>, usize>::is_restarting(reinserter)
+    }
+
+    #[test]
+    fn a_stagnating_population_is_restarted_around_its_elites() {
+        let mut reinserter = RestartReinserter::new(OffspringOnly, Newcomer, 2, 2);
+
+        adapt(&mut reinserter, true);
+        adapt(&mut reinserter, false);
+        expect_that!(&is_restarting(&reinserter), eq(false));
+        expect_that!(&combine(&reinserter), eq(vec![0, 2, 4, 6]));
+
+        adapt(&mut reinserter, false);
+        expect_that!(&is_restarting(&reinserter), eq(true));
+        expect_that!(&combine(&reinserter), eq(vec![9, 7, 102, 103]));
+        expect_that!(&reinserter.restarts().to_vec(), eq(vec![3]));
+    }
+
+    #[test]
+    fn the_stagnation_is_counted_anew_after_a_restart_or_an_improvement() {
+        let mut reinserter = RestartReinserter::new(OffspringOnly, Newcomer, 2, 1);
+
+        for improved in [true, false, false, false, true, false, false] {
+            adapt(&mut reinserter, improved);
+        }
+
+        expect_that!(&reinserter.restarts().to_vec(), eq(vec![3, 7]));
+    }
+
+    #[test]
+    fn a_stagnation_limit_of_0_never_restarts() {
+        let mut reinserter = RestartReinserter::new(OffspringOnly, Newcomer, 0, 1);
+
+        for _ in 0..10 {
+            adapt(&mut reinserter, false);
+        }
+
+        expect_that!(&is_restarting(&reinserter), eq(false));
+        expect_that!(&combine(&reinserter), eq(vec![0, 2, 4, 6]));
+    }
+}
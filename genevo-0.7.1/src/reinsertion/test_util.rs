@@ -0,0 +1,34 @@
+//! Reinserters shared by the tests of the reinserters that wrap another
+//! reinserter.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::Offspring,
+    operator::{GeneticOperator, ReinsertionOp},
+    random::Rng,
+};
+
+/// Replaces the whole population by the offspring, in the order of the
+/// offspring.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OffspringOnly;
+
+impl GeneticOperator for OffspringOnly {
+    fn name() -> String {
+        "Offspring-Only".to_string()
+    }
+}
+
+impl ReinsertionOp<Vec<usize>, usize> for OffspringOnly {
+    fn combine<R>(
+        &self,
+        offspring: &mut Offspring<Vec<usize>>,
+        _: &EvaluatedPopulation<Vec<usize>, usize>,
+        _: &mut R,
+    ) -> Vec<Vec<usize>>
+    where
+        R: Rng + Sized,
+    {
+        std::mem::take(offspring)
+    }
+}
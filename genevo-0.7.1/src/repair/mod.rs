@@ -0,0 +1,117 @@
+//! The `repair` module provides `operator::RepairOp`s which make the
+//! offspring of the genetic algorithm satisfy the constraints of a problem.
+//!
+//! The provided `operator::RepairOp`s are:
+//! * `NoRepair` which leaves the offspring unchanged. It is the default of
+//!   the `ga::GeneticAlgorithm`.
+//! * `RepairFn` (created by `repair_with`) which wraps a user closure, e.g.
+//!   one that fixes the children exceeding the capacity of a knapsack.
+
+use crate::{
+    genetic::Genotype,
+    operator::{GeneticOperator, RepairOp},
+    random::{Rng, RngCore},
+};
+use std::fmt::{self, Debug};
+
+/// The `NoRepair` operator returns each genome unchanged, i.e. the genetic
+/// algorithm does not repair the offspring.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoRepair;
+
+impl GeneticOperator for NoRepair {
+    fn name() -> String {
+        "No-Repair".to_string()
+    }
+}
+
+impl<G> RepairOp<G> for NoRepair
+where
+    G: Genotype,
+{
+    fn repair<R>(&self, genome: G, _: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        genome
+    }
+}
+
+/// Creates a `RepairFn` operator from the given closure.
+pub fn repair_with<F, G>(repair: F) -> RepairFn<F>
+where
+    F: Fn(G, &mut dyn RngCore) -> G + Clone,
+    G: Genotype,
+{
+    RepairFn::new(repair)
+}
+
+/// The `RepairFn` operator repairs each genome by the wrapped closure. The
+/// closure gets the genome and the random number generator of the genetic
+/// algorithm and returns the valid genome, so it can e.g. flip random loci
+/// until a constraint is satisfied.
+#[derive(Clone)]
+pub struct RepairFn<F> {
+    repair: F,
+}
+
+impl<F> RepairFn<F> {
+    pub fn new(repair: F) -> Self {
+        RepairFn { repair }
+    }
+}
+
+impl<F> Debug for RepairFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RepairFn").finish_non_exhaustive()
+    }
+}
+
+impl<F> GeneticOperator for RepairFn<F>
+where
+    F: Clone,
+{
+    fn name() -> String {
+        "Repair-Fn".to_string()
+    }
+}
+
+impl<F, G> RepairOp<G> for RepairFn<F>
+where
+    F: Fn(G, &mut dyn RngCore) -> G + Clone,
+    G: Genotype,
+{
+    fn repair<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        (self.repair)(genome, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn the_closure_repairs_the_genome_with_the_rng_of_the_algorithm() {
+        // keep at most 3 of the loci set
+        let repair = repair_with(|mut genome: Vec<bool>, rng: &mut dyn RngCore| {
+            while genome.iter().filter(|locus| **locus).count() > 3 {
+                let locus = rng.gen_range(0..genome.len());
+                genome[locus] = false;
+            }
+            genome
+        });
+
+        let repaired = repair.repair(vec![true; 8], &mut get_rng(random_seed()));
+
+        expect_that!(&repaired.iter().filter(|locus| **locus).count(), eq(3));
+        expect_that!(
+            &NoRepair.repair(vec![true; 8], &mut get_rng(random_seed())),
+            eq(vec![true; 8])
+        );
+    }
+}
@@ -0,0 +1,462 @@
+//! The `scalarization` module provides fitness functions that combine the
+//! fitness values of several `genetic::FitnessFunction`s, the objectives,
+//! into one scalar `genetic::Fitness` value. This way the genetic algorithm
+//! trades the objectives off by their weights, without the multi-objective
+//! machinery of `nsga2::Nsga2`.
+//!
+//! The fitness of each objective is first normalized to the range from 0 at
+//! its lowest to 1 at its highest possible fitness, so the weights do not
+//! depend on the scales of the objectives. The combined fitness ranges from 0
+//! to the `scale` of the combinator.
+//!
+//! The provided combinators are:
+//! * `WeightedSumFitness` which takes the weighted mean of the normalized
+//!   fitness values. It only finds the solutions on the convex hull of the
+//!   Pareto front, whatever the weights.
+//! * `ChebyshevFitness` which takes the largest weighted distance of the
+//!   normalized fitness values to their highest possible one. With suitable
+//!   weights it finds any solution of the Pareto front.
+
+use crate::genetic::{AsScalar, Fitness, FitnessFunction, FromScalar, Genotype};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// The default highest fitness of the combinators.
+pub const DEFAULT_SCALE: f64 = 1000.;
+
+/// An objective with its fitness normalized to the range from 0 to 1.
+trait Objective<G>: Send + Sync {
+    fn normalized_fitness_of(&self, genome: &G) -> f64;
+
+    fn adapt(&self, generation: u64);
+}
+
+struct Normalized<E, F> {
+    fitness_function: E,
+    lowest: f64,
+    highest: f64,
+    _fitness: PhantomData<fn() -> F>,
+}
+
+impl<G, F, E> Objective<G> for Normalized<E, F>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F> + Send + Sync,
+{
+    fn normalized_fitness_of(&self, genome: &G) -> f64 {
+        if self.highest <= self.lowest {
+            return 1.;
+        }
+        let fitness = self.fitness_function.fitness_of(genome).as_scalar();
+        ((fitness - self.lowest) / (self.highest - self.lowest)).clamp(0., 1.)
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.fitness_function.adapt(generation);
+    }
+}
+
+/// The weighted objectives of a combinator.
+struct WeightedObjectives<G> {
+    objectives: Vec<(Arc<dyn Objective<G>>, f64)>,
+    scale: f64,
+}
+
+impl<G> WeightedObjectives<G>
+where
+    G: Genotype,
+{
+    fn new() -> Self {
+        WeightedObjectives {
+            objectives: Vec::new(),
+            scale: DEFAULT_SCALE,
+        }
+    }
+
+    fn push<F, E>(&mut self, fitness_function: E, weight: f64)
+    where
+        F: Fitness + AsScalar + 'static,
+        E: FitnessFunction<G, F> + Send + Sync + 'static,
+    {
+        assert!(
+            weight.is_finite() && weight >= 0.,
+            "the weight of an objective must be finite and not negative, but is {}",
+            weight
+        );
+        let objective = Normalized {
+            lowest: fitness_function.lowest_possible_fitness().as_scalar(),
+            highest: fitness_function.highest_possible_fitness().as_scalar(),
+            fitness_function,
+            _fitness: PhantomData,
+        };
+        self.objectives.push((Arc::new(objective), weight));
+    }
+
+    fn weights(&self) -> Vec<f64> {
+        self.objectives.iter().map(|(_, weight)| *weight).collect()
+    }
+
+    fn normalized_fitness_of(&self, genome: &G) -> Vec<f64> {
+        self.objectives
+            .iter()
+            .map(|(objective, _)| objective.normalized_fitness_of(genome))
+            .collect()
+    }
+
+    fn average<F>(&self, values: &[F]) -> F
+    where
+        F: AsScalar + FromScalar,
+    {
+        F::from_scalar(values.iter().map(AsScalar::as_scalar).sum::<f64>() / values.len() as f64)
+    }
+
+    fn adapt(&self, generation: u64) {
+        for (objective, _) in &self.objectives {
+            objective.adapt(generation);
+        }
+    }
+}
+
+impl<G> Clone for WeightedObjectives<G> {
+    fn clone(&self) -> Self {
+        WeightedObjectives {
+            objectives: self.objectives.clone(),
+            scale: self.scale,
+        }
+    }
+}
+
+impl<G> Debug for WeightedObjectives<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WeightedObjectives")
+            .field(
+                "weights",
+                &self
+                    .objectives
+                    .iter()
+                    .map(|(_, weight)| weight)
+                    .collect::<Vec<_>>(),
+            )
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+/// The `WeightedSumFitness` combines the objectives by the weighted mean of
+/// their normalized fitness values, i.e. its fitness is
+/// `scale * Sum(w * f) / Sum(w)`. Without any objective of a positive weight
+/// the fitness is 0.
+#[derive(Clone, Debug)]
+pub struct WeightedSumFitness<G> {
+    objectives: WeightedObjectives<G>,
+}
+
+impl<G> WeightedSumFitness<G>
+where
+    G: Genotype,
+{
+    pub fn new() -> Self {
+        WeightedSumFitness {
+            objectives: WeightedObjectives::new(),
+        }
+    }
+
+    /// Adds an objective with the given weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weight is negative or not finite.
+    pub fn with_objective<F, E>(mut self, fitness_function: E, weight: f64) -> Self
+    where
+        F: Fitness + AsScalar + 'static,
+        E: FitnessFunction<G, F> + Send + Sync + 'static,
+    {
+        self.objectives.push(fitness_function, weight);
+        self
+    }
+
+    /// Sets the highest possible fitness, `DEFAULT_SCALE` by default. With
+    /// an integer fitness it is the resolution of the combined fitness.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.objectives.scale = scale;
+        self
+    }
+
+    /// Returns the number of objectives.
+    pub fn num_objectives(&self) -> usize {
+        self.objectives.objectives.len()
+    }
+
+    /// Returns the weights of the objectives.
+    pub fn weights(&self) -> Vec<f64> {
+        self.objectives.weights()
+    }
+
+    /// Returns the highest possible fitness.
+    pub fn scale(&self) -> f64 {
+        self.objectives.scale
+    }
+
+    /// Returns the normalized fitness of the genome by each objective.
+    pub fn normalized_fitness_of(&self, genome: &G) -> Vec<f64> {
+        self.objectives.normalized_fitness_of(genome)
+    }
+}
+
+impl<G> Default for WeightedSumFitness<G>
+where
+    G: Genotype,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, F> FitnessFunction<G, F> for WeightedSumFitness<G>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        let total_weight: f64 = self.weights().iter().sum();
+        if total_weight <= 0. {
+            return F::from_scalar(0.);
+        }
+        let weighted_sum: f64 = self
+            .normalized_fitness_of(genome)
+            .iter()
+            .zip(self.weights())
+            .map(|(fitness, weight)| fitness * weight)
+            .sum();
+        F::from_scalar(self.scale() * weighted_sum / total_weight)
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        self.objectives.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        F::from_scalar(self.scale())
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        F::from_scalar(0.)
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.objectives.adapt(generation);
+    }
+}
+
+/// The `ChebyshevFitness` combines the objectives by the largest weighted
+/// distance of their normalized fitness values to the highest possible
+/// fitness, the ideal point, i.e. its fitness is
+/// `scale * (1 - Max(w * (1 - f)) / Max(w))`. The objective that is the
+/// furthest behind decides the fitness, so raising the weight of an
+/// objective moves the solutions found towards its end of the Pareto front.
+/// Without any objective of a positive weight the fitness is 0.
+#[derive(Clone, Debug)]
+pub struct ChebyshevFitness<G> {
+    objectives: WeightedObjectives<G>,
+}
+
+impl<G> ChebyshevFitness<G>
+where
+    G: Genotype,
+{
+    pub fn new() -> Self {
+        ChebyshevFitness {
+            objectives: WeightedObjectives::new(),
+        }
+    }
+
+    /// Adds an objective with the given weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weight is negative or not finite.
+    pub fn with_objective<F, E>(mut self, fitness_function: E, weight: f64) -> Self
+    where
+        F: Fitness + AsScalar + 'static,
+        E: FitnessFunction<G, F> + Send + Sync + 'static,
+    {
+        self.objectives.push(fitness_function, weight);
+        self
+    }
+
+    /// Sets the highest possible fitness, `DEFAULT_SCALE` by default. With
+    /// an integer fitness it is the resolution of the combined fitness.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.objectives.scale = scale;
+        self
+    }
+
+    /// Returns the number of objectives.
+    pub fn num_objectives(&self) -> usize {
+        self.objectives.objectives.len()
+    }
+
+    /// Returns the weights of the objectives.
+    pub fn weights(&self) -> Vec<f64> {
+        self.objectives.weights()
+    }
+
+    /// Returns the highest possible fitness.
+    pub fn scale(&self) -> f64 {
+        self.objectives.scale
+    }
+
+    /// Returns the normalized fitness of the genome by each objective.
+    pub fn normalized_fitness_of(&self, genome: &G) -> Vec<f64> {
+        self.objectives.normalized_fitness_of(genome)
+    }
+}
+
+impl<G> Default for ChebyshevFitness<G>
+where
+    G: Genotype,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, F> FitnessFunction<G, F> for ChebyshevFitness<G>
+where
+    G: Genotype,
+    F: Fitness + AsScalar + FromScalar,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        let max_weight = self.weights().into_iter().fold(0., f64::max);
+        if max_weight <= 0. {
+            return F::from_scalar(0.);
+        }
+        let max_distance = self
+            .normalized_fitness_of(genome)
+            .iter()
+            .zip(self.weights())
+            .map(|(fitness, weight)| weight / max_weight * (1. - fitness))
+            .fold(0., f64::max);
+        F::from_scalar(self.scale() * (1. - max_distance))
+    }
+
+    fn average(&self, values: &[F]) -> F {
+        self.objectives.average(values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        F::from_scalar(self.scale())
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        F::from_scalar(0.)
+    }
+
+    fn adapt(&self, generation: u64) {
+        self.objectives.adapt(generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    // The number of ones, of at most 4
+    #[derive(Clone, Debug)]
+    struct Ones;
+
+    impl FitnessFunction<Vec<u8>, usize> for Ones {
+        fn fitness_of(&self, genome: &Vec<u8>) -> usize {
+            genome.iter().filter(|value| **value == 1).count()
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            4
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    // The sum of the values, of at most 40
+    #[derive(Clone, Debug)]
+    struct Sum;
+
+    impl FitnessFunction<Vec<u8>, i64> for Sum {
+        fn fitness_of(&self, genome: &Vec<u8>) -> i64 {
+            genome.iter().map(|value| *value as i64).sum()
+        }
+
+        fn average(&self, values: &[i64]) -> i64 {
+            values.iter().sum::<i64>() / values.len() as i64
+        }
+
+        fn highest_possible_fitness(&self) -> i64 {
+            40
+        }
+
+        fn lowest_possible_fitness(&self) -> i64 {
+            0
+        }
+    }
+
+    #[test]
+    fn the_weighted_sum_takes_the_weighted_mean_of_the_normalized_objectives() {
+        let fitness = WeightedSumFitness::new()
+            .with_objective(Ones, 3.)
+            .with_objective(Sum, 1.);
+
+        // normalized 0.5 and 0.05
+        let genome = vec![1, 1, 0, 0];
+        expect_that!(&fitness.normalized_fitness_of(&genome), eq(vec![0.5, 0.05]));
+        expect_that!(
+            &FitnessFunction::<_, usize>::fitness_of(&fitness, &genome),
+            eq(388)
+        );
+        expect_that!(
+            &FitnessFunction::<Vec<u8>, usize>::highest_possible_fitness(&fitness),
+            eq(1000)
+        );
+        expect_that!(
+            &FitnessFunction::<_, usize>::fitness_of(&WeightedSumFitness::new(), &genome),
+            eq(0)
+        );
+    }
+
+    #[test]
+    fn the_chebyshev_fitness_is_decided_by_the_objective_furthest_behind() {
+        let fitness = ChebyshevFitness::new()
+            .with_objective(Ones, 1.)
+            .with_objective(Sum, 0.5)
+            .with_scale(100.);
+
+        // the ones are 0.5 behind, the weighted sum 0.5 * 0.95
+        let balanced = vec![1, 1, 0, 0];
+        expect_that!(
+            &FitnessFunction::<_, usize>::fitness_of(&fitness, &balanced),
+            eq(50)
+        );
+        // the ones are 1 behind, although the sum is at its highest
+        let no_ones = vec![10, 10, 10, 10];
+        expect_that!(
+            &FitnessFunction::<_, usize>::fitness_of(&fitness, &no_ones),
+            eq(0)
+        );
+        // the weighted sum is 0.5 * 0.9 behind
+        let all_ones = vec![1, 1, 1, 1];
+        expect_that!(
+            &FitnessFunction::<_, usize>::fitness_of(&fitness, &all_ones),
+            eq(55)
+        );
+    }
+}
@@ -0,0 +1,446 @@
+//! The `schedule` module provides `Schedule`s, parameters of the genetic
+//! algorithm given as functions of the generation, and operators which wrap
+//! other operators and follow a `Schedule` instead of a fixed parameter,
+//! e.g. to anneal the mutation rate over the course of a run.
+//!
+//! The provided operators are:
+//! * `ScheduledMutator` which sets the mutation rate of the wrapped
+//!   `operator::MutationOp`.
+//! * `ScheduledSelector` which sets the selection ratio of the wrapped
+//!   `operator::SelectionOp`.
+//! * `ScheduledSizeReinserter` which resizes the population combined by the
+//!   wrapped `operator::ReinsertionOp`.
+//!
+//! The operators count the generations by the calls of the `adapt` hook of
+//! their operator trait, which the `ga::GeneticAlgorithm` calls once per
+//! generation. The first generation is generation 1.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Fitness, FitnessFunction, Genotype, Offspring, Parents},
+    mutation::adaptive::MutationRate,
+    operator::{
+        GeneticOperator, MultiObjective, MutationOp, ReinsertionOp, SelectionOp, SingleObjective,
+    },
+    population::GenomeBuilder,
+    random::Rng,
+    selection::SelectionRatio,
+};
+
+/// A `Schedule` defines the value of a parameter in each generation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Schedule {
+    /// The same value in every generation.
+    Constant(f64),
+    /// Interpolates linearly between the given `(generation, value)` points,
+    /// which must be ordered by their generation. Before the first point the
+    /// value is the one of the first point, after the last point the one of
+    /// the last point. There must be at least one point.
+    Piecewise(Vec<(u64, f64)>),
+    /// Decays exponentially from the `initial` value towards the `target`
+    /// value: each generation the distance to the target shrinks by the
+    /// `decay_rate`, i.e. the value in generation `g` is
+    /// `target + (initial - target) * (1 - decay_rate)^(g - 1)`.
+    ExponentialDecay {
+        initial: f64,
+        target: f64,
+        decay_rate: f64,
+    },
+}
+
+impl Schedule {
+    /// Returns the value of the parameter in the given generation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Schedule::Piecewise` has no points.
+    pub fn value_at(&self, generation: u64) -> f64 {
+        match self {
+            Schedule::Constant(value) => *value,
+            Schedule::Piecewise(points) => {
+                let (first, last) = match (points.first(), points.last()) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => panic!("a piecewise schedule needs at least one point"),
+                };
+                if generation <= first.0 {
+                    return first.1;
+                }
+                if generation >= last.0 {
+                    return last.1;
+                }
+                let end = points.partition_point(|point| point.0 <= generation);
+                let (start_generation, start_value) = points[end - 1];
+                let (end_generation, end_value) = points[end];
+                let progress = (generation - start_generation) as f64
+                    / (end_generation - start_generation) as f64;
+                start_value + (end_value - start_value) * progress
+            }
+            Schedule::ExponentialDecay {
+                initial,
+                target,
+                decay_rate,
+            } => {
+                let decay = (1. - decay_rate).powf(generation.saturating_sub(1) as f64);
+                target + (initial - target) * decay
+            }
+        }
+    }
+}
+
+/// The `ScheduledMutator` wraps an `operator::MutationOp` and sets its
+/// mutation rate to the value of the `Schedule` in each generation, e.g. to
+/// explore with a high rate first and to fine-tune with a low rate later.
+///
+/// The rate is set before the wrapped operator adapts to the generation, so
+/// a wrapped `StagnationBoostMutator` boosts the scheduled rate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledMutator<M> {
+    mutator: M,
+    schedule: Schedule,
+    generation: u64,
+}
+
+impl<M> ScheduledMutator<M> {
+    pub fn new(mutator: M, schedule: Schedule) -> Self {
+        ScheduledMutator {
+            mutator,
+            schedule,
+            generation: 0,
+        }
+    }
+
+    /// Returns the wrapped `operator::MutationOp`.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the `Schedule` of the mutation rate.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the number of generations the mutator has adapted to so far.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl<M> GeneticOperator for ScheduledMutator<M>
+where
+    M: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Scheduled-{}", M::name())
+    }
+}
+
+impl<G, M> MutationOp<G> for ScheduledMutator<M>
+where
+    G: Genotype,
+    M: MutationOp<G> + MutationRate,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutator.mutate(genome, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.generation += 1;
+        let rate = self.schedule.value_at(self.generation).clamp(0., 1.);
+        self.mutator.set_mutation_rate(rate);
+        self.mutator.adapt(improved);
+    }
+}
+
+/// The `ScheduledSelector` wraps an `operator::SelectionOp` and sets its
+/// selection ratio to the value of the `Schedule` in each generation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledSelector<S> {
+    selector: S,
+    schedule: Schedule,
+    generation: u64,
+}
+
+impl<S> ScheduledSelector<S> {
+    pub fn new(selector: S, schedule: Schedule) -> Self {
+        ScheduledSelector {
+            selector,
+            schedule,
+            generation: 0,
+        }
+    }
+
+    /// Returns the wrapped `operator::SelectionOp`.
+    pub fn selector(&self) -> &S {
+        &self.selector
+    }
+
+    /// Returns the `Schedule` of the selection ratio.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+}
+
+impl<S> GeneticOperator for ScheduledSelector<S>
+where
+    S: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Scheduled-{}", S::name())
+    }
+}
+
+impl<S> SingleObjective for ScheduledSelector<S> where S: SingleObjective {}
+
+impl<S> MultiObjective for ScheduledSelector<S> where S: MultiObjective {}
+
+impl<G, F, S> SelectionOp<G, F> for ScheduledSelector<S>
+where
+    G: Genotype,
+    F: Fitness,
+    S: SelectionOp<G, F> + SelectionRatio,
+{
+    fn select_from<R>(&self, population: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        self.selector.select_from(population, rng)
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.generation += 1;
+        let ratio = self.schedule.value_at(self.generation).max(0.);
+        self.selector.set_selection_ratio(ratio);
+        self.selector.adapt(improved);
+    }
+}
+
+/// The `ScheduledSizeReinserter` resizes the population combined by the
+/// wrapped `operator::ReinsertionOp` to the rounded value of the `Schedule`
+/// in each generation, but to at least 1 individual.
+///
+/// A population that is too large loses its worst individuals, as ranked by
+/// the given `FitnessFunction`. A population that is too small is filled up
+/// with new individuals built by the given `population::GenomeBuilder`.
+/// As the number of parents selected usually depends on the size of the
+/// population, so does the size of the offspring.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledSizeReinserter<R, B, E> {
+    reinserter: R,
+    genome_builder: B,
+    fitness_evaluator: E,
+    schedule: Schedule,
+    generation: u64,
+}
+
+impl<R, B, E> ScheduledSizeReinserter<R, B, E> {
+    pub fn new(reinserter: R, genome_builder: B, fitness_evaluator: E, schedule: Schedule) -> Self {
+        ScheduledSizeReinserter {
+            reinserter,
+            genome_builder,
+            fitness_evaluator,
+            schedule,
+            generation: 0,
+        }
+    }
+
+    /// Returns the wrapped `operator::ReinsertionOp`.
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    /// Returns the `Schedule` of the population size.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the size of the population in the current generation.
+    pub fn population_size(&self) -> usize {
+        self.schedule.value_at(self.generation).round().max(1.) as usize
+    }
+}
+
+impl<R, B, E> GeneticOperator for ScheduledSizeReinserter<R, B, E>
+where
+    R: GeneticOperator,
+    B: Clone,
+    E: Clone,
+{
+    fn name() -> String {
+        format!("Scheduled-Size-{}", R::name())
+    }
+}
+
+impl<R, B, E> SingleObjective for ScheduledSizeReinserter<R, B, E> where R: SingleObjective {}
+
+impl<G, F, R, B, E> ReinsertionOp<G, F> for ScheduledSizeReinserter<R, B, E>
+where
+    G: Genotype,
+    F: Fitness,
+    R: ReinsertionOp<G, F>,
+    B: GenomeBuilder<G> + Clone,
+    E: FitnessFunction<G, F> + Clone,
+{
+    fn combine<RNG>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut RNG,
+    ) -> Vec<G>
+    where
+        RNG: Rng + Sized,
+    {
+        let mut new_population = self.reinserter.combine(offspring, evaluated, rng);
+        let population_size = self.population_size();
+
+        if new_population.len() > population_size {
+            let fitness_values = self.fitness_evaluator.fitness_of_all(&new_population);
+            let mut indices: Vec<usize> = (0..new_population.len()).collect();
+            // sort indices from worst performing to best performing individual
+            indices.sort_by(|x, y| fitness_values[*x].cmp(&fitness_values[*y]));
+            let mut dropped = vec![false; new_population.len()];
+            for index in indices
+                .into_iter()
+                .take(new_population.len() - population_size)
+            {
+                dropped[index] = true;
+            }
+            let mut index = 0;
+            new_population.retain(|_| {
+                index += 1;
+                !dropped[index - 1]
+            });
+        }
+        while new_population.len() < population_size {
+            let index = new_population.len();
+            new_population.push(self.genome_builder.build_genome(index, rng));
+        }
+        new_population
+    }
+
+    fn adapt(&mut self, improved: bool) {
+        self.generation += 1;
+        self.reinserter.adapt(improved);
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.reinserter.is_restarting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mutation::value::RandomValueMutator,
+        random::{get_rng, random_seed},
+        reinsertion::test_util::OffspringOnly,
+    };
+    use galvanic_assert::matchers::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn a_piecewise_schedule_interpolates_between_its_points() {
+        let schedule = Schedule::Piecewise(vec![(10, 0.5), (20, 0.1), (30, 0.1)]);
+
+        expect_that!(&schedule.value_at(1), eq(0.5));
+        expect_that!(&schedule.value_at(10), eq(0.5));
+        expect_that!(&(schedule.value_at(15) - 0.3).abs(), less_than(1e-9));
+        expect_that!(&schedule.value_at(25), eq(0.1));
+        expect_that!(&schedule.value_at(99), eq(0.1));
+    }
+
+    #[test]
+    fn an_exponential_decay_approaches_its_target() {
+        let schedule = Schedule::ExponentialDecay {
+            initial: 0.5,
+            target: 0.1,
+            decay_rate: 0.5,
+        };
+
+        expect_that!(&schedule.value_at(1), eq(0.5));
+        expect_that!(&(schedule.value_at(2) - 0.3).abs(), less_than(1e-9));
+        expect_that!(&(schedule.value_at(3) - 0.2).abs(), less_than(1e-9));
+        expect_that!(&(schedule.value_at(100) - 0.1).abs(), less_than(1e-9));
+    }
+
+    #[test]
+    fn the_scheduled_mutator_sets_the_rate_of_each_generation() {
+        let mut mutator = ScheduledMutator::new(
+            RandomValueMutator::new(0.5, 0usize, 9),
+            Schedule::Piecewise(vec![(1, 0.4), (3, 0.2)]),
+        );
+
+        MutationOp::<Vec<usize>>::adapt(&mut mutator, true);
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.4));
+        MutationOp::<Vec<usize>>::adapt(&mut mutator, false);
+        MutationOp::<Vec<usize>>::adapt(&mut mutator, false);
+        expect_that!(&mutator.mutator().mutation_rate(), eq(0.2));
+        expect_that!(&mutator.generation(), eq(3));
+    }
+
+    // Builds genomes holding 100 plus their index
+    #[derive(Clone, Debug, PartialEq)]
+    struct IndexBuilder;
+
+    impl GenomeBuilder<Vec<usize>> for IndexBuilder {
+        fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<usize>
+        where
+            R: Rng + Sized,
+        {
+            vec![100 + index]
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FirstValue;
+
+    impl FitnessFunction<Vec<usize>, usize> for FirstValue {
+        fn fitness_of(&self, genome: &Vec<usize>) -> usize {
+            genome[0]
+        }
+
+        fn average(&self, values: &[usize]) -> usize {
+            values.iter().sum::<usize>() / values.len()
+        }
+
+        fn highest_possible_fitness(&self) -> usize {
+            usize::MAX
+        }
+
+        fn lowest_possible_fitness(&self) -> usize {
+            0
+        }
+    }
+
+    fn combine(generation: u64, offspring: Vec<usize>) -> Vec<usize> {
+        let mut reinserter = ScheduledSizeReinserter::new(
+            OffspringOnly,
+            IndexBuilder,
+            FirstValue,
+            Schedule::Piecewise(vec![(1, 3.), (3, 5.)]),
+        );
+        for _ in 0..generation {
+            ReinsertionOp::<Vec<usize>, usize>::adapt(&mut reinserter, false);
+        }
+        let mut offspring = offspring.into_iter().map(|f| vec![f]).collect();
+        let population = EvaluatedPopulation::new(Rc::new(vec![vec![0]]), vec![0], 0, 0, 0);
+        reinserter
+            .combine(&mut offspring, &population, &mut get_rng(random_seed()))
+            .into_iter()
+            .map(|genome| genome[0])
+            .collect()
+    }
+
+    #[test]
+    fn the_worst_individuals_are_dropped_from_a_too_large_population() {
+        expect_that!(&combine(1, vec![4, 1, 3, 0, 2]), eq(vec![4, 3, 2]));
+    }
+
+    #[test]
+    fn a_too_small_population_is_filled_up_with_new_individuals() {
+        expect_that!(&combine(3, vec![4, 1, 3]), eq(vec![4, 1, 3, 103, 104]));
+    }
+}
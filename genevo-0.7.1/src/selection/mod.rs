@@ -8,6 +8,46 @@
 //! * `proportionate`
 //! * `tournament`
 
+use crate::selection::{
+    proportionate::{RouletteWheelSelector, UniversalSamplingSelector},
+    tournament::TournamentSelector,
+    truncation::MaximizeSelector,
+};
+
+/// A `SelectionRatio` gives access to the selection ratio of an
+/// `operator::SelectionOp`, so that it can be adjusted by a wrapper, e.g. by
+/// the `schedule::ScheduledSelector`.
+pub trait SelectionRatio {
+    /// Returns the current selection ratio.
+    fn selection_ratio(&self) -> f64;
+
+    /// Sets the selection ratio to the given value.
+    fn set_selection_ratio(&mut self, value: f64);
+}
+
+macro_rules! impl_selection_ratio {
+    ($($selector:ty),*) => {
+        $(
+            impl SelectionRatio for $selector {
+                fn selection_ratio(&self) -> f64 {
+                    self.selection_ratio()
+                }
+
+                fn set_selection_ratio(&mut self, value: f64) {
+                    self.set_selection_ratio(value);
+                }
+            }
+        )*
+    }
+}
+
+impl_selection_ratio!(
+    MaximizeSelector,
+    TournamentSelector,
+    RouletteWheelSelector,
+    UniversalSamplingSelector
+);
+
 pub mod proportionate;
 
 pub mod ranking;
@@ -188,7 +188,8 @@ where
             WeightedDistribution::from_scalar_values(evaluated.fitness_values());
         let distance = weighted_distribution.sum()
             / (num_parents_to_select * self.num_individuals_per_parents) as f64;
-        let mut pointer = random_probability(rng) * weighted_distribution.sum();
+        // the pointers are evenly spaced, so the first one lies within the first gap
+        let mut pointer = random_probability(rng) * distance;
         for _ in 0..num_parents_to_select {
             let mut tuple = Vec::with_capacity(self.num_individuals_per_parents);
             for _ in 0..self.num_individuals_per_parents {
@@ -201,3 +202,24 @@ where
         parents
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn universal_sampling_pointers_stay_within_the_distribution() {
+        let individuals = Rc::new((0..64u8).map(|i| vec![i]).collect::<Vec<_>>());
+        let fitness = (1..=64usize).collect::<Vec<_>>();
+        let population = EvaluatedPopulation::new(individuals, fitness, 64, 1, 32);
+        let selector = UniversalSamplingSelector::new(0.7, 2);
+
+        for _ in 0..100 {
+            let parents = selector.select_from(&population, &mut get_rng(random_seed()));
+            expect_that!(&parents.len(), eq(45));
+        }
+    }
+}
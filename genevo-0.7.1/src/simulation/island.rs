@@ -0,0 +1,312 @@
+//! The `island` module provides the island model, which runs several
+//! `simulator::Simulator`s (the islands) in parallel and periodically
+//! migrates the best individuals between them.
+//!
+//! Each island evolves its own subpopulation in its own thread. Every
+//! `migration_interval` generations the islands pause, and each island sends
+//! copies of its best individuals to its neighbours as defined by the
+//! `Topology`. The immigrants replace the worst individuals of the receiving
+//! island. Islands explore different regions of the search space in between
+//! migrations, which helps to maintain diversity compared to a single
+//! population of the same total size.
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, OptimizationResult, PopulationAlgorithm},
+    genetic::{Fitness, Genotype},
+    simulation::{
+        simulator::{SimError, Simulator},
+        SimResult, Simulation,
+    },
+    statistic::TrackProcessingTime,
+    termination::Termination,
+};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::channel,
+        Barrier,
+    },
+    thread,
+};
+
+/// The `Topology` defines to which islands the migrants of an island are
+/// sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// Each island sends its migrants to the next island, the last island
+    /// sends them to the first one.
+    Ring,
+    /// Each island sends its migrants to all other islands.
+    FullyConnected,
+}
+
+/// The `IslandModel` defines the number of islands and how individuals
+/// migrate between them.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IslandModel {
+    num_islands: usize,
+    migration_interval: u64,
+    num_migrants: usize,
+    topology: Topology,
+}
+
+/// The result of running an `IslandModel`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IslandResult<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The best solution found on any island.
+    pub best_solution: BestSolution<G, F>,
+    /// The best fitness over all islands at each generation. An island that
+    /// stopped early contributes the best fitness of its last generation.
+    pub curve: Vec<F>,
+    /// The number of generations processed by the islands.
+    pub generations: u64,
+}
+
+impl IslandModel {
+    pub fn new(
+        num_islands: usize,
+        migration_interval: u64,
+        num_migrants: usize,
+        topology: Topology,
+    ) -> Self {
+        assert!(num_islands > 0, "at least one island is required");
+        assert!(
+            migration_interval > 0,
+            "the migration interval must be at least 1"
+        );
+        IslandModel {
+            num_islands,
+            migration_interval,
+            num_migrants,
+            topology,
+        }
+    }
+
+    /// Returns the number of islands.
+    pub fn num_islands(&self) -> usize {
+        self.num_islands
+    }
+
+    /// Returns the number of generations between two migrations.
+    pub fn migration_interval(&self) -> u64 {
+        self.migration_interval
+    }
+
+    /// Returns the number of individuals an island sends to each neighbour.
+    pub fn num_migrants(&self) -> usize {
+        self.num_migrants
+    }
+
+    /// Returns the topology of the islands.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Returns the islands the given island sends its migrants to.
+    pub fn neighbours(&self, island: usize) -> Vec<usize> {
+        match self.topology {
+            Topology::Ring if self.num_islands > 1 => vec![(island + 1) % self.num_islands],
+            Topology::Ring => vec![],
+            Topology::FullyConnected => (0..self.num_islands).filter(|i| *i != island).collect(),
+        }
+    }
+
+    /// Runs the islands until one of them meets its termination criteria.
+    ///
+    /// Each island is built by calling `build_island` with the index of the
+    /// island from within the thread the island runs in, so the algorithm
+    /// does not need to be `Send`. The remaining islands stop at the end of
+    /// the current migration interval.
+    ///
+    /// To select the migrants and the individuals they replace, the fitness
+    /// of the populations is evaluated once more on each migration.
+    pub fn run<A, T, B>(
+        &self,
+        build_island: B,
+    ) -> Result<IslandResult<A::Genotype, A::Fitness>, SimError<A>>
+    where
+        A: PopulationAlgorithm + TrackProcessingTime + Debug,
+        <A as Algorithm>::Output: OptimizationResult<A::Genotype, A::Fitness>,
+        <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+        A::Genotype: Send,
+        A::Fitness: Send,
+        T: Termination<A>,
+        B: Fn(usize) -> Simulator<A, T> + Sync,
+    {
+        self.run_until_stopped(build_island, &AtomicBool::new(false))
+    }
+
+    /// Runs the islands like `run`, but also stops them when `stop_requested`
+    /// is set, e.g. by another thread that gives up on the run.
+    ///
+    /// The flag is checked by each island at the end of a migration interval,
+    /// so the islands stop together at the end of the interval in which the
+    /// flag has been set. The result covers the generations processed so far.
+    pub fn run_until_stopped<A, T, B>(
+        &self,
+        build_island: B,
+        stop_requested: &AtomicBool,
+    ) -> Result<IslandResult<A::Genotype, A::Fitness>, SimError<A>>
+    where
+        A: PopulationAlgorithm + TrackProcessingTime + Debug,
+        <A as Algorithm>::Output: OptimizationResult<A::Genotype, A::Fitness>,
+        <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+        A::Genotype: Send,
+        A::Fitness: Send,
+        T: Termination<A>,
+        B: Fn(usize) -> Simulator<A, T> + Sync,
+    {
+        let barrier = Barrier::new(self.num_islands);
+        let stop = AtomicBool::new(false);
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            (0..self.num_islands).map(|_| channel()).unzip();
+
+        let islands = thread::scope(|scope| {
+            let handles = receivers
+                .into_iter()
+                .enumerate()
+                .map(|(island, receiver)| {
+                    let neighbours = self
+                        .neighbours(island)
+                        .into_iter()
+                        .map(|neighbour| senders[neighbour].clone())
+                        .collect::<Vec<_>>();
+                    let (barrier, stop, build_island) = (&barrier, &stop, &build_island);
+                    scope.spawn(move || {
+                        let mut simulator = build_island(island);
+                        let mut curve = Vec::new();
+                        let mut best_solution: Option<BestSolution<A::Genotype, A::Fitness>> = None;
+                        let mut error = None;
+                        loop {
+                            // Evolve the island for one migration interval
+                            for _ in 0..self.migration_interval {
+                                let (state, last) = match simulator.step() {
+                                    Ok(SimResult::Intermediate(state)) => (state, false),
+                                    Ok(SimResult::Final(state, _, _, _)) => (state, true),
+                                    Err(err) => {
+                                        error = Some(err);
+                                        break;
+                                    }
+                                };
+                                let generation_best = state.result.best_solution();
+                                curve.push(generation_best.solution.fitness.clone());
+                                match best_solution {
+                                    Some(ref best)
+                                        if best.solution.fitness
+                                            >= generation_best.solution.fitness => {}
+                                    _ => best_solution = Some(generation_best.clone()),
+                                }
+                                if last {
+                                    stop.store(true, Ordering::SeqCst);
+                                    break;
+                                }
+                            }
+                            if error.is_some() || stop_requested.load(Ordering::SeqCst) {
+                                stop.store(true, Ordering::SeqCst);
+                            }
+                            barrier.wait();
+                            if stop.load(Ordering::SeqCst) {
+                                break;
+                            }
+
+                            // Send copies of the best individuals to the neighbours
+                            let mut population = simulator.population_snapshot();
+                            population.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+                            let emigrants = population
+                                .iter()
+                                .take(self.num_migrants)
+                                .map(|individual| individual.genome.clone())
+                                .collect::<Vec<_>>();
+                            for neighbour in neighbours.iter() {
+                                let _ = neighbour.send(emigrants.clone());
+                            }
+                            barrier.wait();
+
+                            // Replace the worst individuals by the immigrants
+                            let mut population = population
+                                .into_iter()
+                                .map(|individual| individual.genome)
+                                .collect::<Vec<_>>();
+                            let immigrants = receiver.try_iter().flatten().collect::<Vec<_>>();
+                            let num_replaced = immigrants.len().min(population.len());
+                            population.truncate(population.len() - num_replaced);
+                            population.extend(immigrants.into_iter().take(num_replaced));
+                            simulator.replace_population(population);
+                        }
+                        match error {
+                            Some(error) => Err(error),
+                            None => Ok((curve, best_solution)),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("island thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        let mut curves = Vec::with_capacity(islands.len());
+        let mut best_solution: Option<BestSolution<A::Genotype, A::Fitness>> = None;
+        for island in islands {
+            let (curve, island_best) = island?;
+            curves.push(curve);
+            match (&best_solution, island_best) {
+                (Some(best), Some(island_best))
+                    if best.solution.fitness >= island_best.solution.fitness => {}
+                (_, Some(island_best)) => best_solution = Some(island_best),
+                (_, None) => {}
+            }
+        }
+        let generations = curves.iter().map(Vec::len).max().unwrap_or(0);
+        let curve = (0..generations)
+            .filter_map(|generation| {
+                curves
+                    .iter()
+                    .filter_map(|curve| curve.get(generation).or_else(|| curve.last()))
+                    .max()
+                    .cloned()
+            })
+            .collect();
+        Ok(IslandResult {
+            best_solution: best_solution.expect("at least one generation has been processed"),
+            curve,
+            generations: generations as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn ring_topology_sends_migrants_to_the_next_island() {
+        let model = IslandModel::new(4, 10, 2, Topology::Ring);
+
+        expect_that!(&model.neighbours(0), eq(vec![1]));
+        expect_that!(&model.neighbours(3), eq(vec![0]));
+    }
+
+    #[test]
+    fn fully_connected_topology_sends_migrants_to_all_other_islands() {
+        let model = IslandModel::new(4, 10, 2, Topology::FullyConnected);
+
+        expect_that!(&model.neighbours(2), eq(vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn single_island_has_no_neighbours() {
+        let model = IslandModel::new(1, 10, 2, Topology::Ring);
+
+        expect_that!(&model.neighbours(0), eq(vec![]));
+    }
+}
@@ -0,0 +1,150 @@
+//! The `metrics` module provides the `MetricsWriter`, a
+//! `simulation::observer::SimulationObserver` that streams the metrics of
+//! each generation of a genetic algorithm to a writer while the simulation
+//! is running.
+//!
+//! Each generation is written as one line and the writer is flushed after
+//! each line, so the metrics of long runs do not have to be kept in memory
+//! and survive a crash of the process.
+
+use crate::{
+    algorithm::Algorithm,
+    ga,
+    genetic::{Fitness, Genotype},
+    simulation::{observer::SimulationObserver, State},
+};
+use std::{
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// The format the metrics are written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MetricsFormat {
+    /// Comma separated values with a header line.
+    Csv,
+    /// One JSON object per line (JSON Lines).
+    JsonLines,
+}
+
+/// The `MetricsWriter` writes the generation number, the best, average and
+/// worst fitness and the duration of each generation of a genetic algorithm
+/// in the given `MetricsFormat`.
+///
+/// The fitness values are written using their `Display` implementation,
+/// which must produce a valid value for the chosen format, e.g. a number.
+///
+/// As observers can not fail, the first I/O error is kept and no further
+/// metrics are written afterwards. It can be inspected with `error`.
+#[derive(Debug)]
+pub struct MetricsWriter<W>
+where
+    W: Write,
+{
+    writer: W,
+    format: MetricsFormat,
+    write_header: bool,
+    error: Option<io::Error>,
+}
+
+impl<W> MetricsWriter<W>
+where
+    W: Write,
+{
+    /// Creates a `MetricsWriter` that writes to the given writer. In CSV
+    /// format a header line is written before the first generation.
+    pub fn new(writer: W, format: MetricsFormat) -> Self {
+        MetricsWriter {
+            writer,
+            format,
+            write_header: format == MetricsFormat::Csv,
+            error: None,
+        }
+    }
+
+    /// Returns the format the metrics are written in.
+    pub fn format(&self) -> MetricsFormat {
+        self.format
+    }
+
+    /// Returns the first I/O error that occurred while writing, if any.
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line<F>(&mut self, iteration: u64, fitness: [&F; 3], millis: i64) -> io::Result<()>
+    where
+        F: Display,
+    {
+        let [best, average, worst] = fitness;
+        match self.format {
+            MetricsFormat::Csv => {
+                if self.write_header {
+                    writeln!(
+                        self.writer,
+                        "generation,best_fitness,average_fitness,worst_fitness,duration_ms"
+                    )?;
+                    self.write_header = false;
+                }
+                writeln!(
+                    self.writer,
+                    "{},{},{},{},{}",
+                    iteration, best, average, worst, millis
+                )?;
+            }
+            MetricsFormat::JsonLines => writeln!(
+                self.writer,
+                "{{\"generation\":{},\"best_fitness\":{},\"average_fitness\":{},\"worst_fitness\":{},\"duration_ms\":{}}}",
+                iteration, best, average, worst, millis
+            )?,
+        }
+        self.writer.flush()
+    }
+}
+
+impl MetricsWriter<BufWriter<File>> {
+    /// Opens the file at the given path for appending, creating it if it
+    /// does not exist. In CSV format the header line is only written if the
+    /// file is empty.
+    pub fn append_to<P>(path: P, format: MetricsFormat) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let is_empty = file.metadata()?.len() == 0;
+        let mut writer = MetricsWriter::new(BufWriter::new(file), format);
+        writer.write_header &= is_empty;
+        Ok(writer)
+    }
+}
+
+impl<W, A, G, F, E> SimulationObserver<A, E> for MetricsWriter<W>
+where
+    W: Write,
+    A: Algorithm<Output = ga::State<G, F>>,
+    G: Genotype,
+    F: Fitness + Display,
+{
+    fn on_generation(&mut self, state: &State<A>) {
+        if self.error.is_some() {
+            return;
+        }
+        let population = &state.result.evaluated_population;
+        let fitness = [
+            &state.result.best_solution.solution.fitness,
+            population.average_fitness(),
+            population.lowest_fitness(),
+        ];
+        let millis = state.duration.num_milliseconds();
+        if let Err(error) = self.write_line(state.iteration, fitness, millis) {
+            self.error = Some(error);
+        }
+    }
+}
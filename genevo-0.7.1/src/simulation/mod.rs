@@ -1,9 +1,19 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod island;
+pub mod metrics;
+pub mod observer;
 pub mod simulator;
 
 use crate::{
-    algorithm::Algorithm, random::Seed, statistic::ProcessingTime, termination::StopReason,
+    algorithm::{Algorithm, BestSolution},
+    genetic::{Fitness, Genotype},
+    random::Seed,
+    statistic::ProcessingTime,
+    termination::StopReason,
 };
 use chrono::{DateTime, Duration, Local};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A `Simulation` is the execution of an algorithm.
 pub trait Simulation<A>
@@ -49,6 +59,14 @@ where
 
 /// The `State` struct holds the state of the `Simulation`.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(
+        serialize = "A::Output: Serialize",
+        deserialize = "A::Output: Deserialize<'de>"
+    ))
+)]
 pub struct State<A>
 where
     A: Algorithm,
@@ -73,6 +91,14 @@ where
 
 /// The result of running a step in the `Simulation`.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(
+        serialize = "A::Output: Serialize",
+        deserialize = "A::Output: Deserialize<'de>"
+    ))
+)]
 pub enum SimResult<A>
 where
     A: Algorithm,
@@ -89,3 +115,43 @@ where
     /// * The `StopReason` is the matching criteria why the simulation stopped.
     Final(State<A>, ProcessingTime, Duration, StopReason),
 }
+
+/// Why an anytime run of a `Simulation` has ended.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnytimeStop {
+    /// The wall-clock budget did not allow for another generation.
+    Budget,
+    /// The run has been interrupted from outside before the budget was
+    /// exhausted.
+    Interrupted,
+    /// The termination criteria of the simulation were met before the budget
+    /// was exhausted.
+    Terminated(StopReason),
+}
+
+/// The result of an anytime run of a `Simulation`, i.e. a run that is bound
+/// by a strict wall-clock budget.
+///
+/// An anytime run always produces a well-formed result as long as at least
+/// one generation has been processed, no matter whether it has been stopped
+/// by its budget, by an interrupt or by the termination criteria.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnytimeResult<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The best solution found over all processed generations.
+    pub best_solution: BestSolution<G, F>,
+    /// The fitness of the best solution of each processed generation, in the
+    /// order the generations have been processed.
+    pub curve: Vec<F>,
+    /// The number of generations that have been processed.
+    pub iterations: u64,
+    /// The wall-clock time the run has taken.
+    pub duration: Duration,
+    /// The reason why the run has ended.
+    pub stop_reason: AnytimeStop,
+}
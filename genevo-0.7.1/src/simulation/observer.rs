@@ -0,0 +1,171 @@
+//! The `observer` module provides a decorator for `Simulation`s that notifies
+//! registered observers about the progress of the simulation.
+//!
+//! Observers are a way to collect data, report progress or write results
+//! while a simulation is running, without having to hand-roll the step loop
+//! for each of these concerns.
+
+use crate::{
+    algorithm::Algorithm,
+    simulation::{SimResult, Simulation, State},
+    termination::StopReason,
+};
+use std::fmt::{self, Debug};
+
+/// A `SimulationObserver` is notified by an `Observed` simulation about each
+/// processed generation, the termination and errors of the simulation.
+///
+/// All functions have a default implementation that does nothing, so an
+/// observer only needs to implement the notifications it is interested in.
+pub trait SimulationObserver<A, E>
+where
+    A: Algorithm,
+{
+    /// Called with the `State` of each processed generation, including the
+    /// last one.
+    fn on_generation(&mut self, _state: &State<A>) {}
+
+    /// Called once when the simulation has been terminated, with the `State`
+    /// of the last generation and the reason why the simulation stopped.
+    fn on_termination(&mut self, _state: &State<A>, _stop_reason: &StopReason) {}
+
+    /// Called when the simulation failed with an error.
+    fn on_error(&mut self, _error: &E) {}
+}
+
+impl<A, E, O> SimulationObserver<A, E> for &mut O
+where
+    A: Algorithm,
+    O: SimulationObserver<A, E>,
+{
+    fn on_generation(&mut self, state: &State<A>) {
+        (**self).on_generation(state);
+    }
+
+    fn on_termination(&mut self, state: &State<A>, stop_reason: &StopReason) {
+        (**self).on_termination(state, stop_reason);
+    }
+
+    fn on_error(&mut self, error: &E) {
+        (**self).on_error(error);
+    }
+}
+
+/// Wraps the given `Simulation` so that observers can be registered.
+pub fn observe<'a, S, A>(simulation: S) -> Observed<'a, S, A>
+where
+    S: Simulation<A>,
+    A: Algorithm,
+{
+    Observed::new(simulation)
+}
+
+/// The `Observed` simulation decorates another `Simulation` and notifies
+/// the registered `SimulationObserver`s in the order of their registration.
+pub struct Observed<'a, S, A>
+where
+    S: Simulation<A>,
+    A: Algorithm,
+{
+    simulation: S,
+    observers: Vec<Box<dyn SimulationObserver<A, S::Error> + 'a>>,
+}
+
+impl<'a, S, A> Observed<'a, S, A>
+where
+    S: Simulation<A>,
+    A: Algorithm,
+{
+    pub fn new(simulation: S) -> Self {
+        Observed {
+            simulation,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Registers the given observer and returns this simulation.
+    pub fn with_observer<O>(mut self, observer: O) -> Self
+    where
+        O: SimulationObserver<A, S::Error> + 'a,
+    {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Registers the given observer.
+    pub fn add_observer<O>(&mut self, observer: O)
+    where
+        O: SimulationObserver<A, S::Error> + 'a,
+    {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Returns the decorated simulation.
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    /// Removes all observers and returns the decorated simulation.
+    pub fn into_inner(self) -> S {
+        self.simulation
+    }
+
+    fn notify(&mut self, result: &Result<SimResult<A>, S::Error>) {
+        for observer in self.observers.iter_mut() {
+            match result {
+                Ok(SimResult::Intermediate(state)) => observer.on_generation(state),
+                Ok(SimResult::Final(state, _, _, stop_reason)) => {
+                    observer.on_generation(state);
+                    observer.on_termination(state, stop_reason);
+                }
+                Err(error) => observer.on_error(error),
+            }
+        }
+    }
+}
+
+impl<'a, S, A> Debug for Observed<'a, S, A>
+where
+    S: Simulation<A> + Debug,
+    A: Algorithm,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Observed")
+            .field("simulation", &self.simulation)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl<'a, S, A> Simulation<A> for Observed<'a, S, A>
+where
+    S: Simulation<A>,
+    A: Algorithm,
+{
+    type Error = S::Error;
+
+    /// Runs the decorated simulation step by step, so that the observers are
+    /// notified about each generation.
+    fn run(&mut self) -> Result<SimResult<A>, Self::Error> {
+        loop {
+            match self.step() {
+                Ok(SimResult::Intermediate(_)) => {}
+                result => return result,
+            }
+        }
+    }
+
+    fn step(&mut self) -> Result<SimResult<A>, Self::Error> {
+        let result = self.simulation.step();
+        self.notify(&result);
+        result
+    }
+
+    fn stop(&mut self) -> Result<bool, Self::Error> {
+        self.simulation.stop()
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.simulation.reset()
+    }
+}
@@ -1,15 +1,20 @@
 use crate::{
-    algorithm::Algorithm,
+    algorithm::{Algorithm, BestSolution, Evaluated, OptimizationResult, PopulationAlgorithm},
+    genetic::{Fitness, Genotype},
     random::{get_rng, random_seed, Prng, Seed},
-    simulation::{SimResult, Simulation, SimulationBuilder, State},
+    simulation::{AnytimeResult, AnytimeStop, SimResult, Simulation, SimulationBuilder, State},
     statistic::{ProcessingTime, TrackProcessingTime},
     termination::{StopFlag, Termination},
 };
 use chrono::{DateTime, Local};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 use std::{
     error::Error,
     fmt::{self, Debug, Display},
     hash::Hash,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration as StdDuration,
 };
 
 /// The `simulate` function creates a new `Simulator` for the given
@@ -154,6 +159,131 @@ where
         &self.termination
     }
 
+    pub fn algorithm(&self) -> &A {
+        &self.algorithm
+    }
+
+    /// Returns an owned snapshot of the current population with the fitness
+    /// value of each individual.
+    ///
+    /// In step mode the simulation is paused between two calls of `step`, so
+    /// the population can be inspected and then modified and re-injected
+    /// with `replace_population` before the simulation is resumed.
+    pub fn population_snapshot(
+        &self,
+    ) -> Vec<Evaluated<<A as PopulationAlgorithm>::Genotype, <A as PopulationAlgorithm>::Fitness>>
+    where
+        A: PopulationAlgorithm,
+    {
+        self.algorithm.population_snapshot()
+    }
+
+    /// Replaces the current population with the given one. The next step of
+    /// the simulation continues with the given population.
+    pub fn replace_population(&mut self, population: Vec<<A as PopulationAlgorithm>::Genotype>)
+    where
+        A: PopulationAlgorithm,
+    {
+        self.algorithm.replace_population(population);
+    }
+
+    /// Runs this simulation as an anytime algorithm within the given
+    /// wall-clock budget.
+    ///
+    /// Generations are processed until the termination criteria are met or
+    /// until the budget does not allow for another generation. The time
+    /// needed for the next generation is estimated by the slowest generation
+    /// processed so far. The first generation is always processed, so that
+    /// the returned `AnytimeResult` always holds a best solution.
+    pub fn run_anytime<G, F>(
+        &mut self,
+        budget: StdDuration,
+    ) -> Result<AnytimeResult<G, F>, <Self as Simulation<A>>::Error>
+    where
+        G: Genotype,
+        F: Fitness,
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+    {
+        self.run_anytime_interruptible(budget, &AtomicBool::new(false))
+    }
+
+    /// Runs this simulation as an anytime algorithm within the given
+    /// wall-clock budget, like `run_anytime`, but additionally stops after
+    /// the current generation as soon as the given `interrupt` flag is set.
+    ///
+    /// This way the simulation can be interrupted from another thread while
+    /// still returning the best solution found so far.
+    pub fn run_anytime_interruptible<G, F>(
+        &mut self,
+        budget: StdDuration,
+        interrupt: &AtomicBool,
+    ) -> Result<AnytimeResult<G, F>, <Self as Simulation<A>>::Error>
+    where
+        G: Genotype,
+        F: Fitness,
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+    {
+        match self.run_mode {
+            RunMode::Loop => {
+                return Err(SimError::SimulationAlreadyRunning(format!(
+                    "in loop mode since {}",
+                    &self.started_at
+                )))
+            }
+            RunMode::Step => {
+                return Err(SimError::SimulationAlreadyRunning(format!(
+                    "in step mode since {}",
+                    &self.started_at
+                )))
+            }
+            RunMode::NotRunning => {
+                self.run_mode = RunMode::Loop;
+                self.started_at = Local::now();
+            }
+        }
+        let budget_elapsed = start_stopwatch();
+        let mut slowest_generation = StdDuration::from_secs(0);
+        let mut best_solution: Option<BestSolution<G, F>> = None;
+        let mut curve = Vec::new();
+        let stop_reason = loop {
+            if best_solution.is_some() {
+                if interrupt.load(Ordering::Relaxed) {
+                    break AnytimeStop::Interrupted;
+                }
+                if budget_elapsed() + slowest_generation > budget {
+                    break AnytimeStop::Budget;
+                }
+            }
+            let generation_elapsed = start_stopwatch();
+            let state = match self.process_one_iteration() {
+                Ok(state) => state,
+                Err(error) => {
+                    self.run_mode = RunMode::NotRunning;
+                    return Err(error);
+                }
+            };
+            slowest_generation = slowest_generation.max(generation_elapsed());
+            let generation_best = state.result.best_solution();
+            curve.push(generation_best.solution.fitness.clone());
+            match best_solution {
+                Some(ref best) if best.solution.fitness >= generation_best.solution.fitness => {}
+                _ => best_solution = Some(generation_best.clone()),
+            }
+            // Stage 5: Be aware of the termination:
+            if let StopFlag::StopNow(reason) = self.termination.evaluate(&state) {
+                break AnytimeStop::Terminated(reason);
+            }
+        };
+        self.run_mode = RunMode::NotRunning;
+        Ok(AnytimeResult {
+            best_solution: best_solution.expect("at least one generation has been processed"),
+            iterations: curve.len() as u64,
+            curve,
+            duration: Local::now().signed_duration_since(self.started_at),
+            stop_reason,
+        })
+    }
+
     /// Processes one iteration of the algorithm used in this simulation.
     fn process_one_iteration(&mut self) -> Result<State<A>, <Self as Simulation<A>>::Error> {
         let loop_started_at = Local::now();
@@ -176,6 +306,27 @@ where
     }
 }
 
+/// Starts measuring the time elapsed, returns a function that reads it.
+#[cfg(not(target_arch = "wasm32"))]
+fn start_stopwatch() -> impl Fn() -> StdDuration {
+    let started_at = Instant::now();
+    move || started_at.elapsed()
+}
+
+/// Starts measuring the time elapsed, returns a function that reads it.
+/// `Instant::now` panics on wasm32, where the clock of chrono reads the time
+/// of JavaScript instead (with the `wasm-bindgen` feature).
+#[cfg(target_arch = "wasm32")]
+fn start_stopwatch() -> impl Fn() -> StdDuration {
+    let started_at = Local::now();
+    move || {
+        Local::now()
+            .signed_duration_since(started_at)
+            .to_std()
+            .unwrap_or_default()
+    }
+}
+
 impl<A, T> Simulation<A> for Simulator<A, T>
 where
     A: Algorithm + TrackProcessingTime + Debug,
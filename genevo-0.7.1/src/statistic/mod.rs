@@ -3,6 +3,8 @@
 
 use crate::types::fmt::Display;
 use chrono::{Duration, Local};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     convert::From,
     fmt,
@@ -10,6 +12,7 @@ use std::{
 };
 
 #[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct ProcessingTime {
     duration: Duration,
 }
@@ -63,6 +66,79 @@ impl AddAssign for ProcessingTime {
     }
 }
 
+/// The time spent in each stage of a generation of the genetic algorithm and
+/// the number of children it bred. In case of parallel processing the times
+/// are the accumulated times spent by each thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OperatorStatistics {
+    /// Evaluating the fitness of the population and finding its best solution.
+    pub evaluation: ProcessingTime,
+    pub selection: ProcessingTime,
+    pub crossover: ProcessingTime,
+    pub mutation: ProcessingTime,
+    pub repair: ProcessingTime,
+    pub local_search: ProcessingTime,
+    pub reinsertion: ProcessingTime,
+    /// The children bred by the crossover.
+    pub offspring: usize,
+    /// The children the mutation changed.
+    pub mutated: usize,
+    /// The children the repair changed.
+    pub repaired: usize,
+}
+
+impl OperatorStatistics {
+    pub fn zero() -> Self {
+        OperatorStatistics {
+            evaluation: ProcessingTime::zero(),
+            selection: ProcessingTime::zero(),
+            crossover: ProcessingTime::zero(),
+            mutation: ProcessingTime::zero(),
+            repair: ProcessingTime::zero(),
+            local_search: ProcessingTime::zero(),
+            reinsertion: ProcessingTime::zero(),
+            offspring: 0,
+            mutated: 0,
+            repaired: 0,
+        }
+    }
+
+    /// The time spent in all stages.
+    pub fn total(&self) -> ProcessingTime {
+        self.evaluation
+            + self.selection
+            + self.crossover
+            + self.mutation
+            + self.repair
+            + self.local_search
+            + self.reinsertion
+    }
+}
+
+impl Add for OperatorStatistics {
+    type Output = OperatorStatistics;
+    fn add(mut self, other: Self) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for OperatorStatistics {
+    fn add_assign(&mut self, other: Self) {
+        self.evaluation += other.evaluation;
+        self.selection += other.selection;
+        self.crossover += other.crossover;
+        self.mutation += other.mutation;
+        self.repair += other.repair;
+        self.local_search += other.local_search;
+        self.reinsertion += other.reinsertion;
+        self.offspring += other.offspring;
+        self.mutated += other.mutated;
+        self.repaired += other.repaired;
+    }
+}
+
 pub trait TrackProcessingTime {
     fn processing_time(&self) -> ProcessingTime;
 }
@@ -8,12 +8,18 @@
 //!   iterations has been processed.
 //! * `TimeLimit` - stops the simulation after a the specified time limit
 //!   has been reached.
+//! * `AverageFitnessLimit` - stops the simulation when the average fitness
+//!   of the population has reached a certain value.
+//! * `DiversityLimit` - stops the simulation when the diversity of the
+//!   population has fallen below a certain floor.
+//!
+//! The limits of the fitness and the diversity apply to any algorithm whose
+//! output is a `ga::State`, e.g. the `es::EvolutionStrategy`.
 
 use crate::{
     algorithm::Algorithm,
-    ga::GeneticAlgorithm,
-    genetic::{Fitness, FitnessFunction, Genotype},
-    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
+    ga,
+    genetic::{Fitness, Genotype},
     simulation::State,
     termination::{StopFlag, Termination},
 };
@@ -53,18 +59,13 @@ where
     }
 }
 
-impl<G, F, E, S, C, M, R> Termination<GeneticAlgorithm<G, F, E, S, C, M, R>> for FitnessLimit<G, F>
+impl<G, F, A> Termination<A> for FitnessLimit<G, F>
 where
     G: Genotype,
-    F: Fitness + Send + Sync,
-    E: FitnessFunction<G, F>,
-    E: FitnessFunction<G, F> + Sync,
-    S: SelectionOp<G, F>,
-    C: CrossoverOp<G> + Sync,
-    M: MutationOp<G> + Sync,
-    R: ReinsertionOp<G, F>,
+    F: Fitness,
+    A: Algorithm<Output = ga::State<G, F>>,
 {
-    fn evaluate(&mut self, state: &State<GeneticAlgorithm<G, F, E, S, C, M, R>>) -> StopFlag {
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
         let highest_fitness = &state.result.best_solution.solution.fitness;
         if *highest_fitness >= self.fitness_target {
             StopFlag::StopNow(format!(
@@ -78,6 +79,128 @@ where
     }
 }
 
+/// The `AverageFitnessLimit` condition stops the simulation when the average
+/// fitness of the population has reached a certain value, i.e. when not only
+/// the best solution but the population as a whole is good enough.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AverageFitnessLimit<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    _g: PhantomData<G>,
+    /// The average fitness value that shall be reached to stop the
+    /// simulation.
+    fitness_target: F,
+}
+
+impl<G, F> AverageFitnessLimit<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Create a new instance of `AverageFitnessLimit` with the specified
+    /// target of the average fitness.
+    pub fn new(fitness_target: F) -> Self {
+        AverageFitnessLimit {
+            _g: PhantomData,
+            fitness_target,
+        }
+    }
+
+    /// Returns the average fitness value that shall be reached to stop the
+    /// simulation.
+    pub fn fitness_target(&self) -> &F {
+        &self.fitness_target
+    }
+}
+
+impl<G, F, A> Termination<A> for AverageFitnessLimit<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+    A: Algorithm<Output = ga::State<G, F>>,
+{
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
+        let average_fitness = state.result.evaluated_population.average_fitness();
+        if *average_fitness >= self.fitness_target {
+            StopFlag::StopNow(format!(
+                "Simulation stopped after the average fitness of the population \
+                 has reached {:?}.",
+                average_fitness
+            ))
+        } else {
+            StopFlag::Continue
+        }
+    }
+}
+
+/// The `DiversityLimit` condition stops the simulation when the diversity of
+/// the population has fallen below a floor, i.e. when the population has
+/// effectively converged and further generations are unlikely to find
+/// better solutions.
+///
+/// The diversity is measured as the number of distinct genotypes divided by
+/// the size of the population. It is 1 if all individuals are different and
+/// approaches 0 if all individuals are the same.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversityLimit {
+    /// The diversity below which the simulation is stopped.
+    min_diversity: f64,
+}
+
+impl DiversityLimit {
+    /// Create a new instance of `DiversityLimit` with the specified floor
+    /// of the diversity.
+    pub fn new(min_diversity: f64) -> Self {
+        DiversityLimit { min_diversity }
+    }
+
+    /// Returns the diversity below which the simulation is stopped.
+    pub fn min_diversity(&self) -> f64 {
+        self.min_diversity
+    }
+}
+
+/// Calculates the number of distinct genotypes divided by the number of
+/// all genotypes.
+pub fn genotype_diversity<G>(individuals: &[G]) -> f64
+where
+    G: Genotype,
+{
+    if individuals.is_empty() {
+        return 0.;
+    }
+    let mut distinct: Vec<&G> = Vec::with_capacity(individuals.len());
+    for individual in individuals {
+        if !distinct.contains(&individual) {
+            distinct.push(individual);
+        }
+    }
+    distinct.len() as f64 / individuals.len() as f64
+}
+
+impl<G, F, A> Termination<A> for DiversityLimit
+where
+    G: Genotype,
+    F: Fitness,
+    A: Algorithm<Output = ga::State<G, F>>,
+{
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
+        let diversity = genotype_diversity(&state.result.evaluated_population.individuals());
+        if diversity < self.min_diversity {
+            StopFlag::StopNow(format!(
+                "Simulation stopped after the diversity of the population has \
+                 fallen to {:.3} which is below the floor of {:.3}.",
+                diversity, self.min_diversity
+            ))
+        } else {
+            StopFlag::Continue
+        }
+    }
+}
+
 /// The `GenerationLimit` condition stops the simulation after a maximum
 /// number of generations has been processed.
 #[allow(missing_copy_implementations)]
@@ -133,6 +256,15 @@ impl TimeLimit {
         TimeLimit { max_time }
     }
 
+    /// Create a new instance of `TimeLimit` from a `std::time::Duration`,
+    /// e.g. `TimeLimit::from_std(Duration::from_secs(60))`. A duration that
+    /// is too large to be represented is capped at the maximal duration.
+    pub fn from_std(max_time: std::time::Duration) -> Self {
+        TimeLimit {
+            max_time: Duration::from_std(max_time).unwrap_or(Duration::MAX),
+        }
+    }
+
     /// Returns the maximum time the simulation should run.
     pub fn max_time(&self) -> &Duration {
         &self.max_time
@@ -156,3 +288,30 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn genotype_diversity_is_ratio_of_distinct_genotypes() {
+        let individuals = vec![vec![1, 2], vec![1, 2], vec![2, 1], vec![1, 2]];
+
+        expect_that!(&genotype_diversity(&individuals), eq(0.5));
+    }
+
+    #[test]
+    fn time_limit_from_std_duration() {
+        let time_limit = TimeLimit::from_std(std::time::Duration::from_secs(60));
+
+        expect_that!(time_limit.max_time(), eq(Duration::seconds(60)));
+    }
+
+    #[test]
+    fn time_limit_from_std_duration_caps_too_large_durations() {
+        let time_limit = TimeLimit::from_std(std::time::Duration::MAX);
+
+        expect_that!(time_limit.max_time(), eq(Duration::MAX));
+    }
+}
@@ -19,12 +19,18 @@
 //!
 //! For convenience the provided combinators can be instantiated by using
 //! the public functions `and` and `or` which are reexported by this module.
+//!
+//! Ad-hoc conditions can be expressed as a closure wrapped by the `StopWhen`
+//! condition, which is created by the reexported function `stop_when`.
 
 pub mod combinator;
 pub use self::combinator::{and, or};
 
 pub mod limit;
 
+pub mod predicate;
+pub use self::predicate::stop_when;
+
 use crate::{algorithm::Algorithm, simulation::State};
 
 /// The `StopFlag` is the result of the `Termination` function. It tells
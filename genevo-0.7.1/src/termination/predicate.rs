@@ -0,0 +1,54 @@
+//! The `predicate` package provides a `Termination` function that delegates
+//! the decision whether to stop the simulation to a user defined closure.
+//!
+//! It is useful to express ad-hoc stopping rules in experiments without
+//! implementing a separate type for each of them.
+
+use crate::{
+    algorithm::Algorithm,
+    simulation::State,
+    termination::{StopFlag, Termination},
+};
+use std::fmt::{self, Debug};
+
+/// Creates a `StopWhen` condition from the given closure.
+pub fn stop_when<P, A>(predicate: P) -> StopWhen<P>
+where
+    P: FnMut(&State<A>) -> StopFlag,
+    A: Algorithm,
+{
+    StopWhen::new(predicate)
+}
+
+/// The `StopWhen` condition stops the simulation when the wrapped closure
+/// returns `StopFlag::StopNow` for the current `State` of the simulation.
+///
+/// The closure may capture and update its own state, e.g. to count the
+/// generations without improvement. Such state is not reset when the
+/// simulation is reset.
+#[derive(Clone)]
+pub struct StopWhen<P> {
+    predicate: P,
+}
+
+impl<P> StopWhen<P> {
+    pub fn new(predicate: P) -> Self {
+        StopWhen { predicate }
+    }
+}
+
+impl<P> Debug for StopWhen<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StopWhen").finish_non_exhaustive()
+    }
+}
+
+impl<P, A> Termination<A> for StopWhen<P>
+where
+    P: FnMut(&State<A>) -> StopFlag,
+    A: Algorithm,
+{
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
+        (self.predicate)(state)
+    }
+}
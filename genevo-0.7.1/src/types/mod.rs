@@ -1,5 +1,6 @@
-//! This module provides implementations of the `genetic::Fitness` trait for
-//! some primitive types, such as `i32`, `i64` et cetera.
+//! This module provides implementations of the `genetic::Fitness`,
+//! `genetic::AsScalar` and `genetic::FromScalar` traits for some primitive
+//! types, such as `i32`, `i64` et cetera.
 //! This is because Rust does not allow programmers to implement
 //! a foreign trait for a foreign type, which would stop you as a library user
 //! from using primitive types as fitness values.
@@ -19,7 +20,7 @@
 
 pub mod fmt;
 
-use crate::genetic::{AsScalar, Fitness};
+use crate::genetic::{AsScalar, Fitness, FromScalar};
 
 macro_rules! implement_fitness_for_signed_integer {
     ( $($t:ty),* ) => {
@@ -41,6 +42,13 @@ macro_rules! implement_fitness_for_signed_integer {
                     *self as f64
                 }
             }
+
+            impl FromScalar for $t {
+                #[inline]
+                fn from_scalar(value: f64) -> $t {
+                    value.round() as $t
+                }
+            }
         )*
     }
 }
@@ -70,6 +78,13 @@ macro_rules! implement_fitness_for_unsigned_integer {
                     *self as f64
                 }
             }
+
+            impl FromScalar for $t {
+                #[inline]
+                fn from_scalar(value: f64) -> $t {
+                    value.round() as $t
+                }
+            }
         )*
     }
 }
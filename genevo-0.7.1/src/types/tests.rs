@@ -326,3 +326,31 @@ fn abs_diff_of_unsigned_1_and_max() {
         is(equal_to(usize::MAX - 1))
     );
 }
+
+#[test]
+fn from_scalar_rounds_to_the_nearest_value() {
+    expect_that!(&<i32 as FromScalar>::from_scalar(-2.5), is(equal_to(-3i32)));
+    expect_that!(&<i64 as FromScalar>::from_scalar(41.6), is(equal_to(42i64)));
+    expect_that!(&<u32 as FromScalar>::from_scalar(7.4), is(equal_to(7u32)));
+    expect_that!(
+        &<usize as FromScalar>::from_scalar(0.5),
+        is(equal_to(1usize))
+    );
+}
+
+#[test]
+fn from_scalar_saturates_out_of_range_values() {
+    expect_that!(
+        &<u8 as FromScalar>::from_scalar(300.),
+        is(equal_to(u8::MAX))
+    );
+    expect_that!(&<u8 as FromScalar>::from_scalar(-1.), is(equal_to(0u8)));
+    expect_that!(
+        &<i8 as FromScalar>::from_scalar(-300.),
+        is(equal_to(i8::MIN))
+    );
+    expect_that!(
+        &<usize as FromScalar>::from_scalar(f64::NAN),
+        is(equal_to(0usize))
+    );
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    operator::{prelude::*, CrossoverOp, MutationOp},
+    population::BinaryEncodedGenomeBuilder,
+    prelude::*,
+};
+use std::fmt::Debug;
+
+mod common;
+
+use common::{Bits, CountOnes};
+
+// Random genomes of which none has a one in the first locus
+fn initial_population() -> Population<Bits> {
+    let population: Population<Bits> = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(16))
+        .of_size(20)
+        .using_seed([7; 32]);
+    Population::with_individuals(
+        population
+            .individuals()
+            .iter()
+            .map(|genome| {
+                let mut genome = genome.clone();
+                genome[0] = false;
+                genome
+            })
+            .collect(),
+    )
+}
+
+// Whether any individual of the 50 generations had a one in the first locus
+fn first_locus_ever_set<C, M>(crossover: C, mutation: M) -> bool
+where
+    C: CrossoverOp<Bits> + Debug + PartialEq + Sync,
+    M: MutationOp<Bits> + Debug + PartialEq + Sync,
+{
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(crossover)
+            .with_mutation(mutation)
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(initial_population())
+            .build(),
+    )
+    .until(GenerationLimit::new(50))
+    .build_with_seed([42; 32]);
+
+    let mut ever_set = false;
+    loop {
+        let (state, done) = match sim.step() {
+            Ok(SimResult::Intermediate(state)) => (state, false),
+            Ok(SimResult::Final(state, ..)) => (state, true),
+            Err(error) => panic!("{}", error),
+        };
+        ever_set |= state
+            .result
+            .evaluated_population
+            .individuals()
+            .iter()
+            .any(|genome| genome[0]);
+        if done {
+            return ever_set;
+        }
+    }
+}
+
+#[test]
+fn without_mutation_no_new_alleles_enter_the_population() {
+    expect_that!(
+        &first_locus_ever_set(UniformCrossBreeder::new(), NoOpMutator),
+        eq(false)
+    );
+    expect_that!(
+        &first_locus_ever_set(
+            UniformCrossBreeder::new(),
+            RandomValueMutator::new(0.1, false, true)
+        ),
+        eq(true)
+    );
+}
+
+#[test]
+fn without_crossover_mutation_alone_finds_new_alleles() {
+    expect_that!(
+        &first_locus_ever_set(NoOpCrossover, RandomValueMutator::new(0.1, false, true)),
+        eq(true)
+    );
+}
+
+#[test]
+fn the_stages_can_be_skipped_by_the_builder() {
+    let algorithm = genetic_algorithm()
+        .with_evaluation(CountOnes)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .without_crossover()
+        .without_mutation()
+        .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+        .with_initial_population(initial_population())
+        .build();
+
+    expect_that!(algorithm.breeder(), eq(NoOpCrossover));
+    expect_that!(algorithm.mutator(), eq(NoOpMutator));
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    aco::{tour_length, Tour},
+    prelude::*,
+};
+use std::f64::consts::PI;
+
+const CITIES: usize = 12;
+
+// The cities evenly spaced on the unit circle, visited in order by the shortest tour
+fn distances_on_a_circle() -> Vec<Vec<f64>> {
+    let cities: Vec<(f64, f64)> = (0..CITIES)
+        .map(|i| {
+            let angle = 2. * PI * i as f64 / CITIES as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    cities
+        .iter()
+        .map(|a| {
+            cities
+                .iter()
+                .map(|b| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt())
+                .collect()
+        })
+        .collect()
+}
+
+fn shortest_length() -> f64 {
+    CITIES as f64 * 2. * (PI / CITIES as f64).sin()
+}
+
+// The negated tour length, scaled to integer fitness values
+#[derive(Clone, Debug, PartialEq)]
+struct TourLength(Vec<Vec<f64>>);
+
+impl FitnessFunction<Tour, i64> for TourLength {
+    fn fitness_of(&self, tour: &Tour) -> i64 {
+        -(tour_length(tour, &self.0) * 1e6).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        values.iter().sum::<i64>() / values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        -(shortest_length() * 1e6).round() as i64
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+fn is_permutation(tour: &Tour) -> bool {
+    let mut cities = tour.clone();
+    cities.sort_unstable();
+    cities == (0..CITIES).collect::<Vec<_>>()
+}
+
+#[test]
+fn the_ants_build_tours_through_all_cities_and_find_the_shortest() {
+    let distances = distances_on_a_circle();
+    let algorithm = AntColony::new(TourLength(distances.clone()), distances, 10);
+    let mut sim = simulate(algorithm)
+        .until(or(
+            FitnessLimit::new(TourLength(vec![]).highest_possible_fitness()),
+            GenerationLimit::new(200),
+        ))
+        .build();
+
+    let mut highest_fitness = i64::MIN;
+    let state = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                let fitness = state.result.best_solution.solution.fitness;
+                expect_that!(&fitness, greater_than_or_equal(highest_fitness));
+                let tours = state.result.evaluated_population.individuals();
+                expect_that!(&tours.len(), eq(10));
+                expect_that!(&tours.iter().all(is_permutation), eq(true));
+                highest_fitness = fitness;
+            },
+            Ok(SimResult::Final(state, ..)) => break state,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(
+        &tour_length(
+            &state.result.best_solution.solution.genome,
+            &distances_on_a_circle()
+        ),
+        less_than(shortest_length() + 1e-6)
+    );
+}
+
+#[test]
+fn the_pheromone_evaporates_and_stays_symmetric() {
+    let distances = distances_on_a_circle();
+    let mut algorithm = AntColony::new(TourLength(distances.clone()), distances, 5)
+        .with_coefficients(1., 3., 0.9)
+        .with_elitist_ants(2);
+    let initial = algorithm.pheromone()[0][1];
+
+    algorithm
+        .next(1, &mut genevo::random::get_rng([7; 32]))
+        .unwrap();
+
+    let pheromone = algorithm.pheromone();
+    for i in 0..CITIES {
+        for j in 0..CITIES {
+            expect_that!(&pheromone[i][j], eq(pheromone[j][i]));
+        }
+    }
+    // An edge that no ant took keeps a tenth of its pheromone
+    let least = pheromone
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    expect_that!(&least, less_than(initial * 0.1 + 1e-12));
+}
+
+#[test]
+fn a_colony_without_ants_fails() {
+    let distances = distances_on_a_circle();
+    let algorithm = AntColony::new(TourLength(distances.clone()), distances, 0);
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    expect_that!(&sim.run().is_err(), eq(true));
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::prelude::*;
+use std::time::Duration;
+
+mod common;
+
+use common::{count_ones_simulator, random_population};
+
+#[test]
+fn anytime_run_with_exhausted_budget_processes_exactly_one_generation() {
+    let mut sim = count_ones_simulator(random_population(20), 1_000);
+
+    let result = sim.run_anytime(Duration::from_secs(0)).unwrap();
+
+    expect_that!(&result.iterations, eq(1));
+    expect_that!(&result.curve.len(), eq(1));
+    expect_that!(&result.stop_reason, eq(AnytimeStop::Budget));
+}
+
+#[test]
+fn anytime_run_stops_at_termination_when_budget_is_sufficient() {
+    let mut sim = count_ones_simulator(random_population(20), 5);
+
+    let result = sim.run_anytime(Duration::from_secs(60)).unwrap();
+
+    expect_that!(&result.iterations, eq(5));
+    expect_that!(&result.curve.len(), eq(5));
+    expect_that!(
+        &result.best_solution.solution.fitness,
+        eq(*result.curve.iter().max().unwrap())
+    );
+    match result.stop_reason {
+        AnytimeStop::Terminated(_) => (),
+        other => panic!("expected termination, got {:?}", other),
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::prelude::*;
+
+type Point = Vec<f64>;
+
+// The negated Rosenbrock function, scaled to integer fitness values
+#[derive(Clone, Debug, PartialEq)]
+struct Rosenbrock;
+
+impl FitnessFunction<Point, i64> for Rosenbrock {
+    fn fitness_of(&self, x: &Point) -> i64 {
+        let value: f64 = x
+            .windows(2)
+            .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+            .sum();
+        -(value * 1e9).min(i64::MAX as f64).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        (values.iter().map(|v| *v as i128).sum::<i128>() / values.len() as i128) as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+#[test]
+fn the_cma_es_follows_the_valley_of_rosenbrock_to_its_optimum() {
+    let algorithm = CmaEs::new(Rosenbrock, vec![-1.; 5], 0.5);
+    let mut sim = simulate(algorithm)
+        .until(or(FitnessLimit::new(-1_000), GenerationLimit::new(2_000)))
+        .build();
+
+    let mut highest_fitness = i64::MIN;
+    let state = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                let fitness = state.result.best_solution.solution.fitness;
+                expect_that!(&fitness, greater_than_or_equal(highest_fitness));
+                // 4 + ⌊3 ln 5⌋ candidates per generation
+                expect_that!(
+                    &state.result.evaluated_population.individuals().len(),
+                    eq(8)
+                );
+                highest_fitness = fitness;
+            },
+            Ok(SimResult::Final(state, ..)) => break state,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    // Within 1e-6 of the optimum at (1, ..., 1)
+    expect_that!(
+        &state.result.best_solution.solution.fitness,
+        greater_than_or_equal(-1_000)
+    );
+    for x in &state.result.best_solution.solution.genome {
+        expect_that!(&(x - 1.).abs(), less_than(0.01));
+    }
+}
+
+#[test]
+fn the_candidates_of_the_cma_es_are_clamped_to_the_bounds() {
+    let algorithm = CmaEs::new(Rosenbrock, vec![0.; 4], 10.)
+        .with_population_size(20)
+        .with_bounds(-2., 2.);
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(20)).build();
+
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                let population = &state.result.evaluated_population;
+                expect_that!(&population.individuals().len(), eq(20));
+                for point in population.individuals().iter() {
+                    expect_that!(&point.iter().all(|x| (-2. ..=2.).contains(x)), eq(true));
+                }
+            },
+            Ok(SimResult::Final(..)) => break,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[test]
+fn a_cma_es_sampling_a_single_candidate_fails() {
+    let algorithm = CmaEs::new(Rosenbrock, vec![0.; 4], 1.).with_population_size(1);
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    expect_that!(&sim.run().is_err(), eq(true));
+}
@@ -0,0 +1,88 @@
+//! The fixtures shared by the integration tests: bit strings of up to
+//! `BITS` bits whose fitness is their number of ones, and a genetic algorithm
+//! that maximizes it.
+
+// Each test uses some of the fixtures only
+#![allow(dead_code)]
+
+use genevo::{
+    ga::GeneticAlgorithm, operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*,
+    simulation::simulator::Simulator,
+};
+
+pub type Bits = Vec<bool>;
+
+/// The number of bits of the genotypes of the populations built here.
+pub const BITS: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CountOnes;
+
+impl FitnessFunction<Bits, usize> for CountOnes {
+    fn fitness_of(&self, genome: &Bits) -> usize {
+        genome.iter().filter(|bit| **bit).count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        BITS
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+pub type CountOnesAlgorithm = GeneticAlgorithm<
+    Bits,
+    usize,
+    CountOnes,
+    MaximizeSelector,
+    UniformCrossBreeder,
+    RandomValueMutator<Bits>,
+    ElitistReinserter<Bits, usize, CountOnes>,
+>;
+
+pub type CountOnesSimulator = Simulator<CountOnesAlgorithm, GenerationLimit>;
+
+/// A population of the given size of random bit strings.
+pub fn random_population(size: usize) -> Population<Bits> {
+    build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(BITS))
+        .of_size(size)
+        .uniform_at_random()
+}
+
+/// A population of the given size of bit strings that is the same in every
+/// run.
+pub fn seeded_population(size: usize) -> Population<Bits> {
+    build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(BITS))
+        .of_size(size)
+        .using_seed([7; 32])
+}
+
+/// A genetic algorithm that maximizes the ones of the initial population.
+pub fn count_ones_algorithm(initial_population: Population<Bits>) -> CountOnesAlgorithm {
+    genetic_algorithm()
+        .with_evaluation(CountOnes)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.05, false, true))
+        .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+        .with_initial_population(initial_population)
+        .build()
+}
+
+/// A simulation of the `count_ones_algorithm` up to the generation limit.
+pub fn count_ones_simulator(
+    initial_population: Population<Bits>,
+    generation_limit: u64,
+) -> CountOnesSimulator {
+    simulate(count_ones_algorithm(initial_population))
+        .until(GenerationLimit::new(generation_limit))
+        .build()
+}
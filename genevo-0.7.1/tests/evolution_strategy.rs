@@ -0,0 +1,80 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, prelude::*};
+
+mod common;
+
+use common::{random_population, CountOnes, BITS};
+
+#[test]
+fn the_one_plus_one_ea_never_gets_worse_and_finds_the_optimum_of_onemax() {
+    let algorithm = EvolutionStrategy::one_plus_one(
+        CountOnes,
+        RandomValueMutator::new(2. / BITS as f64, false, true),
+        random_population(1),
+    );
+    let mut sim = simulate(algorithm)
+        .until(or(FitnessLimit::new(BITS), GenerationLimit::new(10_000)))
+        .build();
+
+    let mut highest_fitness = 0;
+    let state = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                let fitness = state.result.best_solution.solution.fitness;
+                expect_that!(&fitness, greater_than_or_equal(highest_fitness));
+                expect_that!(&state.result.evaluated_population.individuals().len(), eq(1));
+                highest_fitness = fitness;
+            }
+            Ok(SimResult::Final(state, ..)) => break state,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(&state.result.best_solution.solution.fitness, eq(BITS));
+}
+
+#[test]
+fn the_mu_plus_lambda_es_keeps_the_mu_best_and_runs_generations_per_iteration() {
+    let algorithm = EvolutionStrategy::new(
+        CountOnes,
+        RandomValueMutator::new(2. / BITS as f64, false, true),
+        4,
+        16,
+        random_population(8),
+    )
+    .with_generations_per_iteration(10);
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    let state = match sim.run() {
+        Ok(SimResult::Final(state, ..)) => state,
+        other => panic!("expected a final result, got {:?}", other),
+    };
+
+    let population = &state.result.evaluated_population;
+    expect_that!(&population.individuals().len(), eq(4));
+    expect_that!(
+        population.highest_fitness(),
+        eq(state.result.best_solution.solution.fitness)
+    );
+    // 50 generations of 16 offspring are plenty for 64 bits
+    expect_that!(population.lowest_fitness(), greater_than_or_equal(BITS - 4));
+}
+
+#[test]
+fn an_es_with_more_parents_than_the_initial_population_fails() {
+    let algorithm = EvolutionStrategy::new(
+        CountOnes,
+        RandomValueMutator::new(0.1, false, true),
+        4,
+        4,
+        random_population(2),
+    );
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    expect_that!(&sim.run().is_err(), eq(true));
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    encoding::{Primitive, Primitives, TreeGenome},
+    operator::prelude::*,
+    population::TreeGenomeBuilder,
+    prelude::*,
+};
+
+const MAX_DEPTH: usize = 6;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Op {
+    X,
+    One,
+    Add,
+    Mul,
+}
+
+impl Primitive for Op {
+    fn arity(&self) -> usize {
+        match self {
+            Op::X | Op::One => 0,
+            Op::Add | Op::Mul => 2,
+        }
+    }
+}
+
+fn value_at(expression: &TreeGenome<Op>, x: i64) -> i64 {
+    expression.evaluate(|op, args: &[i64]| match op {
+        Op::X => x,
+        Op::One => 1,
+        Op::Add => args[0].saturating_add(args[1]),
+        Op::Mul => args[0].saturating_mul(args[1]),
+    })
+}
+
+// The number of samples of x² + x + 1 the expression hits
+#[derive(Clone, Debug, PartialEq)]
+struct Hits;
+
+impl FitnessFunction<TreeGenome<Op>, usize> for Hits {
+    fn fitness_of(&self, expression: &TreeGenome<Op>) -> usize {
+        (-5..=5)
+            .filter(|x| value_at(expression, *x) == x * x + x + 1)
+            .count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        11
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn the_expression_of_a_polynomial_is_evolved_within_the_max_depth() {
+    let primitives = Primitives::new(vec![Op::X, Op::One], vec![Op::Add, Op::Mul]);
+    let initial_population: Population<TreeGenome<Op>> = build_population()
+        .with_genome_builder(TreeGenomeBuilder::new(primitives.clone(), 1, 4))
+        .of_size(100)
+        .using_seed([7; 32]);
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(Hits)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(SubtreeCrossBreeder::new().with_max_depth(MAX_DEPTH))
+            .with_mutation(SubtreeMutator::new(0.2, primitives, 2).with_max_depth(MAX_DEPTH))
+            .with_reinsertion(ElitistReinserter::new(Hits, false, 0.5))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(or(FitnessLimit::new(11), GenerationLimit::new(200)))
+    .build_with_seed([17; 32]);
+
+    let best = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                for individual in step.result.evaluated_population.individuals().iter() {
+                    expect_that!(&individual.depth(), less_than_or_equal(MAX_DEPTH));
+                }
+            }
+            Ok(SimResult::Final(state, ..)) => break state.result.best_solution.solution,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(&best.fitness, eq(11));
+    expect_that!(&value_at(&best.genome, 10), eq(111));
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::prelude::*;
+use std::sync::atomic::AtomicBool;
+
+mod common;
+
+use common::{count_ones_simulator, random_population};
+
+#[test]
+fn islands_run_until_the_termination_of_an_island() {
+    let model = IslandModel::new(4, 5, 2, Topology::Ring);
+
+    let result = model
+        .run(|_| count_ones_simulator(random_population(10), 20))
+        .unwrap();
+
+    expect_that!(&result.generations, eq(20));
+    expect_that!(&result.curve.len(), eq(20));
+    expect_that!(
+        &result.best_solution.solution.fitness,
+        eq(*result.curve.iter().max().unwrap())
+    );
+}
+
+#[test]
+fn islands_stop_at_the_end_of_the_migration_interval() {
+    let model = IslandModel::new(3, 4, 1, Topology::FullyConnected);
+
+    let result = model
+        .run(|island| {
+            count_ones_simulator(random_population(10), if island == 0 { 6 } else { 100 })
+        })
+        .unwrap();
+
+    expect_that!(&result.generations, eq(8));
+}
+
+#[test]
+fn islands_stop_at_the_end_of_the_migration_interval_once_stop_is_requested() {
+    let model = IslandModel::new(2, 3, 1, Topology::Ring);
+
+    let result = model
+        .run_until_stopped(
+            |_| count_ones_simulator(random_population(10), 100),
+            &AtomicBool::new(true),
+        )
+        .unwrap();
+
+    expect_that!(&result.generations, eq(3));
+    expect_that!(&result.curve.len(), eq(3));
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, prelude::*};
+use std::sync::Mutex;
+
+mod common;
+
+use common::{seeded_population, Bits, CountOnes};
+
+// The generation, the number of parents and the genomes of a birth
+type BirthRecord = (u64, usize, Bits, Bits, Bits);
+
+#[derive(Debug, Default)]
+struct Recorder {
+    births: Mutex<Vec<BirthRecord>>,
+}
+
+impl LineageHook<Bits> for Recorder {
+    fn on_birth(&self, birth: &Birth<Bits>) {
+        self.births.lock().unwrap().push((
+            birth.generation,
+            birth.parents.len(),
+            birth.crossed.clone(),
+            birth.mutated.clone(),
+            birth.child.clone(),
+        ));
+    }
+}
+
+fn run<H>(size: usize, lineage: H, generations: u64) -> Vec<Bits>
+where
+    H: LineageHook<Bits>,
+{
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(seeded_population(size))
+            .with_lineage(lineage)
+            .build(),
+    )
+    .until(GenerationLimit::new(100))
+    .build_with_seed([42; 32]);
+    for _ in 0..generations {
+        sim.step().unwrap();
+    }
+    sim.population_snapshot()
+        .into_iter()
+        .map(|evaluated| evaluated.genome)
+        .collect()
+}
+
+#[test]
+fn the_hook_sees_the_birth_of_each_child() {
+    let recorder = Recorder::default();
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(seeded_population(20))
+            .with_lineage(recorder)
+            .build(),
+    )
+    .until(GenerationLimit::new(100))
+    .build();
+    sim.step().unwrap();
+    sim.step().unwrap();
+
+    let births = sim.algorithm().lineage().births.lock().unwrap();
+    // 10 tuples of 2 parents breed 2 children each generation
+    expect_that!(&births.len(), eq(40));
+    expect_that!(&births.iter().filter(|b| b.0 == 1).count(), eq(20));
+    expect_that!(&births.iter().filter(|b| b.0 == 2).count(), eq(20));
+    for (_, parents, _, mutated, child) in births.iter() {
+        expect_that!(parents, eq(2));
+        // there is no local search, so the child is the mutated genome
+        expect_that!(child, eq(mutated.clone()));
+    }
+}
+
+#[test]
+fn tracking_does_not_change_the_evolution() {
+    // 100 tuples of parents are bred in parallel
+    for size in &[20, 200] {
+        let untracked = run(*size, NoLineage, 5);
+        let tracked = run(*size, Recorder::default(), 5);
+        expect_that!(&tracked, eq(untracked));
+    }
+}
+
+#[test]
+fn the_family_tree_of_the_best_individual_goes_back_to_the_initial_population() {
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(seeded_population(20))
+            .with_lineage(Ancestry::new())
+            .build(),
+    )
+    .until(GenerationLimit::new(100))
+    .build();
+    let mut best = vec![];
+    for _ in 0..10 {
+        if let Ok(SimResult::Intermediate(state)) = sim.step() {
+            best = state.result.best_solution.solution.genome;
+        }
+    }
+
+    let ancestry = sim.algorithm().lineage();
+    let tree = ancestry.family_tree(&best);
+    expect_that!(&tree.is_empty(), eq(false));
+    expect_that!(&tree.last().map(|i| i.id), eq(ancestry.id_of(&best)));
+    expect_that!(&tree.iter().any(|i| i.parent_ids.is_empty()), eq(true));
+    for individual in &tree {
+        expect_that!(
+            &individual.parent_ids.is_empty(),
+            eq(individual.generation == 1)
+        );
+        for parent_id in &individual.parent_ids {
+            let parent = tree.iter().find(|i| i.id == *parent_id).unwrap();
+            expect_that!(&parent.generation, less_than(individual.generation));
+        }
+    }
+}
+
+#[test]
+fn family_trees_are_written_as_dot() {
+    let tree = vec![
+        Individual {
+            id: 0,
+            parent_ids: vec![],
+            generation: 1,
+            genome: vec![true],
+        },
+        Individual {
+            id: 1,
+            parent_ids: vec![],
+            generation: 1,
+            genome: vec![false],
+        },
+        Individual {
+            id: 2,
+            parent_ids: vec![0, 1],
+            generation: 2,
+            genome: vec![true],
+        },
+    ];
+
+    let dot = family_tree_to_dot(&tree, |i| format!("#{} {:?}", i.id, i.genome));
+
+    let expected = "digraph ancestry {\n    node [shape=box];\n    n0 [label=\"#0 [true]\"];\n    \
+        n1 [label=\"#1 [false]\"];\n    n2 [label=\"#2 [true]\"];\n    n0 -> n2;\n    \
+        n1 -> n2;\n}\n";
+    expect_that!(&dot, eq(expected.to_string()));
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    encoding::Matrix,
+    operator::{prelude::*, CrossoverOp},
+    population::{HammingDistance, MatrixGenomeBuilder},
+    prelude::*,
+};
+use std::fmt::Debug;
+
+const ROWS: usize = 6;
+const COLS: usize = 8;
+
+// The number of cells that match a checkerboard pattern
+#[derive(Clone, Debug, PartialEq)]
+struct Checkerboard;
+
+impl FitnessFunction<Matrix<u8>, usize> for Checkerboard {
+    fn fitness_of(&self, genome: &Matrix<u8>) -> usize {
+        (0..ROWS)
+            .flat_map(|row| (0..COLS).map(move |col| (row, col)))
+            .filter(|(row, col)| *genome.get(*row, *col) as usize == (row + col) % 2)
+            .count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        ROWS * COLS
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The best fitness after at most 300 generations of breeding the matrices by the crossover
+fn best_fitness<C>(crossover: C) -> usize
+where
+    C: CrossoverOp<Matrix<u8>> + Debug + PartialEq + Sync,
+{
+    let initial_population: Population<Matrix<u8>> = build_population()
+        .with_genome_builder(MatrixGenomeBuilder::new(ROWS, COLS, 0, 2))
+        .of_size(40)
+        .using_seed([3; 32]);
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(Checkerboard)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(crossover)
+            .with_mutation(RandomValueMutator::new(0.02, 0, 2))
+            .with_reinsertion(ElitistReinserter::new(Checkerboard, false, 0.5))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(or(
+        FitnessLimit::new(ROWS * COLS),
+        GenerationLimit::new(300),
+    ))
+    .build_with_seed([11; 32]);
+
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(_)) => (),
+            Ok(SimResult::Final(state, ..)) => {
+                return state.result.best_solution.solution.fitness;
+            }
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[test]
+fn the_matrix_crossovers_evolve_a_checkerboard() {
+    expect_that!(&best_fitness(RowCrossBreeder::new()), eq(ROWS * COLS));
+    expect_that!(&best_fitness(ColumnCrossBreeder::new()), eq(ROWS * COLS));
+    expect_that!(&best_fitness(BlockCrossBreeder::new()), eq(ROWS * COLS));
+}
+
+#[test]
+fn matrices_are_as_distant_as_the_cells_they_differ_in() {
+    let mut other = Matrix::new(2, 2, 0u8);
+    other.set(1, 1, 1);
+
+    expect_that!(&Matrix::new(2, 2, 0u8).hamming_distance(&other), eq(1));
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    operator::{prelude::*, GeneticOperator, LocalSearchOp},
+    prelude::*,
+};
+
+mod common;
+
+use common::{random_population, Bits, CountOnes};
+
+// A "local search" that jumps straight to the optimum
+#[derive(Clone, Debug, PartialEq)]
+struct SetAllBits;
+
+impl GeneticOperator for SetAllBits {
+    fn name() -> String {
+        "Set-All-Bits".to_string()
+    }
+}
+
+impl LocalSearchOp<Bits, usize> for SetAllBits {
+    fn improve<E, R>(&self, genome: Bits, _: &E, _: &mut R) -> Bits
+    where
+        E: FitnessFunction<Bits, usize>,
+        R: Rng + Sized,
+    {
+        vec![true; genome.len()]
+    }
+}
+
+#[test]
+fn offspring_is_improved_by_the_local_search_stage() {
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(random_population(20))
+            .with_local_search(SetAllBits)
+            .build(),
+    )
+    .until(GenerationLimit::new(100))
+    .build();
+    sim.step().unwrap();
+
+    match sim.step() {
+        Ok(SimResult::Intermediate(state)) => {
+            expect_that!(&state.result.best_solution.solution.fitness, eq(64));
+        }
+        other => panic!("expected an intermediate result, got {:?}", other),
+    }
+}
+
+#[test]
+fn hill_climbing_finds_the_optimum() {
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(random_population(20))
+            .with_local_search(HillClimber::new(
+                RandomValueMutator::new(1. / 64., false, true),
+                16,
+            ))
+            .build(),
+    )
+    .until(or(
+        FitnessLimit::new(CountOnes.highest_possible_fitness()),
+        GenerationLimit::new(2_000),
+    ))
+    .build();
+
+    match sim.run() {
+        Ok(SimResult::Final(state, _, _, _)) => {
+            expect_that!(&state.result.best_solution.solution.fitness, eq(64));
+        }
+        other => panic!("expected a final result, got {:?}", other),
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{nsga2::dominates, operator::prelude::*, prelude::*};
+use rand::Rng;
+
+type Point = Vec<f64>;
+
+/// Schaffer's problem: minimize x^2 and (x - 2)^2. The Pareto optimal
+/// solutions are all x within [0, 2].
+#[derive(Clone, Debug, PartialEq)]
+struct Schaffer;
+
+impl ObjectiveFunction<Point> for Schaffer {
+    fn objectives_of(&self, genome: &Point) -> Objectives {
+        let x = genome[0];
+        vec![x * x, (x - 2.) * (x - 2.)]
+    }
+}
+
+struct RandomPointBuilder;
+
+impl GenomeBuilder<Point> for RandomPointBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Point
+    where
+        R: Rng + Sized,
+    {
+        vec![rng.gen_range(-10.0..10.0)]
+    }
+}
+
+#[test]
+fn nsga2_converges_to_the_pareto_front_of_schaffers_problem() {
+    let initial_population: Population<Point> = build_population()
+        .with_genome_builder(RandomPointBuilder)
+        .of_size(40)
+        .uniform_at_random();
+    let algorithm = Nsga2::new(
+        Schaffer,
+        BlendCrossBreeder::new(0.5),
+        GaussianMutator::new(1., 0.1),
+        initial_population,
+    );
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(50)).build();
+
+    let state = match sim.run() {
+        Ok(SimResult::Final(state, _, _, _)) => state,
+        other => panic!("expected a final result, got {:?}", other),
+    };
+
+    expect_that!(&state.result.population.len(), eq(40));
+    expect_that!(&state.result.pareto_front.len(), eq(40));
+    for solution in state.result.pareto_front.iter() {
+        expect_that!(&solution.genome[0], is(greater_than_or_equal(-0.1)));
+        expect_that!(&solution.genome[0], is(less_than_or_equal(2.1)));
+        for other in state.result.pareto_front.iter() {
+            expect_that!(
+                &dominates(&other.objectives, &solution.objectives),
+                eq(false)
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{prelude::*, simulation::State, termination::StopReason};
+
+mod common;
+
+use common::{count_ones_simulator, random_population, Bits};
+
+#[derive(Default)]
+struct Recorder {
+    best_fitness: Vec<usize>,
+    stop_reasons: Vec<StopReason>,
+}
+
+impl<A, E> SimulationObserver<A, E> for Recorder
+where
+    A: Algorithm<Output = genevo::ga::State<Bits, usize>>,
+{
+    fn on_generation(&mut self, state: &State<A>) {
+        self.best_fitness
+            .push(state.result.best_solution.solution.fitness);
+    }
+
+    fn on_termination(&mut self, _state: &State<A>, stop_reason: &StopReason) {
+        self.stop_reasons.push(stop_reason.clone());
+    }
+}
+
+#[test]
+fn observers_are_notified_about_each_generation_and_the_termination() {
+    let sim = count_ones_simulator(random_population(20), 7);
+    let mut recorder = Recorder::default();
+
+    let result = observe(sim).with_observer(&mut recorder).run();
+
+    expect_that!(&result.is_ok(), eq(true));
+    expect_that!(&recorder.best_fitness.len(), eq(7));
+    expect_that!(&recorder.stop_reasons.len(), eq(1));
+}
+
+#[test]
+fn metrics_writer_streams_one_line_per_generation() {
+    let sim = count_ones_simulator(random_population(20), 5);
+    let mut csv = MetricsWriter::new(Vec::new(), MetricsFormat::Csv);
+    let mut json = MetricsWriter::new(Vec::new(), MetricsFormat::JsonLines);
+
+    let result = observe(sim)
+        .with_observer(&mut csv)
+        .with_observer(&mut json)
+        .run();
+
+    expect_that!(&result.is_ok(), eq(true));
+    let csv = String::from_utf8(csv.into_inner()).unwrap();
+    let lines: Vec<String> = csv.lines().map(String::from).collect();
+    expect_that!(&lines.len(), eq(6));
+    expect_that!(
+        &lines[0],
+        eq("generation,best_fitness,average_fitness,worst_fitness,duration_ms".to_string())
+    );
+    expect_that!(&lines[1].starts_with("1,"), eq(true));
+    let json = String::from_utf8(json.into_inner()).unwrap();
+    expect_that!(&json.lines().count(), eq(5));
+    expect_that!(&json.starts_with("{\"generation\":1,"), eq(true));
+}
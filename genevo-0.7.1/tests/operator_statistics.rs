@@ -0,0 +1,81 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, prelude::*, statistic::OperatorStatistics};
+
+mod common;
+
+use common::{seeded_population, CountOnes};
+
+// Big enough for the offspring to be bred in parallel
+// The best fitness and the operator statistics of each generation
+fn run(
+    mutation_rate: f64,
+    operator_statistics: bool,
+) -> (Vec<usize>, Vec<Option<OperatorStatistics>>) {
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(mutation_rate, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(seeded_population(200))
+            .with_operator_statistics(operator_statistics)
+            .build(),
+    )
+    .until(GenerationLimit::new(10))
+    .build_with_seed([42; 32]);
+
+    let (mut best, mut statistics) = (vec![], vec![]);
+    loop {
+        let (state, done) = match sim.step() {
+            Ok(SimResult::Intermediate(state)) => (state, false),
+            Ok(SimResult::Final(state, ..)) => (state, true),
+            Err(error) => panic!("{}", error),
+        };
+        best.push(state.result.best_solution.solution.fitness);
+        statistics.push(state.result.operator_statistics);
+        if done {
+            return (best, statistics);
+        }
+    }
+}
+
+#[test]
+fn the_children_bred_and_mutated_are_counted() {
+    let (_, statistics) = run(0.05, true);
+
+    for statistics in statistics {
+        let statistics = statistics.unwrap();
+        // 100 pairs of parents breed two children each
+        expect_that!(&statistics.offspring, eq(200));
+        expect_that!(&statistics.mutated, greater_than(100));
+        expect_that!(&statistics.mutated, less_than_or_equal(200));
+        expect_that!(&statistics.repaired, eq(0));
+        expect_that!(
+            &statistics.total(),
+            eq(statistics.evaluation
+                + statistics.selection
+                + statistics.crossover
+                + statistics.mutation
+                + statistics.repair
+                + statistics.local_search
+                + statistics.reinsertion)
+        );
+    }
+    let (_, statistics) = run(0., true);
+    expect_that!(&statistics[0].unwrap().mutated, eq(0));
+}
+
+#[test]
+fn the_statistics_do_not_change_the_evolution() {
+    let (with_statistics, _) = run(0.05, true);
+    let (without_statistics, statistics) = run(0.05, false);
+
+    expect_that!(&with_statistics, eq(without_statistics));
+    expect_that!(&statistics.iter().all(Option::is_none), eq(true));
+}
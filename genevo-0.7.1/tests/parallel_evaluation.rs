@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, prelude::*};
+
+mod common;
+
+use common::{seeded_population, CountOnes};
+
+// Big enough to be split up by the parallel evaluation
+fn best_fitness_per_generation(parallel_evaluation: bool) -> Vec<usize> {
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(seeded_population(200))
+            .with_parallel_evaluation(parallel_evaluation)
+            .build(),
+    )
+    .until(GenerationLimit::new(10))
+    .build_with_seed([42; 32]);
+
+    let mut best = vec![];
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                best.push(state.result.best_solution.solution.fitness)
+            }
+            Ok(SimResult::Final(state, _, _, _)) => {
+                best.push(state.result.best_solution.solution.fitness);
+                return best;
+            }
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[test]
+fn parallel_evaluation_is_on_by_default() {
+    let alg = genetic_algorithm()
+        .with_evaluation(CountOnes)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.05, false, true))
+        .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+        .with_initial_population(seeded_population(200))
+        .build();
+
+    expect_that!(&alg.parallel_evaluation(), eq(true));
+}
+
+#[test]
+fn serial_evaluation_evolves_the_same_as_parallel_evaluation() {
+    let serial = best_fitness_per_generation(false);
+    let parallel = best_fitness_per_generation(true);
+
+    expect_that!(&serial.len(), eq(10));
+    expect_that!(&serial, eq(parallel));
+}
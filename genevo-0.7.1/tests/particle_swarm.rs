@@ -0,0 +1,113 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{prelude::*, random::Rng};
+
+type Point = Vec<f64>;
+
+// The negated sphere function, scaled to integer fitness values
+#[derive(Clone, Debug, PartialEq)]
+struct Sphere;
+
+impl FitnessFunction<Point, i64> for Sphere {
+    fn fitness_of(&self, point: &Point) -> i64 {
+        -(point.iter().map(|x| x * x).sum::<f64>() * 1e6).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        values.iter().sum::<i64>() / values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+struct RandomPoint;
+
+impl GenomeBuilder<Point> for RandomPoint {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Point
+    where
+        R: Rng + Sized,
+    {
+        (0..5).map(|_| rng.gen_range(-5.0..5.0)).collect()
+    }
+}
+
+fn random_points(size: usize) -> Population<Point> {
+    build_population()
+        .with_genome_builder(RandomPoint)
+        .of_size(size)
+        .uniform_at_random()
+}
+
+#[test]
+fn the_best_solution_of_the_swarm_never_gets_worse_and_approaches_the_optimum() {
+    let algorithm = ParticleSwarm::new(Sphere, random_points(20)).with_bounds(-5., 5.);
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(200)).build();
+
+    let mut highest_fitness = i64::MIN;
+    let state = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                let fitness = state.result.best_solution.solution.fitness;
+                expect_that!(&fitness, greater_than_or_equal(highest_fitness));
+                expect_that!(
+                    &state.result.evaluated_population.individuals().len(),
+                    eq(20)
+                );
+                for point in state.result.evaluated_population.individuals().iter() {
+                    expect_that!(&point.iter().all(|x| (-5. ..=5.).contains(x)), eq(true));
+                }
+                highest_fitness = fitness;
+            },
+            Ok(SimResult::Final(state, ..)) => break state,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    // Within 0.001 of the optimum
+    expect_that!(
+        &state.result.best_solution.solution.fitness,
+        greater_than_or_equal(-1_000)
+    );
+}
+
+#[test]
+fn the_swarm_stops_at_the_fitness_limit() {
+    let algorithm = ParticleSwarm::new(Sphere, random_points(20))
+        .with_coefficients(0.6, 1.7, 1.7)
+        .with_max_velocity(1.);
+    let mut sim = simulate(algorithm)
+        .until(or(
+            FitnessLimit::new(-1_000_000),
+            GenerationLimit::new(1_000),
+        ))
+        .build();
+
+    let state = match sim.run() {
+        Ok(SimResult::Final(state, ..)) => state,
+        other => panic!("expected a final result, got {:?}", other),
+    };
+
+    expect_that!(
+        &state.result.best_solution.solution.fitness,
+        greater_than_or_equal(-1_000_000)
+    );
+    expect_that!(&state.iteration, less_than(1_000));
+}
+
+#[test]
+fn a_swarm_without_particles_fails() {
+    let algorithm = ParticleSwarm::new(Sphere, Population::with_individuals(vec![]));
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    expect_that!(&sim.run().is_err(), eq(true));
+}
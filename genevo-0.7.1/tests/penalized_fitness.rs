@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*};
+
+type Selection = Vec<bool>;
+
+// The (weight, value) of the items of a knapsack of capacity 50
+const ITEMS: [(usize, usize); 12] = [
+    (23, 92),
+    (31, 57),
+    (29, 49),
+    (44, 68),
+    (53, 60),
+    (38, 43),
+    (63, 67),
+    (85, 84),
+    (89, 87),
+    (82, 72),
+    (5, 30),
+    (7, 33),
+];
+const CAPACITY: usize = 50;
+
+fn total(selection: &Selection, of: impl Fn(&(usize, usize)) -> usize) -> usize {
+    ITEMS
+        .iter()
+        .zip(selection)
+        .filter(|(_, selected)| **selected)
+        .map(|(item, _)| of(item))
+        .sum()
+}
+
+// The value of the selected items, regardless of their weight
+#[derive(Clone, Debug, PartialEq)]
+struct Value;
+
+impl FitnessFunction<Selection, usize> for Value {
+    fn fitness_of(&self, selection: &Selection) -> usize {
+        total(selection, |item| item.1)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        ITEMS.iter().map(|item| item.1).sum()
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+fn overweight(selection: &Selection) -> f64 {
+    total(selection, |item| item.0).saturating_sub(CAPACITY) as f64
+}
+
+fn best_feasible_value() -> usize {
+    (0..1u32 << ITEMS.len())
+        .map(|bits| (0..ITEMS.len()).map(|i| bits & 1 << i != 0).collect())
+        .filter(|selection: &Selection| overweight(selection) == 0.)
+        .map(|selection| total(&selection, |item| item.1))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn the_growing_penalty_drives_the_search_to_the_best_feasible_selection() {
+    let fitness = PenalizedFitness::new(Value)
+        .with_scheduled_penalty(overweight, Schedule::Piecewise(vec![(1, 0.5), (20, 10.)]));
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(fitness.clone())
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.1, false, true))
+            .with_reinsertion(ElitistReinserter::new(fitness.clone(), false, 0.5))
+            .with_initial_population(
+                build_population()
+                    .with_genome_builder(BinaryEncodedGenomeBuilder::new(ITEMS.len()))
+                    .of_size(30)
+                    .using_seed([5; 32]),
+            )
+            .build(),
+    )
+    .until(GenerationLimit::new(40))
+    .build_with_seed([15; 32]);
+
+    let best = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(_)) => {}
+            Ok(SimResult::Final(state, ..)) => break state.result.best_solution.solution,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(&fitness.generation(), eq(40));
+    expect_that!(&fitness.is_feasible(&best.genome), eq(true));
+    expect_that!(&best.fitness, eq(best_feasible_value()));
+}
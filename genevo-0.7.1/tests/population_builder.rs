@@ -5,9 +5,11 @@ extern crate galvanic_assert;
 use galvanic_assert::matchers::*;
 
 use genevo::{
-    population::{BinaryEncodedGenomeBuilder, ValueEncodedGenomeBuilder},
+    population::{
+        BinaryEncodedGenomeBuilder, LatinHypercubeGenomeBuilder, ValueEncodedGenomeBuilder,
+    },
     prelude::*,
-    random::Rng,
+    random::{get_rng, random_seed, Rng},
 };
 
 #[test]
@@ -92,3 +94,16 @@ mod fixedbitset_population_builder {
         assert_that!(&population.size(), eq(200));
     }
 }
+
+#[test]
+fn create_population_of_vec_of_f64_by_latin_hypercube_sampling() {
+    let builder = LatinHypercubeGenomeBuilder::new(4, -2., 2., 200, &mut get_rng(random_seed()));
+
+    let population: Population<Vec<f64>> = build_population()
+        .with_genome_builder(builder)
+        .of_size(200)
+        .uniform_at_random();
+
+    println!("{:?}", population);
+    assert_that!(&population.size(), eq(200));
+}
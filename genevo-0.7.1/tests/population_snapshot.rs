@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, prelude::*};
+
+mod common;
+
+use common::{count_ones_simulator, random_population, Bits, CountOnes};
+
+#[test]
+fn population_snapshot_holds_each_individual_with_its_fitness() {
+    let mut sim = count_ones_simulator(random_population(20), 100);
+    sim.step().unwrap();
+
+    let snapshot = sim.population_snapshot();
+
+    expect_that!(&snapshot.len(), eq(20));
+    for individual in snapshot {
+        let ones = individual.genome.iter().filter(|bit| **bit).count();
+        expect_that!(&individual.fitness, eq(ones));
+    }
+}
+
+#[test]
+fn replaced_population_is_processed_by_the_next_step() {
+    let mut sim = count_ones_simulator(random_population(20), 100);
+    sim.step().unwrap();
+
+    let mut population: Vec<Bits> = sim
+        .population_snapshot()
+        .into_iter()
+        .map(|individual| individual.genome)
+        .collect();
+    population[0] = vec![true; 64];
+    sim.replace_population(population);
+
+    match sim.step() {
+        Ok(SimResult::Intermediate(state)) => {
+            expect_that!(&state.result.best_solution.solution.fitness, eq(64));
+        }
+        other => panic!("expected an intermediate result, got {:?}", other),
+    }
+}
+
+#[test]
+fn each_state_holds_a_snapshot_of_its_population_if_switched_on() {
+    let algorithm = genetic_algorithm()
+        .with_evaluation(CountOnes)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.05, false, true))
+        .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+        .with_initial_population(random_population(20));
+    let mut with_snapshots = simulate(algorithm.clone().with_population_snapshots(true).build())
+        .until(GenerationLimit::new(100))
+        .build();
+    let mut without_snapshots = simulate(algorithm.build())
+        .until(GenerationLimit::new(100))
+        .build();
+
+    let state = match with_snapshots.step() {
+        Ok(SimResult::Intermediate(state)) => state.result,
+        other => panic!("expected an intermediate result, got {:?}", other),
+    };
+    let snapshot = state.population_snapshot.unwrap();
+    // The snapshot can be looked at in another thread
+    let fitness_values = std::thread::spawn(move || {
+        snapshot
+            .iter()
+            .map(|individual| individual.fitness)
+            .collect::<Vec<_>>()
+    })
+    .join()
+    .unwrap();
+
+    expect_that!(
+        &fitness_values,
+        eq(state.evaluated_population.fitness_values().to_vec())
+    );
+    match without_snapshots.step() {
+        Ok(SimResult::Intermediate(state)) => {
+            expect_that!(&state.result.population_snapshot.is_none(), eq(true));
+        }
+        other => panic!("expected an intermediate result, got {:?}", other),
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(unix)]
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*};
+use std::fmt::Debug;
+
+mod common;
+
+use common::{Bits, CountOnes};
+
+// The same fitness, counted by awk from the bits rendered as a line of 0s and 1s
+fn count_ones_by_awk() -> ProcessFitness<Bits, usize> {
+    let render = |genome: &Bits| {
+        genome
+            .iter()
+            .map(|bit| if *bit { '1' } else { '0' })
+            .collect()
+    };
+    ProcessFitness::new("sh", render, 0, 32)
+        .with_args(&["-c", "awk '{ print gsub(/1/, \"\") }'"])
+        .with_batch_size(8)
+        .with_workers(3)
+}
+
+fn best_fitness_per_generation<E>(fitness: E) -> Vec<usize>
+where
+    E: FitnessFunction<Bits, usize> + Debug + Sync,
+{
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(fitness.clone())
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(fitness, false, 0.5))
+            .with_initial_population(
+                build_population()
+                    .with_genome_builder(BinaryEncodedGenomeBuilder::new(32))
+                    .of_size(40)
+                    .using_seed([3; 32]),
+            )
+            .with_parallel_evaluation(false)
+            .build(),
+    )
+    .until(GenerationLimit::new(5))
+    .build_with_seed([13; 32]);
+
+    let mut best = vec![];
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => {
+                best.push(state.result.best_solution.solution.fitness)
+            }
+            Ok(SimResult::Final(state, ..)) => {
+                best.push(state.result.best_solution.solution.fitness);
+                return best;
+            }
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[test]
+fn the_external_command_evolves_the_same_as_the_fitness_function() {
+    let by_awk = count_ones_by_awk();
+
+    let expected = best_fitness_per_generation(CountOnes);
+    let evolved = best_fitness_per_generation(by_awk.clone());
+
+    expect_that!(&evolved, eq(expected));
+    // each generation evaluates the population and the offspring in batches
+    expect_that!(
+        &by_awk.runs(),
+        less_than_or_equal(5 * (40 / 8 + 40 / 8 + 1))
+    );
+}
+
+#[test]
+fn a_cached_command_runs_once_per_batch_of_new_genotypes() {
+    let by_awk = count_ones_by_awk();
+    let cached = CachedFitness::new(by_awk.clone(), 1024);
+
+    let strands = vec![vec![true; 32], vec![false; 32], vec![true; 32]];
+    expect_that!(&cached.fitness_of_all(&strands), eq(vec![32, 0, 32]));
+    expect_that!(&cached.fitness_of_all(&strands), eq(vec![32, 0, 32]));
+
+    expect_that!(&by_awk.runs(), eq(1));
+}
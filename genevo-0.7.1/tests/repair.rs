@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    operator::prelude::*,
+    population::ValueEncodedGenomeBuilder,
+    prelude::*,
+    random::{get_rng, RngCore},
+};
+
+// Strands of the nucleotides A, C, G and T, encoded as 0 to 3
+const STRAND_SIZE: usize = 40;
+const MIN_GC: usize = 16;
+const MAX_GC: usize = 24;
+
+fn gc_content(strand: &[u8]) -> usize {
+    strand
+        .iter()
+        .filter(|nucleotide| **nucleotide == 1 || **nucleotide == 2)
+        .count()
+}
+
+// Replaces random nucleotides until the GC content is within the bounds
+fn repair_gc_content(mut strand: Vec<u8>, rng: &mut dyn RngCore) -> Vec<u8> {
+    while gc_content(&strand) < MIN_GC || gc_content(&strand) > MAX_GC {
+        let too_low = gc_content(&strand) < MIN_GC;
+        let locus = rng.gen_range(0..strand.len());
+        let is_gc = strand[locus] == 1 || strand[locus] == 2;
+        if too_low != is_gc {
+            strand[locus] = if too_low {
+                1 + rng.gen_range(0..2)
+            } else {
+                3 * rng.gen_range(0..2)
+            };
+        }
+    }
+    strand
+}
+
+// The number of A and T nucleotides, which pushes the GC content below its min
+#[derive(Clone, Debug, PartialEq)]
+struct AtContent;
+
+impl FitnessFunction<Vec<u8>, usize> for AtContent {
+    fn fitness_of(&self, strand: &Vec<u8>) -> usize {
+        STRAND_SIZE - gc_content(strand)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        STRAND_SIZE
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn the_repaired_offspring_keeps_the_gc_content_within_its_bounds() {
+    let random_population: Population<Vec<u8>> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(STRAND_SIZE, 0, 4))
+        .of_size(40)
+        .using_seed([9; 32]);
+    // only the offspring is repaired, so the population starts out valid
+    let mut rng = get_rng([9; 32]);
+    let initial_population = Population::with_individuals(
+        random_population
+            .individuals()
+            .iter()
+            .map(|strand| repair_gc_content(strand.clone(), &mut rng))
+            .collect(),
+    );
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(AtContent)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(SinglePointCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, 0, 4))
+            .with_reinsertion(ElitistReinserter::new(AtContent, false, 0.5))
+            .with_initial_population(initial_population)
+            .with_repair(repair_with(repair_gc_content))
+            .with_operator_statistics(true)
+            .build(),
+    )
+    .until(GenerationLimit::new(100))
+    .build_with_seed([19; 32]);
+
+    let mut repaired = 0;
+    let best = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                repaired += step.result.operator_statistics.unwrap().repaired;
+                for strand in step.result.evaluated_population.individuals().iter() {
+                    expect_that!(&gc_content(strand), greater_than_or_equal(MIN_GC));
+                    expect_that!(&gc_content(strand), less_than_or_equal(MAX_GC));
+                }
+            }
+            Ok(SimResult::Final(state, ..)) => break state.result.best_solution.solution,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(&best.fitness, eq(STRAND_SIZE - MIN_GC));
+    expect_that!(&repaired, greater_than(0));
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*};
+
+mod common;
+
+use common::{random_population, CountOnes};
+
+#[test]
+fn the_restarts_are_reported_in_the_state_of_their_generation() {
+    // Without mutation the population soon converges, so it stagnates
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0., false, true))
+            .with_reinsertion(RestartReinserter::new(
+                ElitistReinserter::new(CountOnes, false, 0.5),
+                BinaryEncodedGenomeBuilder::new(64),
+                3,
+                2,
+            ))
+            .with_initial_population(random_population(8))
+            .build(),
+    )
+    .until(GenerationLimit::new(60))
+    .build();
+
+    let mut restarted_generations = vec![];
+    let mut highest_fitness = 0;
+    loop {
+        let (state, done) = match sim.step() {
+            Ok(SimResult::Intermediate(state)) => (state, false),
+            Ok(SimResult::Final(state, ..)) => (state, true),
+            Err(error) => panic!("{}", error),
+        };
+        let fitness = state.result.best_solution.solution.fitness;
+        // The elites keep the best fitness through a restart
+        expect_that!(&fitness, greater_than_or_equal(highest_fitness));
+        highest_fitness = fitness;
+        if state.result.restarted {
+            restarted_generations.push(state.iteration);
+        }
+        if done {
+            break;
+        }
+    }
+
+    expect_that!(&restarted_generations.is_empty(), eq(false));
+    expect_that!(
+        &restarted_generations,
+        eq(sim.algorithm().reinserter().restarts().to_vec())
+    );
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, population::BinaryEncodedGenomeBuilder, prelude::*};
+
+mod common;
+
+use common::{random_population, CountOnes};
+
+#[test]
+fn the_population_grows_and_shrinks_by_its_schedule() {
+    let population_sizes = Schedule::Piecewise(vec![(1, 20.), (5, 40.), (9, 10.)]);
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(ScheduledSelector::new(
+                MaximizeSelector::new(0.5, 2),
+                Schedule::ExponentialDecay {
+                    initial: 0.8,
+                    target: 0.4,
+                    decay_rate: 0.1,
+                },
+            ))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(ScheduledMutator::new(
+                RandomValueMutator::new(0.05, false, true),
+                Schedule::Piecewise(vec![(1, 0.1), (10, 0.01)]),
+            ))
+            .with_reinsertion(ScheduledSizeReinserter::new(
+                ElitistReinserter::new(CountOnes, false, 0.5),
+                BinaryEncodedGenomeBuilder::new(64),
+                CountOnes,
+                population_sizes.clone(),
+            ))
+            .with_initial_population(random_population(20))
+            .build(),
+    )
+    .until(GenerationLimit::new(12))
+    .build();
+
+    // The population of a generation has been combined in the generation before
+    for generation in 1..=12u64 {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) | Ok(SimResult::Final(state, ..)) => {
+                let expected = population_sizes.value_at(generation.max(2) - 1).round();
+                expect_that!(
+                    &state.result.evaluated_population.individuals().len(),
+                    eq(expected as usize)
+                );
+            }
+            other => panic!("expected a result, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    encoding::{Matrix, PackedGenome, Primitive, TreeGenome},
+    operator::prelude::*,
+    population::BinaryEncodedGenomeBuilder,
+    prelude::*,
+    simulation::{simulator::Simulator, State},
+    termination::limit::GenerationLimit,
+};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+use common::{Bits, CountOnes, CountOnesAlgorithm};
+
+fn simulation() -> Simulator<CountOnesAlgorithm, GenerationLimit> {
+    simulate(
+        genetic_algorithm()
+            .with_evaluation(CountOnes)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.05, false, true))
+            .with_reinsertion(ElitistReinserter::new(CountOnes, false, 0.5))
+            .with_initial_population(
+                build_population()
+                    .with_genome_builder(BinaryEncodedGenomeBuilder::new(16))
+                    .of_size(12)
+                    .using_seed([5; 32]),
+            )
+            .with_population_snapshots(true)
+            .with_operator_statistics(true)
+            .build(),
+    )
+    .until(GenerationLimit::new(3))
+    .build_with_seed([9; 32])
+}
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn the_states_of_a_simulation_survive_a_round_trip() {
+    let mut sim = simulation();
+
+    let mut states: Vec<State<CountOnesAlgorithm>> = vec![];
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(state)) => states.push(state),
+            Ok(SimResult::Final(state, ..)) => {
+                states.push(state);
+                break;
+            }
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    for state in states {
+        let copy = round_trip(&state);
+        expect_that!(&copy, eq(state));
+        expect_that!(&copy.result.population_snapshot.is_some(), eq(true));
+        expect_that!(&copy.result.operator_statistics.is_some(), eq(true));
+    }
+}
+
+#[test]
+fn a_population_survives_a_round_trip() {
+    let population = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(8))
+        .of_size(5)
+        .using_seed([1; 32]);
+
+    let copy: Population<Bits> = round_trip(&population);
+
+    expect_that!(&copy, eq(population));
+}
+
+#[test]
+fn the_genotypes_survive_a_round_trip() {
+    let mut packed = PackedGenome::new(40);
+    packed.set(3, 2);
+    packed.set(39, 3);
+    let matrix = Matrix::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let adaptive = SelfAdaptive::new(vec![0.5, 1.5], 0.01);
+    let tree = TreeGenome::node(Op::Neg, vec![TreeGenome::leaf(Op::X)]);
+
+    expect_that!(&round_trip(&packed), eq(packed));
+    expect_that!(&round_trip(&matrix), eq(matrix));
+    expect_that!(&round_trip(&adaptive), eq(adaptive));
+    expect_that!(&round_trip(&tree), eq(tree));
+}
+
+#[test]
+fn malformed_genotypes_are_rejected() {
+    // 40 alleles take 2 words, and the bits beyond the 40th must be 0
+    let short = serde_json::from_str::<PackedGenome>(r#"{"words":[0],"len":40}"#);
+    let dirty = serde_json::from_str::<PackedGenome>(r#"{"words":[0,65536],"len":40}"#);
+    let matrix = serde_json::from_str::<Matrix<u8>>(r#"{"cells":[1,2,3],"rows":2,"cols":2}"#);
+    let tree = serde_json::from_str::<TreeGenome<Op>>(r#"{"nodes":["Neg"]}"#);
+
+    expect_that!(&short.is_err(), eq(true));
+    expect_that!(&dirty.is_err(), eq(true));
+    expect_that!(&matrix.is_err(), eq(true));
+    expect_that!(&tree.is_err(), eq(true));
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum Op {
+    X,
+    Neg,
+}
+
+impl Primitive for Op {
+    fn arity(&self) -> usize {
+        match self {
+            Op::X => 0,
+            Op::Neg => 1,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    prelude::*,
+    simulation::{simulator::Simulator, State},
+    termination::StopFlag,
+};
+
+mod common;
+
+use common::{count_ones_algorithm, random_population, CountOnesAlgorithm};
+
+#[test]
+fn average_fitness_limit_stops_when_population_average_is_reached() {
+    let mut sim: Simulator<_, _> = simulate(count_ones_algorithm(random_population(20)))
+        .until(or(
+            AverageFitnessLimit::new(40),
+            GenerationLimit::new(10_000),
+        ))
+        .build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, _) => {
+            expect_that!(
+                state.result.evaluated_population.average_fitness(),
+                is(greater_than_or_equal(40))
+            );
+            expect_that!(&state.iteration, is(less_than(10_000)));
+        }
+        SimResult::Intermediate(_) => panic!("expected the simulation to be finished"),
+    }
+}
+
+#[test]
+fn diversity_limit_above_one_stops_after_the_first_generation() {
+    let mut sim: Simulator<_, _> = simulate(count_ones_algorithm(random_population(20)))
+        .until(DiversityLimit::new(1.1))
+        .build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, _) => {
+            expect_that!(&state.iteration, eq(1));
+        }
+        SimResult::Intermediate(_) => panic!("expected the simulation to be finished"),
+    }
+}
+
+#[test]
+fn stop_when_stops_when_the_closure_says_so() {
+    let mut sim: Simulator<_, _> = simulate(count_ones_algorithm(random_population(20)))
+        .until(stop_when(|state: &State<CountOnesAlgorithm>| {
+            if state.iteration >= 3 {
+                StopFlag::StopNow("three generations are enough".to_string())
+            } else {
+                StopFlag::Continue
+            }
+        }))
+        .build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, stop_reason) => {
+            expect_that!(&state.iteration, eq(3));
+            expect_that!(&stop_reason, eq("three generations are enough".to_string()));
+        }
+        SimResult::Intermediate(_) => panic!("expected the simulation to be finished"),
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{operator::prelude::*, population::VariableLengthGenomeBuilder, prelude::*};
+
+const TARGET_LENGTH: usize = 20;
+const MIN_LENGTH: usize = 1;
+const MAX_LENGTH: usize = 32;
+
+// The genomes are best at the target length with all values set to 1, so
+// their length has to grow from the initial few values
+#[derive(Clone, Debug, PartialEq)]
+struct OnesOfTargetLength;
+
+impl FitnessFunction<Vec<u8>, usize> for OnesOfTargetLength {
+    fn fitness_of(&self, genome: &Vec<u8>) -> usize {
+        let zeros = genome.iter().filter(|value| **value == 0).count();
+        let length_error = genome.len().max(TARGET_LENGTH) - genome.len().min(TARGET_LENGTH);
+        self.highest_possible_fitness() - zeros - length_error
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        2 * MAX_LENGTH
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn the_length_of_the_genomes_evolves_to_the_target_length() {
+    let initial_population: Population<Vec<u8>> = build_population()
+        .with_genome_builder(VariableLengthGenomeBuilder::new(MIN_LENGTH, 4, 0, 2))
+        .of_size(40)
+        .using_seed([5; 32]);
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(OnesOfTargetLength)
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(
+                CutAndSpliceCrossBreeder::new().with_length_limits(MIN_LENGTH, MAX_LENGTH),
+            )
+            .with_mutation(EnsembleMutator::new(
+                (
+                    RandomValueMutator::new(0.05, 0, 2),
+                    GeneInsertionMutator::new(0.1, 0, 2).with_max_length(MAX_LENGTH),
+                    GeneDeletionMutator::new(0.1).with_min_length(MIN_LENGTH),
+                ),
+                OperatorWeights::equal(3),
+            ))
+            .with_reinsertion(ElitistReinserter::new(OnesOfTargetLength, false, 0.5))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(or(
+        FitnessLimit::new(2 * MAX_LENGTH),
+        GenerationLimit::new(300),
+    ))
+    .build_with_seed([13; 32]);
+
+    let best = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                for individual in step.result.evaluated_population.individuals().iter() {
+                    expect_that!(&individual.len(), greater_than_or_equal(MIN_LENGTH));
+                    expect_that!(&individual.len(), less_than_or_equal(MAX_LENGTH));
+                }
+            }
+            Ok(SimResult::Final(state, ..)) => break state.result.best_solution.solution,
+            Err(error) => panic!("{}", error),
+        }
+    };
+
+    expect_that!(&best.genome, eq(vec![1u8; TARGET_LENGTH]));
+}
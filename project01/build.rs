@@ -0,0 +1,25 @@
+// Generates the C header of the API of src/ffi.rs with the `ffi` feature, see cbindgen.toml
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+// The header goes into OUT_DIR, or into the directory named by PROJECT01_HEADER_DIR (e.g.
+// `PROJECT01_HEADER_DIR=include`) for a path that C and C++ builds can include it from
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=PROJECT01_HEADER_DIR");
+    let dir = std::env::var_os("PROJECT01_HEADER_DIR")
+        .or_else(|| std::env::var_os("OUT_DIR"))
+        .expect("cargo sets OUT_DIR");
+    let config = cbindgen::Config::from_file("cbindgen.toml").expect("cannot read cbindgen.toml");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src("src/ffi.rs")
+        .generate()
+        .expect("cannot generate the C header of src/ffi.rs")
+        .write_to_file(std::path::Path::new(&dir).join("project01.h"));
+}
@@ -0,0 +1,441 @@
+use ga_lab::style::{chart_style, chart_text, load_chart_style, CHART_STYLE_FILE};
+use genevo::{
+    self, ga,
+    operator::{
+        prelude::{
+            ArithmeticCrossBreeder, BlendCrossBreeder, ElitistReinserter, GaussianMutator,
+            MaximizeSelector, SimulatedBinaryCrossBreeder,
+        },
+        CrossoverOp,
+    },
+    prelude::*,
+    simulation::State,
+    statistic::TrackProcessingTime,
+    termination::StopFlag,
+};
+use plotters::prelude::*;
+use rand::Rng;
+use std::{f64::consts::PI, fmt::Debug, fs::create_dir_all, thread, time::Instant};
+
+// Unchanging simulation parameters
+const DIMENSIONS: usize = 10;
+const POPULATION_SIZE: usize = 256;
+const GENERATION_LIMIT: u64 = 1_000;
+const BATCH_SIZE: u64 = 8;
+const STALL_LIMIT: u64 = 250;
+
+// Fitness values are integers in genevo, so objective values are scaled by this factor
+// before they are negated (the GA maximizes, the benchmarks are minimized)
+const FITNESS_SCALE: f64 = 1e6;
+// The smallest error that can be told apart from the optimum after scaling
+const ERROR_FLOOR: f64 = 1.0 / FITNESS_SCALE;
+
+// The continuous benchmark functions; all of them have their known optimum of 0
+#[derive(Clone, Copy, Debug)]
+enum Benchmark {
+    Sphere,
+    Rastrigin,
+    Rosenbrock,
+}
+
+impl Benchmark {
+    fn name(&self) -> &'static str {
+        match self {
+            Benchmark::Sphere => "sphere",
+            Benchmark::Rastrigin => "rastrigin",
+            Benchmark::Rosenbrock => "rosenbrock",
+        }
+    }
+
+    // The usual search domain of the benchmark, the same for every dimension
+    fn bounds(&self) -> (f64, f64) {
+        match self {
+            Benchmark::Sphere => (-5.12, 5.12),
+            Benchmark::Rastrigin => (-5.12, 5.12),
+            Benchmark::Rosenbrock => (-2.048, 2.048),
+        }
+    }
+
+    fn optimum(&self) -> f64 {
+        0.0
+    }
+
+    fn evaluate(&self, x: &[f64]) -> f64 {
+        match self {
+            Benchmark::Sphere => x.iter().map(|xi| xi * xi).sum(),
+            Benchmark::Rastrigin => {
+                10.0 * x.len() as f64
+                    + x.iter()
+                        .map(|xi| xi * xi - 10.0 * (2.0 * PI * xi).cos())
+                        .sum::<f64>()
+            }
+            Benchmark::Rosenbrock => x
+                .windows(2)
+                .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+                .sum(),
+        }
+    }
+}
+
+// The crossover operators being compared
+#[derive(Clone, Copy, Debug)]
+enum Crossover {
+    Arithmetic,
+    Blend(f64),
+    SimulatedBinary(f64),
+}
+
+impl Crossover {
+    fn name(&self) -> String {
+        match self {
+            Crossover::Arithmetic => "arithmetic".to_string(),
+            Crossover::Blend(alpha) => format!("BLX-{}", alpha),
+            Crossover::SimulatedBinary(eta) => format!("SBX-{}", eta),
+        }
+    }
+}
+
+// The solvers being compared: the GA with one of the crossover operators, a particle swarm, or
+// the CMA-ES as the baseline
+#[derive(Clone, Copy, Debug)]
+enum Solver {
+    GeneticAlgorithm(Crossover),
+    ParticleSwarm,
+    CmaEs,
+}
+
+impl Solver {
+    fn name(&self) -> String {
+        match self {
+            Solver::GeneticAlgorithm(crossover) => crossover.name(),
+            Solver::ParticleSwarm => "PSO".to_string(),
+            Solver::CmaEs => "CMA-ES".to_string(),
+        }
+    }
+}
+
+type Genome = Vec<f64>;
+
+// Negated, scaled objective value of a benchmark as the fitness of a `Genome`
+#[derive(Clone, Debug)]
+struct BenchmarkFitnessCalculator(Benchmark);
+
+impl FitnessFunction<Genome, i64> for BenchmarkFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> i64 {
+        -(self.0.evaluate(genome) * FITNESS_SCALE).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        values.iter().sum::<i64>() / values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        -(self.0.optimum() * FITNESS_SCALE).round() as i64
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+// Build random points uniformly distributed within the benchmark's bounds
+struct RandomPointBuilder(Benchmark);
+
+impl GenomeBuilder<Genome> for RandomPointBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Genome
+    where
+        R: Rng + Sized,
+    {
+        let (low, high) = self.0.bounds();
+        (0..DIMENSIONS).map(|_| rng.gen_range(low..high)).collect()
+    }
+}
+
+// The distance of the best objective value to the known optimum at each generation
+type ErrorCurve = Vec<f64>;
+
+type BenchmarkAlgorithm<C> = GeneticAlgorithm<
+    Genome,
+    i64,
+    BenchmarkFitnessCalculator,
+    MaximizeSelector,
+    C,
+    GaussianMutator,
+    ElitistReinserter<Genome, i64, BenchmarkFitnessCalculator>,
+>;
+
+fn build_initial_population(benchmark: Benchmark) -> Population<Genome> {
+    build_population()
+        .with_genome_builder(RandomPointBuilder(benchmark))
+        .of_size(POPULATION_SIZE)
+        .uniform_at_random()
+}
+
+// Runs a simulation of a benchmark by the GA with the given crossover operator
+fn run_ga_sim<C>(benchmark: Benchmark, crossover: C) -> Option<ErrorCurve>
+where
+    C: CrossoverOp<Genome> + Debug + PartialEq + Sync,
+{
+    let (low, high) = benchmark.bounds();
+
+    // Keep mutated points within the search domain
+    let mut mutator = GaussianMutator::new(0.1, (high - low) / 100.0);
+    mutator.set_bounds(low, high);
+
+    let alg: BenchmarkAlgorithm<C> = genetic_algorithm()
+        .with_evaluation(BenchmarkFitnessCalculator(benchmark))
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(crossover)
+        .with_mutation(mutator)
+        .with_reinsertion(ElitistReinserter::new(
+            BenchmarkFitnessCalculator(benchmark),
+            true,
+            0.5,
+        ))
+        .with_initial_population(build_initial_population(benchmark))
+        .build();
+
+    run_sim(benchmark, alg)
+}
+
+// Runs a simulation of a benchmark by a swarm of as many particles as the GA has strands, kept
+// within the search domain
+fn run_pso_sim(benchmark: Benchmark) -> Option<ErrorCurve> {
+    let (low, high) = benchmark.bounds();
+    let alg = ParticleSwarm::new(
+        BenchmarkFitnessCalculator(benchmark),
+        build_initial_population(benchmark),
+    )
+    .with_bounds(low, high);
+
+    run_sim(benchmark, alg)
+}
+
+// Runs a simulation of a benchmark by the CMA-ES from a random point, sampling as many candidates
+// per generation as the GA has strands and starting with a step size of a third of the domain
+fn run_cma_es_sim(benchmark: Benchmark) -> Option<ErrorCurve> {
+    let (low, high) = benchmark.bounds();
+    let initial_mean = RandomPointBuilder(benchmark).build_genome(0, &mut rand::thread_rng());
+    let alg = CmaEs::new(
+        BenchmarkFitnessCalculator(benchmark),
+        initial_mean,
+        (high - low) / 3.0,
+    )
+    .with_population_size(POPULATION_SIZE)
+    .with_bounds(low, high);
+
+    run_sim(benchmark, alg)
+}
+
+// Runs a simulation of a benchmark by any algorithm with the output of the GA, until the
+// generation limit or a stall
+fn run_sim<A>(benchmark: Benchmark, alg: A) -> Option<ErrorCurve>
+where
+    A: Algorithm<Output = ga::State<Genome, i64>, Error = ga::GeneticAlgorithmError>
+        + TrackProcessingTime
+        + Debug,
+{
+    // Also stop once the best solution has stalled for STALL_LIMIT generations
+    let mut best_so_far = i64::MIN;
+    let mut stalled_since = 0;
+    let mut sim = simulate(alg)
+        .until(or(
+            GenerationLimit::new(GENERATION_LIMIT),
+            stop_when(move |state: &State<A>| {
+                let fitness = state.result.best_solution.solution.fitness;
+                if fitness > best_so_far {
+                    best_so_far = fitness;
+                    stalled_since = state.iteration;
+                }
+                if state.iteration - stalled_since >= STALL_LIMIT {
+                    StopFlag::StopNow(format!("stalled for {} generations", STALL_LIMIT))
+                } else {
+                    StopFlag::Continue
+                }
+            }),
+        ))
+        .build();
+
+    // Stores the error of the best solution at each iteration of the simulation
+    let mut curve = vec![];
+
+    loop {
+        let (fitness, last) = match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                (step.result.best_solution.solution.fitness, false)
+            }
+            Ok(SimResult::Final(step, _, _, _)) => {
+                (step.result.best_solution.solution.fitness, true)
+            }
+            Err(error) => {
+                println!("{}", error);
+                return None;
+            }
+        };
+        let error = -fitness as f64 / FITNESS_SCALE - benchmark.optimum();
+        curve.push(error.max(ERROR_FLOOR));
+        if last {
+            return Some(curve);
+        }
+    }
+}
+
+// Runs a batch of simulations of a benchmark and solver in parallel and averages their curves
+fn run_sim_batch(benchmark: Benchmark, solver: Solver) -> Option<ErrorCurve> {
+    println!(
+        "[thread pool]: Running {} simulations of {} with {}.",
+        BATCH_SIZE,
+        benchmark.name(),
+        solver.name()
+    );
+
+    let curves = thread::scope(|scope| {
+        let handles = (0..BATCH_SIZE)
+            .map(|_| {
+                scope.spawn(move || match solver {
+                    Solver::GeneticAlgorithm(Crossover::Arithmetic) => {
+                        run_ga_sim(benchmark, ArithmeticCrossBreeder::new())
+                    }
+                    Solver::GeneticAlgorithm(Crossover::Blend(alpha)) => {
+                        run_ga_sim(benchmark, BlendCrossBreeder::new(alpha))
+                    }
+                    Solver::GeneticAlgorithm(Crossover::SimulatedBinary(eta)) => {
+                        run_ga_sim(benchmark, SimulatedBinaryCrossBreeder::new(eta))
+                    }
+                    Solver::ParticleSwarm => run_pso_sim(benchmark),
+                    Solver::CmaEs => run_cma_es_sim(benchmark),
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Option<Vec<ErrorCurve>>>()
+    })?;
+
+    // Geometric mean, as the errors span several orders of magnitude
+    let len = curves.iter().map(|c| c.len()).max()?;
+    Some(
+        (0..len)
+            .map(|g| {
+                let log_sum = curves
+                    .iter()
+                    .map(|c| c[g.min(c.len() - 1)].ln())
+                    .sum::<f64>();
+                (log_sum / curves.len() as f64).exp()
+            })
+            .collect(),
+    )
+}
+
+fn generate_graph(
+    graph_name: &str,
+    dataset: &[(String, ErrorCurve)],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gens_max = dataset.iter().map(|(_, d)| d.len()).max().unwrap_or(1) as u32;
+    let error_max = dataset
+        .iter()
+        .flat_map(|(_, d)| d.iter().copied())
+        .fold(1.0, f64::max);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(1..gens_max, (ERROR_FLOOR..error_max).log_scale())?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(8)
+        .x_labels(16)
+        .y_desc("distance to optimum")
+        .x_desc("gens")
+        .y_label_formatter(&|y| format!("{:.0e}", y))
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each line in the dataset
+    for (idx, (label, data)) in dataset.iter().enumerate() {
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(
+                data.iter().enumerate().map(|(x, y)| (x as u32 + 1, *y)),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(format!(
+                "{} (final: {:.2e})",
+                label,
+                data.last().copied().unwrap_or_default()
+            ))
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+fn main() {
+    create_dir_all("output").unwrap();
+    load_chart_style(CHART_STYLE_FILE).unwrap();
+
+    let start_time = Instant::now();
+
+    let solvers = [
+        Solver::GeneticAlgorithm(Crossover::Arithmetic),
+        Solver::GeneticAlgorithm(Crossover::Blend(0.5)),
+        Solver::GeneticAlgorithm(Crossover::SimulatedBinary(15.0)),
+        Solver::ParticleSwarm,
+        Solver::CmaEs,
+    ];
+    for benchmark in [
+        Benchmark::Sphere,
+        Benchmark::Rastrigin,
+        Benchmark::Rosenbrock,
+    ] {
+        let dataset = solvers
+            .iter()
+            .map(|solver| {
+                let curve = run_sim_batch(benchmark, *solver).unwrap();
+                (solver.name(), curve)
+            })
+            .collect::<Vec<_>>();
+
+        generate_graph(
+            &format!("{} (d = {}): Convergence", benchmark.name(), DIMENSIONS),
+            &dataset,
+            &format!("output/continuous_{}.png", benchmark.name()),
+        )
+        .unwrap();
+    }
+
+    println!(
+        "Finished execution in {} seconds!",
+        start_time.elapsed().as_secs()
+    );
+}
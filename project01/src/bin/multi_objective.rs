@@ -0,0 +1,467 @@
+use ga_lab::{
+    fitness::{clusters_of_4, ClustersOf4FitnessCalculator},
+    genome::{Genome, Nucleotide},
+    problem::{ClustersOf4, RandomStrandBuilder},
+    style::{chart_style, chart_text, load_chart_style, CHART_STYLE_FILE},
+};
+use genevo::{
+    self,
+    nsga2::State as Nsga2State,
+    operator::prelude::{ElitistReinserter, MaximizeSelector, RandomValueMutator},
+    prelude::*,
+    recombination::discrete::SinglePointCrossBreeder,
+    simulation::State as SimState,
+};
+use plotters::prelude::*;
+use std::{
+    fmt::Debug,
+    fs::{create_dir_all, File},
+    io::{BufWriter, Write},
+    time::Instant,
+};
+
+// Output file paths of the Pareto front scatter plot and the per-generation quality metrics
+const OUT_PARETO_FRONT: &str = "output/pareto_front.png";
+const OUT_PARETO_METRICS_CSV: &str = "output/pareto_metrics.csv";
+const OUT_PARETO_METRICS: &str = "output/pareto_metrics.png";
+
+// Unchanging simulation parameters
+const STRAND_SIZE: usize = 100;
+const POPULATION_SIZE: usize = 200;
+const GENERATION_LIMIT: u64 = 2_000;
+const MUTATION_RATE: f64 = 0.02;
+
+// The weights of the clusters of 4 (the transitions get 1 minus it) the plain GA is run with for
+// each scalarization, and the generations of each of these runs
+const SCALARIZED_WEIGHTS: [f64; 5] = [0.1, 0.3, 0.5, 0.7, 0.9];
+const SCALARIZED_GENERATION_LIMIT: u64 = 500;
+
+// The number of neighbouring nucleotides that differ
+fn transitions(genome: &Genome) -> usize {
+    genome.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+// Maximizes both clusters of 4 and transitions. Every cluster costs 3 transitions, so the
+// objectives conflict and the Pareto front runs from all clusters to no clusters at all.
+// (Clusters of 4 and the number of Ts do not conflict, a strand of "AAAA"s is best at both.)
+#[derive(Clone, Debug, PartialEq)]
+struct ClustersVsTransitions;
+
+impl ObjectiveFunction<Genome> for ClustersVsTransitions {
+    fn objectives_of(&self, genome: &Genome) -> Objectives {
+        // NSGA-II minimizes, so both objectives are negated
+        vec![
+            -(clusters_of_4(genome) as f64),
+            -(transitions(genome) as f64),
+        ]
+    }
+}
+
+// The transitions counting fitness function, the second objective of the scalarized GA runs
+#[derive(Clone, Debug)]
+struct TransitionsFitnessCalculator;
+
+impl FitnessFunction<Genome, usize> for TransitionsFitnessCalculator {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        transitions(genome)
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        STRAND_SIZE - 1
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+// The true Pareto front: every cluster costs 3 of the STRAND_SIZE - 1 possible transitions
+fn true_pareto_front() -> Vec<Objectives> {
+    (0..=STRAND_SIZE / 4)
+        .map(|c| vec![-(c as f64), -((STRAND_SIZE - 1 - 3 * c) as f64)])
+        .collect()
+}
+
+// Reference point of the hypervolume, just beyond the worst possible strand (no clusters and
+// no transitions) so that the extreme points of the front contribute as well
+const HV_REFERENCE: [f64; 2] = [1.0, 1.0];
+
+// A point in the objective space as (clusters of 4, transitions)
+type Point = (u32, u32);
+
+fn as_point(solution: &ParetoSolution<Genome>) -> Point {
+    (
+        -solution.objectives[0] as u32,
+        -solution.objectives[1] as u32,
+    )
+}
+
+// The quality of the archived non-dominated strands after one generation
+#[derive(Clone, Copy, Debug)]
+struct ParetoMetrics {
+    archive_size: usize,
+    hypervolume: f64,
+    igd: f64,
+}
+
+// Archives the non-dominated strands of every generation and records the quality of the archive
+struct ParetoTracker {
+    archive: ParetoArchive<Genome>,
+    reference_front: Vec<Objectives>,
+    metrics: Vec<ParetoMetrics>,
+}
+
+impl ParetoTracker {
+    fn new() -> Self {
+        ParetoTracker {
+            archive: ParetoArchive::new(),
+            reference_front: true_pareto_front(),
+            metrics: vec![],
+        }
+    }
+
+    // Writes the metrics of each generation as CSV
+    fn write_csv(&self, out_file: &str) -> std::io::Result<()> {
+        let mut out = BufWriter::new(File::create(out_file)?);
+        writeln!(out, "generation,archive_size,hypervolume,igd")?;
+        for (generation, m) in self.metrics.iter().enumerate() {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                generation + 1,
+                m.archive_size,
+                m.hypervolume,
+                m.igd
+            )?;
+        }
+        out.flush()
+    }
+}
+
+impl<A, E> SimulationObserver<A, E> for ParetoTracker
+where
+    A: Algorithm<Output = Nsga2State<Genome>>,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        self.archive.update(&state.result.pareto_front);
+        let objectives = self
+            .archive
+            .solutions()
+            .iter()
+            .map(|solution| solution.objectives.clone())
+            .collect::<Vec<_>>();
+        self.metrics.push(ParetoMetrics {
+            archive_size: objectives.len(),
+            hypervolume: hypervolume(&objectives, &HV_REFERENCE),
+            igd: inverted_generational_distance(&objectives, &self.reference_front),
+        });
+    }
+}
+
+// Runs NSGA-II, returning the final population, its Pareto front and the tracked metrics
+fn run_sim() -> Option<(Vec<Point>, Vec<Point>, ParetoTracker)> {
+    let initial_population: Population<Genome> = build_population()
+        .with_genome_builder(RandomStrandBuilder(ClustersOf4::new(STRAND_SIZE)))
+        .of_size(POPULATION_SIZE)
+        .uniform_at_random();
+
+    let alg = Nsga2::new(
+        ClustersVsTransitions,
+        SinglePointCrossBreeder::new(),
+        RandomValueMutator::new(MUTATION_RATE, Nucleotide::A, Nucleotide::A),
+        initial_population,
+    );
+
+    let mut tracker = ParetoTracker::new();
+    let mut sim = observe(
+        simulate(alg)
+            .until(GenerationLimit::new(GENERATION_LIMIT))
+            .build(),
+    )
+    .with_observer(&mut tracker);
+
+    let result = sim.run();
+    // Release the tracker's borrow
+    drop(sim);
+
+    match result {
+        Ok(SimResult::Final(state, _, duration, _)) => {
+            println!(
+                "Found {} non-dominated strands after {} generations in {} ms ({} archived).",
+                state.result.pareto_front.len(),
+                state.iteration,
+                duration.num_milliseconds(),
+                tracker.archive.len()
+            );
+            Some((
+                state.result.population.iter().map(as_point).collect(),
+                state.result.pareto_front.iter().map(as_point).collect(),
+                tracker,
+            ))
+        }
+        Ok(SimResult::Intermediate(_)) => None,
+        Err(error) => {
+            println!("{}", error);
+            None
+        }
+    }
+}
+
+// Runs the plain GA on a scalarization of both objectives, returning the point of its best strand
+fn run_scalarized_sim<E>(fitness: E) -> Option<Point>
+where
+    E: FitnessFunction<Genome, usize> + Debug + Sync,
+{
+    let initial_population: Population<Genome> = build_population()
+        .with_genome_builder(RandomStrandBuilder(ClustersOf4::new(STRAND_SIZE)))
+        .of_size(POPULATION_SIZE)
+        .uniform_at_random();
+
+    let alg = genetic_algorithm()
+        .with_evaluation(fitness.clone())
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(SinglePointCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(
+            MUTATION_RATE,
+            Nucleotide::A,
+            Nucleotide::A,
+        ))
+        .with_reinsertion(ElitistReinserter::new(fitness, true, 0.5))
+        .with_initial_population(initial_population)
+        .build();
+
+    match simulate(alg)
+        .until(GenerationLimit::new(SCALARIZED_GENERATION_LIMIT))
+        .build()
+        .run()
+    {
+        Ok(SimResult::Final(state, ..)) => {
+            let best = &state.result.best_solution.solution.genome;
+            Some((clusters_of_4(best) as u32, transitions(best) as u32))
+        }
+        Ok(SimResult::Intermediate(_)) => None,
+        Err(error) => {
+            println!("{}", error);
+            None
+        }
+    }
+}
+
+// Runs the plain GA with each of the SCALARIZED_WEIGHTS, by the weighted sum and by the Chebyshev
+// scalarization of the objectives
+fn run_scalarized_sims() -> (Vec<Point>, Vec<Point>) {
+    let weighted_sum = SCALARIZED_WEIGHTS
+        .iter()
+        .filter_map(|weight| {
+            run_scalarized_sim(
+                WeightedSumFitness::new()
+                    .with_objective(ClustersOf4FitnessCalculator::new(STRAND_SIZE), *weight)
+                    .with_objective(TransitionsFitnessCalculator, 1.0 - weight),
+            )
+        })
+        .collect::<Vec<_>>();
+    let chebyshev = SCALARIZED_WEIGHTS
+        .iter()
+        .filter_map(|weight| {
+            run_scalarized_sim(
+                ChebyshevFitness::new()
+                    .with_objective(ClustersOf4FitnessCalculator::new(STRAND_SIZE), *weight)
+                    .with_objective(TransitionsFitnessCalculator, 1.0 - weight),
+            )
+        })
+        .collect::<Vec<_>>();
+    println!(
+        "Scalarized GA runs with clusters of 4 weights {:?} found (clusters, transitions) of \
+         {:?} by the weighted sum and {:?} by the Chebyshev distance.",
+        SCALARIZED_WEIGHTS, weighted_sum, chebyshev
+    );
+    (weighted_sum, chebyshev)
+}
+
+fn generate_graph(
+    graph_name: &str,
+    population: &[Point],
+    pareto_front: &[Point],
+    scalarized: &[(&str, Vec<Point>)],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0..(STRAND_SIZE / 4) as u32 + 1, 0..STRAND_SIZE as u32)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(10)
+        .x_labels(13)
+        .y_desc("transitions")
+        .x_desc("clusters of 4")
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    let dominated_color = style.foreground().mix(0.2);
+    chart
+        .draw_series(
+            population
+                .iter()
+                .map(|point| Circle::new(*point, 5, dominated_color.filled())),
+        )?
+        .label("final population")
+        .legend(move |(x, y)| Circle::new((x + 10, y), 5, dominated_color.filled()));
+
+    let front_color = style.line_color(0).mix(0.9);
+    chart
+        .draw_series(
+            pareto_front
+                .iter()
+                .map(|point| Circle::new(*point, 6, front_color.filled())),
+        )?
+        .label(format!("Pareto front ({} strands)", pareto_front.len()))
+        .legend(move |(x, y)| Circle::new((x + 10, y), 6, front_color.filled()));
+
+    // The best strands of the scalarized GA runs, as triangles on top of the front
+    for (idx, (name, points)) in scalarized.iter().enumerate() {
+        let color = style.line_color(idx + 1).mix(0.9);
+        chart
+            .draw_series(
+                points
+                    .iter()
+                    .map(|point| TriangleMarker::new(*point, 10, color.stroke_width(3))),
+            )?
+            .label(format!("GA, {} scalarization", name))
+            .legend(move |(x, y)| TriangleMarker::new((x + 10, y), 10, color.stroke_width(3)));
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+// Plots the hypervolume (relative to the one of the true front) and the IGD of each generation
+fn generate_metrics_graph(
+    graph_name: &str,
+    metrics: &[ParetoMetrics],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gens_max = metrics.len().max(2) as u32;
+    let optimal_hypervolume = hypervolume(&true_pareto_front(), &HV_REFERENCE);
+    let igd_max = metrics.iter().map(|m| m.igd).fold(1.0, f64::max);
+
+    // Drawing root, split into one panel per metric
+    let style = chart_style();
+    let root =
+        BitMapBackend::new(out_file, (style.width, style.height * 3 / 2)).into_drawing_area();
+    root.fill(&style.background())?;
+    let root = root.titled(graph_name, chart_text(50))?;
+    let (upper, lower) = root.split_vertically(50.percent());
+
+    let hv_color = style.line_color(0).mix(0.9);
+    let mut chart = ChartBuilder::on(&upper)
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(1..gens_max, 0.0..1.0)?;
+    chart
+        .configure_mesh()
+        .y_labels(10)
+        .x_labels(16)
+        .y_desc("hypervolume (of optimal)")
+        .x_desc("gens")
+        .y_label_formatter(&|y| format!("{:.1}", y))
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+    chart.draw_series(LineSeries::new(
+        metrics
+            .iter()
+            .enumerate()
+            .map(|(x, m)| (x as u32 + 1, m.hypervolume / optimal_hypervolume)),
+        hv_color.stroke_width(style.line_width),
+    ))?;
+
+    let igd_color = style.line_color(1).mix(0.9);
+    let mut chart = ChartBuilder::on(&lower)
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(1..gens_max, 0.0..igd_max)?;
+    chart
+        .configure_mesh()
+        .y_labels(10)
+        .x_labels(16)
+        .y_desc("IGD")
+        .x_desc("gens")
+        .y_label_formatter(&|y| format!("{:.1}", y))
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+    chart.draw_series(LineSeries::new(
+        metrics
+            .iter()
+            .enumerate()
+            .map(|(x, m)| (x as u32 + 1, m.igd)),
+        igd_color.stroke_width(style.line_width),
+    ))?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+fn main() {
+    create_dir_all("output").unwrap();
+    load_chart_style(CHART_STYLE_FILE).unwrap();
+
+    let start_time = Instant::now();
+
+    let (population, pareto_front, tracker) = run_sim().unwrap();
+    let (weighted_sum, chebyshev) = run_scalarized_sims();
+    generate_graph(
+        "NSGA-II: Clusters of 4 vs. Transitions",
+        &population,
+        &pareto_front,
+        &[("weighted sum", weighted_sum), ("Chebyshev", chebyshev)],
+        OUT_PARETO_FRONT,
+    )
+    .unwrap();
+
+    tracker.write_csv(OUT_PARETO_METRICS_CSV).unwrap();
+    generate_metrics_graph(
+        "NSGA-II: Quality of the Archived Front",
+        &tracker.metrics,
+        OUT_PARETO_METRICS,
+    )
+    .unwrap();
+
+    println!(
+        "Finished execution in {} seconds!",
+        start_time.elapsed().as_secs()
+    );
+}
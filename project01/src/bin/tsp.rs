@@ -0,0 +1,333 @@
+use ga_lab::style::{chart_style, chart_text, load_chart_style, CHART_STYLE_FILE};
+use genevo::{
+    self,
+    aco::{tour_length, Tour},
+    ga,
+    operator::{
+        prelude::{
+            ElitistReinserter, MaximizeSelector, OrderOneCrossover, PartiallyMappedCrossover,
+            SwapOrderMutator,
+        },
+        CrossoverOp,
+    },
+    prelude::*,
+    statistic::TrackProcessingTime,
+};
+use plotters::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{fmt::Debug, fs::create_dir_all, thread, time::Instant};
+
+// Output file path of the comparison graph
+const OUT_TSP: &str = "output/tsp_ga_vs_aco.png";
+
+// Unchanging simulation parameters
+const CITY_COUNT: usize = 50;
+const CITY_SEED: u64 = 415; // the cities are the same on every run
+const POPULATION_SIZE: usize = 100; // the tours of the GA, and the ants of the colony
+const GENERATION_LIMIT: u64 = 500;
+const BATCH_SIZE: u64 = 8;
+const MUTATION_RATE: f64 = 0.02;
+
+// Fitness values are integers in genevo, so tour lengths are scaled by this factor before they
+// are negated (the GA maximizes, tours are minimized)
+const FITNESS_SCALE: f64 = 1e3;
+
+// The solvers being compared: the GA with one of the permutation crossover operators, or the ant
+// colony with its weights of the pheromone (α) and the distance (β), its evaporation rate (ρ)
+// and its number of elitist ants
+#[derive(Clone, Copy, Debug)]
+enum Solver {
+    OrderOneCrossover,
+    PartiallyMappedCrossover,
+    AntColony(f64, f64, f64, usize),
+}
+
+impl Solver {
+    fn name(&self) -> String {
+        match self {
+            Solver::OrderOneCrossover => "GA, OX1 crossover".to_string(),
+            Solver::PartiallyMappedCrossover => "GA, PMX crossover".to_string(),
+            Solver::AntColony(alpha, beta, rho, 0) => {
+                format!("ACO, α = {}, β = {}, ρ = {}", alpha, beta, rho)
+            }
+            Solver::AntColony(alpha, beta, rho, elitist_ants) => format!(
+                "ACO, α = {}, β = {}, ρ = {}, {} elitist ants",
+                alpha, beta, rho, elitist_ants
+            ),
+        }
+    }
+}
+
+// The distances between cities placed at random in a square of side 100
+fn random_cities() -> Vec<Vec<f64>> {
+    let mut rng = StdRng::seed_from_u64(CITY_SEED);
+    let cities: Vec<(f64, f64)> = (0..CITY_COUNT)
+        .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect();
+    cities
+        .iter()
+        .map(|a| {
+            cities
+                .iter()
+                .map(|b| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt())
+                .collect()
+        })
+        .collect()
+}
+
+// Negated, scaled length of a tour as its fitness
+#[derive(Clone, Debug)]
+struct TourLengthFitnessCalculator(Vec<Vec<f64>>);
+
+impl FitnessFunction<Tour, i64> for TourLengthFitnessCalculator {
+    fn fitness_of(&self, tour: &Tour) -> i64 {
+        -(tour_length(tour, &self.0) * FITNESS_SCALE).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        values.iter().sum::<i64>() / values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+// Build random tours through all the cities
+struct RandomTourBuilder;
+
+impl GenomeBuilder<Tour> for RandomTourBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Tour
+    where
+        R: Rng + Sized,
+    {
+        let mut tour: Tour = (0..CITY_COUNT).collect();
+        tour.shuffle(rng);
+        tour
+    }
+}
+
+// The length of the best tour at each generation
+type LengthCurve = Vec<f64>;
+
+// Runs a simulation of the GA with the given crossover operator
+fn run_ga_sim<C>(distances: &[Vec<f64>], crossover: C) -> Option<LengthCurve>
+where
+    C: CrossoverOp<Tour> + Debug + PartialEq + Sync,
+{
+    let fitness = TourLengthFitnessCalculator(distances.to_vec());
+    let alg = genetic_algorithm()
+        .with_evaluation(fitness.clone())
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(crossover)
+        .with_mutation(SwapOrderMutator::new(MUTATION_RATE))
+        .with_reinsertion(ElitistReinserter::new(fitness, true, 0.5))
+        .with_initial_population(
+            build_population()
+                .with_genome_builder(RandomTourBuilder)
+                .of_size(POPULATION_SIZE)
+                .uniform_at_random(),
+        )
+        .build();
+
+    run_sim(alg)
+}
+
+// Runs a simulation of the ant colony
+fn run_aco_sim(
+    distances: &[Vec<f64>],
+    (alpha, beta, rho, elitist_ants): (f64, f64, f64, usize),
+) -> Option<LengthCurve> {
+    let alg = AntColony::new(
+        TourLengthFitnessCalculator(distances.to_vec()),
+        distances.to_vec(),
+        POPULATION_SIZE,
+    )
+    .with_coefficients(alpha, beta, rho)
+    .with_elitist_ants(elitist_ants);
+
+    run_sim(alg)
+}
+
+// Runs a simulation of any algorithm with the output of the GA to the generation limit
+fn run_sim<A>(alg: A) -> Option<LengthCurve>
+where
+    A: Algorithm<Output = ga::State<Tour, i64>, Error = ga::GeneticAlgorithmError>
+        + TrackProcessingTime
+        + Debug,
+{
+    let mut sim = simulate(alg)
+        .until(GenerationLimit::new(GENERATION_LIMIT))
+        .build();
+
+    // Stores the length of the best tour at each iteration of the simulation
+    let mut curve = vec![];
+
+    loop {
+        let (fitness, last) = match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                (step.result.best_solution.solution.fitness, false)
+            }
+            Ok(SimResult::Final(step, _, _, _)) => {
+                (step.result.best_solution.solution.fitness, true)
+            }
+            Err(error) => {
+                println!("{}", error);
+                return None;
+            }
+        };
+        curve.push(-fitness as f64 / FITNESS_SCALE);
+        if last {
+            return Some(curve);
+        }
+    }
+}
+
+// Runs a batch of simulations of a solver in parallel and averages their curves
+fn run_sim_batch(distances: &[Vec<f64>], solver: Solver) -> Option<LengthCurve> {
+    println!(
+        "[thread pool]: Running {} simulations of the TSP with {}.",
+        BATCH_SIZE,
+        solver.name()
+    );
+
+    let curves = thread::scope(|scope| {
+        let handles = (0..BATCH_SIZE)
+            .map(|_| {
+                scope.spawn(move || match solver {
+                    Solver::OrderOneCrossover => run_ga_sim(distances, OrderOneCrossover::new()),
+                    Solver::PartiallyMappedCrossover => {
+                        run_ga_sim(distances, PartiallyMappedCrossover::new())
+                    }
+                    Solver::AntColony(alpha, beta, rho, elitist_ants) => {
+                        run_aco_sim(distances, (alpha, beta, rho, elitist_ants))
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Option<Vec<LengthCurve>>>()
+    })?;
+
+    let len = curves.iter().map(|c| c.len()).max()?;
+    Some(
+        (0..len)
+            .map(|g| {
+                curves.iter().map(|c| c[g.min(c.len() - 1)]).sum::<f64>() / curves.len() as f64
+            })
+            .collect(),
+    )
+}
+
+fn generate_graph(
+    graph_name: &str,
+    dataset: &[(String, LengthCurve)],
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gens_max = dataset.iter().map(|(_, d)| d.len()).max().unwrap_or(1) as u32;
+    let length_max = dataset
+        .iter()
+        .flat_map(|(_, d)| d.iter().copied())
+        .fold(0.0, f64::max);
+
+    // Drawing root
+    let style = chart_style();
+    let root = BitMapBackend::new(out_file, (style.width, style.height)).into_drawing_area();
+    root.fill(&style.background())?;
+
+    // Chart
+    let mut chart = ChartBuilder::on(&root)
+        .caption(graph_name, chart_text(50))
+        .margin(10)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(1..gens_max, 0.0..length_max)?;
+
+    // Mesh configuration
+    chart
+        .configure_mesh()
+        .y_labels(8)
+        .x_labels(16)
+        .y_desc("best tour length")
+        .x_desc("gens")
+        .y_label_formatter(&|y| format!("{:.0}", y))
+        .label_style(chart_text(25))
+        .bold_line_style(style.foreground().mix(0.2))
+        .light_line_style(style.foreground().mix(0.1))
+        .axis_style(style.foreground())
+        .draw()?;
+
+    // Draw each line in the dataset
+    for (idx, (label, data)) in dataset.iter().enumerate() {
+        let color = style.line_color(idx).mix(0.6);
+
+        chart
+            .draw_series(LineSeries::new(
+                data.iter().enumerate().map(|(x, y)| (x as u32 + 1, *y)),
+                color.stroke_width(style.line_width),
+            ))?
+            .label(format!(
+                "{} (final: {:.1})",
+                label,
+                data.last().copied().unwrap_or_default()
+            ))
+            .legend(move |(x, y)| {
+                PathElement::new(
+                    vec![(x, y), (x + 20, y)],
+                    color.stroke_width(style.line_width),
+                )
+            });
+    }
+
+    chart
+        .configure_series_labels()
+        .label_font(chart_text(25))
+        .background_style(style.background().mix(0.8))
+        .border_style(style.foreground())
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
+
+fn main() {
+    create_dir_all("output").unwrap();
+    load_chart_style(CHART_STYLE_FILE).unwrap();
+
+    let start_time = Instant::now();
+
+    let distances = random_cities();
+    let solvers = [
+        Solver::OrderOneCrossover,
+        Solver::PartiallyMappedCrossover,
+        Solver::AntColony(1.0, 2.0, 0.5, 0),
+        Solver::AntColony(1.0, 5.0, 0.1, 0),
+        Solver::AntColony(1.0, 5.0, 0.1, CITY_COUNT),
+    ];
+    let dataset = solvers
+        .iter()
+        .map(|solver| {
+            let curve = run_sim_batch(&distances, *solver).unwrap();
+            (solver.name(), curve)
+        })
+        .collect::<Vec<_>>();
+
+    generate_graph(
+        &format!("TSP ({} cities): GA vs. ACO", CITY_COUNT),
+        &dataset,
+        OUT_TSP,
+    )
+    .unwrap();
+
+    println!(
+        "Finished execution in {} seconds!",
+        start_time.elapsed().as_secs()
+    );
+}
@@ -0,0 +1,227 @@
+// A C API of the simulation engine (with the `ffi` feature), so that programs in C or C++ run the
+// same GA as the experiments: create a simulation of the clusters-of-4 problem from a spec of the
+// parameters and a seed, step it generation by generation, query its best strand and destroy it.
+// The header project01.h is generated from this file by cbindgen on every build with the feature,
+// into the directory named by PROJECT01_HEADER_DIR or else OUT_DIR (see build.rs), the library is
+// target/<profile>/libproject01.so (or .a).
+//
+// The doc comments end up in the header, so they are written for the C side.
+
+use super::{build_sim, initial_strand_builder, Parameters, StrandSimulator, GENERATION_LIMIT};
+use ga_lab::{
+    genome::Genome,
+    problem::{read_seed_strands, ClustersOf4, Problem},
+};
+use genevo::prelude::*;
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+thread_local! {
+    // Why the latest failed call of the thread failed
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+// Runs a call of the API, recording its error (or panic, which must not unwind into C) for
+// `ga_last_error` and returning the fallback instead
+fn guard<T>(fallback: T, call: impl FnOnce() -> Result<T, String>) -> T {
+    let error = match panic::catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => error,
+        Err(panic) => panic
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "the simulation panicked".to_string()),
+    };
+    let error = CString::new(error.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(error));
+    fallback
+}
+
+/// A run of the genetic algorithm on the clusters-of-4 problem. Created by
+/// `ga_simulation_new`, destroyed by `ga_simulation_free`.
+pub struct GaSimulation {
+    sim: StrandSimulator<ClustersOf4>,
+    problem: ClustersOf4,
+    generation: u64,
+    best_fitness: usize,
+    best_genome: Genome,
+    finished: bool,
+}
+
+/// Creates a simulation of strands of `strand_size` nucleotides (a multiple of
+/// 4) from the parameters of the experiments, e.g. "mutation_rate=0.01,
+/// selection_ratio=0.25" or "default", and a seed. The same parameters and seed
+/// evolve the same strands. Only the plain (non self-adaptive, single island)
+/// genetic algorithm runs, with a single crossover and mutation.
+///
+/// Returns NULL if the parameters are not valid, see `ga_last_error`.
+///
+/// # Safety
+///
+/// `parms` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_new(
+    parms: *const c_char,
+    strand_size: usize,
+    seed: u64,
+) -> *mut GaSimulation {
+    guard(ptr::null_mut(), || {
+        if parms.is_null() {
+            return Err("the parameters are NULL".to_string());
+        }
+        let spec = CStr::from_ptr(parms)
+            .to_str()
+            .map_err(|error| format!("the parameters are not UTF-8: {}", error))?;
+        let parms = Parameters::parse(spec)?;
+        if !parms.is_plain_ga() {
+            return Err("only the plain (non self-adaptive, single island) GA runs".to_string());
+        }
+        if strand_size == 0 || !strand_size.is_multiple_of(4) {
+            return Err(format!(
+                "the strand size must be a positive multiple of 4, got {}",
+                strand_size
+            ));
+        }
+        // Fail here rather than in the run
+        let problem = ClustersOf4::new(strand_size);
+        if !parms.seed_file.is_empty() {
+            read_seed_strands(&problem, &parms.seed_file)?;
+        }
+        initial_strand_builder(&problem, &parms)?;
+
+        let sim = build_sim(&problem, &parms, parms.population_size, seed, NoLineage);
+        Ok(Box::into_raw(Box::new(GaSimulation {
+            sim,
+            problem,
+            generation: 0,
+            best_fitness: 0,
+            best_genome: Genome::new(),
+            finished: false,
+        })))
+    })
+}
+
+/// Runs the next generation of the simulation. Returns 1 if the simulation
+/// goes on, 0 once it has found the optimum or run GENERATION_LIMIT
+/// generations (without running another one) and -1 if it failed, see
+/// `ga_last_error`.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_step(sim: *mut GaSimulation) -> c_int {
+    guard(-1, || {
+        let sim = sim.as_mut().ok_or("the simulation is NULL")?;
+        if sim.finished {
+            return Ok(0);
+        }
+        let (state, last) = match sim.sim.step() {
+            Ok(SimResult::Intermediate(state)) => (state, false),
+            Ok(SimResult::Final(state, ..)) => (state, true),
+            Err(error) => return Err(error.to_string()),
+        };
+        let best = &state.result.best_solution.solution;
+        if sim.generation == 0 || best.fitness > sim.best_fitness {
+            sim.best_fitness = best.fitness;
+            sim.best_genome = best.genome.clone();
+        }
+        sim.generation += 1;
+        sim.finished = last;
+        Ok(if last { 0 } else { 1 })
+    })
+}
+
+/// The number of generations the simulation has run, at most GENERATION_LIMIT.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_generation(sim: *const GaSimulation) -> u64 {
+    sim.as_ref().map_or(0, |sim| sim.generation)
+}
+
+/// The best fitness of the strands so far: the number of clusters of 4
+/// identical nucleotides of the best strand.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_best_fitness(sim: *const GaSimulation) -> usize {
+    sim.as_ref().map_or(0, |sim| sim.best_fitness)
+}
+
+/// The highest possible fitness, at which the simulation stops.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_optimum(sim: *const GaSimulation) -> usize {
+    sim.as_ref().map_or(0, |sim| sim.problem.fitness_bounds().1)
+}
+
+/// Writes the best strand so far as a NUL-terminated string of nucleotides,
+/// e.g. "AAAACCCC...", into the buffer of `len` bytes, cut short if it does not
+/// fit. Returns the length of the whole strand (without the NUL), so like
+/// `snprintf` a result of `len` or more means the buffer was too small. The
+/// strand is empty before the first generation.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet,
+/// `buffer` must point to `len` writable bytes (or be NULL if `len` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_best_genome(
+    sim: *const GaSimulation,
+    buffer: *mut c_char,
+    len: usize,
+) -> usize {
+    let Some(sim) = sim.as_ref() else {
+        return 0;
+    };
+    let strand = sim.problem.render(&sim.best_genome);
+    if !buffer.is_null() && len > 0 {
+        let written = strand.len().min(len - 1);
+        ptr::copy_nonoverlapping(strand.as_ptr().cast::<c_char>(), buffer, written);
+        *buffer.add(written) = 0;
+    }
+    strand.len()
+}
+
+/// Destroys the simulation. Does nothing if `sim` is NULL.
+///
+/// # Safety
+///
+/// `sim` must have been created by `ga_simulation_new` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn ga_simulation_free(sim: *mut GaSimulation) {
+    if !sim.is_null() {
+        drop(Box::from_raw(sim));
+    }
+}
+
+/// Why the latest failed call of this thread failed, NULL if none has. The
+/// string stays valid until the next failed call of the thread.
+#[no_mangle]
+pub extern "C" fn ga_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |error| error.as_ptr())
+    })
+}
+
+/// The number of generations after which a simulation stops at the latest.
+#[no_mangle]
+pub extern "C" fn ga_generation_limit() -> u64 {
+    GENERATION_LIMIT
+}
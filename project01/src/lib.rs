@@ -0,0 +1,3561 @@
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "results-db")]
+mod results;
+
+#[cfg(feature = "tui")]
+use ga_lab::dashboard;
+#[cfg(any(feature = "tui", feature = "serve"))]
+use ga_lab::monitor;
+#[cfg(feature = "serve")]
+use ga_lab::server;
+use ga_lab::{
+    batch::{
+        average_runs, average_runs_over_time, convergence_gen, normalized_run,
+        run_batch_with_policy, run_footprint, runs_from_csv, runs_to_csv, time_to_target, Data,
+        DataSetWithLables, NormalizedDataSetWithLables, RunPolicy, RunsWithLables, Timestamps,
+    },
+    fasta::{to_fasta, FastaRecord},
+    fitness::AlignmentScores,
+    genome::{allele_frequencies, AlleleFrequencies, Genome, Phenome},
+    hall_of_fame::{
+        fasta_out_file, hall_of_fame_out_file, hall_of_fame_to_csv, hall_of_fame_to_fasta,
+        HallOfFame,
+    },
+    json::json_string,
+    plot::{
+        differential_out_file, generate_allele_heatmap, generate_differential_graph,
+        generate_evolution_graph, generate_fitness_animation, generate_graph,
+        generate_graph_in_bounds, generate_normalized_graph, generate_run_length_graph,
+        generate_success_rate_graph, generate_wall_time_graph, success_rate_out_file,
+        vertical_lines, wall_time_out_file, zoomed_out_file, Annotation,
+    },
+    problem::{
+        read_seed_strands, Alignment, BiasedStrandBuilder, ClustersOf4, DeceptiveTraps, GcContent,
+        LeadingOnes, Motif, Nk, OneMax, Problem, RandomStrandBuilder, RoyalRoad,
+        SelfAdaptiveFitnessCalculator, SelfAdaptiveStrandBuilder, SingleLocusFlip,
+        StandardBitMutation, Strand,
+    },
+    report,
+    stats::{
+        best_setting, comparison_table, label_with_p_values, solver_table, summarize,
+        summary_out_file, summary_table,
+    },
+    style::{load_chart_style, CHART_STYLE_FILE},
+    theory::{
+        expected_fitness_per_gen, leading_ones_expected_fitness, leading_ones_expected_runtime,
+        one_max_expected_fitness, one_max_expected_runtime,
+    },
+};
+#[cfg(feature = "serve")]
+use genevo::termination::limit::genotype_diversity;
+use genevo::{
+    self,
+    algorithm::EvaluatedPopulation,
+    ga::State,
+    operator::{
+        prelude::{
+            EnsembleCrossover, EnsembleMutator, HillClimber, InsertOrderMutator,
+            MultiPointCrossBreeder, NoOpCrossover, NoOpMutator, OperatorWeights,
+            ProbabilisticCrossover, RandomValueMutator, SelfAdaptive, SelfAdaptiveCrossBreeder,
+            SelfAdaptiveMutator, StagnationBoostMutator, SwapOrderMutator, UniformCrossBreeder,
+            WeightAdaptation,
+        },
+        CrossoverOp, MutationOp,
+    },
+    population::{HammingDistance, SeededGenomeBuilder},
+    prelude::*,
+    random::{get_rng, Seed},
+    recombination::discrete::SinglePointCrossBreeder,
+    reinsertion::{
+        elitism::ElitismReinserter,
+        elitist::ElitistReinserter,
+        immigrants::{ImmigrantReinserter, ImmigrationReinserter},
+        restart::RestartReinserter,
+    },
+    schedule::{Schedule, ScheduledMutator, ScheduledSelector, ScheduledSizeReinserter},
+    selection::truncation::*,
+    simulation::{simulator::Simulator, State as SimState},
+    statistic::{OperatorStatistics, ProcessingTime},
+    termination::{combinator::Or, StopReason},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display},
+    fs::{create_dir_all, read_to_string, remove_dir_all, remove_file, write, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+// Output file paths and flags for whether or not to generate the file
+const OUT_DEFAULT: (&str, bool) = ("output/default_parameters.png", true);
+const OUT_VAR_NUM_INDIV: (&str, bool) = ("output/various_num_indivs.png", true);
+const OUT_VAR_SELECTION: (&str, bool) = ("output/various_selection_ratios.png", true);
+const OUT_VAR_MUTATION: (&str, bool) = ("output/various_mutation_rates.png", true);
+const OUT_VAR_REINSERTION: (&str, bool) = ("output/various_reinsertion_ratios.png", true);
+const OUT_BEST_OF_EACH: (&str, bool) = ("output/best_of_each_varied_parm.png", true);
+const OUT_VAR_STAGNATION: (&str, bool) = ("output/various_stagnation_limits.png", true);
+const OUT_SELF_ADAPTIVE: (&str, bool) = ("output/self_adaptive_vs_fixed_rates.png", true);
+const OUT_ISLANDS: (&str, bool) = ("output/island_model_vs_single_population.png", true);
+const OUT_MEMETIC: (&str, bool) = ("output/memetic_vs_plain_ga.png", true);
+const OUT_BASELINES: (&str, bool) = ("output/ga_vs_baselines.png", true);
+const OUT_VAR_POPULATION: (&str, bool) = ("output/various_population_sizes.png", true);
+const OUT_VAR_STRAND_SIZE: (&str, bool) = ("output/various_strand_sizes.png", true);
+const OUT_VAR_ELITES: (&str, bool) = ("output/various_elite_counts.png", true);
+const OUT_IMMIGRANTS: (&str, bool) = ("output/duplicates_vs_immigrants.png", true);
+const OUT_VAR_IMMIGRANT_RATE: (&str, bool) = ("output/various_immigrant_rates.png", true);
+const OUT_VAR_INIT: (&str, bool) = ("output/uniform_vs_biased_initialization.png", true);
+const OUT_VAR_INIT_DISTANCE: (&str, bool) = ("output/various_init_min_distances.png", true);
+const OUT_MUTATION_SCHEDULE: (&str, bool) = ("output/fixed_vs_annealed_mutation_rates.png", true);
+const OUT_VAR_RESTART: (&str, bool) = ("output/various_restart_limits.png", true);
+const OUT_PIPELINES: (&str, bool) = ("output/crossover_vs_mutation_ablation.png", true);
+const OUT_VAR_CROSSOVER: (&str, bool) = ("output/various_crossover_rates.png", true);
+const OUT_ENSEMBLES: (&str, bool) = ("output/single_operators_vs_ensembles.png", true);
+const OUT_VAR_GC_CONTENT: (&str, bool) = ("output/various_gc_content_targets.png", true);
+const OUT_VAR_MOTIF: (&str, bool) = ("output/various_motifs.png", true);
+const OUT_VAR_GAP_SCORE: (&str, bool) = ("output/various_alignment_gap_scores.png", true);
+const OUT_VAR_NK: (&str, bool) = ("output/various_nk_landscape_ruggedness.png", true);
+const OUT_ROYAL_ROAD: (&str, bool) = ("output/royal_road_crossover_vs_mutation.png", true);
+const OUT_TRAPS: (&str, bool) = ("output/deceptive_traps_crossover_vs_mutation.png", true);
+const OUT_ONE_MAX: (&str, bool) = ("output/one_max_vs_theory.png", true);
+const OUT_LEADING_ONES: (&str, bool) = ("output/leading_ones_vs_theory.png", true);
+const OUT_ES: (&str, bool) = ("output/ga_vs_evolution_strategies.png", true);
+
+// Output file path and flag for the best strand found by each run
+const OUT_WINNERS: (&str, bool) = ("output/winners.txt", true);
+
+// Output file path and flag for the best strand over the generations of a single run
+const OUT_EVOLUTION: (&str, bool) = ("output/best_strand_evolution.png", true);
+const OUT_ALLELES: (&str, bool) = ("output/allele_frequencies.png", true);
+const OUT_FITNESS_ANIMATION: (&str, bool) = ("output/fitness_distribution.gif", true);
+
+// Output directory and flag for the per-generation metrics streamed to disk during each run
+const OUT_METRICS: (&str, bool) = ("output/metrics", true);
+
+// Directory path and flag for the hall of fame of each run, named after its parameters and seed.
+// The hall of fame of the runs of each graph is written next to it.
+const OUT_HALL_OF_FAME: (&str, bool) = ("output/hall_of_fame", true);
+
+// Output directory and flag for the runs of each sweep, kept across invocations to be compared
+// with `project01 compare <saved runs>...`
+const OUT_SAVED_RUNS: (&str, bool) = ("output/saved_runs", true);
+
+// Output file path of the overlay graph of `project01 compare`, its delta table goes next to it
+const OUT_COMPARISON: &str = "output/comparison.png";
+
+// Output file path of the overlay graph of `project01 solvers`, its summary table goes next to it
+const OUT_SOLVERS: &str = "output/solver_comparison.png";
+
+// Output file path of the run-length distributions of `project01 rld` over the generations, the
+// ones over the wall-clock time go next to it
+const OUT_RUN_LENGTHS: &str = "output/run_lengths.png";
+
+// Where `project01 --replay <seed> <parms>` writes the final population of the run as FASTA
+const OUT_FINAL_POPULATION: &str = "output/final_population.fasta";
+
+// Where `project01 --trace <seed> <parms>` writes the new best strands of the run as JSON Lines
+const OUT_TRACE: &str = "output/trace.jsonl";
+
+// Where `project01 --ancestry <seed> <parms>` writes the family tree of the best strand of the run
+// in the GraphViz DOT format
+const OUT_ANCESTRY: &str = "output/ancestry.dot";
+
+// Database file path and flag for the record of every run (with the `results-db` feature)
+#[cfg(feature = "results-db")]
+const OUT_RESULTS_DB: (&str, bool) = ("output/results.sqlite", true);
+
+// Unchanging simulation parameters
+const STRAND_SIZE: usize = 100; // default, the strand size sweep builds a problem of each size
+const POPULATION_SIZE: usize = 256; // default of the population_size parameter
+const SMALL_POPULATION_SIZE: usize = 16; // of the diverse initialization sweep
+const GENERATION_LIMIT: u64 = 16_384; // 2^14
+const RUN_TIME_LIMIT: Duration = Duration::from_secs(10 * 60); // per simulation run
+const RUN_TIMEOUT: Duration = Duration::from_secs(15 * 60); // wall-clock, then a run is abandoned
+const RUN_RETRIES: u32 = 2; // of a failed or abandoned run with a new seed, before the batch fails
+const BATCH_SIZE: u64 = 16;
+const SIGNIFICANCE_LEVEL: f64 = 0.05; // p-value a sweep's best setting must beat the default by
+const ALLOW_CENSORED_RUNS: bool = false; // keep the GA runs a limit stopped short of the optimum
+const PARALLEL_EVALUATION: bool = true; // evaluate the fitness of a population in parallel
+const FITNESS_CACHE_SIZE: usize = 1024; // strands whose fitness is remembered, 0 turns it off
+const HALL_OF_FAME_SIZE: usize = 16; // best distinct strands kept of each run and batch
+const BOOST_DURATION: u64 = 16; // gens the mutation rate stays boosted after stagnation
+const RESTART_ELITES: usize = 4; // best strands that survive a restart of the population
+const STRIP_SAMPLES: usize = 64; // generations drawn in the best strand evolution graph
+const HEATMAP_SAMPLES: usize = 256; // generations drawn in the allele frequency heatmap
+const ANIMATION_FRAMES: usize = 100; // generations drawn in the fitness distribution animation
+const ANIMATION_FRAME_DELAY: u32 = 100; // milliseconds each frame of the animation is shown
+const WALL_TIME_POINTS: usize = 512; // seconds sampled of the runs in the graphs over time
+const MIN_MUTATION_RATE: f64 = 0.001; // bounds of self-adaptive mutation rates
+const MAX_MUTATION_RATE: f64 = 0.5;
+const MIGRATION_INTERVAL: u64 = 16; // gens between two migrations of the island model
+const NUM_MIGRANTS: usize = 4; // best individuals sent to each neighbouring island
+const SA_INITIAL_TEMPERATURE: f64 = 1.0; // simulated annealing temperature at the first gen
+const SA_COOLING_RATE: f64 = 0.99; // temperature factor per gen of simulated annealing
+const ES_OFFSPRING_PER_PARENT: usize = 4; // λ/μ of the (μ+λ)-ES, whose λ is the population size
+const SOLVERS_SEED: u64 = 415; // of the seeds `project01 solvers` runs every solver from by default
+
+// The strand the alignment sweep aligns strands of the same size with, short as aligning takes
+// the product of their sizes
+const ALIGNMENT_REFERENCE: &str = "TACTAGCCAGGGCAGAGTCCACGTCTCAACGGGTGCTTCT";
+
+// The bits of the NK landscapes of the ruggedness sweep and the seed they are drawn from, the same
+// for each K so the sweep can be run again
+const NK_SIZE: usize = 64;
+const NK_SEED: u64 = 415;
+
+// The bitstrings of the bitstring benchmarks, split into Royal Road blocks and deceptive traps
+const BITSTRING_SIZE: usize = 64;
+const ROYAL_ROAD_BLOCK_SIZE: usize = 8;
+const TRAP_SIZE: usize = 4;
+
+// Bytes the runs of a batch may take at once (by their estimated footprint), set with
+// `--memory-budget <MiB>`. The runs of a batch all start at once without one.
+static MEMORY_BUDGET: OnceLock<u64> = OnceLock::new();
+
+// The Parameter struct defines the changing parameters need to run a simulation
+#[derive(Debug, Clone)]
+struct Parameters {
+    parms_name: String,
+    population_size: usize,
+    num_individuals_per_parents: usize,
+    selection_ratio: f64,
+    crossover_rate: f64, // of the selected parents, the others are passed on as they are
+    mutation_rate: f64,
+    reinsertion_ratio: f64,
+    n_elites: usize, // best individuals that survive each generation whatever the reinsertion_ratio
+    eliminate_duplicates: bool, // replace duplicate children by random immigrants
+    immigrant_rate: f64, // fraction of the worst individuals replaced by random immigrants per gen
+    stagnation_limit: u64, // 0 disables the mutation boost
+    mutation_boost: f64,
+    self_adaptive: bool, // evolve per-individual mutation rates starting at mutation_rate
+    islands: usize,      // the population is split evenly between the islands
+    topology: Topology,  // how migrants travel between the islands
+    local_search_steps: usize, // hill-climbing steps per child, 0 disables the local search
+    seed_file: String, // strands seeding the initial population (e.g. a hall of fame CSV or FASTA)
+    init_block_size: usize, // loci of the initial strands that share a random allele
+    init_weights: Vec<f64>, // of the alleles drawn for the initial strands, empty for uniform
+    init_min_distance: usize, // Hamming distance between any two initial strands, 0 for any
+    // Schedules over the generations that replace the fixed mutation_rate, selection_ratio and
+    // population_size (of each island) of the non self-adaptive GA, None to keep them fixed
+    mutation_schedule: Option<Schedule>,
+    selection_schedule: Option<Schedule>,
+    population_schedule: Option<Schedule>,
+    // Gens without improvement after which the non self-adaptive GA keeps its RESTART_ELITES best
+    // strands and re-randomizes the rest of the population, 0 never restarts
+    restart_after: u64,
+    pipeline: Pipeline, // of the breeding of the plain GA
+    // The operators the plain GA picks one of for each crossover and mutation, by their weights
+    // (see `parse_ensemble`), empty for just the single point crossover and the random value
+    // mutation
+    crossovers: Vec<(CrossoverKind, f64)>,
+    mutations: Vec<(MutationKind, f64)>,
+    adaptive_operators: bool, // adapt the weights of the operators to their success
+    solver: Solver,
+}
+
+// The algorithms a set of parameters can be solved with; the baselines ignore the GA parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+enum Solver {
+    GeneticAlgorithm,
+    SimulatedAnnealing,
+    RandomSearch,   // samples a new random strand at every step
+    HillClimbing,   // first-improvement, moves only to a better neighbouring strand
+    OnePlusOneEa,   // a single strand, each allele of its offspring replaced at 1/strand size
+    MuPlusLambdaEs, // the best of parents and offspring (see ES_OFFSPRING_PER_PARENT) survive
+}
+
+impl Solver {
+    fn name(&self) -> &'static str {
+        match self {
+            Solver::GeneticAlgorithm => "genetic algorithm",
+            Solver::SimulatedAnnealing => "simulated annealing",
+            Solver::RandomSearch => "random search",
+            Solver::HillClimbing => "hill climbing",
+            Solver::OnePlusOneEa => "(1+1)-EA",
+            Solver::MuPlusLambdaEs => "(μ+λ)-ES",
+        }
+    }
+}
+
+const SOLVERS: [Solver; 6] = [
+    Solver::GeneticAlgorithm,
+    Solver::SimulatedAnnealing,
+    Solver::RandomSearch,
+    Solver::HillClimbing,
+    Solver::OnePlusOneEa,
+    Solver::MuPlusLambdaEs,
+];
+
+// The stages the plain GA breeds the strands by: both, or one of them alone to see what the other
+// one contributes (an ablation)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+enum Pipeline {
+    CrossoverAndMutation,
+    MutationOnly,
+    CrossoverOnly,
+}
+
+impl Pipeline {
+    fn name(&self) -> &'static str {
+        match self {
+            Pipeline::CrossoverAndMutation => "crossover and mutation",
+            Pipeline::MutationOnly => "mutation only",
+            Pipeline::CrossoverOnly => "crossover only",
+        }
+    }
+}
+
+// The operators of the ensembles of the plain GA, in the order of the operators of the ensemble
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+enum CrossoverKind {
+    SinglePoint,
+    TwoPoint,
+    Uniform,
+}
+
+const CROSSOVER_KINDS: [CrossoverKind; 3] = [
+    CrossoverKind::SinglePoint,
+    CrossoverKind::TwoPoint,
+    CrossoverKind::Uniform,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+enum MutationKind {
+    RandomValue, // the mutation of the GA, boosted on stagnation and following the schedule
+    Swap,        // swaps the alleles of two loci
+    Insert,      // moves the allele of a locus to another one
+}
+
+const MUTATION_KINDS: [MutationKind; 3] = [
+    MutationKind::RandomValue,
+    MutationKind::Swap,
+    MutationKind::Insert,
+];
+
+// The weight an adapting ensemble gives to the success of the operators in the latest generation
+const OPERATOR_LEARNING_RATE: f64 = 0.1;
+const MIN_OPERATOR_PROBABILITY: f64 = 0.05; // of each operator of an adapting ensemble
+
+// The baselines whose curves are added as a reference to the graph of every GA experiment
+const REFERENCE_BASELINES: &[Solver] = &[Solver::RandomSearch, Solver::HillClimbing];
+
+#[derive(Debug, Default)]
+enum Variation {
+    #[default]
+    Default,
+    NumIdiv(Vec<usize>),
+    Selection(Vec<f64>),
+    Crossover(Vec<f64>),
+    Mutation(Vec<f64>),
+    Reinsertion(Vec<f64>),
+    BestOfEach {
+        num_individuals_per_parents: usize,
+        selection_ratio: f64,
+        mutation_rate: f64,
+        reinsertion_ratio: f64,
+    },
+    Stagnation(Vec<u64>),
+    SelfAdaptation(Vec<f64>),
+    Islands(Vec<usize>),
+    LocalSearch(Vec<usize>),
+    Solvers(Vec<Solver>),
+    PopulationSize(Vec<usize>),
+    Elites(Vec<usize>),
+    DuplicateElimination(Vec<f64>),
+    ImmigrantRate(Vec<f64>),
+    Initialization(Vec<(usize, Vec<f64>)>), // block sizes and allele weights
+    InitMinDistance(Vec<usize>),            // of a small population
+    MutationSchedule(Vec<&'static str>),    // specs of mutation_schedule, see parse_schedule
+    Restart(Vec<u64>),
+    Pipelines(Vec<Pipeline>),
+    Ensembles(Vec<&'static str>), // specs of the parameters, see Parameters::parse
+}
+
+// Parses the operators of an ensemble and their weights: "<operator>:<weight>+...", e.g.
+// "SinglePoint:2+Uniform:1", the weight 1 if left out
+fn parse_ensemble<K: Debug + Copy>(spec: &str, kinds: &[K]) -> Result<Vec<(K, f64)>, String> {
+    spec.split('+')
+        .map(|operator| {
+            let (name, weight) = operator.split_once(':').unwrap_or((operator, "1"));
+            let kind = kinds
+                .iter()
+                .find(|kind| format!("{:?}", kind) == name)
+                .ok_or_else(|| format!("unknown operator {} of ensemble {}", name, spec))?;
+            let weight = weight
+                .parse::<f64>()
+                .ok()
+                .filter(|weight| *weight >= 0.)
+                .ok_or_else(|| format!("bad weight {} of ensemble {}", weight, spec))?;
+            Ok((*kind, weight))
+        })
+        .collect()
+}
+
+// Parses a schedule of a parameter: a number for a constant, "exp:<initial>:<target>:<decay_rate>"
+// for an exponential decay or "pw:<generation>@<value>:..." for a piecewise linear schedule, e.g.
+// "pw:1@0.2:500@0.01"
+fn parse_schedule(spec: &str) -> Result<Schedule, String> {
+    fn number<T: std::str::FromStr>(spec: &str, value: &str) -> Result<T, String> {
+        value
+            .parse()
+            .map_err(|_| format!("bad value {} of schedule {}", value, spec))
+    }
+
+    let mut parts = spec.split(':');
+    match parts.next() {
+        Some("exp") => match parts.collect::<Vec<_>>()[..] {
+            [initial, target, decay_rate] => Ok(Schedule::ExponentialDecay {
+                initial: number(spec, initial)?,
+                target: number(spec, target)?,
+                decay_rate: number(spec, decay_rate)?,
+            }),
+            _ => Err(format!(
+                "expected exp:<initial>:<target>:<decay_rate>, got {}",
+                spec
+            )),
+        },
+        Some("pw") => {
+            let mut points = vec![];
+            for point in parts {
+                let (generation, value) = point
+                    .split_once('@')
+                    .ok_or_else(|| format!("expected <generation>@<value>, got {}", point))?;
+                points.push((number(spec, generation)?, number(spec, value)?));
+            }
+            if points.is_empty() || points.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return Err(format!(
+                    "expected points ordered by generation, got {}",
+                    spec
+                ));
+            }
+            Ok(Schedule::Piecewise(points))
+        }
+        _ => Ok(Schedule::Constant(number(spec, spec)?)),
+    }
+}
+
+impl Parameters {
+    // Whether the parameters run a single population of (non self-adaptive) strands by the GA,
+    // breeding them by both crossover and mutation, each by a single operator
+    fn is_plain_ga(&self) -> bool {
+        !self.self_adaptive
+            && self.islands <= 1
+            && self.solver == Solver::GeneticAlgorithm
+            && self.pipeline == Pipeline::CrossoverAndMutation
+            && !self.has_ensembles()
+    }
+
+    fn has_ensembles(&self) -> bool {
+        !self.crossovers.is_empty() || !self.mutations.is_empty()
+    }
+
+    // Parses the parameters of the single run modes, the default ones changed by a comma separated
+    // list of `parm=value`s (or just "default"), e.g. "mutation_rate=0.01,solver=HillClimbing"
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut p = Parameters {
+            parms_name: spec.to_string(),
+            ..Parameters::default()
+        };
+        for change in spec.split(',').filter(|c| *c != "default") {
+            let (parm, v) = change
+                .split_once('=')
+                .ok_or_else(|| format!("expected parm=value, got {}", change))?;
+            p.set(parm, v)?;
+        }
+        Ok(p)
+    }
+
+    // Sets a single parameter from its value as text, in the format of `parse`
+    fn set(&mut self, parm: &str, v: &str) -> Result<(), String> {
+        fn value<T: std::str::FromStr>(parm: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("bad value {} of {}", value, parm))
+        }
+
+        let p = self;
+        match parm.trim() {
+            "population_size" => p.population_size = value(parm, v)?,
+            "num_indiv_per_parent" | "num_individuals_per_parents" => {
+                p.num_individuals_per_parents = value(parm, v)?
+            }
+            "selection_ratio" => p.selection_ratio = value(parm, v)?,
+            "crossover_rate" => p.crossover_rate = value(parm, v)?,
+            "mutation_rate" => p.mutation_rate = value(parm, v)?,
+            "reinsertion_ratio" => p.reinsertion_ratio = value(parm, v)?,
+            "n_elites" => p.n_elites = value(parm, v)?,
+            "eliminate_duplicates" => p.eliminate_duplicates = value(parm, v)?,
+            "immigrant_rate" => p.immigrant_rate = value(parm, v)?,
+            "stagnation_limit" => p.stagnation_limit = value(parm, v)?,
+            "mutation_boost" => p.mutation_boost = value(parm, v)?,
+            "self_adaptive" => p.self_adaptive = value(parm, v)?,
+            "islands" => p.islands = value(parm, v)?,
+            "local_search_steps" => p.local_search_steps = value(parm, v)?,
+            "seed_file" => p.seed_file = v.to_string(),
+            "init_block_size" => p.init_block_size = value(parm, v)?,
+            "init_min_distance" => p.init_min_distance = value(parm, v)?,
+            "mutation_schedule" => p.mutation_schedule = Some(parse_schedule(v)?),
+            "selection_schedule" => p.selection_schedule = Some(parse_schedule(v)?),
+            "population_schedule" => p.population_schedule = Some(parse_schedule(v)?),
+            "restart_after" => p.restart_after = value(parm, v)?,
+            "crossovers" => p.crossovers = parse_ensemble(v, &CROSSOVER_KINDS)?,
+            "mutations" => p.mutations = parse_ensemble(v, &MUTATION_KINDS)?,
+            "adaptive_operators" => p.adaptive_operators = value(parm, v)?,
+            "init_weights" => {
+                p.init_weights = v
+                    .split(':')
+                    .map(|w| value(parm, w))
+                    .collect::<Result<_, _>>()?
+            }
+            "topology" => {
+                p.topology = [Topology::Ring, Topology::FullyConnected]
+                    .into_iter()
+                    .find(|t| format!("{:?}", t) == v)
+                    .ok_or_else(|| format!("unknown topology {}", v))?
+            }
+            "pipeline" => {
+                p.pipeline = [
+                    Pipeline::CrossoverAndMutation,
+                    Pipeline::MutationOnly,
+                    Pipeline::CrossoverOnly,
+                ]
+                .into_iter()
+                .find(|x| format!("{:?}", x) == v)
+                .ok_or_else(|| format!("unknown pipeline {}", v))?
+            }
+            "solver" => {
+                p.solver = SOLVERS
+                    .into_iter()
+                    .find(|s| format!("{:?}", s) == v)
+                    .ok_or_else(|| format!("unknown solver {}", v))?
+            }
+            _ => return Err(format!("unknown parm {}", parm)),
+        }
+        Ok(())
+    }
+
+    fn new(var: &Variation) -> Vec<Self> {
+        let mut parms_list: Vec<Parameters> = vec![];
+
+        match var {
+            Variation::Default => {
+                parms_list.push(Parameters::default());
+            }
+            Variation::NumIdiv(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "num_indiv_per_parent = {}{}",
+                        *x,
+                        if *x == p.num_individuals_per_parents {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.num_individuals_per_parents = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Selection(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "selection_ratio = {}{}",
+                        *x,
+                        if *x == p.selection_ratio {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.selection_ratio = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Crossover(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "crossover_rate = {}{}",
+                        *x,
+                        if *x == p.crossover_rate {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.crossover_rate = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Mutation(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "mutation_rate = {}{}",
+                        *x,
+                        if *x == p.mutation_rate {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.mutation_rate = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Reinsertion(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "reinsertion_ratio = {}{}",
+                        *x,
+                        if *x == p.reinsertion_ratio {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.reinsertion_ratio = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::BestOfEach {
+                num_individuals_per_parents,
+                selection_ratio,
+                mutation_rate,
+                reinsertion_ratio,
+            } => {
+                parms_list.push(Parameters {
+                    parms_name: "best of each varied parm".to_string(),
+                    num_individuals_per_parents: *num_individuals_per_parents,
+                    selection_ratio: *selection_ratio,
+                    mutation_rate: *mutation_rate,
+                    reinsertion_ratio: *reinsertion_ratio,
+                    ..Parameters::default()
+                });
+            }
+            Variation::Stagnation(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "stagnation_limit = {}{}",
+                        *x,
+                        if *x == p.stagnation_limit {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.stagnation_limit = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::SelfAdaptation(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "fixed mutation_rate = {}{}",
+                        *x,
+                        if *x == p.mutation_rate {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.mutation_rate = *x;
+                    parms_list.push(p);
+                }
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!("self-adaptive from mutation_rate = {}", *x),
+                        mutation_rate: *x,
+                        self_adaptive: true,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::Islands(v) => {
+                for x in v {
+                    if *x == 1 {
+                        parms_list.push(Parameters {
+                            parms_name: "single population (default)".to_string(),
+                            ..Parameters::default()
+                        });
+                        continue;
+                    }
+                    for topology in [Topology::Ring, Topology::FullyConnected] {
+                        parms_list.push(Parameters {
+                            parms_name: format!("islands = {} ({:?})", *x, topology),
+                            islands: *x,
+                            topology,
+                            ..Parameters::default()
+                        });
+                    }
+                }
+            }
+            Variation::LocalSearch(v) => {
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: if *x == 0 {
+                            "plain GA (default)".to_string()
+                        } else {
+                            format!("memetic, local_search_steps = {}", *x)
+                        },
+                        local_search_steps: *x,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::Solvers(v) => {
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!(
+                            "{}{}",
+                            x.name(),
+                            if *x == Solver::GeneticAlgorithm {
+                                " (default)"
+                            } else {
+                                ""
+                            }
+                        ),
+                        solver: *x,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::PopulationSize(v) => {
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!(
+                            "population_size = {}{}",
+                            *x,
+                            if *x == POPULATION_SIZE {
+                                " (default)"
+                            } else {
+                                ""
+                            }
+                        ),
+                        population_size: *x,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::Elites(v) => {
+                // The default reinsertion keeps the best half of the old population anyway, so the
+                // elites are compared when the offspring replaces the whole population
+                parms_list.push(Parameters {
+                    parms_name: "n_elites = 0 (default)".to_string(),
+                    ..Parameters::default()
+                });
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!("n_elites = {}, reinsertion_ratio = 1", *x),
+                        n_elites: *x,
+                        reinsertion_ratio: 1.0,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::DuplicateElimination(v) => {
+                // The lower the mutation rate, the more duplicate children are bred
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "mutation_rate = {}{}",
+                        *x,
+                        if *x == p.mutation_rate {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.mutation_rate = *x;
+                    parms_list.push(p);
+                }
+                for x in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!("mutation_rate = {}, duplicates eliminated", *x),
+                        mutation_rate: *x,
+                        eliminate_duplicates: true,
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::ImmigrantRate(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "immigrant_rate = {}{}",
+                        *x,
+                        if *x == p.immigrant_rate {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.immigrant_rate = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Initialization(v) => {
+                for (block_size, weights) in v {
+                    let mut p = Parameters::default();
+                    let mut names = vec![];
+                    if *block_size != p.init_block_size {
+                        names.push(format!("init_block_size = {}", block_size));
+                    }
+                    if !weights.is_empty() {
+                        let weights = weights.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+                        names.push(format!("init_weights = {}", weights.join(":")));
+                    }
+                    p.parms_name = if names.is_empty() {
+                        "uniform (default)".to_string()
+                    } else {
+                        names.join(", ")
+                    };
+                    p.init_block_size = *block_size;
+                    p.init_weights = weights.clone();
+                    parms_list.push(p);
+                }
+            }
+            Variation::InitMinDistance(v) => {
+                // A small population, a large one is spread out by chance
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "init_min_distance = {}{}",
+                        *x,
+                        if *x == p.init_min_distance {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.population_size = SMALL_POPULATION_SIZE;
+                    p.init_min_distance = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::MutationSchedule(v) => {
+                let mut p = Parameters::default();
+                p.parms_name = format!("mutation_rate = {} (default)", p.mutation_rate);
+                parms_list.push(p);
+                for spec in v {
+                    parms_list.push(Parameters {
+                        parms_name: format!("mutation_schedule = {}", spec),
+                        mutation_schedule: Some(parse_schedule(spec).unwrap()),
+                        ..Parameters::default()
+                    });
+                }
+            }
+            Variation::Restart(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "restart_after = {}{}",
+                        *x,
+                        if *x == p.restart_after {
+                            " (default)"
+                        } else {
+                            ""
+                        }
+                    );
+                    p.restart_after = *x;
+                    parms_list.push(p);
+                }
+            }
+            Variation::Ensembles(v) => {
+                parms_list.push(Parameters::default());
+                for spec in v {
+                    parms_list.push(Parameters::parse(spec).unwrap());
+                }
+            }
+            Variation::Pipelines(v) => {
+                for x in v {
+                    let mut p = Parameters::default();
+                    p.parms_name = format!(
+                        "{}{}",
+                        x.name(),
+                        if *x == p.pipeline { " (default)" } else { "" }
+                    );
+                    p.pipeline = *x;
+                    parms_list.push(p);
+                }
+            }
+        }
+        parms_list
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            parms_name: "default".to_string(),
+            population_size: POPULATION_SIZE,
+            num_individuals_per_parents: 2,
+            selection_ratio: 0.5,
+            crossover_rate: 1.0,
+            mutation_rate: 0.05,
+            reinsertion_ratio: 0.5,
+            n_elites: 0,
+            eliminate_duplicates: false,
+            immigrant_rate: 0.0,
+            stagnation_limit: 0,
+            mutation_boost: 4.0,
+            self_adaptive: false,
+            islands: 1,
+            topology: Topology::Ring,
+            local_search_steps: 0,
+            seed_file: String::new(),
+            init_block_size: 1,
+            init_weights: vec![],
+            init_min_distance: 0,
+            mutation_schedule: None,
+            selection_schedule: None,
+            population_schedule: None,
+            restart_after: 0,
+            pipeline: Pipeline::CrossoverAndMutation,
+            crossovers: vec![],
+            mutations: vec![],
+            adaptive_operators: false,
+            solver: Solver::GeneticAlgorithm,
+        }
+    }
+}
+
+// Expands the seed of a run to the seed of genevo's random number generator
+fn prng_seed(seed: u64) -> Seed {
+    let mut prng_seed = Seed::default();
+    prng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+    prng_seed
+}
+
+// Runs a simulation of a problem based on a set of give parameters. The seed determines the run,
+// so `--replay <seed> <parms>` can rerun it (up to the run time limit). The GA stops early once
+// the batch abandons the run (past RUN_TIMEOUT), the islands and the baselines run to the end.
+fn run_sim_from_parms<P: Problem>(
+    problem: &P,
+    parms: &Parameters,
+    thread_number: Option<u64>,
+    seed: u64,
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> Option<DataSetWithLables> {
+    match thread_number {
+        Some(n) => report!(
+            "\t[thread #{}]: Starting a simulation with {} parms (seed {}).",
+            n,
+            parms.parms_name,
+            seed
+        ),
+        None => report!(
+            "Starting a simulation with {} parameters (seed {}).",
+            parms.parms_name,
+            seed
+        ),
+    }
+
+    // Self-adaptive individuals carry (and evolve) their own mutation rate,
+    // starting from `mutation_rate`. The stagnation boost does not apply to them.
+    if parms.self_adaptive {
+        let seeds = seed_strands(problem, parms)
+            .into_iter()
+            .map(|strand| SelfAdaptive::new(strand, parms.mutation_rate))
+            .collect();
+        let initial_population: Population<SelfAdaptive<Strand<P>>> = build_initial_population(
+            SeededGenomeBuilder::new(
+                seeds,
+                SelfAdaptiveStrandBuilder(problem.clone(), parms.mutation_rate),
+            ),
+            parms.population_size,
+            parms,
+            seed,
+        );
+        let (min_allele, max_allele) = problem.allele_bounds();
+        let mut reinserter = ImmigrantReinserter::new(
+            ElitistReinserter::new(
+                SelfAdaptiveFitnessCalculator(problem.fitness_function()),
+                true,
+                parms.reinsertion_ratio,
+            ),
+            SelfAdaptiveStrandBuilder(problem.clone(), parms.mutation_rate),
+        );
+        reinserter.set_active(parms.eliminate_duplicates);
+        let reinserter = ImmigrationReinserter::new(
+            reinserter,
+            SelfAdaptiveStrandBuilder(problem.clone(), parms.mutation_rate),
+            SelfAdaptiveFitnessCalculator(problem.fitness_function()),
+            parms.immigrant_rate,
+        );
+
+        let alg = genetic_algorithm()
+            .with_evaluation(SelfAdaptiveFitnessCalculator(problem.fitness_function()))
+            .with_selection(MaximizeSelector::new(
+                parms.selection_ratio,
+                parms.num_individuals_per_parents,
+            ))
+            .with_crossover(ProbabilisticCrossover::new(
+                SelfAdaptiveCrossBreeder::new(SinglePointCrossBreeder::new()),
+                parms.crossover_rate,
+            ))
+            .with_mutation(SelfAdaptiveMutator::new(
+                RandomValueMutator::new(parms.mutation_rate, min_allele, max_allele),
+                1.0 / (problem.strand_size() as f64).sqrt(),
+                MIN_MUTATION_RATE,
+                MAX_MUTATION_RATE,
+            ))
+            .with_reinsertion(ElitismReinserter::new(reinserter, parms.n_elites))
+            .with_initial_population(initial_population)
+            .with_parallel_evaluation(PARALLEL_EVALUATION)
+            .with_operator_statistics(true)
+            .build();
+
+        let sim = simulate(alg)
+            .until(or(
+                or(
+                    FitnessLimit::new(problem.fitness_bounds().1),
+                    GenerationLimit::new(GENERATION_LIMIT),
+                ),
+                TimeLimit::from_std(RUN_TIME_LIMIT),
+            ))
+            .build_with_seed(prng_seed(seed));
+
+        let render = |genome: &SelfAdaptive<Strand<P>>| {
+            format!(
+                "{} (mutation_rate = {:.4})",
+                problem.render(&genome.genome),
+                genome.mutation_rate
+            )
+        };
+        return run_sim_to_end(
+            sim,
+            problem,
+            parms,
+            (thread_number, seed),
+            &render,
+            batch,
+            abandoned,
+        );
+    }
+
+    // The islands and the baselines do not keep a hall of fame
+    if parms.islands > 1 {
+        return run_island_sim(problem, parms, thread_number, seed, batch, abandoned);
+    }
+
+    if matches!(parms.solver, Solver::OnePlusOneEa | Solver::MuPlusLambdaEs) {
+        return run_es_sim(problem, parms, (thread_number, seed), batch, abandoned);
+    }
+
+    if parms.solver != Solver::GeneticAlgorithm {
+        return run_baseline(problem, parms, thread_number, seed, batch, abandoned);
+    }
+
+    let (result, fitness_cache) = match parms.pipeline {
+        Pipeline::CrossoverAndMutation if parms.has_ensembles() => run_strand_sim(
+            build_sim_with_operators(
+                problem,
+                parms,
+                parms.population_size,
+                seed,
+                NoLineage,
+                ensemble_crossover(parms),
+                ensemble_mutator(problem, parms),
+            ),
+            problem,
+            parms,
+            (thread_number, seed),
+            batch,
+            abandoned,
+        ),
+        Pipeline::CrossoverAndMutation => run_strand_sim(
+            build_sim(problem, parms, parms.population_size, seed, NoLineage),
+            problem,
+            parms,
+            (thread_number, seed),
+            batch,
+            abandoned,
+        ),
+        Pipeline::MutationOnly => run_strand_sim(
+            build_sim_with_operators(
+                problem,
+                parms,
+                parms.population_size,
+                seed,
+                NoLineage,
+                NoOpCrossover,
+                strand_mutator(problem, parms),
+            ),
+            problem,
+            parms,
+            (thread_number, seed),
+            batch,
+            abandoned,
+        ),
+        Pipeline::CrossoverOnly => run_strand_sim(
+            build_sim_with_operators(
+                problem,
+                parms,
+                parms.population_size,
+                seed,
+                NoLineage,
+                strand_crossover(parms),
+                NoOpMutator,
+            ),
+            problem,
+            parms,
+            (thread_number, seed),
+            batch,
+            abandoned,
+        ),
+    };
+
+    if FITNESS_CACHE_SIZE > 0 {
+        report!(
+            "{}Fitness cache of the run with {} parms: {}.",
+            thread_number.map_or("".to_string(), |n| format!("\t[thread #{}]: ", n)),
+            parms.parms_name,
+            fitness_cache.stats()
+        );
+    }
+    result
+}
+
+// Runs a built simulation of strands to its end, returns its data and its fitness cache
+fn run_strand_sim<P, C, M>(
+    sim: StrandSimulator<P, NoLineage, C, M>,
+    problem: &P,
+    parms: &Parameters,
+    (thread_number, seed): (Option<u64>, u64),
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> (Option<DataSetWithLables>, StrandFitness<P>)
+where
+    P: Problem,
+    C: CrossoverOp<Strand<P>> + Sync + Debug + PartialEq,
+    M: MutationOp<Strand<P>> + Sync + Debug + PartialEq,
+{
+    let fitness_cache = sim.algorithm().evaluator().clone();
+    let render = |genome: &Strand<P>| problem.render(genome);
+    let result = run_sim_to_end(
+        sim,
+        problem,
+        parms,
+        (thread_number, seed),
+        &render,
+        batch,
+        abandoned,
+    );
+    (result, fitness_cache)
+}
+
+// Runs one of the evolution strategies like the GA. Each generation evaluates as many strands as a
+// generation of the GA does: the (μ+λ)-ES has as many offspring as the population has strands,
+// the (1+1)-EA runs a step for each of them.
+fn run_es_sim<P: Problem>(
+    problem: &P,
+    parms: &Parameters,
+    (thread_number, seed): (Option<u64>, u64),
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> Option<DataSetWithLables> {
+    let (mu, lambda, mutation_rate, steps) = match parms.solver {
+        Solver::OnePlusOneEa => (
+            1,
+            1,
+            1.0 / problem.strand_size() as f64,
+            parms.population_size as u64,
+        ),
+        _ => (
+            (parms.population_size / ES_OFFSPRING_PER_PARENT).max(1),
+            parms.population_size,
+            parms.mutation_rate,
+            1,
+        ),
+    };
+    let initial_population = build_initial_population(
+        SeededGenomeBuilder::new(
+            seed_strands(problem, parms),
+            RandomStrandBuilder(problem.clone()),
+        ),
+        mu,
+        parms,
+        seed,
+    );
+    let alg = EvolutionStrategy::new(
+        problem.fitness_function(),
+        StandardBitMutation(problem.clone(), mutation_rate),
+        mu,
+        lambda,
+        initial_population,
+    )
+    .with_generations_per_iteration(steps);
+
+    let sim = simulate(alg)
+        .until(or(
+            or(
+                FitnessLimit::new(problem.fitness_bounds().1),
+                GenerationLimit::new(GENERATION_LIMIT),
+            ),
+            TimeLimit::from_std(RUN_TIME_LIMIT),
+        ))
+        .build_with_seed(prng_seed(seed));
+
+    let render = |genome: &Strand<P>| problem.render(genome);
+    run_sim_to_end(
+        sim,
+        problem,
+        parms,
+        (thread_number, seed),
+        &render,
+        batch,
+        abandoned,
+    )
+}
+
+// Runs the island model, each island evolving an even share of the population in its own thread.
+// An abandoned run stops at the end of the current migration interval and records nothing.
+fn run_island_sim<P: Problem>(
+    problem: &P,
+    parms: &Parameters,
+    thread_number: Option<u64>,
+    seed: u64,
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> Option<DataSetWithLables> {
+    let model = IslandModel::new(
+        parms.islands,
+        MIGRATION_INTERVAL,
+        NUM_MIGRANTS,
+        parms.topology,
+    );
+    let reporter = OutcomeReporter {
+        parms_name: &parms.parms_name,
+        thread_number,
+        optimum: problem.fitness_bounds().1,
+    };
+
+    let island_size = parms.population_size / parms.islands;
+    let start_time = Instant::now();
+    let result = model.run_until_stopped(
+        |island| {
+            build_sim(
+                problem,
+                parms,
+                island_size,
+                seed.wrapping_add(island as u64),
+                NoLineage,
+            )
+        },
+        abandoned,
+    );
+    if abandoned.load(Ordering::Relaxed) {
+        return None;
+    }
+    match result {
+        Ok(result) => {
+            report!(
+                "{}Optimal solution was {}found after {} generations with {} parms.",
+                reporter.prefix(),
+                if result.best_solution.solution.fitness == reporter.optimum {
+                    ""
+                } else {
+                    "not "
+                },
+                result.generations,
+                parms.parms_name
+            );
+            let data = result.curve.iter().map(|f| *f as u32).collect::<Data>();
+            // The islands only tell how long they took together, each generation takes its share
+            let secs = start_time.elapsed().as_secs_f64();
+            let timestamps = (1..=data.len())
+                .map(|gen| secs * gen as f64 / data.len() as f64)
+                .collect();
+            batch
+                .lock()
+                .unwrap()
+                .record_timestamps(thread_number, timestamps);
+            Some(vec![(parms.parms_name.clone(), data)])
+        }
+        Err(error) => {
+            report!("{}{}", reporter.prefix(), error);
+            None
+        }
+    }
+}
+
+// Runs one of the single strand baselines for the GA. Each generation makes as many moves as the
+// population has strands, so it evaluates as many strands as a generation of the GA does. An
+// abandoned run stops after its current generation and records nothing.
+fn run_baseline<P: Problem>(
+    problem: &P,
+    parms: &Parameters,
+    thread_number: Option<u64>,
+    seed: u64,
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> Option<DataSetWithLables> {
+    let mut rng = get_rng(prng_seed(seed));
+    let fitness = problem.fitness_function();
+    let optimum = problem.fitness_bounds().1;
+    let builder = RandomStrandBuilder(problem.clone());
+    let neighbour_op = SingleLocusFlip(problem.clone());
+    let start_time = Instant::now();
+
+    let mut current = builder.build_genome(0, &mut rng);
+    let mut current_fitness = fitness.fitness_of(&current);
+    let mut best = (current.clone(), current_fitness);
+    let mut temperature = SA_INITIAL_TEMPERATURE;
+
+    // Stores the best fitness value and the end of each generation of the baseline
+    let mut data = vec![];
+    let mut timestamps = vec![];
+
+    while best.1 < optimum
+        && (data.len() as u64) < GENERATION_LIMIT
+        && start_time.elapsed() < RUN_TIME_LIMIT
+    {
+        if abandoned.load(Ordering::Relaxed) {
+            return None;
+        }
+        for _ in 0..parms.population_size {
+            let candidate = match parms.solver {
+                Solver::RandomSearch => builder.build_genome(0, &mut rng),
+                _ => neighbour_op.mutate(current.clone(), &mut rng),
+            };
+            let candidate_fitness = fitness.fitness_of(&candidate);
+
+            let accepted = match parms.solver {
+                // Always accept a move that is not worse, a worse one with the Boltzmann probability
+                Solver::SimulatedAnnealing => {
+                    let delta = candidate_fitness as f64 - current_fitness as f64;
+                    delta >= 0.0 || rng.gen::<f64>() < (delta / temperature).exp()
+                }
+                Solver::HillClimbing => candidate_fitness > current_fitness,
+                _ => true,
+            };
+            if accepted {
+                current = candidate;
+                current_fitness = candidate_fitness;
+                if current_fitness > best.1 {
+                    best = (current.clone(), current_fitness);
+                }
+            }
+        }
+        data.push(best.1 as u32);
+        timestamps.push(start_time.elapsed().as_secs_f64());
+        temperature *= SA_COOLING_RATE;
+    }
+
+    let reporter = OutcomeReporter {
+        parms_name: &parms.parms_name,
+        thread_number,
+        optimum,
+    };
+    report!(
+        "{}Optimal solution was {}found after {} generationns with {} parms.",
+        reporter.prefix(),
+        if best.1 == optimum { "" } else { "not " },
+        data.len(),
+        parms.parms_name
+    );
+    if OUT_WINNERS.1 {
+        write_winner(
+            &parms.parms_name,
+            thread_number,
+            best.1,
+            data.len() as u64,
+            &problem.render(&best.0),
+        );
+    }
+    batch
+        .lock()
+        .unwrap()
+        .record_timestamps(thread_number, timestamps);
+
+    Some(vec![(parms.parms_name.clone(), data)])
+}
+
+// The strands of the seed file of the parameters, which the initial population starts with and
+// the random strands fill up
+fn seed_strands<P: Problem>(problem: &P, parms: &Parameters) -> Vec<Strand<P>> {
+    if parms.seed_file.is_empty() {
+        return Vec::new();
+    }
+    read_seed_strands(problem, &parms.seed_file)
+        .unwrap_or_else(|error| panic!("Problem reading the seed strands: {}", error))
+}
+
+// The builder of the random strands of the initial population, biased by the init_ parameters.
+// The self-adaptive GA and the baselines start from uniformly drawn strands.
+fn initial_strand_builder<P: Problem>(
+    problem: &P,
+    parms: &Parameters,
+) -> Result<BiasedStrandBuilder<P>, String> {
+    BiasedStrandBuilder::new(problem.clone(), parms.init_block_size, &parms.init_weights)
+}
+
+// Builds an initial population from a seed, its strands kept init_min_distance apart
+fn build_initial_population<G, B>(
+    builder: B,
+    population_size: usize,
+    parms: &Parameters,
+    seed: u64,
+) -> Population<G>
+where
+    G: Genotype + HammingDistance,
+    B: GenomeBuilder<G>,
+{
+    let population = build_population()
+        .with_genome_builder(builder)
+        .of_size(population_size);
+    if parms.init_min_distance > 0 {
+        population
+            .with_min_distance(parms.init_min_distance)
+            .using_seed(prng_seed(seed))
+    } else {
+        population.using_seed(prng_seed(seed))
+    }
+}
+
+// The schedule of a parameter, or the fixed value of the parameter in each generation
+fn schedule_or_fixed(schedule: &Option<Schedule>, fixed: f64) -> Schedule {
+    schedule.clone().unwrap_or(Schedule::Constant(fixed))
+}
+
+type StrandFitness<P> = CachedFitness<Strand<P>, usize, <P as Problem>::Fitness>;
+
+// The crossover of the strands, applied to the parents at the crossover rate
+type StrandCrossover = ProbabilisticCrossover<SinglePointCrossBreeder>;
+
+// The mutation of the strands, boosted on stagnation and following the mutation schedule
+type StrandMutator<P> = ScheduledMutator<StagnationBoostMutator<RandomValueMutator<Strand<P>>>>;
+
+type StrandAlgorithm<P, H = NoLineage, C = StrandCrossover, M = StrandMutator<P>> =
+    GeneticAlgorithm<
+        Strand<P>,
+        usize,
+        StrandFitness<P>,
+        ScheduledSelector<MaximizeSelector>,
+        C,
+        M,
+        ScheduledSizeReinserter<
+            RestartReinserter<
+                ElitismReinserter<
+                    ImmigrationReinserter<
+                        ImmigrantReinserter<
+                            ElitistReinserter<Strand<P>, usize, StrandFitness<P>>,
+                            RandomStrandBuilder<P>,
+                        >,
+                        RandomStrandBuilder<P>,
+                        StrandFitness<P>,
+                    >,
+                >,
+                RandomStrandBuilder<P>,
+            >,
+            RandomStrandBuilder<P>,
+            StrandFitness<P>,
+        >,
+        HillClimber<SingleLocusFlip<P>>,
+        H,
+    >;
+
+type StrandTermination<P, H = NoLineage, C = StrandCrossover, M = StrandMutator<P>> = Or<
+    Or<FitnessLimit<Strand<P>, usize>, GenerationLimit, StrandAlgorithm<P, H, C, M>>,
+    TimeLimit,
+    StrandAlgorithm<P, H, C, M>,
+>;
+
+type StrandSimulator<P, H = NoLineage, C = StrandCrossover, M = StrandMutator<P>> =
+    Simulator<StrandAlgorithm<P, H, C, M>, StrandTermination<P, H, C, M>>;
+
+fn strand_crossover(parms: &Parameters) -> StrandCrossover {
+    ProbabilisticCrossover::new(SinglePointCrossBreeder::new(), parms.crossover_rate)
+}
+
+fn strand_mutator<P: Problem>(problem: &P, parms: &Parameters) -> StrandMutator<P> {
+    let (min_allele, max_allele) = problem.allele_bounds();
+    ScheduledMutator::new(
+        StagnationBoostMutator::new(
+            RandomValueMutator::new(parms.mutation_rate, min_allele, max_allele),
+            parms.stagnation_limit,
+            parms.mutation_boost,
+            BOOST_DURATION,
+        ),
+        schedule_or_fixed(&parms.mutation_schedule, parms.mutation_rate),
+    )
+}
+
+type StrandEnsembleCrossover = ProbabilisticCrossover<
+    EnsembleCrossover<(
+        SinglePointCrossBreeder,
+        MultiPointCrossBreeder,
+        UniformCrossBreeder,
+    )>,
+>;
+
+type StrandEnsembleMutator<P> =
+    EnsembleMutator<(StrandMutator<P>, SwapOrderMutator, InsertOrderMutator)>;
+
+// The weights of the operators of the given kinds in the ensemble, just the default operator if it
+// is empty
+fn operator_weights<K: PartialEq>(
+    kinds: &[K],
+    ensemble: &[(K, f64)],
+    default: K,
+    adaptive: bool,
+) -> OperatorWeights {
+    let weights = OperatorWeights::new(
+        kinds
+            .iter()
+            .map(|kind| match ensemble {
+                [] => f64::from(*kind == default),
+                _ => ensemble
+                    .iter()
+                    .filter(|(k, _)| k == kind)
+                    .map(|(_, weight)| weight)
+                    .sum(),
+            })
+            .collect(),
+    );
+    if adaptive {
+        weights.with_adaptation(WeightAdaptation {
+            learning_rate: OPERATOR_LEARNING_RATE,
+            min_probability: MIN_OPERATOR_PROBABILITY,
+        })
+    } else {
+        weights
+    }
+}
+
+fn ensemble_crossover(parms: &Parameters) -> StrandEnsembleCrossover {
+    ProbabilisticCrossover::new(
+        EnsembleCrossover::new(
+            (
+                SinglePointCrossBreeder::new(),
+                MultiPointCrossBreeder::new(2),
+                UniformCrossBreeder::new(),
+            ),
+            operator_weights(
+                &CROSSOVER_KINDS,
+                &parms.crossovers,
+                CrossoverKind::SinglePoint,
+                parms.adaptive_operators,
+            ),
+        ),
+        parms.crossover_rate,
+    )
+}
+
+fn ensemble_mutator<P: Problem>(problem: &P, parms: &Parameters) -> StrandEnsembleMutator<P> {
+    EnsembleMutator::new(
+        (
+            strand_mutator(problem, parms),
+            SwapOrderMutator::new(parms.mutation_rate),
+            InsertOrderMutator::new(parms.mutation_rate),
+        ),
+        operator_weights(
+            &MUTATION_KINDS,
+            &parms.mutations,
+            MutationKind::RandomValue,
+            parms.adaptive_operators,
+        ),
+    )
+}
+
+// Builds a simulation of (non self-adaptive) strands of a problem from a set of given parameters,
+// the lineage hook is told about the birth of each strand
+fn build_sim<P: Problem, H: LineageHook<Strand<P>>>(
+    problem: &P,
+    parms: &Parameters,
+    population_size: usize,
+    seed: u64,
+    lineage: H,
+) -> StrandSimulator<P, H> {
+    build_sim_with_operators(
+        problem,
+        parms,
+        population_size,
+        seed,
+        lineage,
+        strand_crossover(parms),
+        strand_mutator(problem, parms),
+    )
+}
+
+// Like `build_sim`, breeding the strands by the given crossover and mutation
+fn build_sim_with_operators<P, H, C, M>(
+    problem: &P,
+    parms: &Parameters,
+    population_size: usize,
+    seed: u64,
+    lineage: H,
+    crossover: C,
+    mutation: M,
+) -> StrandSimulator<P, H, C, M>
+where
+    P: Problem,
+    H: LineageHook<Strand<P>>,
+    C: CrossoverOp<Strand<P>> + Sync + Debug + PartialEq,
+    M: MutationOp<Strand<P>> + Sync + Debug + PartialEq,
+{
+    let initial_population: Population<Strand<P>> = build_initial_population(
+        SeededGenomeBuilder::new(
+            seed_strands(problem, parms),
+            initial_strand_builder(problem, parms)
+                .unwrap_or_else(|error| panic!("Problem building the initial strands: {}", error)),
+        ),
+        population_size,
+        parms,
+        seed,
+    );
+    // Shared by the evaluation and the reinsertion
+    let fitness = CachedFitness::new(problem.fitness_function(), FITNESS_CACHE_SIZE);
+    let mut reinserter = ImmigrantReinserter::new(
+        ElitistReinserter::new(fitness.clone(), true, parms.reinsertion_ratio),
+        RandomStrandBuilder(problem.clone()),
+    );
+    reinserter.set_active(parms.eliminate_duplicates);
+    let reinserter = ImmigrationReinserter::new(
+        reinserter,
+        RandomStrandBuilder(problem.clone()),
+        fitness.clone(),
+        parms.immigrant_rate,
+    );
+
+    let alg = genetic_algorithm()
+        .with_evaluation(fitness.clone())
+        .with_selection(ScheduledSelector::new(
+            MaximizeSelector::new(parms.selection_ratio, parms.num_individuals_per_parents),
+            schedule_or_fixed(&parms.selection_schedule, parms.selection_ratio),
+        ))
+        .with_crossover(crossover)
+        .with_mutation(mutation)
+        .with_reinsertion(ScheduledSizeReinserter::new(
+            RestartReinserter::new(
+                ElitismReinserter::new(reinserter, parms.n_elites),
+                RandomStrandBuilder(problem.clone()),
+                parms.restart_after,
+                RESTART_ELITES,
+            ),
+            RandomStrandBuilder(problem.clone()),
+            fitness.clone(),
+            schedule_or_fixed(&parms.population_schedule, population_size as f64),
+        ))
+        .with_initial_population(initial_population)
+        .with_local_search(HillClimber::new(
+            SingleLocusFlip(problem.clone()),
+            parms.local_search_steps,
+        ))
+        .with_parallel_evaluation(PARALLEL_EVALUATION)
+        .with_operator_statistics(true)
+        .with_lineage(lineage)
+        .build();
+
+    simulate(alg)
+        .until(or(
+            or(
+                FitnessLimit::new(problem.fitness_bounds().1),
+                GenerationLimit::new(GENERATION_LIMIT),
+            ),
+            TimeLimit::from_std(RUN_TIME_LIMIT),
+        ))
+        .build_with_seed(prng_seed(seed))
+}
+
+// Records the best strand of each generation of a simulation
+struct BestGenomeRecorder<'a>(&'a mut Vec<Genome>);
+
+impl<'a, A, E> SimulationObserver<A, E> for BestGenomeRecorder<'a>
+where
+    A: Algorithm<Output = State<Genome, usize>>,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        self.0
+            .push(state.result.best_solution.solution.genome.clone());
+    }
+}
+
+// Records the dominant allele frequencies of the population of each generation of a simulation
+struct AlleleFrequencyRecorder<'a>(&'a mut Vec<AlleleFrequencies>);
+
+impl<'a, A, E> SimulationObserver<A, E> for AlleleFrequencyRecorder<'a>
+where
+    A: Algorithm<Output = State<Genome, usize>>,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        self.0.push(allele_frequencies(
+            &state.result.evaluated_population.individuals(),
+        ));
+    }
+}
+
+// Records the fitness values of the population of each generation of a simulation
+struct FitnessDistributionRecorder<'a>(&'a mut Vec<Vec<usize>>);
+
+impl<'a, A, G, E> SimulationObserver<A, E> for FitnessDistributionRecorder<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        self.0
+            .push(state.result.evaluated_population.fitness_values().to_vec());
+    }
+}
+
+// The records of each generation of a single simulation
+struct GenerationRecords {
+    best_genomes: Vec<Genome>,
+    frequencies: Vec<AlleleFrequencies>,
+    fitness_values: Vec<Vec<usize>>, // of the whole population
+}
+
+// Runs a single simulation, recording the best strand, the allele frequencies and the fitness values
+// of the population of each generation
+fn run_sim_recording_strands(
+    problem: &ClustersOf4,
+    parms: &Parameters,
+) -> Option<GenerationRecords> {
+    report!(
+        "Starting a simulation with {} parameters, recording its strands.",
+        parms.parms_name
+    );
+
+    let mut best_genomes = vec![];
+    let mut frequencies = vec![];
+    let mut fitness_values = vec![];
+    let result = observe(build_sim(
+        problem,
+        parms,
+        parms.population_size,
+        rand::random(),
+        NoLineage,
+    ))
+    .with_observer(BestGenomeRecorder(&mut best_genomes))
+    .with_observer(AlleleFrequencyRecorder(&mut frequencies))
+    .with_observer(FitnessDistributionRecorder(&mut fitness_values))
+    .run();
+    match result {
+        Ok(_) => Some(GenerationRecords {
+            best_genomes,
+            frequencies,
+            fitness_values,
+        }),
+        Err(error) => {
+            report!("{}", error);
+            None
+        }
+    }
+}
+
+// Records the best fitness value of each generation of a simulation
+struct BestFitnessRecorder<'a>(&'a mut Data);
+
+impl<'a, A, G, E> SimulationObserver<A, E> for BestFitnessRecorder<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        self.0
+            .push(state.result.best_solution.solution.fitness as u32);
+    }
+}
+
+// Records the seconds from the start of a simulation to the end of each of its generations
+struct TimestampRecorder<'a>(&'a mut Timestamps, Instant);
+
+impl<'a, A: Algorithm, E> SimulationObserver<A, E> for TimestampRecorder<'a> {
+    fn on_generation(&mut self, _: &SimState<A>) {
+        self.0.push(self.1.elapsed().as_secs_f64());
+    }
+}
+
+// Enters the individuals of each generation of a simulation into a hall of fame
+struct HallOfFameRecorder<'a, G>(&'a mut HallOfFame<G>);
+
+impl<'a, A, G, E> SimulationObserver<A, E> for HallOfFameRecorder<'a, G>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        let population = &state.result.evaluated_population;
+        for (genome, fitness) in population
+            .individuals()
+            .iter()
+            .zip(population.fitness_values())
+        {
+            self.0.insert(genome, *fitness);
+        }
+    }
+}
+
+// Records the generations of a simulation that start from a restarted population
+struct RestartRecorder<'a>(&'a mut Vec<u32>);
+
+impl<'a, A, G, E> SimulationObserver<A, E> for RestartRecorder<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        if state.result.restarted {
+            self.0.push(state.iteration as u32 + 1);
+        }
+    }
+}
+
+// Adds up the time spent in the operators of each generation of a simulation
+struct OperatorStatisticsRecorder<'a>(&'a mut OperatorStatistics);
+
+impl<'a, A, G, E> SimulationObserver<A, E> for OperatorStatisticsRecorder<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        if let Some(statistics) = state.result.operator_statistics {
+            *self.0 += statistics;
+        }
+    }
+}
+
+// Shows the best fitness of each generation of a run of a batch on the dashboard
+#[cfg(any(feature = "tui", feature = "serve"))]
+struct DashboardReporter<'a> {
+    parms_name: &'a str,
+    thread_number: u64,
+}
+
+#[cfg(any(feature = "tui", feature = "serve"))]
+impl<'a, A, G, E> SimulationObserver<A, E> for DashboardReporter<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        monitor::update_run(
+            self.thread_number,
+            self.parms_name,
+            state.result.best_solution.solution.fitness as u32,
+        );
+    }
+}
+
+// Publishes the best and the average fitness, the diversity and any new best strand of each
+// generation of a run of a batch to the subscribers of the events of the metrics server
+#[cfg(feature = "serve")]
+struct EventPublisher<'a, G> {
+    parms_name: &'a str,
+    thread_number: u64,
+    render: &'a dyn Fn(&G) -> Phenome,
+    best_fitness: Option<usize>, // so far
+}
+
+#[cfg(feature = "serve")]
+impl<'a, A, G, E> SimulationObserver<A, E> for EventPublisher<'a, G>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        let best = &state.result.best_solution.solution;
+        let is_new_best = self
+            .best_fitness
+            .is_none_or(|fitness| best.fitness > fitness);
+        self.best_fitness = Some(best.fitness);
+        if !server::has_subscribers() {
+            return;
+        }
+
+        let population = &state.result.evaluated_population;
+        let new_best = is_new_best.then(|| (self.render)(&best.genome));
+        server::publish_generation(
+            self.thread_number,
+            self.parms_name,
+            &server::GenerationEvent {
+                generation: state.iteration,
+                best_fitness: best.fitness,
+                average_fitness: *population.average_fitness(),
+                diversity: genotype_diversity(&population.individuals()),
+                new_best: new_best.as_deref(),
+            },
+        );
+    }
+}
+
+// Prints the best strand and a histogram of the fitness of the population of each generation
+struct GenerationReporter<'a, G> {
+    render: &'a dyn Fn(&G) -> Phenome,
+}
+
+impl<'a, A, G, E> SimulationObserver<A, E> for GenerationReporter<'a, G>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_generation(&mut self, state: &SimState<A>) {
+        let best = &state.result.best_solution.solution;
+        println!(
+            "Generation {}: best fitness {}: {}",
+            state.iteration,
+            best.fitness,
+            (self.render)(&best.genome)
+        );
+
+        let fitness_values = state.result.evaluated_population.fitness_values();
+        let mut histogram = BTreeMap::<usize, usize>::new();
+        for fitness in fitness_values {
+            *histogram.entry(*fitness).or_default() += 1;
+        }
+        for (fitness, count) in histogram.iter().rev() {
+            println!(
+                "\t{:>4} | {:>4} {}",
+                fitness,
+                count,
+                "#".repeat((count * 64).div_ceil(fitness_values.len()))
+            );
+        }
+        if state.result.restarted {
+            println!(
+                "Restarting the population around its {} best strands.",
+                RESTART_ELITES
+            );
+        }
+    }
+}
+
+// Writes a line of JSON for each strand that is better than any strand before it: the operator
+// that made it the best, the fitness of its parents and its fitness after each step of breeding
+#[derive(Debug)]
+struct BestStrandTracer<P: Problem> {
+    problem: P,
+    // The highest fitness so far and the trace file
+    best: Mutex<(usize, File)>,
+}
+
+impl<P: Problem> LineageHook<Strand<P>> for BestStrandTracer<P> {
+    fn on_birth(&self, birth: &Birth<Strand<P>>) {
+        let fitness = self.problem.fitness_function();
+        let fitness_of = |strand: &Strand<P>| fitness.fitness_of(strand);
+        let (crossed, mutated, child) = (
+            fitness_of(birth.crossed),
+            fitness_of(birth.mutated),
+            fitness_of(birth.child),
+        );
+
+        let mut best = self.best.lock().unwrap();
+        if child <= best.0 {
+            return;
+        }
+        // The first step of breeding that went past the best strand so far
+        let operator = if crossed > best.0 {
+            "crossover"
+        } else if mutated > best.0 {
+            "mutation"
+        } else {
+            // The repair and the local search both come after the mutation
+            "repair_or_local_search"
+        };
+        let parents = birth.parents.iter().map(fitness_of).collect::<Vec<_>>();
+        let line = format!(
+            "{{\"generation\":{},\"operator\":\"{}\",\"fitness\":{},\"delta\":{},\
+            \"parent_fitnesses\":{:?},\"crossover_fitness\":{},\"mutation_fitness\":{},\
+            \"strand\":{}}}",
+            birth.generation,
+            operator,
+            child,
+            child - best.0,
+            parents,
+            crossed,
+            mutated,
+            json_string(&self.problem.render(birth.child)),
+        );
+        if let Err(error) = writeln!(best.1, "{}", line) {
+            println!("Problem writing the trace: {:?}", error);
+        }
+        best.0 = child;
+    }
+}
+
+// Prints the outcome of a simulation to std out
+struct OutcomeReporter<'a> {
+    parms_name: &'a str,
+    thread_number: Option<u64>,
+    optimum: usize,
+}
+
+impl<'a> OutcomeReporter<'a> {
+    fn prefix(&self) -> String {
+        match self.thread_number {
+            Some(n) => format!("\t[thread #{}]: ", n),
+            None => "".to_string(),
+        }
+    }
+}
+
+impl<'a, A, G, E> SimulationObserver<A, E> for OutcomeReporter<'a>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+    E: Display,
+{
+    fn on_termination(&mut self, state: &SimState<A>, _stop_reason: &StopReason) {
+        let best_fitness = state.result.best_solution.solution.fitness;
+        report!(
+            "{}Optimal solution was {}found after {} generationns with {} parms.",
+            self.prefix(),
+            if best_fitness == self.optimum {
+                ""
+            } else {
+                "not "
+            },
+            state.iteration,
+            self.parms_name
+        );
+    }
+
+    fn on_error(&mut self, error: &E) {
+        report!("{}{}", self.prefix(), error);
+    }
+}
+
+// Serializes the runs' appends to the winners file
+static WINNERS_LOCK: Mutex<()> = Mutex::new(());
+
+// Appends the best strand of a simulation, with its parameters and fitness, to the winners file
+struct WinnerWriter<'a, G> {
+    parms_name: &'a str,
+    thread_number: Option<u64>,
+    render: &'a dyn Fn(&G) -> Phenome,
+}
+
+impl<'a, A, G, E> SimulationObserver<A, E> for WinnerWriter<'a, G>
+where
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+{
+    fn on_termination(&mut self, state: &SimState<A>, _stop_reason: &StopReason) {
+        let best = &state.result.best_solution.solution;
+        write_winner(
+            self.parms_name,
+            self.thread_number,
+            best.fitness,
+            state.iteration,
+            &(self.render)(&best.genome),
+        );
+    }
+}
+
+// Appends a line with the best strand of a run to the winners file
+fn write_winner(
+    parms_name: &str,
+    thread_number: Option<u64>,
+    fitness: usize,
+    generations: u64,
+    phenome: &Phenome,
+) {
+    let line = format!(
+        "[thread #{}] {} parms, fitness {} after {} generations: {}\n",
+        thread_number.unwrap_or(0),
+        parms_name,
+        fitness,
+        generations,
+        phenome
+    );
+
+    let _lock = WINNERS_LOCK.lock().unwrap();
+    if let Err(error) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(OUT_WINNERS.0)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+    {
+        report!("Problem writing winner: {:?}", error);
+    }
+}
+
+// The records shared by the runs of a batch
+struct BatchRecords {
+    hall_of_fame: HallOfFame<Phenome>,
+    restarts: HashMap<String, Vec<u32>>, // the generations restarted by the runs of each parms_name
+    operator_statistics: OperatorStatistics, // of all GA runs
+    timestamps: HashMap<u64, Timestamps>, // of the generations of the run of each thread
+}
+
+impl BatchRecords {
+    fn new() -> Self {
+        Self {
+            hall_of_fame: HallOfFame::new(HALL_OF_FAME_SIZE),
+            restarts: HashMap::new(),
+            operator_statistics: OperatorStatistics::zero(),
+            timestamps: HashMap::new(),
+        }
+    }
+
+    // Keeps the timestamps of the run of a thread, a retry replaces the run it retries
+    fn record_timestamps(&mut self, thread_number: Option<u64>, timestamps: Timestamps) {
+        self.timestamps
+            .insert(thread_number.unwrap_or_default(), timestamps);
+    }
+
+    // Takes the timestamps of each run of each parameters of a batch, in the order of the runs
+    fn take_timestamps(&mut self, runs_list: &RunsWithLables) -> Vec<Vec<Timestamps>> {
+        runs_list
+            .iter()
+            .enumerate()
+            .map(|(item_idx, (_, runs))| {
+                (0..runs.len())
+                    .map(|run_idx| {
+                        let thread_number = item_idx as u64 * BATCH_SIZE + run_idx as u64 + 1;
+                        self.timestamps.remove(&thread_number).unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Runs a built simulation until it is final, recording the best fitness of each generation. An
+// abandoned run stops after its current generation and records nothing.
+fn run_sim_to_end<S, A, G, P>(
+    sim: S,
+    problem: &P,
+    parms: &Parameters,
+    (thread_number, seed): (Option<u64>, u64),
+    render: &dyn Fn(&G) -> Phenome,
+    batch: &Mutex<BatchRecords>,
+    abandoned: &AtomicBool,
+) -> Option<DataSetWithLables>
+where
+    S: Simulation<A>,
+    S::Error: Display,
+    A: Algorithm<Output = State<G, usize>>,
+    G: Genotype,
+    P: Problem,
+{
+    // Stores the best fitness value at each iteration of the simulation
+    let mut data = vec![];
+    let mut timestamps = vec![];
+    let mut hall_of_fame = HallOfFame::new(HALL_OF_FAME_SIZE);
+    let mut restarts = vec![];
+    let mut operator_statistics = OperatorStatistics::zero();
+
+    let mut sim = observe(sim)
+        .with_observer(BestFitnessRecorder(&mut data))
+        .with_observer(TimestampRecorder(&mut timestamps, Instant::now()))
+        .with_observer(HallOfFameRecorder(&mut hall_of_fame))
+        .with_observer(RestartRecorder(&mut restarts))
+        .with_observer(OperatorStatisticsRecorder(&mut operator_statistics))
+        .with_observer(OutcomeReporter {
+            parms_name: &parms.parms_name,
+            thread_number,
+            optimum: problem.fitness_bounds().1,
+        });
+
+    #[cfg(any(feature = "tui", feature = "serve"))]
+    if let Some(thread_number) = thread_number {
+        sim.add_observer(DashboardReporter {
+            parms_name: &parms.parms_name,
+            thread_number,
+        });
+        #[cfg(feature = "serve")]
+        sim.add_observer(EventPublisher {
+            parms_name: &parms.parms_name,
+            thread_number,
+            render,
+            best_fitness: None,
+        });
+    }
+
+    if OUT_WINNERS.1 {
+        sim.add_observer(WinnerWriter {
+            parms_name: &parms.parms_name,
+            thread_number,
+            render,
+        });
+    }
+
+    // Stream the metrics of each generation to disk, so they survive a crash of a long run
+    if OUT_METRICS.1 {
+        match MetricsWriter::append_to(
+            run_out_file(
+                OUT_METRICS.0,
+                &parms.parms_name,
+                &format!("thread{}", thread_number.unwrap_or(0)),
+            ),
+            MetricsFormat::Csv,
+        ) {
+            Ok(writer) => sim.add_observer(writer),
+            Err(error) => report!("Problem creating metrics file: {:?}", error),
+        }
+    }
+
+    let result = loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(_)) if !abandoned.load(Ordering::Relaxed) => {}
+            Ok(SimResult::Intermediate(_)) => break None,
+            result => break Some(result),
+        }
+    };
+    // Release the observers' borrow of the data
+    drop(sim);
+    let result = result?;
+
+    let hall_of_fame = hall_of_fame.rendered(render);
+    write_run_hall_of_fame(&parms.parms_name, seed, &hall_of_fame);
+    let mut batch = batch.lock().unwrap();
+    batch.hall_of_fame.merge(&hall_of_fame);
+    batch
+        .restarts
+        .entry(parms.parms_name.clone())
+        .or_default()
+        .extend(restarts);
+    batch.operator_statistics += operator_statistics;
+    batch.record_timestamps(thread_number, timestamps);
+
+    // Return the none varient if we encouter an error
+    result.ok()?;
+    Some(vec![(parms.parms_name.clone(), data)])
+}
+
+// Writes the hall of fame of a single run to OUT_HALL_OF_FAME
+fn write_run_hall_of_fame(parms_name: &str, seed: u64, hall_of_fame: &HallOfFame<Phenome>) {
+    if !OUT_HALL_OF_FAME.1 {
+        return;
+    }
+    let out_file = run_out_file(OUT_HALL_OF_FAME.0, parms_name, &format!("seed{}", seed));
+    if let Err(error) = write_hall_of_fame(&out_file, hall_of_fame) {
+        report!("Problem writing the hall of fame: {:?}", error);
+    }
+}
+
+// Writes a hall of fame as a CSV to the file and as FASTA next to it, its strands named after the
+// file
+fn write_hall_of_fame(out_file: &str, hall_of_fame: &HallOfFame<Phenome>) -> std::io::Result<()> {
+    let name = Path::new(out_file)
+        .file_stem()
+        .map_or("strand".into(), |stem| stem.to_string_lossy());
+    write(out_file, hall_of_fame_to_csv(hall_of_fame))?;
+    write(
+        fasta_out_file(out_file),
+        hall_of_fame_to_fasta(hall_of_fame, &name),
+    )
+}
+
+// The wall-time of each run of each parameters of a batch, in the order of the runs
+type WallTimes = Vec<Vec<Duration>>;
+
+// Runs a simulation batch of a problem from a given parameters list. Returns an option of the
+// labled runs, the wall-time of each run and the records of the batch
+fn run_sim_batch<P: Problem>(
+    problem: &P,
+    parms_list: &[Parameters],
+    variation: Option<Variation>,
+    seeds: Option<&[u64]>,
+) -> Option<(RunsWithLables, WallTimes, BatchRecords)> {
+    let labeled_parms = parms_list
+        .iter()
+        .map(|parms| (parms.parms_name.clone(), parms))
+        .collect::<Vec<_>>();
+    let variation = variation.unwrap_or_default();
+    let optimum = problem.fitness_bounds().1;
+
+    // Create a pool of threads
+    let start_time = Instant::now();
+    report!(
+        "[thread pool]: Creating a threadpool (batch size: {}) with {:?} variation.",
+        BATCH_SIZE,
+        variation
+    );
+    #[cfg(any(feature = "tui", feature = "serve"))]
+    monitor::start_batch(
+        &format!("{:?}", variation),
+        labeled_parms.len() as u64 * BATCH_SIZE,
+        optimum as u64,
+    );
+    // The wall-time of each run of each parameters with the number of its thread
+    let wall_times = Mutex::new(HashMap::<String, Vec<(u64, Duration)>>::new());
+    let batch = Mutex::new(BatchRecords::new());
+    let policy = RunPolicy {
+        timeout: Some(RUN_TIMEOUT),
+        retries: RUN_RETRIES,
+        memory_budget: MEMORY_BUDGET.get().copied(),
+    };
+    // A strand is a vector of alleles, each individual of a self-adaptive GA carries a rate too
+    let genome_size = |parms: &Parameters| {
+        let rate_size = if parms.self_adaptive {
+            size_of::<f64>()
+        } else {
+            0
+        };
+        size_of::<Strand<P>>() + problem.strand_size() * size_of::<P::Allele>() + rate_size
+    };
+    let footprint = |parms: &&Parameters| run_footprint(parms.population_size, genome_size(parms));
+    let runs_list = run_batch_with_policy(
+        &labeled_parms,
+        BATCH_SIZE,
+        policy,
+        footprint,
+        |parms, context| {
+            let thread_number = context.thread_number;
+            let run_start_time = Instant::now();
+            // The nth run of each parameters starts from the nth of the given seeds, a retry from
+            // a random one
+            let seed = match seeds {
+                Some(seeds) if context.attempt == 0 => {
+                    seeds[(thread_number as usize - 1) % seeds.len()]
+                }
+                _ => rand::random(),
+            };
+            // A retry starts over on the dashboard
+            #[cfg(any(feature = "tui", feature = "serve"))]
+            if context.attempt > 0 {
+                monitor::retry_run(thread_number);
+            }
+            let data = run_sim_from_parms(
+                problem,
+                parms,
+                Some(thread_number),
+                seed,
+                &batch,
+                context.abandoned(),
+            );
+            #[cfg(any(feature = "tui", feature = "serve"))]
+            monitor::finish_run(thread_number, &parms.parms_name);
+            let data = data?.first()?.1.clone();
+            wall_times
+                .lock()
+                .unwrap()
+                .entry(parms.parms_name.clone())
+                .or_default()
+                .push((thread_number, run_start_time.elapsed()));
+
+            #[cfg(feature = "results-db")]
+            if OUT_RESULTS_DB.1 {
+                if let Err(error) = results::record_run(&results::RunRecord {
+                    variation: &format!("{:?}", variation),
+                    parms,
+                    strand_size: problem.strand_size(),
+                    thread_number,
+                    seed,
+                    data: &data,
+                    optimum,
+                    wall_time: run_start_time.elapsed(),
+                }) {
+                    report!("Problem recording the run: {:?}", error);
+                }
+            }
+
+            // The baselines are only a reference, they need not find the optimum. Censored GA runs
+            // only show up as unconverged in the success rates.
+            (*data.last()? as usize == optimum
+                || parms.solver != Solver::GeneticAlgorithm
+                || ALLOW_CENSORED_RUNS)
+                .then_some(data)
+        },
+    )?;
+
+    // The islands and the baselines do not report their operators
+    let batch = batch.into_inner().unwrap();
+    if batch.operator_statistics.offspring > 0 {
+        report!(
+            "[thread pool]: {}",
+            operator_report(&batch.operator_statistics)
+        );
+    }
+    report!(
+        "[thread pool]: Finished threadpool with {:?} variation after {} seconds.\n",
+        variation,
+        start_time.elapsed().as_secs()
+    );
+
+    let mut wall_times = wall_times.into_inner().unwrap();
+    // In the order of the threads, like the runs
+    let wall_times = runs_list
+        .iter()
+        .map(|(label, _)| {
+            let mut times = wall_times.remove(label).unwrap_or_default();
+            times.sort_by_key(|(thread_number, _)| *thread_number);
+            times.into_iter().map(|(_, time)| time).collect()
+        })
+        .collect();
+    Some((runs_list, wall_times, batch))
+}
+
+// The share of the time the GA runs spent in each operator, to find their bottleneck, and the
+// share of the children the mutation changed
+fn operator_report(statistics: &OperatorStatistics) -> String {
+    let nanos = |time: ProcessingTime| time.duration().num_nanoseconds().unwrap_or(i64::MAX) as f64;
+    let total = nanos(statistics.total()).max(1.0);
+    let stages = [
+        ("evaluation", statistics.evaluation),
+        ("selection", statistics.selection),
+        ("crossover", statistics.crossover),
+        ("mutation", statistics.mutation),
+        ("repair", statistics.repair),
+        ("local search", statistics.local_search),
+        ("reinsertion", statistics.reinsertion),
+    ]
+    .iter()
+    .map(|(stage, time)| format!("{} {:.1}%", stage, 100.0 * nanos(*time) / total))
+    .collect::<Vec<_>>();
+    format!(
+        "Time in the operators of the GA runs: {} ({:.1} seconds in all). {} children bred, {:.1}% of them mutated.",
+        stages.join(", "),
+        total / 1e9,
+        statistics.offspring,
+        100.0 * statistics.mutated as f64 / statistics.offspring.max(1) as f64
+    )
+}
+
+// The generations at which the piecewise schedules of a set of parameters change their slope
+fn schedule_changes(parms: &Parameters) -> Vec<u32> {
+    [
+        &parms.mutation_schedule,
+        &parms.selection_schedule,
+        &parms.population_schedule,
+    ]
+    .into_iter()
+    .flat_map(|schedule| match schedule {
+        Some(Schedule::Piecewise(points)) => points.iter().map(|point| point.0 as u32).collect(),
+        _ => vec![],
+    })
+    .collect()
+}
+
+// Runs and graphs a variation. Returns the parameters of its setting that converged significantly
+// faster than the default, or the default ones if there is none (or the graph is turned off).
+fn generate_graph_from_variation<P: Problem>(
+    problem: &P,
+    graph_name: &str,
+    variation: Variation,
+    out_file: (&str, bool),
+) -> Result<Parameters, Box<dyn std::error::Error>> {
+    generate_graph_from_variation_with_curves(problem, graph_name, variation, vec![], out_file)
+}
+
+// Like `generate_graph_from_variation`, with the given curves (e.g. what the theory expects)
+// drawn over the runs
+fn generate_graph_from_variation_with_curves<P: Problem>(
+    problem: &P,
+    graph_name: &str,
+    variation: Variation,
+    curves: Vec<Annotation>,
+    out_file: (&str, bool),
+) -> Result<Parameters, Box<dyn std::error::Error>> {
+    let mut best_parms = Parameters::default();
+    if out_file.1 {
+        let mut parms_list = Parameters::new(&variation);
+        let num_experiments = parms_list.len();
+        parms_list.extend(REFERENCE_BASELINES.iter().map(|solver| Parameters {
+            parms_name: format!("baseline: {}", solver.name()),
+            solver: *solver,
+            ..Parameters::default()
+        }));
+        let (mut runs, wall_times, mut batch) =
+            run_sim_batch(problem, &parms_list, Some(variation), None).unwrap();
+        if OUT_HALL_OF_FAME.1 {
+            write_hall_of_fame(&hall_of_fame_out_file(out_file.0), &batch.hall_of_fame)?;
+        }
+        let timestamps = batch.take_timestamps(&runs);
+        // Mark the restarts of the runs of each line and where its schedules change on the graph
+        let restarts = runs
+            .iter()
+            .map(|(label, _)| batch.restarts.remove(label).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let schedule_changes = parms_list.iter().map(schedule_changes).collect::<Vec<_>>();
+        let optimum = problem.fitness_bounds().1 as u32;
+        let mut annotations = vertical_lines(&restarts);
+        annotations.extend(vertical_lines(&schedule_changes));
+        annotations.extend(curves);
+        annotations.push(Annotation::HorizontalLine { fitness: optimum });
+        annotations.push(Annotation::Text {
+            gen: 1,
+            fitness: optimum,
+            text: "optimum".to_string(),
+        });
+
+        // Cut the reference curves to the length of the experiment, the baselines rarely converge
+        let gens_max = runs[..num_experiments]
+            .iter()
+            .flat_map(|(_, runs)| runs.iter().map(|r| r.len()))
+            .max()
+            .unwrap_or_default();
+        for (_, baseline_runs) in runs[num_experiments..].iter_mut() {
+            for run in baseline_runs.iter_mut() {
+                run.truncate(gens_max);
+            }
+        }
+        if OUT_SAVED_RUNS.1 {
+            save_runs(&runs, problem.fitness_bounds().1, out_file.0)?;
+        }
+
+        let summaries = runs
+            .iter()
+            .zip(wall_times.iter())
+            .map(|((label, runs), times)| summarize(label, runs, problem.fitness_bounds().1, times))
+            .collect::<Vec<_>>();
+        write(summary_out_file(out_file.0), summary_table(&summaries))?;
+
+        if let Some(best) = best_setting(
+            &runs[..num_experiments],
+            problem.fitness_bounds().1,
+            SIGNIFICANCE_LEVEL,
+        ) {
+            best_parms = parms_list[best].clone();
+        }
+
+        // Tell apart the real differences to the default from the noise of the batch
+        let runs = label_with_p_values(&runs, problem.fitness_bounds().1);
+
+        generate_graph(
+            problem,
+            graph_name,
+            average_runs(&runs),
+            &annotations,
+            out_file.0,
+        )?;
+        generate_differential_graph(
+            &format!("{} (vs. default)", graph_name),
+            &runs,
+            &differential_out_file(out_file.0),
+        )?;
+        generate_success_rate_graph(
+            &format!("{} (success rate)", graph_name),
+            &runs,
+            problem.fitness_bounds().1,
+            &success_rate_out_file(out_file.0),
+        )?;
+        // A setting that takes fewer gens may still take longer, e.g. to select more parents
+        generate_wall_time_graph(
+            &format!("{} (over time)", graph_name),
+            problem.fitness_bounds(),
+            average_runs_over_time(&runs, &timestamps, WALL_TIME_POINTS),
+            &annotations,
+            &wall_time_out_file(out_file.0),
+        )?;
+    }
+    Ok(best_parms)
+}
+
+// Saves the runs of a sweep to a new file named after its graph and the time of the invocation
+fn save_runs(
+    runs: &RunsWithLables,
+    optimum: usize,
+    out_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    create_dir_all(OUT_SAVED_RUNS.0)?;
+    let name = Path::new(out_file)
+        .file_stem()
+        .map_or("runs".into(), |stem| stem.to_string_lossy());
+    let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    write(
+        format!("{}/{}_{}.csv", OUT_SAVED_RUNS.0, name, time),
+        runs_to_csv(runs, optimum),
+    )?;
+    Ok(())
+}
+
+// Overlays the averaged runs of two or more saved sweeps in a graph and tables the difference of
+// their convergence to the first one
+fn compare_saved_runs(paths: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if paths.len() < 2 {
+        return Err("usage: project01 compare <saved runs.csv> <saved runs.csv>...".into());
+    }
+
+    let mut names = vec![];
+    let mut dataset = vec![];
+    let mut summaries = vec![];
+    let mut highest_optimum = 0;
+    for path in paths {
+        let (runs, optimum) = runs_from_csv(&read_to_string(path)?)?;
+        let name = Path::new(path)
+            .file_stem()
+            .map_or(path.clone(), |stem| stem.to_string_lossy().into_owned());
+        highest_optimum = highest_optimum.max(optimum);
+        dataset.extend(
+            average_runs(&runs)
+                .into_iter()
+                .map(|(label, data)| (format!("{}: {}", name, label), data)),
+        );
+        summaries.push(
+            runs.iter()
+                .map(|(label, runs)| summarize(label, runs, optimum, &[]))
+                .collect::<Vec<_>>(),
+        );
+        names.push(name);
+    }
+
+    if let Some(dir) = Path::new(OUT_COMPARISON).parent() {
+        create_dir_all(dir)?;
+    }
+    generate_graph_in_bounds(
+        "Comparison of Saved Runs",
+        (0, highest_optimum),
+        dataset,
+        &[Annotation::HorizontalLine {
+            fitness: highest_optimum as u32,
+        }],
+        OUT_COMPARISON,
+    )?;
+    let table = comparison_table(&names, &summaries);
+    print!("{}", table);
+    write(OUT_COMPARISON.replace(".png", ".md"), table)?;
+    Ok(())
+}
+
+// Runs each of the solvers BATCH_SIZE times on the default problem, the nth run of every solver
+// from the same nth seed, and overlays them in a graph with a table of how far each one got
+fn compare_solvers(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let seed = match args {
+        [] => SOLVERS_SEED,
+        [seed] => seed.parse::<u64>()?,
+        _ => return Err("usage: project01 solvers [<seed>]".into()),
+    };
+    let problem = ClustersOf4::new(STRAND_SIZE);
+    let (runs, wall_times, mut batch) = run_solvers(&problem, seed)?;
+    let optimum = problem.fitness_bounds().1;
+
+    if let Some(dir) = Path::new(OUT_SOLVERS).parent() {
+        create_dir_all(dir)?;
+    }
+    let annotations = [
+        Annotation::HorizontalLine {
+            fitness: optimum as u32,
+        },
+        Annotation::Text {
+            gen: 1,
+            fitness: optimum as u32,
+            text: "optimum".to_string(),
+        },
+    ];
+    generate_graph(
+        &problem,
+        &format!("Solvers from the Same {} Seeds", BATCH_SIZE),
+        average_runs(&runs),
+        &annotations,
+        OUT_SOLVERS,
+    )?;
+    // A generation of each solver evaluates as many strands, but takes a different time
+    generate_wall_time_graph(
+        &format!("Solvers from the Same {} Seeds (over time)", BATCH_SIZE),
+        problem.fitness_bounds(),
+        average_runs_over_time(&runs, &batch.take_timestamps(&runs), WALL_TIME_POINTS),
+        &annotations,
+        &wall_time_out_file(OUT_SOLVERS),
+    )?;
+    let summaries = runs
+        .iter()
+        .zip(wall_times.iter())
+        .map(|((label, runs), times)| summarize(label, runs, optimum, times))
+        .collect::<Vec<_>>();
+    let table = solver_table(&summaries, &runs);
+    print!("{}", table);
+    write(summary_out_file(OUT_SOLVERS), table)?;
+    Ok(())
+}
+
+// Runs each of the solvers BATCH_SIZE times on the problem, the nth run of every solver from the
+// nth of the seeds drawn from the given one, returns the runs with their wall-times and records
+fn run_solvers<P: Problem>(
+    problem: &P,
+    seed: u64,
+) -> Result<(RunsWithLables, WallTimes, BatchRecords), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let seeds = (0..BATCH_SIZE).map(|_| rng.gen()).collect::<Vec<u64>>();
+    report!(
+        "[solvers]: Running every solver from the seeds {:?}.",
+        seeds
+    );
+
+    let variation = Variation::Solvers(SOLVERS.to_vec());
+    let parms_list = Parameters::new(&variation);
+    Ok(
+        run_sim_batch(problem, &parms_list, Some(variation), Some(&seeds))
+            .ok_or("the batch failed")?,
+    )
+}
+
+// Plots the run-length distributions of the solvers for a target fitness on the default problem:
+// the share of the runs of each solver that reached it within each number of generations, and
+// within each number of seconds, since a generation of each solver takes a different time
+fn run_lengths(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let usage = "usage: project01 rld <target fitness> [<seed>]";
+    let (target, seed) = match args {
+        [target] => (target.parse::<usize>()?, SOLVERS_SEED),
+        [target, seed] => (target.parse::<usize>()?, seed.parse::<u64>()?),
+        _ => return Err(usage.into()),
+    };
+    let problem = ClustersOf4::new(STRAND_SIZE);
+    let (lowest, highest) = problem.fitness_bounds();
+    if !(lowest..=highest).contains(&target) {
+        return Err(format!("the target must be within {} and {}", lowest, highest).into());
+    }
+    let (runs, _, mut batch) = run_solvers(&problem, seed)?;
+    let timestamps = batch.take_timestamps(&runs);
+
+    if let Some(dir) = Path::new(OUT_RUN_LENGTHS).parent() {
+        create_dir_all(dir)?;
+    }
+    let gens = runs
+        .iter()
+        .map(|(label, runs)| {
+            let gens = runs
+                .iter()
+                .map(|run| convergence_gen(run, target).map(|gen| gen as f64))
+                .collect();
+            (label.clone(), gens)
+        })
+        .collect::<Vec<_>>();
+    generate_run_length_graph(
+        &format!("Run-Length Distributions (target fitness: {})", target),
+        "gens",
+        &gens,
+        OUT_RUN_LENGTHS,
+    )?;
+    let secs = runs
+        .iter()
+        .zip(timestamps.iter())
+        .map(|((label, runs), timestamps)| {
+            let secs = runs
+                .iter()
+                .zip(timestamps.iter())
+                .map(|(run, timestamps)| time_to_target(run, target, timestamps))
+                .collect();
+            (label.clone(), secs)
+        })
+        .collect::<Vec<_>>();
+    generate_run_length_graph(
+        &format!("Time-to-Target Distributions (target fitness: {})", target),
+        "seconds",
+        &secs,
+        &wall_time_out_file(OUT_RUN_LENGTHS),
+    )?;
+    Ok(())
+}
+
+// Reruns a single run of a batch from its seed and parameters on its own, with the breeding and
+// evaluation on a single thread, printing each generation of the GA to debug it
+fn replay(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (seed, parms) = single_run_args(args, "--replay")?;
+    let problem = ClustersOf4::new(STRAND_SIZE);
+
+    // The generations of the self-adaptive GA, the islands, the ablations and the baselines are
+    // not printed
+    if !parms.is_plain_ga() {
+        run_sim_from_parms(
+            &problem,
+            &parms,
+            None,
+            seed,
+            &Mutex::new(BatchRecords::new()),
+            &AtomicBool::new(false),
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Replaying a simulation with {} parameters (seed {}).",
+        parms.parms_name, seed
+    );
+    let render = |genome: &Genome| problem.render(genome);
+    let mut hall_of_fame = HallOfFame::new(HALL_OF_FAME_SIZE);
+    let result = observe(build_sim(
+        &problem,
+        &parms,
+        parms.population_size,
+        seed,
+        NoLineage,
+    ))
+    .with_observer(GenerationReporter { render: &render })
+    .with_observer(HallOfFameRecorder(&mut hall_of_fame))
+    .with_observer(OutcomeReporter {
+        parms_name: &parms.parms_name,
+        thread_number: None,
+        optimum: problem.fitness_bounds().1,
+    })
+    .run()
+    .map_err(|error| error.to_string())?;
+    write_run_hall_of_fame(&parms.parms_name, seed, &hall_of_fame.rendered(&render));
+    if let SimResult::Final(state, ..) = result {
+        write_final_population(&state.result.evaluated_population, &render)?;
+    }
+    Ok(())
+}
+
+// Writes the strands of a population to OUT_FINAL_POPULATION as FASTA, the fittest first, each with
+// its fitness in the header
+fn write_final_population(
+    population: &EvaluatedPopulation<Genome, usize>,
+    render: &dyn Fn(&Genome) -> Phenome,
+) -> std::io::Result<()> {
+    let mut strands = population
+        .individuals()
+        .iter()
+        .zip(population.fitness_values())
+        .map(|(genome, fitness)| (render(genome), *fitness))
+        .collect::<Vec<_>>();
+    strands.sort_by_key(|(_, fitness)| Reverse(*fitness));
+    let records = strands
+        .into_iter()
+        .enumerate()
+        .map(|(rank, (phenome, fitness))| {
+            FastaRecord::new(format!("strand_{} fitness={}", rank + 1, fitness), phenome)
+        })
+        .collect::<Vec<_>>();
+    write(OUT_FINAL_POPULATION, to_fasta(&records))?;
+    println!(
+        "The final population is written to {}.",
+        OUT_FINAL_POPULATION
+    );
+    Ok(())
+}
+
+// Parses the seed and the parameters of a single run of a mode, which runs on a single thread so
+// that it runs the same from the same seed
+fn single_run_args(
+    args: &[String],
+    mode: &str,
+) -> Result<(u64, Parameters), Box<dyn std::error::Error>> {
+    let [seed, parms] = args else {
+        return Err(format!("usage: project01 {} <seed> <parm=value,...|default>", mode).into());
+    };
+    let seed = seed.parse::<u64>()?;
+    let parms = Parameters::parse(parms)?;
+    // Fail before the run rather than in it
+    let problem = ClustersOf4::new(STRAND_SIZE);
+    if !parms.seed_file.is_empty() {
+        read_seed_strands(&problem, &parms.seed_file)?;
+    }
+    initial_strand_builder(&problem, &parms)?;
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build_global()?;
+
+    // The other outputs of the run are written as in a batch
+    create_dir_all(OUT_METRICS.0)?;
+    create_dir_all(OUT_HALL_OF_FAME.0)?;
+    Ok((seed, parms))
+}
+
+// Runs a single plain GA from a seed and parameters, tracing each new best strand to OUT_TRACE
+fn trace(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (seed, parms) = single_run_args(args, "--trace")?;
+    if !parms.is_plain_ga() {
+        return Err(
+            "only runs of the plain (non self-adaptive, single island) GA are traced".into(),
+        );
+    }
+    let problem = ClustersOf4::new(STRAND_SIZE);
+
+    let tracer = BestStrandTracer {
+        problem: problem.clone(),
+        best: Mutex::new((0, File::create(OUT_TRACE)?)),
+    };
+    let sim = build_sim(&problem, &parms, parms.population_size, seed, tracer);
+    // Only the strands that beat the best of the initial population are traced
+    let initial_best = sim
+        .population_snapshot()
+        .iter()
+        .map(|evaluated| evaluated.fitness)
+        .max()
+        .unwrap_or_default();
+    sim.algorithm().lineage().best.lock().unwrap().0 = initial_best;
+
+    println!(
+        "Tracing a simulation with {} parameters (seed {}) to {}.",
+        parms.parms_name, seed, OUT_TRACE
+    );
+    observe(sim)
+        .with_observer(OutcomeReporter {
+            parms_name: &parms.parms_name,
+            thread_number: None,
+            optimum: problem.fitness_bounds().1,
+        })
+        .run()
+        .map_err(|error| error.to_string())?;
+    Ok(())
+}
+
+// Runs a single plain GA from a seed and parameters, writing the family tree of its best strand to
+// OUT_ANCESTRY
+fn ancestry(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (seed, parms) = single_run_args(args, "--ancestry")?;
+    if !parms.is_plain_ga() {
+        return Err(
+            "only the ancestry of runs of the plain (non self-adaptive, single island) GA is \
+             tracked"
+                .into(),
+        );
+    }
+    let problem = ClustersOf4::new(STRAND_SIZE);
+
+    println!(
+        "Tracking the ancestry of a simulation with {} parameters (seed {}).",
+        parms.parms_name, seed
+    );
+    let mut sim = observe(build_sim(
+        &problem,
+        &parms,
+        parms.population_size,
+        seed,
+        Ancestry::new(),
+    ))
+    .with_observer(OutcomeReporter {
+        parms_name: &parms.parms_name,
+        thread_number: None,
+        optimum: problem.fitness_bounds().1,
+    });
+    let SimResult::Final(state, ..) = sim.run().map_err(|error| error.to_string())? else {
+        return Err("the simulation did not finish".into());
+    };
+
+    let ancestry = sim.simulation().algorithm().lineage();
+    let tree = ancestry.family_tree(&state.result.best_solution.solution.genome);
+    let fitness = problem.fitness_function();
+    let dot = family_tree_to_dot(&tree, |individual| {
+        format!(
+            "#{} (generation {}): {}\n{}",
+            individual.id,
+            individual.generation,
+            fitness.fitness_of(&individual.genome),
+            problem.render(&individual.genome)
+        )
+    });
+    write(OUT_ANCESTRY, dot)?;
+    println!(
+        "The best strand has {} ancestors among the {} strands of the run, written to {}.",
+        tree.len() - 1,
+        ancestry.len(),
+        OUT_ANCESTRY
+    );
+    Ok(())
+}
+
+// Runs the default parameters on the problem of each strand size and graphs their fitness
+// normalized by the bounds of the problem, as the longer strands have a higher optimum
+fn generate_graph_from_strand_sizes(
+    graph_name: &str,
+    strand_sizes: &[usize],
+    out_file: (&str, bool),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let problems = strand_sizes
+        .iter()
+        .map(|strand_size| {
+            let label = format!(
+                "strand_size = {}{}",
+                strand_size,
+                if *strand_size == STRAND_SIZE {
+                    " (default)"
+                } else {
+                    ""
+                }
+            );
+            (label, ClustersOf4::new(*strand_size))
+        })
+        .collect::<Vec<_>>();
+    generate_graph_from_problems(graph_name, &problems, out_file)
+}
+
+// Runs the default parameters on each labeled problem (e.g. a fitness function of each setting)
+// and graphs their fitness normalized by the bounds of the problem, as their optima differ
+fn generate_graph_from_problems<P: Problem>(
+    graph_name: &str,
+    problems: &[(String, P)],
+    out_file: (&str, bool),
+) -> Result<(), Box<dyn std::error::Error>> {
+    if out_file.1 {
+        let mut dataset: NormalizedDataSetWithLables = vec![];
+        for (label, problem) in problems {
+            let parms = Parameters {
+                parms_name: label.clone(),
+                ..Parameters::default()
+            };
+            let (runs, ..) = run_sim_batch(problem, &[parms], None, None)
+                .ok_or_else(|| format!("a run of {} failed", label))?;
+            dataset.extend(
+                average_runs(&runs)
+                    .into_iter()
+                    .map(|(label, data)| (label, normalized_run(&data, problem.fitness_bounds()))),
+            );
+        }
+
+        generate_normalized_graph(graph_name, dataset, out_file.0)?;
+    }
+    Ok(())
+}
+
+// The output path of a CSV of a single run in a directory, named after its parameters and e.g. its
+// thread or seed
+fn run_out_file(dir: &str, parms_name: &str, run: &str) -> String {
+    let name = parms_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    format!("{}/{}_{}.csv", dir, name, run)
+}
+
+fn delete_file(file: (&str, bool)) {
+    if file.1 {
+        for path in [
+            file.0.to_string(),
+            differential_out_file(file.0),
+            success_rate_out_file(file.0),
+            zoomed_out_file(file.0),
+            wall_time_out_file(file.0),
+            summary_out_file(file.0),
+            hall_of_fame_out_file(file.0),
+        ] {
+            match remove_file(path) {
+                Ok(_) => (),
+                Err(error) if error.kind() == ErrorKind::NotFound => { /* do nothing */ }
+                Err(error) => panic!("Problem deleting file: {:?}", error),
+            }
+        }
+    }
+}
+
+// Runs the experiments, or one of the other modes picked by the command line arguments
+pub fn run() {
+    if let Err(error) = load_chart_style(CHART_STYLE_FILE) {
+        println!("Problem loading the chart style: {}", error);
+        return;
+    }
+
+    // Replay, trace or track the ancestry of a run, compare saved runs or the solvers, plot the
+    // run-length distributions of the solvers or query the recorded runs instead of running the
+    // experiments
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("--replay") => {
+            if let Err(error) = replay(&args[1..]) {
+                println!("Problem replaying the run: {}", error);
+            }
+            return;
+        }
+        Some("--trace") => {
+            if let Err(error) = trace(&args[1..]) {
+                println!("Problem tracing the run: {}", error);
+            }
+            return;
+        }
+        Some("--ancestry") => {
+            if let Err(error) = ancestry(&args[1..]) {
+                println!("Problem tracking the ancestry of the run: {}", error);
+            }
+            return;
+        }
+        Some("compare") => {
+            if let Err(error) = compare_saved_runs(&args[1..]) {
+                println!("Problem comparing the runs: {}", error);
+            }
+            return;
+        }
+        Some("solvers") => {
+            if let Err(error) = compare_solvers(&args[1..]) {
+                println!("Problem comparing the solvers: {}", error);
+            }
+            return;
+        }
+        Some("rld") => {
+            if let Err(error) = run_lengths(&args[1..]) {
+                println!("Problem plotting the run-length distributions: {}", error);
+            }
+            return;
+        }
+        #[cfg(feature = "results-db")]
+        Some("results") => {
+            if let Err(error) = results::run_cli(&args[1..]) {
+                println!("Problem querying the results: {}", error);
+            }
+            return;
+        }
+        _ => (),
+    }
+
+    // Run only as many runs of a batch at once as fit in the memory budget
+    if let Some(idx) = args.iter().position(|arg| arg == "--memory-budget") {
+        let Some(mib) = args.get(idx + 1).and_then(|mib| mib.parse::<u64>().ok()) else {
+            println!("usage: project01 --memory-budget <MiB>");
+            return;
+        };
+        MEMORY_BUDGET.set(mib << 20).unwrap();
+    }
+
+    // Follow the experiments from a browser and on the dashboard
+    #[cfg(feature = "serve")]
+    if let Some(idx) = args.iter().position(|arg| arg == "--serve") {
+        let Some(port) = args.get(idx + 1).and_then(|port| port.parse().ok()) else {
+            println!("usage: project01 --serve <port> [--tui]");
+            return;
+        };
+        if let Err(error) = server::start(port) {
+            println!("Problem starting the metrics server: {}", error);
+            return;
+        }
+        println!(
+            "Serving the metrics of the runs on http://localhost:{}/.",
+            port
+        );
+    }
+    #[cfg(feature = "tui")]
+    if args.iter().any(|arg| arg == "--tui") {
+        if let Err(error) = dashboard::start() {
+            println!("Problem starting the dashboard: {}", error);
+            return;
+        }
+    }
+
+    delete_file(OUT_DEFAULT);
+    delete_file(OUT_VAR_NUM_INDIV);
+    delete_file(OUT_VAR_SELECTION);
+    delete_file(OUT_VAR_MUTATION);
+    delete_file(OUT_VAR_REINSERTION);
+    delete_file(OUT_BEST_OF_EACH);
+    delete_file(OUT_VAR_STAGNATION);
+    delete_file(OUT_SELF_ADAPTIVE);
+    delete_file(OUT_ISLANDS);
+    delete_file(OUT_MEMETIC);
+    delete_file(OUT_BASELINES);
+    delete_file(OUT_VAR_POPULATION);
+    delete_file(OUT_VAR_STRAND_SIZE);
+    delete_file(OUT_VAR_ELITES);
+    delete_file(OUT_IMMIGRANTS);
+    delete_file(OUT_VAR_IMMIGRANT_RATE);
+    delete_file(OUT_VAR_INIT);
+    delete_file(OUT_VAR_INIT_DISTANCE);
+    delete_file(OUT_MUTATION_SCHEDULE);
+    delete_file(OUT_VAR_RESTART);
+    delete_file(OUT_PIPELINES);
+    delete_file(OUT_VAR_CROSSOVER);
+    delete_file(OUT_ENSEMBLES);
+    delete_file(OUT_VAR_GC_CONTENT);
+    delete_file(OUT_VAR_MOTIF);
+    delete_file(OUT_VAR_GAP_SCORE);
+    delete_file(OUT_VAR_NK);
+    delete_file(OUT_ROYAL_ROAD);
+    delete_file(OUT_TRAPS);
+    delete_file(OUT_ONE_MAX);
+    delete_file(OUT_LEADING_ONES);
+    delete_file(OUT_ES);
+    delete_file(OUT_WINNERS);
+    delete_file(OUT_EVOLUTION);
+    delete_file(OUT_ALLELES);
+    delete_file(OUT_FITNESS_ANIMATION);
+
+    if OUT_METRICS.1 {
+        match remove_dir_all(OUT_METRICS.0) {
+            Ok(_) => (),
+            Err(error) if error.kind() == ErrorKind::NotFound => { /* do nothing */ }
+            Err(error) => panic!("Problem deleting directory: {:?}", error),
+        }
+        create_dir_all(OUT_METRICS.0).unwrap();
+    }
+    if OUT_HALL_OF_FAME.1 {
+        match remove_dir_all(OUT_HALL_OF_FAME.0) {
+            Ok(_) => (),
+            Err(error) if error.kind() == ErrorKind::NotFound => { /* do nothing */ }
+            Err(error) => panic!("Problem deleting directory: {:?}", error),
+        }
+        create_dir_all(OUT_HALL_OF_FAME.0).unwrap();
+    }
+
+    let start_time = Instant::now();
+    let problem = ClustersOf4::new(STRAND_SIZE);
+
+    generate_graph_from_variation(
+        &problem,
+        "3.1: Default Parameters",
+        Variation::Default,
+        OUT_DEFAULT,
+    )
+    .unwrap();
+
+    let best_num_indiv = generate_graph_from_variation(
+        &problem,
+        "3.2: Various Numbers of Individuals Per Parent",
+        Variation::NumIdiv(vec![2, 4, 8, 16, 32, 64, 128]),
+        OUT_VAR_NUM_INDIV,
+    )
+    .unwrap();
+
+    let best_selection = generate_graph_from_variation(
+        &problem,
+        "3.3: Various Selection Ratios",
+        Variation::Selection(vec![0.25, 0.5, 1.0, 2.0, 4.0, 8.0]),
+        OUT_VAR_SELECTION,
+    )
+    .unwrap();
+
+    let best_mutation = generate_graph_from_variation(
+        &problem,
+        "3.4: Various Mutation Rates",
+        Variation::Mutation(vec![0.001, 0.005, 0.01, 0.025, 0.05]),
+        OUT_VAR_MUTATION,
+    )
+    .unwrap();
+
+    let best_reinsertion = generate_graph_from_variation(
+        &problem,
+        "3.5: Various Reinsertion Ratios",
+        Variation::Reinsertion(vec![0.01, 0.1, 0.25, 0.5, 0.75, 0.9]),
+        OUT_VAR_REINSERTION,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.6: Using Best Value of Each Varied Parameter",
+        Variation::BestOfEach {
+            num_individuals_per_parents: best_num_indiv.num_individuals_per_parents,
+            selection_ratio: best_selection.selection_ratio,
+            mutation_rate: best_mutation.mutation_rate,
+            reinsertion_ratio: best_reinsertion.reinsertion_ratio,
+        },
+        OUT_BEST_OF_EACH,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.7: Various Stagnation Limits (Mutation Boost)",
+        Variation::Stagnation(vec![0, 16, 64, 256, 1024]),
+        OUT_VAR_STAGNATION,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.8: Self-Adaptive vs. Fixed Mutation Rates",
+        Variation::SelfAdaptation(vec![0.01, 0.05]),
+        OUT_SELF_ADAPTIVE,
+    )
+    .unwrap();
+
+    if OUT_EVOLUTION.1 || OUT_ALLELES.1 || OUT_FITNESS_ANIMATION.1 {
+        let records = run_sim_recording_strands(&problem, &Parameters::default()).unwrap();
+        if OUT_EVOLUTION.1 {
+            generate_evolution_graph(
+                "3.9: Evolution of the Best Strand",
+                &records.best_genomes,
+                STRIP_SAMPLES,
+                OUT_EVOLUTION.0,
+            )
+            .unwrap();
+        }
+        if OUT_ALLELES.1 {
+            generate_allele_heatmap(
+                "3.10: Dominant Allele Frequencies",
+                &records.frequencies,
+                HEATMAP_SAMPLES,
+                OUT_ALLELES.0,
+            )
+            .unwrap();
+        }
+        // An animation for presentations rather than a graph of the report
+        if OUT_FITNESS_ANIMATION.1 {
+            generate_fitness_animation(
+                "Fitness Distribution",
+                problem.fitness_bounds(),
+                &records.fitness_values,
+                ANIMATION_FRAMES,
+                ANIMATION_FRAME_DELAY,
+                OUT_FITNESS_ANIMATION.0,
+            )
+            .unwrap();
+        }
+    }
+
+    generate_graph_from_variation(
+        &problem,
+        "3.11: Island Model vs. Single Population",
+        Variation::Islands(vec![1, 4, 8]),
+        OUT_ISLANDS,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.12: Memetic vs. Plain GA",
+        Variation::LocalSearch(vec![0, 4, 16]),
+        OUT_MEMETIC,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.13: Genetic Algorithm vs. Baselines",
+        Variation::Solvers(vec![Solver::GeneticAlgorithm, Solver::SimulatedAnnealing]),
+        OUT_BASELINES,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.14: Various Population Sizes",
+        Variation::PopulationSize(vec![16, 64, 256, 1024]),
+        OUT_VAR_POPULATION,
+    )
+    .unwrap();
+
+    generate_graph_from_strand_sizes(
+        "3.15: Various Strand Sizes",
+        &[40, 100, 400, 1000],
+        OUT_VAR_STRAND_SIZE,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.16: Various Numbers of Elites",
+        Variation::Elites(vec![0, 1, 4, 16]),
+        OUT_VAR_ELITES,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.17: Duplicate Children vs. Random Immigrants",
+        Variation::DuplicateElimination(vec![0.01, 0.05]),
+        OUT_IMMIGRANTS,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.18: Various Random Immigrant Rates",
+        Variation::ImmigrantRate(vec![0.0, 0.01, 0.05, 0.1, 0.25]),
+        OUT_VAR_IMMIGRANT_RATE,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.19: Uniform vs. Biased Initialization",
+        Variation::Initialization(vec![
+            (1, vec![]),
+            (1, vec![2.0, 1.0, 1.0, 1.0]),
+            (1, vec![8.0, 1.0, 1.0, 1.0]),
+            (2, vec![]),
+            (3, vec![]),
+            (4, vec![]),
+        ]),
+        OUT_VAR_INIT,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.20: Various Minimum Distances of a Small Initial Population",
+        Variation::InitMinDistance(vec![0, 25, 50, 60]),
+        OUT_VAR_INIT_DISTANCE,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.21: Fixed vs. Annealed Mutation Rates",
+        Variation::MutationSchedule(vec![
+            "exp:0.2:0.01:0.01",
+            "exp:0.1:0.02:0.005",
+            "pw:1@0.2:500@0.01",
+        ]),
+        OUT_MUTATION_SCHEDULE,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.22: Various Restart Limits",
+        Variation::Restart(vec![0, 50, 100, 200]),
+        OUT_VAR_RESTART,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.23: Crossover and Mutation vs. Either Alone",
+        Variation::Pipelines(vec![
+            Pipeline::CrossoverAndMutation,
+            Pipeline::MutationOnly,
+            Pipeline::CrossoverOnly,
+        ]),
+        OUT_PIPELINES,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.24: Various Crossover Rates",
+        Variation::Crossover(vec![0.2, 0.6, 0.8, 1.0]),
+        OUT_VAR_CROSSOVER,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.25: Single Operators vs. Fixed and Adaptive Ensembles",
+        Variation::Ensembles(vec![
+            "crossovers=SinglePoint+TwoPoint+Uniform,mutations=RandomValue+Swap+Insert",
+            "crossovers=SinglePoint+TwoPoint+Uniform,mutations=RandomValue+Swap+Insert,\
+             adaptive_operators=true",
+        ]),
+        OUT_ENSEMBLES,
+    )
+    .unwrap();
+
+    // The same GA on biologically flavored fitness functions of the strand composition
+    let gc_content_targets = [25.0, 50.0, 75.0]
+        .map(|percent| {
+            let problem = GcContent::new(STRAND_SIZE, percent).unwrap();
+            (format!("target GC-content = {}%", percent), problem)
+        })
+        .to_vec();
+    generate_graph_from_problems(
+        "3.26: Various GC-Content Targets",
+        &gc_content_targets,
+        OUT_VAR_GC_CONTENT,
+    )
+    .unwrap();
+
+    let motifs = ["ACGT", "AAAA", "ACAC", "GATTACA"]
+        .map(|motif| {
+            (
+                format!("motif = {}", motif),
+                Motif::new(STRAND_SIZE, motif).unwrap(),
+            )
+        })
+        .to_vec();
+    generate_graph_from_problems("3.27: Various Motifs", &motifs, OUT_VAR_MOTIF).unwrap();
+
+    // An epistatic landscape: a gap shifts the alignment of all the nucleotides after it
+    let gap_scores = [-1, -2, -4]
+        .map(|gap| {
+            let scores = AlignmentScores {
+                gap,
+                ..AlignmentScores::default()
+            };
+            let problem =
+                Alignment::new(ALIGNMENT_REFERENCE.len(), ALIGNMENT_REFERENCE, scores).unwrap();
+            (format!("gap score = {}", gap), problem)
+        })
+        .to_vec();
+    generate_graph_from_problems(
+        "3.28: Alignment With a Reference Strand by Various Gap Scores",
+        &gap_scores,
+        OUT_VAR_GAP_SCORE,
+    )
+    .unwrap();
+
+    // Tunable ruggedness: the more neighbours each bit interacts with, the more local optima
+    let nk_landscapes = [0, 1, 2, 4, 8]
+        .map(|k| (format!("K = {}", k), Nk::new(NK_SIZE, k, NK_SEED).unwrap()))
+        .to_vec();
+    generate_graph_from_problems(
+        "3.29: NK Landscapes of Various K",
+        &nk_landscapes,
+        OUT_VAR_NK,
+    )
+    .unwrap();
+
+    // Benchmarks built for crossover: it puts together blocks of 1s, whereas mutation has to find
+    // each block at once against the pull of the traps
+    let pipelines = vec![
+        Pipeline::CrossoverAndMutation,
+        Pipeline::MutationOnly,
+        Pipeline::CrossoverOnly,
+    ];
+    generate_graph_from_variation(
+        &RoyalRoad::new(BITSTRING_SIZE, ROYAL_ROAD_BLOCK_SIZE).unwrap(),
+        "3.30: Royal Road, Crossover and Mutation vs. Either Alone",
+        Variation::Pipelines(pipelines.clone()),
+        OUT_ROYAL_ROAD,
+    )
+    .unwrap();
+    generate_graph_from_variation(
+        &DeceptiveTraps::new(BITSTRING_SIZE, TRAP_SIZE).unwrap(),
+        "3.31: Deceptive Traps, Crossover and Mutation vs. Either Alone",
+        Variation::Pipelines(pipelines),
+        OUT_TRAPS,
+    )
+    .unwrap();
+
+    // What the runtime analysis expects of the (1+1)-EA, spread over the evaluations of a
+    // generation as for the baselines
+    let one_max_theory = Annotation::Curve {
+        fitness: expected_fitness_per_gen(
+            |evaluations| one_max_expected_fitness(BITSTRING_SIZE, evaluations),
+            POPULATION_SIZE as u64,
+            GENERATION_LIMIT as u32,
+        ),
+        label: format!(
+            "(1+1)-EA in theory (e n ln n = {:.0} evaluations)",
+            one_max_expected_runtime(BITSTRING_SIZE)
+        ),
+    };
+    generate_graph_from_variation_with_curves(
+        &OneMax::new(BITSTRING_SIZE),
+        "3.32: OneMax vs. the (1+1)-EA in Theory",
+        Variation::Solvers(vec![Solver::GeneticAlgorithm, Solver::OnePlusOneEa]),
+        vec![one_max_theory],
+        OUT_ONE_MAX,
+    )
+    .unwrap();
+
+    let leading_ones_theory = Annotation::Curve {
+        fitness: expected_fitness_per_gen(
+            |evaluations| leading_ones_expected_fitness(BITSTRING_SIZE, evaluations),
+            POPULATION_SIZE as u64,
+            GENERATION_LIMIT as u32,
+        ),
+        label: format!(
+            "(1+1)-EA in theory (0.86 n² = {:.0} evaluations)",
+            leading_ones_expected_runtime(BITSTRING_SIZE)
+        ),
+    };
+    generate_graph_from_variation_with_curves(
+        &LeadingOnes::new(BITSTRING_SIZE),
+        "3.33: LeadingOnes vs. the (1+1)-EA in Theory",
+        Variation::Solvers(vec![Solver::GeneticAlgorithm, Solver::OnePlusOneEa]),
+        vec![leading_ones_theory],
+        OUT_LEADING_ONES,
+    )
+    .unwrap();
+
+    generate_graph_from_variation(
+        &problem,
+        "3.34: Genetic Algorithm vs. Evolution Strategies",
+        Variation::Solvers(vec![
+            Solver::GeneticAlgorithm,
+            Solver::OnePlusOneEa,
+            Solver::MuPlusLambdaEs,
+        ]),
+        OUT_ES,
+    )
+    .unwrap();
+
+    #[cfg(feature = "tui")]
+    dashboard::stop();
+    println!(
+        "Finished execution in {} seconds!",
+        start_time.elapsed().as_secs()
+    );
+}
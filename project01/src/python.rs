@@ -0,0 +1,222 @@
+// Python bindings of the experiments (with the `python` feature), to pull the best fitness of each
+// generation of the runs straight into a notebook as numpy arrays instead of reading the CSV files
+// of the saved runs back in. See pyproject.toml for how to build them.
+//
+// The parameters are built like the ones of the single run modes, by the names of the fields of
+// `Parameters`: `Parameters(mutation_rate=0.02, solver=Solver.HillClimbing)`. The ensembles are
+// lists of (operator, weight) pairs, the schedules the specs of `parse_schedule`. The runs write
+// their winners, metrics and halls of fame under output/ like the ones of the experiments.
+
+use super::{
+    run_sim_batch, run_sim_from_parms, BatchRecords, CrossoverKind, MutationKind, Parameters,
+    Pipeline, Solver, BATCH_SIZE, GENERATION_LIMIT, OUT_HALL_OF_FAME, OUT_METRICS, STRAND_SIZE,
+};
+use ga_lab::{problem::ClustersOf4, report};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use std::{
+    fs::create_dir_all,
+    sync::{atomic::AtomicBool, Mutex},
+};
+
+#[pyclass(name = "Parameters", module = "project01")]
+#[derive(Clone)]
+struct PyParameters(Parameters);
+
+#[pymethods]
+impl PyParameters {
+    // The default parameters changed by the keyword arguments, named after the changes unless a
+    // name is given
+    #[new]
+    #[pyo3(signature = (name = None, **changes))]
+    fn new(name: Option<String>, changes: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut parms = Parameters::default();
+        let spec = apply_changes(&mut parms, changes)?;
+        parms.parms_name = name.unwrap_or(if spec.is_empty() {
+            "default".to_string()
+        } else {
+            spec
+        });
+        Ok(PyParameters(parms))
+    }
+
+    // Parses the parameters of a single run mode, e.g. "mutation_rate=0.01,solver=HillClimbing"
+    #[staticmethod]
+    fn parse(spec: &str) -> PyResult<Self> {
+        Parameters::parse(spec)
+            .map(PyParameters)
+            .map_err(PyValueError::new_err)
+    }
+
+    // A copy of the parameters changed by the keyword arguments, keeping their name unless a new
+    // one is given
+    #[pyo3(signature = (name = None, **changes))]
+    fn replace(&self, name: Option<String>, changes: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut parms = self.0.clone();
+        apply_changes(&mut parms, changes)?;
+        if let Some(name) = name {
+            parms.parms_name = name;
+        }
+        Ok(PyParameters(parms))
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        &self.0.parms_name
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+// Sets the parameters named by the keys of the changes, returns the changes as a spec of `parse`
+fn apply_changes(parms: &mut Parameters, changes: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+    let mut spec = vec![];
+    for (parm, value) in changes.into_iter().flatten() {
+        let parm = parm.extract::<String>()?;
+        let value = parm_value(&value)?;
+        parms.set(&parm, &value).map_err(PyValueError::new_err)?;
+        spec.push(format!("{}={}", parm, value));
+    }
+    Ok(spec.join(","))
+}
+
+// The text of a value of a parameter in the format of `Parameters::set`
+fn parm_value(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    fn ensemble<K: std::fmt::Debug>(operators: &[(K, f64)]) -> String {
+        operators
+            .iter()
+            .map(|(kind, weight)| format!("{:?}:{}", kind, weight))
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    // A bool before the numbers, which it would pass for
+    if let Ok(flag) = value.extract::<bool>() {
+        return Ok(flag.to_string());
+    }
+    if let Ok(solver) = value.extract::<Solver>() {
+        return Ok(format!("{:?}", solver));
+    }
+    if let Ok(pipeline) = value.extract::<Pipeline>() {
+        return Ok(format!("{:?}", pipeline));
+    }
+    if let Ok(crossovers) = value.extract::<Vec<(CrossoverKind, f64)>>() {
+        return Ok(ensemble(&crossovers));
+    }
+    if let Ok(mutations) = value.extract::<Vec<(MutationKind, f64)>>() {
+        return Ok(ensemble(&mutations));
+    }
+    if let Ok(weights) = value.extract::<Vec<f64>>() {
+        let weights = weights.iter().map(f64::to_string).collect::<Vec<_>>();
+        return Ok(weights.join(":"));
+    }
+    Ok(value.str()?.to_string())
+}
+
+// The problem of the experiments, of the given strand size
+fn problem(strand_size: usize) -> PyResult<ClustersOf4> {
+    if strand_size == 0 || !strand_size.is_multiple_of(4) {
+        return Err(PyValueError::new_err(format!(
+            "the strand size must be a positive multiple of 4, got {}",
+            strand_size
+        )));
+    }
+    Ok(ClustersOf4::new(strand_size))
+}
+
+// Creates the directories the runs write to, which the experiments create on start
+fn create_out_dirs() {
+    for (dir, enabled) in [OUT_METRICS, OUT_HALL_OF_FAME] {
+        if enabled {
+            if let Err(error) = create_dir_all(dir) {
+                report!("Problem creating directory {}: {:?}", dir, error);
+            }
+        }
+    }
+}
+
+// Runs a single simulation from the seed, like `project01 --replay <seed> <parms>` does. Returns
+// the best fitness of each generation.
+#[pyfunction]
+#[pyo3(name = "run_sim_from_parms", signature = (parms, seed, strand_size = STRAND_SIZE))]
+fn py_run_sim_from_parms(
+    py: Python<'_>,
+    parms: PyParameters,
+    seed: u64,
+    strand_size: usize,
+) -> PyResult<Bound<'_, PyArray1<u32>>> {
+    let problem = problem(strand_size)?;
+    create_out_dirs();
+    let data = py.detach(|| {
+        run_sim_from_parms(
+            &problem,
+            &parms.0,
+            None,
+            seed,
+            &Mutex::new(BatchRecords::new()),
+            &AtomicBool::new(false),
+        )
+    });
+    let (_, data) = data
+        .and_then(|data| data.into_iter().next())
+        .ok_or_else(|| PyRuntimeError::new_err("the simulation failed"))?;
+    Ok(data.into_pyarray(py))
+}
+
+// Runs a batch of BATCH_SIZE simulations of each of the parameters on random seeds. Returns two
+// dicts by the names of the parameters: of the best fitness of each generation of each run, and
+// of the wall-times of the runs in seconds.
+#[pyfunction]
+#[pyo3(name = "run_sim_batch", signature = (parms_list, strand_size = STRAND_SIZE))]
+fn py_run_sim_batch(
+    py: Python<'_>,
+    parms_list: Vec<PyParameters>,
+    strand_size: usize,
+) -> PyResult<(Bound<'_, PyDict>, Bound<'_, PyDict>)> {
+    let problem = problem(strand_size)?;
+    let parms_list = parms_list
+        .into_iter()
+        .map(|parms| parms.0)
+        .collect::<Vec<_>>();
+    create_out_dirs();
+    let (runs_list, wall_times, _) = py
+        .detach(|| run_sim_batch(&problem, &parms_list, None, None))
+        .ok_or_else(|| PyRuntimeError::new_err("the batch failed"))?;
+
+    let runs = PyDict::new(py);
+    let times = PyDict::new(py);
+    for ((label, runs_data), wall_times) in runs_list.into_iter().zip(wall_times) {
+        let wall_times = wall_times
+            .iter()
+            .map(|wall_time| wall_time.as_secs_f64())
+            .collect::<Vec<_>>();
+        times.set_item(&label, wall_times.into_pyarray(py))?;
+        let runs_data = runs_data
+            .into_iter()
+            .map(|data| data.into_pyarray(py))
+            .collect::<Vec<_>>();
+        runs.set_item(label, runs_data)?;
+    }
+    Ok((runs, times))
+}
+
+#[pymodule]
+fn project01(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyParameters>()?;
+    m.add_class::<Solver>()?;
+    m.add_class::<Pipeline>()?;
+    m.add_class::<CrossoverKind>()?;
+    m.add_class::<MutationKind>()?;
+    m.add_function(wrap_pyfunction!(py_run_sim_from_parms, m)?)?;
+    m.add_function(wrap_pyfunction!(py_run_sim_batch, m)?)?;
+    m.add("STRAND_SIZE", STRAND_SIZE)?;
+    m.add("BATCH_SIZE", BATCH_SIZE)?;
+    m.add("GENERATION_LIMIT", GENERATION_LIMIT)?;
+    Ok(())
+}
@@ -0,0 +1,310 @@
+// An SQLite store of every run of the batches, so the results of past invocations stay queryable
+// instead of being scattered over graphs. Built with the `results-db` feature, queried with
+// `project01 results list` and `project01 results compare --param <parameter>`.
+
+use super::{Parameters, OUT_RESULTS_DB};
+use ga_lab::batch::Data;
+use genevo::schedule::Schedule;
+use rusqlite::{params, types::Value, Connection};
+use std::{
+    error::Error,
+    fmt::Debug,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+// The columns of the runs table the runs can be compared by
+const PARAMS: &[&str] = &[
+    "strand_size",
+    "population_size",
+    "num_individuals_per_parents",
+    "selection_ratio",
+    "crossover_rate",
+    "mutation_rate",
+    "reinsertion_ratio",
+    "n_elites",
+    "eliminate_duplicates",
+    "immigrant_rate",
+    "stagnation_limit",
+    "mutation_boost",
+    "self_adaptive",
+    "islands",
+    "topology",
+    "local_search_steps",
+    "seed_file",
+    "init_block_size",
+    "init_weights",
+    "init_min_distance",
+    "mutation_schedule",
+    "selection_schedule",
+    "population_schedule",
+    "restart_after",
+    "pipeline",
+    "crossovers",
+    "mutations",
+    "adaptive_operators",
+    "solver",
+];
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS runs (
+        id INTEGER PRIMARY KEY,
+        recorded_at INTEGER NOT NULL, -- unix time
+        variation TEXT NOT NULL,
+        parms_name TEXT NOT NULL,
+        thread_number INTEGER NOT NULL,
+        seed INTEGER NOT NULL, -- replay the run with `project01 --replay <seed> <parms>`
+        strand_size INTEGER NOT NULL,
+        population_size INTEGER NOT NULL,
+        num_individuals_per_parents INTEGER NOT NULL,
+        selection_ratio REAL NOT NULL,
+        crossover_rate REAL NOT NULL,
+        mutation_rate REAL NOT NULL,
+        reinsertion_ratio REAL NOT NULL,
+        n_elites INTEGER NOT NULL,
+        eliminate_duplicates INTEGER NOT NULL,
+        immigrant_rate REAL NOT NULL,
+        stagnation_limit INTEGER NOT NULL,
+        mutation_boost REAL NOT NULL,
+        self_adaptive INTEGER NOT NULL,
+        islands INTEGER NOT NULL,
+        topology TEXT NOT NULL,
+        local_search_steps INTEGER NOT NULL,
+        seed_file TEXT NOT NULL, -- '' for a random initial population
+        init_block_size INTEGER NOT NULL,
+        init_weights TEXT NOT NULL, -- colon separated, '' for uniform
+        init_min_distance INTEGER NOT NULL,
+        mutation_schedule TEXT NOT NULL, -- '' for the fixed parameter
+        selection_schedule TEXT NOT NULL,
+        population_schedule TEXT NOT NULL,
+        restart_after INTEGER NOT NULL, -- 0 for no restarts
+        pipeline TEXT NOT NULL,
+        crossovers TEXT NOT NULL, -- '' for the single point crossover alone
+        mutations TEXT NOT NULL, -- '' for the random value mutation alone
+        adaptive_operators INTEGER NOT NULL,
+        solver TEXT NOT NULL,
+        generations INTEGER NOT NULL,
+        best_fitness INTEGER NOT NULL,
+        converged INTEGER NOT NULL,
+        wall_time REAL NOT NULL -- seconds
+    );
+    CREATE TABLE IF NOT EXISTS generations (
+        run_id INTEGER NOT NULL REFERENCES runs(id),
+        generation INTEGER NOT NULL,
+        best_fitness INTEGER NOT NULL,
+        PRIMARY KEY (run_id, generation)
+    );";
+
+fn open() -> rusqlite::Result<Connection> {
+    let conn = Connection::open(OUT_RESULTS_DB.0)?;
+    // The runs of a batch finish in parallel
+    conn.busy_timeout(Duration::from_secs(30))?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+// A run of a batch: its parameters, the outcome and the best fitness of each generation
+pub struct RunRecord<'a> {
+    pub variation: &'a str,
+    pub parms: &'a Parameters,
+    pub strand_size: usize,
+    pub thread_number: u64,
+    pub seed: u64,
+    pub data: &'a Data,
+    pub optimum: usize,
+    pub wall_time: Duration,
+}
+
+// A schedule as stored in the runs table
+fn schedule_column(schedule: &Option<Schedule>) -> String {
+    schedule
+        .as_ref()
+        .map_or(String::new(), |schedule| format!("{:?}", schedule))
+}
+
+// The operators of an ensemble as they are parsed, see `parse_ensemble`
+fn ensemble_column<K: Debug>(operators: &[(K, f64)]) -> String {
+    operators
+        .iter()
+        .map(|(kind, weight)| format!("{:?}:{}", kind, weight))
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+pub fn record_run(run: &RunRecord) -> rusqlite::Result<()> {
+    let RunRecord {
+        variation,
+        parms,
+        strand_size,
+        thread_number,
+        seed,
+        data,
+        optimum,
+        wall_time,
+    } = *run;
+    let mut conn = open()?;
+    let tx = conn.transaction()?;
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let best_fitness = data.last().copied().unwrap_or_default();
+    tx.execute(
+        "INSERT INTO runs (
+            recorded_at, variation, parms_name, thread_number, seed, strand_size, population_size,
+            num_individuals_per_parents, selection_ratio, crossover_rate, mutation_rate,
+            reinsertion_ratio, n_elites, eliminate_duplicates, immigrant_rate, stagnation_limit, mutation_boost, self_adaptive, islands, topology, local_search_steps,
+            seed_file, init_block_size, init_weights, init_min_distance, mutation_schedule,
+            selection_schedule, population_schedule, restart_after, pipeline, crossovers, mutations,
+            adaptive_operators, solver, generations, best_fitness, converged, wall_time
+        ) VALUES (
+            ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19,
+            ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34, ?35, ?36,
+            ?37, ?38
+        )",
+        params![
+            recorded_at,
+            variation,
+            parms.parms_name,
+            thread_number,
+            seed as i64, // SQLite integers are signed
+            strand_size,
+            parms.population_size,
+            parms.num_individuals_per_parents,
+            parms.selection_ratio,
+            parms.crossover_rate,
+            parms.mutation_rate,
+            parms.reinsertion_ratio,
+            parms.n_elites,
+            parms.eliminate_duplicates,
+            parms.immigrant_rate,
+            parms.stagnation_limit,
+            parms.mutation_boost,
+            parms.self_adaptive,
+            parms.islands,
+            format!("{:?}", parms.topology),
+            parms.local_search_steps,
+            parms.seed_file,
+            parms.init_block_size,
+            parms
+                .init_weights
+                .iter()
+                .map(|w| w.to_string())
+                .collect::<Vec<_>>()
+                .join(":"),
+            parms.init_min_distance,
+            schedule_column(&parms.mutation_schedule),
+            schedule_column(&parms.selection_schedule),
+            schedule_column(&parms.population_schedule),
+            parms.restart_after,
+            format!("{:?}", parms.pipeline),
+            ensemble_column(&parms.crossovers),
+            ensemble_column(&parms.mutations),
+            parms.adaptive_operators,
+            format!("{:?}", parms.solver),
+            data.len(),
+            best_fitness,
+            best_fitness as usize >= optimum,
+            wall_time.as_secs_f64(),
+        ],
+    )?;
+    let run_id = tx.last_insert_rowid();
+    {
+        let mut insert = tx.prepare(
+            "INSERT INTO generations (run_id, generation, best_fitness) VALUES (?1, ?2, ?3)",
+        )?;
+        for (gen, fitness) in data.iter().enumerate() {
+            insert.execute(params![run_id, gen + 1, fitness])?;
+        }
+    }
+    tx.commit()
+}
+
+// Runs a `results` subcommand with the given arguments
+pub fn run_cli(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match args[..] {
+        ["list"] => list(&open()?),
+        ["compare", "--param", param] => compare(&open()?, param),
+        _ => {
+            println!("usage: project01 results list");
+            println!(
+                "       project01 results compare --param <{}>",
+                PARAMS.join("|")
+            );
+            Ok(())
+        }
+    }
+}
+
+// Prints a line per recorded run
+fn list(conn: &Connection) -> Result<(), Box<dyn Error>> {
+    let mut query = conn.prepare(
+        "SELECT id, datetime(recorded_at, 'unixepoch'), variation, parms_name, thread_number,
+            generations, best_fitness, converged, wall_time, seed
+        FROM runs ORDER BY id",
+    )?;
+    let mut rows = query.query([])?;
+    while let Some(row) = rows.next()? {
+        println!(
+            "#{} {} [{}] [thread #{}] {} parms: fitness {} after {} generations ({}), {:.2}s, seed {}",
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, i64>(4)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, i64>(6)?,
+            row.get::<_, i64>(5)?,
+            if row.get::<_, bool>(7)? {
+                "converged"
+            } else {
+                "not converged"
+            },
+            row.get::<_, f64>(8)?,
+            row.get::<_, i64>(9)? as u64,
+        );
+    }
+    Ok(())
+}
+
+// Prints a Markdown table of the runs grouped by the value of a parameter
+fn compare(conn: &Connection, param: &str) -> Result<(), Box<dyn Error>> {
+    // The parameter ends up in the query, so it has to be one of the known columns
+    if !PARAMS.contains(&param) {
+        return Err(format!(
+            "unknown parameter {}, expected one of {}",
+            param,
+            PARAMS.join(", ")
+        )
+        .into());
+    }
+
+    let mut query = conn.prepare(&format!(
+        "SELECT {0}, COUNT(*), AVG(converged), AVG(CASE WHEN converged THEN generations END),
+            AVG(wall_time)
+        FROM runs GROUP BY {0} ORDER BY {0}",
+        param
+    ))?;
+    let mut rows = query.query([])?;
+    println!(
+        "| {} | runs | success rate | mean gens | mean wall-time |",
+        param
+    );
+    println!("|---|---:|---:|---:|---:|");
+    while let Some(row) = rows.next()? {
+        let value = match row.get::<_, Value>(0)? {
+            Value::Integer(i) => i.to_string(),
+            Value::Real(r) => r.to_string(),
+            Value::Text(t) => t,
+            _ => "-".to_string(),
+        };
+        println!(
+            "| {} | {} | {:.0}% | {} | {:.2}s |",
+            value,
+            row.get::<_, i64>(1)?,
+            row.get::<_, f64>(2)? * 100.0,
+            row.get::<_, Option<f64>>(3)?
+                .map_or("-".to_string(), |gens| format!("{:.1}", gens)),
+            row.get::<_, f64>(4)?,
+        );
+    }
+    Ok(())
+}
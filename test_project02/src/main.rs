@@ -0,0 +1,114 @@
+use genevo::{
+    operator::prelude::*, population::ValueEncodedGenomeBuilder, prelude::*,
+    termination::combinator::Or,
+};
+
+// Simulation parameters
+const STRAND_SIZE: usize = 16;
+const POPULATION_SIZE: usize = 100;
+const GENERATION_LIMIT: u64 = 500;
+const NUM_INDIVIDUALS_PER_PARENTS: usize = 2;
+const SELECTION_RATIO: f64 = 0.7;
+const MUTATION_RATE: f64 = 0.05;
+const REINSERTION_RATIO: f64 = 0.7;
+
+// The printable ASCII characters, the max value of the range is exclusive
+const MIN_ALLELE: u8 = b' ';
+const MAX_ALLELE: u8 = 0x7F;
+
+// The letter to evolve
+const TARGET: u8 = b'T';
+
+// The genotype
+type Genome = Vec<u8>;
+
+// How do the genes of the genotype show up in the phenotype
+fn render(genome: &Genome) -> String {
+    String::from_utf8_lossy(genome).into_owned()
+}
+
+// The "T" counting fitness function
+#[derive(Clone, Debug)]
+struct FitnessCalc;
+
+impl FitnessFunction<Genome, usize> for FitnessCalc {
+    fn fitness_of(&self, genome: &Genome) -> usize {
+        genome.iter().filter(|c| **c == TARGET).count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        STRAND_SIZE
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+fn main() {
+    let initial_population: Population<Genome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(
+            STRAND_SIZE,
+            MIN_ALLELE,
+            MAX_ALLELE,
+        ))
+        .of_size(POPULATION_SIZE)
+        .uniform_at_random();
+
+    let mut sim = simulate(
+        genetic_algorithm()
+            .with_evaluation(FitnessCalc)
+            .with_selection(MaximizeSelector::new(
+                SELECTION_RATIO,
+                NUM_INDIVIDUALS_PER_PARENTS,
+            ))
+            .with_crossover(SinglePointCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(
+                MUTATION_RATE,
+                MIN_ALLELE,
+                MAX_ALLELE,
+            ))
+            .with_reinsertion(ElitistReinserter::new(FitnessCalc, true, REINSERTION_RATIO))
+            .with_initial_population(initial_population)
+            .build(),
+    )
+    .until(Or::new(
+        FitnessLimit::new(FitnessCalc.highest_possible_fitness()),
+        GenerationLimit::new(GENERATION_LIMIT),
+    ))
+    .build();
+
+    loop {
+        match sim.step() {
+            Ok(SimResult::Intermediate(step)) => {
+                let best = step.result.best_solution.solution;
+                println!(
+                    "Generation {:>4}: {} (fitness {})",
+                    step.iteration,
+                    render(&best.genome),
+                    best.fitness
+                );
+            }
+            Ok(SimResult::Final(step, _, _, stop_reason)) => {
+                let best = step.result.best_solution;
+                println!("{}", stop_reason);
+                println!(
+                    "Final generation {:>4}: best found in generation {}: {} (fitness {})",
+                    step.iteration,
+                    best.generation,
+                    render(&best.solution.genome),
+                    best.solution.fitness
+                );
+                break;
+            }
+            Err(error) => {
+                println!("{}", error);
+                break;
+            }
+        }
+    }
+}
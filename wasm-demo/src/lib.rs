@@ -0,0 +1,246 @@
+//! Evolves the strands of the clusters-of-4 problem in the browser and plots the best and the
+//! average fitness of each generation live on a canvas. The page (www/index.html) steps the GA a
+//! few generations per animation frame, so the browser stays responsive while it runs.
+
+use ga_lab::problem::{ClustersOf4, Problem, RandomStrandBuilder};
+use genevo::{
+    ga::State, operator::prelude::RandomValueMutator, prelude::*, random::Seed,
+    recombination::discrete::SinglePointCrossBreeder, reinsertion::elitist::ElitistReinserter,
+    selection::truncation::MaximizeSelector,
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+// The parameters of the GA the page does not change, the defaults of project01
+const NUM_INDIVIDUALS_PER_PARENTS: usize = 2;
+const SELECTION_RATIO: f64 = 0.5;
+const REINSERTION_RATIO: f64 = 0.5;
+const GENERATION_LIMIT: u64 = 16_384;
+
+// The look of the plot
+const MARGIN: f64 = 48.0; // left of and below the axes, for their labels
+const FONT: &str = "12px sans-serif";
+const AXIS_COLOR: &str = "#444";
+const BEST_COLOR: &str = "#d62728";
+const AVERAGE_COLOR: &str = "#1f77b4";
+
+// The best and the average fitness of a generation and its best strand as a phenotype
+struct Generation {
+    best_fitness: usize,
+    average_fitness: usize,
+    best_strand: String,
+}
+
+// Steps the simulation by a generation, tells whether it was the last one
+type Step = Box<dyn FnMut() -> Result<(Generation, bool), String>>;
+
+// A run of the GA on the clusters-of-4 problem and the fitness of its generations so far
+#[wasm_bindgen]
+pub struct Demo {
+    step: Step,
+    optimum: usize,
+    best: Vec<usize>,
+    average: Vec<usize>,
+    best_strand: String,
+    finished: bool,
+}
+
+#[wasm_bindgen]
+impl Demo {
+    // Builds a run on strands of the given size from the seed, the same seed evolves the same
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        strand_size: usize,
+        population_size: usize,
+        mutation_rate: f64,
+        seed: u32,
+    ) -> Result<Demo, JsError> {
+        if strand_size == 0 || !strand_size.is_multiple_of(4) {
+            return Err(JsError::new(
+                "the strand size must be a positive multiple of 4",
+            ));
+        }
+        if population_size < NUM_INDIVIDUALS_PER_PARENTS {
+            return Err(JsError::new("the population must hold at least 2 strands"));
+        }
+        if !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(JsError::new("the mutation rate must be between 0 and 1"));
+        }
+
+        let problem = ClustersOf4::new(strand_size);
+        let optimum = problem.fitness_bounds().1;
+        let (min_allele, max_allele) = problem.allele_bounds();
+        let alg = genetic_algorithm()
+            .with_evaluation(problem.fitness_function())
+            .with_selection(MaximizeSelector::new(
+                SELECTION_RATIO,
+                NUM_INDIVIDUALS_PER_PARENTS,
+            ))
+            .with_crossover(SinglePointCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(
+                mutation_rate,
+                min_allele,
+                max_allele,
+            ))
+            .with_reinsertion(ElitistReinserter::new(
+                problem.fitness_function(),
+                true,
+                REINSERTION_RATIO,
+            ))
+            .with_initial_population(
+                build_population()
+                    .with_genome_builder(RandomStrandBuilder(problem.clone()))
+                    .of_size(population_size)
+                    .using_seed(prng_seed(seed)),
+            )
+            .build();
+        let mut sim = simulate(alg)
+            .until(or(
+                FitnessLimit::new(optimum),
+                GenerationLimit::new(GENERATION_LIMIT),
+            ))
+            .build_with_seed(prng_seed(seed));
+
+        let step = move || match sim.step() {
+            Ok(SimResult::Intermediate(state)) => Ok((generation(&problem, &state.result), false)),
+            Ok(SimResult::Final(state, ..)) => Ok((generation(&problem, &state.result), true)),
+            Err(error) => Err(error.to_string()),
+        };
+        Ok(Demo {
+            step: Box::new(step),
+            optimum,
+            best: vec![],
+            average: vec![],
+            best_strand: String::new(),
+            finished: false,
+        })
+    }
+
+    // Runs up to the given number of generations, tells whether the run goes on
+    pub fn step(&mut self, generations: u32) -> Result<bool, JsError> {
+        for _ in 0..generations {
+            if self.finished {
+                break;
+            }
+            let (generation, last) = (self.step)().map_err(|error| JsError::new(&error))?;
+            self.best.push(generation.best_fitness);
+            self.average.push(generation.average_fitness);
+            self.best_strand = generation.best_strand;
+            self.finished = last;
+        }
+        Ok(!self.finished)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn generation(&self) -> usize {
+        self.best.len()
+    }
+
+    #[wasm_bindgen(getter = bestFitness)]
+    pub fn best_fitness(&self) -> usize {
+        self.best.last().copied().unwrap_or_default()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn optimum(&self) -> usize {
+        self.optimum
+    }
+
+    #[wasm_bindgen(getter = bestStrand)]
+    pub fn best_strand(&self) -> String {
+        self.best_strand.clone()
+    }
+
+    // Plots the best and the average fitness of the generations so far on the canvas
+    pub fn draw(&self, canvas: &HtmlCanvasElement) -> Result<(), JsValue> {
+        let context = canvas
+            .get_context("2d")?
+            .ok_or_else(|| JsValue::from_str("the canvas has no 2d context"))?
+            .dyn_into::<CanvasRenderingContext2d>()?;
+        let (width, height) = (canvas.width() as f64, canvas.height() as f64);
+        let (left, top, right, bottom) =
+            (MARGIN, MARGIN / 2.0, width - MARGIN / 2.0, height - MARGIN);
+        context.clear_rect(0.0, 0.0, width, height);
+
+        // The axes, the fitness from 0 to the optimum over the generations so far
+        context.set_stroke_style_str(AXIS_COLOR);
+        context.set_fill_style_str(AXIS_COLOR);
+        context.set_line_width(1.0);
+        context.set_font(FONT);
+        context.begin_path();
+        context.move_to(left, top);
+        context.line_to(left, bottom);
+        context.line_to(right, bottom);
+        context.stroke();
+        context.set_text_align("right");
+        context.fill_text(&self.optimum.to_string(), left - 6.0, top + 4.0)?;
+        context.fill_text("0", left - 6.0, bottom + 4.0)?;
+        context.fill_text(
+            &format!("{} generations", self.generation()),
+            right,
+            bottom + 20.0,
+        )?;
+
+        let x = |generation: usize| {
+            left + (right - left) * generation as f64 / (self.best.len().max(2) - 1) as f64
+        };
+        let y = |fitness: usize| bottom - (bottom - top) * fitness as f64 / self.optimum as f64;
+        for (curve, color) in [(&self.average, AVERAGE_COLOR), (&self.best, BEST_COLOR)] {
+            context.set_stroke_style_str(color);
+            context.set_line_width(2.0);
+            context.begin_path();
+            for (generation, fitness) in curve.iter().enumerate() {
+                match generation {
+                    0 => context.move_to(x(generation), y(*fitness)),
+                    _ => context.line_to(x(generation), y(*fitness)),
+                }
+            }
+            context.stroke();
+        }
+
+        // The legend, in the top left corner of the plot
+        context.set_text_align("left");
+        for (row, (label, color)) in [("best", BEST_COLOR), ("average", AVERAGE_COLOR)]
+            .into_iter()
+            .enumerate()
+        {
+            context.set_fill_style_str(color);
+            context.fill_text(label, left + 8.0, top + 16.0 * (row + 1) as f64)?;
+        }
+        Ok(())
+    }
+}
+
+// Expands the seed of a run to the seed of genevo's random number generator
+fn prng_seed(seed: u32) -> Seed {
+    let mut prng_seed = Seed::default();
+    prng_seed[..4].copy_from_slice(&seed.to_le_bytes());
+    prng_seed
+}
+
+fn generation<P: Problem>(problem: &P, state: &State<Vec<P::Allele>, usize>) -> Generation {
+    Generation {
+        best_fitness: state.best_solution.solution.fitness,
+        average_fitness: *state.evaluated_population.average_fitness(),
+        best_strand: problem.render(&state.best_solution.solution.genome),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_evolves_the_same() {
+        let run = |seed| {
+            let mut demo = Demo::new(16, 32, 0.05, seed).unwrap();
+            while demo.step(10).unwrap() {}
+            (demo.best.clone(), demo.best_strand())
+        };
+
+        let (best, best_strand) = run(7);
+        assert_eq!(*best.last().unwrap(), 4);
+        assert!(best.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!((best, best_strand), run(7));
+    }
+}